@@ -1,6 +1,6 @@
-use crate::distribution::{Binomial, Discrete, DiscreteCDF};
+use crate::distribution::{Binomial, Discrete, DiscreteCDF, Fit};
 use crate::statistics::*;
-use crate::Result;
+use crate::{Result, StatsError};
 use rand::Rng;
 
 /// Implements the
@@ -100,6 +100,19 @@ impl DiscreteCDF<u64, f64> for Bernoulli {
     fn cdf(&self, x: u64) -> f64 {
         self.b.cdf(x)
     }
+    /// Calculates the survival function for the
+    /// bernoulli distribution at `x`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < 0 { 1 }
+    /// else if x >= 1 { 0 }
+    /// else { p }
+    /// ```
+    fn sf(&self, x: u64) -> f64 {
+        self.b.sf(x)
+    }
 }
 
 impl Min<u64> for Bernoulli {
@@ -238,3 +251,23 @@ impl Discrete<u64, f64> for Bernoulli {
         self.b.ln_pmf(x)
     }
 }
+
+impl Fit<u64> for Bernoulli {
+    /// Fits a bernoulli distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p = (1 / n) * Σ x_i
+    /// ```
+    fn fit(data: &[u64]) -> Result<Bernoulli> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x > 1) {
+            return Err(StatsError::ArgLte("data", 1.0));
+        }
+        let sum: u64 = data.iter().sum();
+        Bernoulli::new(sum as f64 / data.len() as f64)
+    }
+}
@@ -1,4 +1,5 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::function::{beta, gamma};
 use crate::is_zero;
 use crate::statistics::*;
@@ -128,6 +129,145 @@ impl ContinuousCDF<f64, f64> for Beta {
             beta::beta_reg(self.shape_a, self.shape_b, x)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the beta
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I^-1_x(α, β)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I^-1` is the inverse of the
+    /// regularized lower incomplete beta function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            x
+        } else {
+            beta::inv_beta_reg(self.shape_a, self.shape_b, x)
+        }
+    }
+    /// Calculates the survival function for the beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(1 - x)(β, α)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I_x` is the regularized
+    /// lower incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else if x >= 1.0 {
+            0.0
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            1.0 - x
+        } else {
+            beta::beta_reg(self.shape_b, self.shape_a, 1.0 - x)
+        }
+    }
+    /// Calculates the inverse survival function for the beta
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - I^-1_x(β, α)
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I^-1` is the inverse of the
+    /// regularized lower incomplete beta function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.shape_a.is_infinite() {
+            1.0
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            1.0 - x
+        } else if beta::above_mean(self.shape_b, self.shape_a, x) {
+            // the quantile lies below the mean, where it is found directly
+            beta::inv_beta_reg(self.shape_a, self.shape_b, 1.0 - x)
+        } else {
+            1.0 - beta::inv_beta_reg(self.shape_b, self.shape_a, x)
+        }
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// beta distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(I(x, α, β))
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I` is the regularized
+    /// incomplete beta function, evaluated in log space so that it stays
+    /// finite where the cdf underflows
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            f64::NEG_INFINITY
+        } else if x >= 1.0 {
+            0.0
+        } else if self.shape_a.is_infinite() {
+            f64::NEG_INFINITY
+        } else if self.shape_b.is_infinite() {
+            0.0
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            x.ln()
+        } else {
+            beta::ln_beta_reg(self.shape_a, self.shape_b, x)
+        }
+    }
+    /// Calculates the log of the survival function for the beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(I(1 - x, β, α))
+    /// ```
+    ///
+    /// where `α` is shapeA, `β` is shapeB, and `I` is the regularized
+    /// incomplete beta function, evaluated in log space so that it stays
+    /// finite where the survival function underflows
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            0.0
+        } else if x >= 1.0 {
+            f64::NEG_INFINITY
+        } else if self.shape_a.is_infinite() {
+            0.0
+        } else if self.shape_b.is_infinite() {
+            f64::NEG_INFINITY
+        } else if ulps_eq!(self.shape_a, 1.0) && ulps_eq!(self.shape_b, 1.0) {
+            (-x).ln_1p()
+        } else {
+            beta::ln_beta_reg(self.shape_b, self.shape_a, 1.0 - x)
+        }
+    }
 }
 
 impl Min<f64> for Beta {
@@ -368,6 +508,51 @@ impl Continuous<f64, f64> for Beta {
     }
 }
 
+impl Fit<f64> for Beta {
+    /// Fits a beta distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ψ(α) - ψ(α + β) = (1 / n) * Σ ln(x_i)
+    /// ψ(β) - ψ(α + β) = (1 / n) * Σ ln(1 - x_i)
+    /// ```
+    ///
+    /// where `ψ` is the digamma function. Expressing `α` and `β` through the
+    /// inverse digamma function of `ψ(α + β)` reduces this to a single
+    /// equation in `α + β`, which is solved by bisection
+    fn fit(data: &[f64]) -> Result<Beta> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0 || x >= 1.0) {
+            return Err(StatsError::ArgIntervalExcl("data", 0.0, 1.0));
+        }
+        if data.iter().all(|&x| x == data[0]) {
+            return Err(StatsError::BadParams);
+        }
+        let mean_ln_a = data.iter().map(|x| x.ln()).mean();
+        let mean_ln_b = data.iter().map(|x| (-x).ln_1p()).mean();
+        let shapes = |sum: f64| {
+            let psi = gamma::digamma(sum);
+            (
+                gamma::inv_digamma(psi + mean_ln_a),
+                gamma::inv_digamma(psi + mean_ln_b),
+            )
+        };
+        let sum = solve_decreasing(
+            |sum| {
+                let (a, b) = shapes(sum);
+                a + b - sum
+            },
+            1.0,
+        )
+        .ok_or(StatsError::ComputationFailedToConverge)?;
+        let (a, b) = shapes(sum);
+        Beta::new(a, b)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(all(test, feature = "nightly"))]
 mod tests {
@@ -611,4 +796,79 @@ mod tests {
         test::check_continuous_distribution(&try_create((1.2, 3.4)), 0.0, 1.0);
         test::check_continuous_distribution(&try_create((4.5, 6.7)), 0.0, 1.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let f = |arg: f64| move |x: Beta| x.inverse_cdf(arg);
+        let test = [
+            ((2.0, 5.0), 0.05, 0.062849891708354381852),
+            ((2.0, 5.0), 0.5, 0.26444998329565996232),
+            ((2.0, 5.0), 0.95, 0.58180340925202583141),
+            ((0.5, 0.5), 0.25, 0.1464466094067262378),
+            ((1.0, 1.0), 0.3, 0.3),
+        ];
+        for &(arg, x, res) in test.iter() {
+            test_case_special(arg, res, 1e-14, f(x));
+        }
+        test_case((2.0, 5.0), 0.0, f(0.0));
+        test_case((2.0, 5.0), 1.0, f(1.0));
+        test_case_special((2.0, 30.0), 4.6373889576016827257e-52, 1e-64, f(1e-100));
+        test_case_special((2.0, 30.0), 4.6373889576016827257e-152, 1e-164, f(1e-300));
+        test_case_special((5.0, 0.3), 1.5663228424658330402e-40, 1e-52, f(1e-200));
+        test_case_special((0.1, 0.1), 8.8692806555502438777e-28, 1e-40, f(1e-3));
+        test_case((0.5, 0.5), 0.0, f(1e-300));
+        test_case_special((0.01, 5.0), 4.0560026574505144103e-17, 1e-28, f(0.7));
+    }
+
+    #[test]
+    fn test_sf() {
+        let f = |arg: f64| move |x: Beta| x.sf(arg);
+        test_case((2.0, 5.0), 1.0, f(0.0));
+        test_case_special((2.0, 5.0), 5.95e-10, 1e-23, f(0.99));
+        test_case_special((1.0, 1.0), 0.75, 1e-15, f(0.25));
+        test_case((2.0, 5.0), 0.0, f(1.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let f = |arg: f64| move |x: Beta| x.inverse_sf(arg);
+        test_case_special((2.0, 5.0), 0.99300355133656779186, 1e-14, f(1e-10));
+        test_case_special((2.0, 5.0), 0.062849891708354381852, 1e-14, f(0.95));
+        test_case_special((2.0, 30.0), 0.99958603806266776191, 1e-15, f(1e-100));
+        test_case_special((0.01, 5.0), 9.8922404241125255946e-32, 1e-43, f(0.5));
+        test_case((2.0, 5.0), 1.0, f(0.0));
+        test_case((2.0, 5.0), 0.0, f(1.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let f = |arg: f64| move |x: Beta| x.ln_cdf(arg);
+        test_case((2.0, 5.0), f64::NEG_INFINITY, f(0.0));
+        test_case_special((2.0, 5.0), -918.32598699651606358, 1e-11, f(1e-200));
+        test_case_special((2.0, 5.0), -0.54502894510748895078, 1e-14, f(0.3));
+        test_case_special((5.0, 2.0), -21.242459710382918344, 1e-13, f(0.01));
+        test_case_special((1.0, 1.0), -1.3862943611198906188, 1e-15, f(0.25));
+        test_case((2.0, 5.0), 0.0, f(1.0));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let f = |arg: f64| move |x: Beta| x.ln_sf(arg);
+        test_case((2.0, 5.0), 0.0, f(0.0));
+        test_case_special((2.0, 5.0), -21.242459710382918344, 1e-13, f(0.99));
+        test_case_special((5.0, 2.0), -0.54502894510748895078, 1e-14, f(0.7));
+        test_case_special((1.0, 1.0), -0.28768207245178092744, 1e-15, f(0.25));
+        test_case((2.0, 5.0), f64::NEG_INFINITY, f(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let data = [0.12, 0.35, 0.5, 0.27, 0.81, 0.44, 0.63, 0.2];
+        let n = Beta::fit(&data).unwrap();
+        assert_abs_diff_eq!(n.shape_a(), 1.96210286647722687363215533547, epsilon = 1e-12);
+        assert_abs_diff_eq!(n.shape_b(), 2.71723916697274845489618095873, epsilon = 1e-12);
+        assert!(Beta::fit(&[]).is_err());
+        assert!(Beta::fit(&[0.5, 1.0]).is_err());
+        assert!(Beta::fit(&[0.5, 0.5]).is_err());
+    }
 }
@@ -85,6 +85,44 @@ impl Binomial {
     pub fn n(&self) -> u64 {
         self.n
     }
+
+    /// Fits a binomial distribution with a known number of trials `n` to
+    /// `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p = Σ x_i / (m * n)
+    /// ```
+    ///
+    /// where `m` is the number of samples. The number of trials is not
+    /// estimated since it cannot be recovered from the counts alone, so
+    /// `Binomial` does not implement `Fit`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if any sample is greater than
+    /// `n`, or if `n` is `0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Binomial;
+    ///
+    /// let n = Binomial::fit_with_trials(10, &[2, 5, 3, 6]).unwrap();
+    /// assert_eq!(n.p(), 0.4);
+    /// assert_eq!(n.n(), 10);
+    /// ```
+    pub fn fit_with_trials(n: u64, data: &[u64]) -> Result<Binomial> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x > n) {
+            return Err(StatsError::ArgLte("data", n as f64));
+        }
+        let sum: u64 = data.iter().sum();
+        Binomial::new(sum as f64 / (data.len() as f64 * n as f64), n)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Binomial {
@@ -119,6 +157,64 @@ impl DiscreteCDF<u64, f64> for Binomial {
             beta::beta_reg((self.n - k) as f64, k as f64 + 1.0, 1.0 - self.p)
         }
     }
+    /// Calculates the survival function for the
+    /// binomial distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(p)(x + 1, n - x)
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function
+    fn sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            0.0
+        } else {
+            let k = x;
+            beta::beta_reg(k as f64 + 1.0, (self.n - k) as f64, self.p)
+        }
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// binomial distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(I_(1 - p)(n - x, 1 + x))
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function,
+    /// evaluated in log space so that it stays finite where the cdf
+    /// underflows
+    fn ln_cdf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            0.0
+        } else {
+            let k = x;
+            beta::ln_beta_reg((self.n - k) as f64, k as f64 + 1.0, 1.0 - self.p)
+        }
+    }
+    /// Calculates the log of the survival function for the binomial
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(I_(p)(x + 1, n - x))
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function,
+    /// evaluated in log space so that it stays finite where the survival
+    /// function underflows
+    fn ln_sf(&self, x: u64) -> f64 {
+        if x >= self.n {
+            f64::NEG_INFINITY
+        } else {
+            let k = x;
+            beta::ln_beta_reg(k as f64 + 1.0, (self.n - k) as f64, self.p)
+        }
+    }
 }
 
 impl Min<u64> for Binomial {
@@ -515,4 +611,54 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.3, 5), 5);
         test::check_discrete_distribution(&try_create(0.7, 10), 10);
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Binomial| x.sf(arg);
+        test_case(0.0, 1, 0.0, sf(0));
+        test_case(1.0, 1, 1.0, sf(0));
+        test_almost(0.3, 3, 0.657, 1e-14, sf(0));
+        test_almost(0.5, 10, 0.623046875, 1e-14, sf(4));
+        test_almost(0.5, 100, 1.6610244897268264416e-18, 1e-30, sf(90));
+        test_case(0.5, 100, 0.0, sf(100));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Binomial| x.inverse_sf(arg);
+        test_case(0.5, 10, 5, inverse_sf(0.5));
+        test_case(0.5, 10, 4, inverse_sf(0.623046875));
+        test_case(0.5, 100, 90, inverse_sf(1e-17));
+        test_case(0.5, 100, 100, inverse_sf(0.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: u64| move |x: Binomial| x.ln_cdf(arg);
+        test_almost(0.5, 2000, -1386.2943611198906188, 1e-10, ln_cdf(0));
+        test_almost(0.5, 10, -0.9756344361346222329, 1e-14, ln_cdf(4));
+        test_almost(0.3, 3, -0.24334625863172918633, 1e-14, ln_cdf(1));
+        test_case(0.3, 3, 0.0, ln_cdf(3));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: u64| move |x: Binomial| x.ln_sf(arg);
+        test_almost(0.5, 2000, -1378.6929587853068854, 1e-10, ln_sf(1998));
+        test_almost(0.5, 10, -0.47313352225466321351, 1e-14, ln_sf(4));
+        test_almost(0.3, 3, -1.5324768712979721144, 1e-14, ln_sf(1));
+        test_case(0.3, 3, f64::NEG_INFINITY, ln_sf(3));
+    }
+
+    #[test]
+    fn test_fit_with_trials() {
+        let n = Binomial::fit_with_trials(10, &[2, 5, 3, 6]).unwrap();
+        assert_eq!(n.p(), 0.4);
+        assert_eq!(n.n(), 10);
+        let n = Binomial::fit_with_trials(3, &[0, 0, 0]).unwrap();
+        assert_eq!(n.p(), 0.0);
+        assert!(Binomial::fit_with_trials(10, &[]).is_err());
+        assert!(Binomial::fit_with_trials(3, &[1, 4]).is_err());
+        assert!(Binomial::fit_with_trials(0, &[0]).is_err());
+    }
 }
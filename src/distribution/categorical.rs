@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{Discrete, DiscreteCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -314,12 +314,28 @@ fn test_binary_index() {
     assert_eq!(5, binary_index(&arr, 10.1));
 }
 
+impl Fit<u64> for Categorical {
+    /// Fits a categorical distribution to `data` by maximum likelihood, i.e.
+    /// to the relative frequencies of the categories `0..=max_i x_i`
+    fn fit(data: &[u64]) -> Result<Categorical> {
+        let max = match data.iter().max() {
+            Some(&max) => max as usize,
+            None => return Err(StatsError::ArgGte("data.len()", 1.0)),
+        };
+        let mut counts = vec![0.0; max + 1];
+        for &x in data {
+            counts[x as usize] += 1.0;
+        }
+        Categorical::new(&counts)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{Categorical, Discrete, DiscreteCDF};
+    use crate::distribution::{Categorical, Discrete, DiscreteCDF, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -491,4 +507,14 @@ mod tests {
         test::check_discrete_distribution(&try_create(&[1.0, 2.0, 3.0, 4.0]), 4);
         test::check_discrete_distribution(&try_create(&[0.0, 1.0, 2.0, 3.0, 4.0]), 5);
     }
+
+    #[test]
+    fn test_fit() {
+        let n = Categorical::fit(&[0, 1, 1, 2, 2, 2]).unwrap();
+        assert_almost_eq!(n.pmf(0), 1.0 / 6.0, 1e-15);
+        assert_almost_eq!(n.pmf(1), 1.0 / 3.0, 1e-15);
+        assert_almost_eq!(n.pmf(2), 0.5, 1e-15);
+        assert_eq!(n.pmf(3), 0.0);
+        assert!(Categorical::fit(&[]).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -99,6 +99,69 @@ impl ContinuousCDF<f64, f64> for Cauchy {
     fn cdf(&self, x: f64) -> f64 {
         (1.0 / f64::consts::PI) * ((x - self.location) / self.scale).atan() + 0.5
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0 + γ * tan(π * (x - 1 / 2))
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if x == 0.0 {
+            f64::NEG_INFINITY
+        } else if x == 1.0 {
+            f64::INFINITY
+        } else {
+            self.location + self.scale * (f64::consts::PI * (x - 0.5)).tan()
+        }
+    }
+    /// Calculates the survival function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / π) * arctan2(γ, x - x_0)
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        (1.0 / f64::consts::PI) * self.scale.atan2(x - self.location)
+    }
+    /// Calculates the inverse survival function for the
+    /// cauchy distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_0 + γ / tan(π * x)
+    /// ```
+    ///
+    /// where `x_0` is the location and `γ` is the scale
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if x == 0.0 {
+            f64::INFINITY
+        } else if x == 1.0 {
+            f64::NEG_INFINITY
+        } else {
+            self.location + self.scale / (f64::consts::PI * x).tan()
+        }
+    }
 }
 
 impl Min<f64> for Cauchy {
@@ -209,11 +272,68 @@ impl Continuous<f64, f64> for Cauchy {
     }
 }
 
+impl Fit<f64> for Cauchy {
+    /// Fits a cauchy distribution to `data` by maximum likelihood
+    ///
+    /// The location and scale are estimated with the EM algorithm for a
+    /// student's t-distribution with one degree of freedom, which iterates
+    ///
+    /// ```ignore
+    /// w_i = 2 / (1 + ((x_i - x_0) / γ)^2)
+    /// x_0 = Σ w_i * x_i / Σ w_i
+    /// γ^2 = (1 / n) * Σ w_i * (x_i - x_0)^2
+    /// ```
+    ///
+    /// starting from the median and half the interquartile range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the interquartile range is
+    /// zero, or if the iteration does not converge
+    fn fit(data: &[f64]) -> Result<Cauchy> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        let eps = 1e-12;
+        let n = data.len() as f64;
+        let mut order = Data::new(data.to_vec());
+        let mut location = order.median();
+        let mut scale = 0.5 * order.interquartile_range();
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let mut weights = vec![0.0; data.len()];
+        for _ in 0..10_000 {
+            for (w, x) in weights.iter_mut().zip(data) {
+                let k = (x - location) / scale;
+                *w = 2.0 / (1.0 + k * k);
+            }
+            let w_sum: f64 = weights.iter().sum();
+            let next_location = weights.iter().zip(data).map(|(w, x)| w * x).sum::<f64>() / w_sum;
+            let next_scale = (weights
+                .iter()
+                .zip(data)
+                .map(|(w, x)| w * (x - next_location) * (x - next_location))
+                .sum::<f64>()
+                / n)
+                .sqrt();
+            let converged = (next_location - location).abs() <= eps * next_scale
+                && (next_scale - scale).abs() <= eps * next_scale;
+            location = next_location;
+            scale = next_scale;
+            if converged {
+                return Cauchy::new(location, scale);
+            }
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Cauchy};
+    use crate::distribution::{ContinuousCDF, Continuous, Cauchy, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -415,4 +535,44 @@ mod tests {
         test::check_continuous_distribution(&try_create(-1.2, 3.4), -1500.0, 1500.0);
         test::check_continuous_distribution(&try_create(-4.5, 6.7), -5000.0, 5000.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Cauchy| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -1.0, 1e-15, inverse_cdf(0.25));
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.5));
+        test_almost(5.0, 2.0, 7.0, 1e-14, inverse_cdf(0.75));
+        test_almost(5.0, 2.0, 5.0 + 2.0 * 31.820515953773958039, 1e-11, inverse_cdf(0.99));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Cauchy| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, 0.75, 1e-15, sf(-1.0));
+        test_case(0.0, 1.0, 0.5, sf(0.0));
+        test_almost(0.0, 1.0, 3.1830988618379067154e-11, 1e-25, sf(1e10));
+        test_case(0.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Cauchy| x.inverse_sf(arg);
+        test_case(0.0, 1.0, f64::INFINITY, inverse_sf(0.0));
+        test_almost(0.0, 1.0, 1e10, 1e-3, inverse_sf(3.1830988618379067154e-11));
+        test_almost(5.0, 2.0, 3.0, 1e-14, inverse_sf(0.75));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let data = [-3.1, -0.4, 0.2, 0.5, 0.9, 1.1, 1.3, 2.0, 2.4, 9.5];
+        let n = Cauchy::fit(&data).unwrap();
+        assert_almost_eq!(n.location(), 0.927183284294044010320433324108, 1e-10);
+        assert_almost_eq!(n.scale(), 0.800056314205412087142294077539, 1e-10);
+        assert!(Cauchy::fit(&[]).is_err());
+        assert!(Cauchy::fit(&[1.0, 1.0, 1.0]).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -99,6 +99,74 @@ impl ContinuousCDF<f64, f64> for Chi {
             gamma::gamma_lr(self.freedom / 2.0, x * x / 2.0)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the chi
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2 * P^-1(k / 2, x))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `P^-1` is the inverse of
+    /// the lower regularized incomplete gamma function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.freedom == f64::INFINITY {
+            0.0
+        } else {
+            (2.0 * gamma::inv_gamma_lr(self.freedom / 2.0, x)).sqrt()
+        }
+    }
+    /// Calculates the survival function for the chi
+    /// distribution at `x`.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q(k / 2, x^2 / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Q` is
+    /// the upper regularized Gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if self.freedom == f64::INFINITY || x == f64::INFINITY {
+            0.0
+        } else if x <= 0.0 {
+            1.0
+        } else {
+            gamma::gamma_ur(self.freedom / 2.0, x * x / 2.0)
+        }
+    }
+    /// Calculates the inverse survival function for the chi
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt(2 * Q^-1(k / 2, x))
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Q^-1` is the inverse of
+    /// the upper regularized incomplete gamma function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.freedom == f64::INFINITY {
+            0.0
+        } else {
+            (2.0 * gamma::inv_gamma_ur(self.freedom / 2.0, x)).sqrt()
+        }
+    }
 }
 
 impl Min<f64> for Chi {
@@ -292,12 +360,34 @@ impl Continuous<f64, f64> for Chi {
     }
 }
 
+impl Fit<f64> for Chi {
+    /// Fits a chi distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k = 2 * ψ^-1((2 / n) * Σ ln(x_i) - ln(2))
+    /// ```
+    ///
+    /// where `ψ^-1` is the inverse digamma function
+    fn fit(data: &[f64]) -> Result<Chi> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let mean_ln = data.iter().map(|x| x.ln()).mean();
+        Chi::new(2.0 * gamma::inv_digamma(2.0 * mean_ln - f64::consts::LN_2))
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::f64;
     use crate::distribution::internal::*;
-    use crate::distribution::{Chi, Continuous, ContinuousCDF};
+    use crate::distribution::{Chi, Continuous, ContinuousCDF, Fit};
     use crate::statistics::*;
     use crate::consts::ACC;
 
@@ -564,4 +654,38 @@ mod tests {
         test::check_continuous_distribution(&try_create(2.0), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(5.0), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Chi| x.inverse_cdf(arg);
+        test_case(3.0, 0.0, inverse_cdf(0.0));
+        test_almost(3.0, 0.76444383322464135144, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 1.5381722544550523344, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 2.5002777108094060085, 1e-14, inverse_cdf(0.9));
+        test_almost(2.0, (2.0 * 2f64.ln()).sqrt(), 1e-15, inverse_cdf(0.5));
+        test_case(3.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Chi| x.sf(arg);
+        test_case(3.0, 1.0, sf(0.0));
+        test_almost(3.0, 1.5541594313896049214e-21, 1e-34, sf(10.0));
+        test_almost(3.0, 0.5, 1e-15, sf(1.5381722544550523344));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Chi| x.inverse_sf(arg);
+        test_almost(3.0, 7.0386188934707685715, 1e-13, inverse_sf(1e-10));
+        test_almost(3.0, 2.5002777108094060085, 1e-14, inverse_sf(0.1));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Chi::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_almost_eq!(n.freedom(), 2.84140408659401733717307457136, 1e-12);
+        assert!(Chi::fit(&[]).is_err());
+        assert!(Chi::fit(&[1.0, 0.0]).is_err());
+    }
 }
@@ -1,6 +1,7 @@
-use crate::distribution::{Continuous, ContinuousCDF, Gamma};
+use crate::distribution::{Continuous, ContinuousCDF, Fit, Gamma};
+use crate::function::gamma;
 use crate::statistics::*;
-use crate::Result;
+use crate::{Result, StatsError};
 use rand::Rng;
 use std::f64;
 
@@ -117,6 +118,56 @@ impl ContinuousCDF<f64, f64> for ChiSquared {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * P^-1(k / 2, x)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `P^-1` is the inverse of
+    /// the lower regularized incomplete gamma function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.g.inverse_cdf(x)
+    }
+    /// Calculates the survival function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(k / 2)) * Γ(k / 2, x / 2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `Γ` is the gamma function,
+    /// and `Γ(a, x)` is the upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        self.g.sf(x)
+    }
+    /// Calculates the inverse survival function for the
+    /// chi-squared distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * Q^-1(k / 2, x)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `Q^-1` is the inverse of
+    /// the upper regularized incomplete gamma function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        self.g.inverse_sf(x)
+    }
 }
 
 impl Min<f64> for ChiSquared {
@@ -264,11 +315,33 @@ impl Continuous<f64, f64> for ChiSquared {
     }
 }
 
+impl Fit<f64> for ChiSquared {
+    /// Fits a chi-squared distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k = 2 * ψ^-1((1 / n) * Σ ln(x_i) - ln(2))
+    /// ```
+    ///
+    /// where `ψ^-1` is the inverse digamma function
+    fn fit(data: &[f64]) -> Result<ChiSquared> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let mean_ln = data.iter().map(|x| x.ln()).mean();
+        ChiSquared::new(2.0 * gamma::inv_digamma(mean_ln - f64::consts::LN_2))
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::Median;
-    use crate::distribution::ChiSquared;
+    use crate::distribution::{ChiSquared, ContinuousCDF, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -311,4 +384,36 @@ mod tests {
         test::check_continuous_distribution(&try_create(2.0), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(5.0), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: ChiSquared| x.inverse_cdf(arg);
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 3.8414588206941244691, 1e-13, inverse_cdf(0.95));
+        test_almost(2.0, 1.3862943611198906188, 1e-14, inverse_cdf(0.5));
+        test_almost(10.0, 18.307038053275144003, 1e-12, inverse_cdf(0.95));
+        test_case(10.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: ChiSquared| x.sf(arg);
+        test_almost(10.0, 5.4497019829205293351e-17, 1e-30, sf(100.0));
+        test_almost(2.0, 0.5, 1e-15, sf(1.3862943611198906188));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: ChiSquared| x.inverse_sf(arg);
+        test_almost(1.0, 3.8414588206941244691, 1e-13, inverse_sf(0.05));
+        test_almost(6.0, 68.104748380151383, 1e-12, inverse_sf(1e-12));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = ChiSquared::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_almost_eq!(n.freedom(), 2.28162835265198467631366465034, 1e-12);
+        assert!(ChiSquared::fit(&[]).is_err());
+        assert!(ChiSquared::fit(&[1.0, 0.0]).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -64,6 +64,36 @@ impl ContinuousCDF<f64, f64> for Dirac {
             1.0
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// dirac distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// v
+    /// ```
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.0
+        }
+    }
+    /// Calculates the survival function for the
+    /// dirac distribution at `x`
+    ///
+    /// Where the value is 0 if x >= `v`, 1 otherwise.
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.0 {
+            1.0
+        } else {
+            0.0
+        }
+    }
 }
 
 impl Min<f64> for Dirac {
@@ -170,6 +200,32 @@ impl Mode<Option<f64>> for Dirac {
     }
 }
 
+impl Fit<f64> for Dirac {
+    /// Fits a dirac distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// v = x_1
+    /// ```
+    ///
+    /// since the likelihood is zero unless every sample equals `v`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the samples are not all
+    /// equal, or if they are `NaN`
+    fn fit(data: &[f64]) -> Result<Dirac> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x != data[0]) {
+            return Err(StatsError::BadParams);
+        }
+        Dirac::new(data[0])
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -275,4 +331,29 @@ mod tests {
         test_case(f64::INFINITY, 0.0, cdf(1.0));
         test_case(f64::INFINITY, 1.0, cdf(f64::INFINITY));
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Dirac| x.inverse_cdf(arg);
+        test_case(3.0, 3.0, inverse_cdf(0.1));
+        test_case(3.0, 3.0, inverse_cdf(0.9));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Dirac| x.sf(arg);
+        test_case(3.0, 1.0, sf(1.0));
+        test_case(3.0, 0.0, sf(3.0));
+        test_case(3.0, 0.0, sf(5.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        use crate::distribution::Fit;
+        let n = Dirac::fit(&[2.5, 2.5, 2.5]).unwrap();
+        assert_eq!(n.mean().unwrap(), 2.5);
+        assert!(Dirac::fit(&[]).is_err());
+        assert!(Dirac::fit(&[2.5, 3.0]).is_err());
+        assert!(Dirac::fit(&[f64::NAN]).is_err());
+    }
 }
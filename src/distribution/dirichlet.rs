@@ -1,4 +1,5 @@
-use crate::distribution::Continuous;
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{Continuous, Fit};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{prec, Result, StatsError};
@@ -299,6 +300,57 @@ fn is_valid_alpha(a: &[f64]) -> bool {
     a.len() >= 2 && super::internal::is_valid_multinomial(a, false)
 }
 
+impl Fit<DVector<f64>> for Dirichlet {
+    /// Fits a dirichlet distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ψ(α_k) - ψ(Σ α) = (1 / n) * Σ_i ln(x_ik)
+    /// ```
+    ///
+    /// where `ψ` is the digamma function. Expressing every `α_k` through the
+    /// inverse digamma function of `ψ(Σ α)` reduces this to a single
+    /// equation in `Σ α`, which is solved by bisection
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the samples have different
+    /// dimensions, if any component lies outside `(0, 1)`, if all samples are
+    /// equal, or if the solver fails to converge
+    fn fit(data: &[DVector<f64>]) -> Result<Dirichlet> {
+        let dim = match data.first() {
+            Some(x) => x.len(),
+            None => return Err(StatsError::ArgGte("data.len()", 1.0)),
+        };
+        if data.iter().any(|x| x.len() != dim) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if data
+            .iter()
+            .flat_map(|x| x.iter())
+            .any(|&x| x <= 0.0 || x >= 1.0)
+        {
+            return Err(StatsError::ArgIntervalExcl("data", 0.0, 1.0));
+        }
+        if data.iter().all(|x| x == &data[0]) {
+            return Err(StatsError::BadParams);
+        }
+        let n = data.len() as f64;
+        let mean_ln = data
+            .iter()
+            .fold(DVector::zeros(dim), |acc, x| acc + x.map(f64::ln))
+            .unscale(n);
+        let alpha = |sum: f64| {
+            let psi = gamma::digamma(sum);
+            mean_ln.map(|x| gamma::inv_digamma(psi + x))
+        };
+        let sum = solve_decreasing(|sum| alpha(sum).sum() - sum, 1.0)
+            .ok_or(StatsError::ComputationFailedToConverge)?;
+        Dirichlet::new(alpha(sum).as_slice().to_vec())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -452,4 +504,24 @@ mod tests {
         let n = try_create(&[0.1, 0.3, 0.5, 0.8]);
         n.ln_pdf(&dvec![0.5, 0.25, 0.8, 0.9]);
     }
+
+    #[test]
+    fn test_fit() {
+        let data = [
+            DVector::from_vec(vec![0.2, 0.3, 0.5]),
+            DVector::from_vec(vec![0.1, 0.6, 0.3]),
+            DVector::from_vec(vec![0.4, 0.4, 0.2]),
+            DVector::from_vec(vec![0.25, 0.25, 0.5]),
+            DVector::from_vec(vec![0.3, 0.5, 0.2]),
+        ];
+        let n = Dirichlet::fit(&data).unwrap();
+        let expected = [3.61597144754943091976972919653, 5.87056380182105881435371632067, 4.80777570404917789683952521759];
+        for (&a, &e) in n.alpha().iter().zip(expected.iter()) {
+            assert_almost_eq!(a, e, 1e-11);
+        }
+        assert!(Dirichlet::fit(&[]).is_err());
+        assert!(Dirichlet::fit(&[data[0].clone(), DVector::from_vec(vec![0.5, 0.5])]).is_err());
+        assert!(Dirichlet::fit(&[DVector::from_vec(vec![0.0, 1.0])]).is_err());
+        assert!(Dirichlet::fit(&data[..1]).is_err());
+    }
 }
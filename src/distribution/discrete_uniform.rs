@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{Discrete, DiscreteCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -82,6 +82,25 @@ impl DiscreteCDF<i64, f64> for DiscreteUniform {
             }
         }
     }
+    /// Calculates the survival function for the
+    /// discrete uniform distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - floor(x)) / (max - min + 1)
+    /// ```
+    fn sf(&self, x: i64) -> f64 {
+        if x < self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else {
+            let lower = self.min as f64;
+            let upper = self.max as f64;
+            (upper - x as f64) / (upper - lower + 1.0)
+        }
+    }
 }
 
 impl Min<i64> for DiscreteUniform {
@@ -226,12 +245,30 @@ impl Discrete<i64, f64> for DiscreteUniform {
     }
 }
 
+impl Fit<i64> for DiscreteUniform {
+    /// Fits a discrete uniform distribution to `data` by maximum likelihood,
+    /// i.e. to the range of the samples
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min = min_i x_i
+    /// max = max_i x_i
+    /// ```
+    fn fit(data: &[i64]) -> Result<DiscreteUniform> {
+        match (data.iter().min(), data.iter().max()) {
+            (Some(&min), Some(&max)) => DiscreteUniform::new(min, max),
+            _ => Err(StatsError::ArgGte("data.len()", 1.0)),
+        }
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{DiscreteCDF, Discrete, DiscreteUniform};
+    use crate::distribution::{DiscreteCDF, Discrete, DiscreteUniform, Fit};
     use crate::consts::ACC;
 
     fn try_create(min: i64, max: i64) -> DiscreteUniform {
@@ -375,4 +412,21 @@ mod tests {
         let cdf = |arg: i64| move |x: DiscreteUniform| x.cdf(arg);
         test_case(0, 3, 1.0, cdf(5));
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: i64| move |x: DiscreteUniform| x.sf(arg);
+        test_case(-10, 10, 1.0, sf(-11));
+        test_case(-10, 10, 1.0 - 1.0 / 21.0, sf(-10));
+        test_case(-10, 10, 10.0 / 21.0, sf(0));
+        test_case(-10, 10, 0.0, sf(10));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = DiscreteUniform::fit(&[3, -2, 7]).unwrap();
+        assert_eq!(n.min(), -2);
+        assert_eq!(n.max(), 7);
+        assert!(DiscreteUniform::fit(&[]).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF, Uniform};
+use crate::distribution::{Continuous, ContinuousCDF, Fit, Uniform};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use ::num_traits::float::Float;
@@ -111,47 +111,12 @@ impl Empirical {
             }
         }
     }
-    // Due to issues with rounding and floating-point accuracy the default
-    // implementation may be ill-behaved.
-    // Specialized inverse cdfs should be used whenever possible.
-    // Performs a binary search on the domain of `cdf` to obtain an approximation
-    // of `F^-1(p) := inf { x | F(x) >= p }`. Needless to say, performance may
-    // may be lacking.
-    // This function is identical to the default method implementation in the
-    // `ContinuousCDF` trait and is used to implement the rand trait `Distribution`.
-    fn __inverse_cdf(&self, p: f64) -> f64 {
-        if p == 0.0 {
-            return self.min();
-        };
-        if p == 1.0 {
-            return self.max();
-        };
-        let mut high = 2.0;
-        let mut low = -high;
-        while self.cdf(low) > p {
-            low = low + low;
-        }
-        while self.cdf(high) < p {
-            high = high + high;
-        }
-        let mut i = 16;
-        while i != 0 {
-            let mid = (high + low) / 2.0;
-            if self.cdf(mid) >= p {
-                high = mid;
-            } else {
-                low = mid;
-            }
-            i -= 1;
-        }
-        (high + low) / 2.0
-    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Empirical {
     fn sample<R: ?Sized + Rng>(&self, rng: &mut R) -> f64 {
         let uniform = Uniform::new(0.0, 1.0).unwrap();
-        self.__inverse_cdf(uniform.sample(rng))
+        self.inverse_cdf(uniform.sample(rng))
     }
 }
 
@@ -189,6 +154,50 @@ impl ContinuousCDF<f64, f64> for Empirical {
         }
         sum as f64 / self.sum
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// empirical distribution at `x`, i.e. the smallest sample `s` such
+    /// that `cdf(s) >= x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`, or if there are no samples
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        let target = x * self.sum;
+        let mut sum = 0;
+        for (keys, values) in &self.data {
+            sum += values;
+            if sum as f64 >= target {
+                return keys.0;
+            }
+        }
+        self.max()
+    }
+    /// Calculates the survival function for the empirical distribution at
+    /// `x`, i.e. the proportion of samples strictly greater than `x`
+    fn sf(&self, x: f64) -> f64 {
+        let mut sum = 0;
+        for (keys, values) in self.data.iter().rev() {
+            if keys.0 <= x {
+                break;
+            }
+            sum += values;
+        }
+        sum as f64 / self.sum
+    }
+}
+
+impl Fit<f64> for Empirical {
+    /// Returns the empirical distribution of `data`, which is the
+    /// nonparametric maximum likelihood estimate of the distribution
+    fn fit(data: &[f64]) -> Result<Empirical> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        Ok(Empirical::from_vec(data.to_vec()))
+    }
 }
 
 #[cfg(test)]
@@ -221,4 +230,31 @@ mod tests {
         // due to the mean and variance being calculated in a streaming way
         assert_eq!(unchanged, empirical);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let empirical = Empirical::from_vec(vec![2.0, 2.0, 5.0, 10.0]);
+        assert_eq!(empirical.inverse_cdf(0.0), 2.0);
+        assert_eq!(empirical.inverse_cdf(0.5), 2.0);
+        assert_eq!(empirical.inverse_cdf(0.6), 5.0);
+        assert_eq!(empirical.inverse_cdf(0.75), 5.0);
+        assert_eq!(empirical.inverse_cdf(0.8), 10.0);
+        assert_eq!(empirical.inverse_cdf(1.0), 10.0);
+    }
+
+    #[test]
+    fn test_sf() {
+        let empirical = Empirical::from_vec(vec![2.0, 2.0, 5.0, 10.0]);
+        assert_eq!(empirical.sf(0.0), 1.0);
+        assert_eq!(empirical.sf(2.0), 0.5);
+        assert_eq!(empirical.sf(7.0), 0.25);
+        assert_eq!(empirical.sf(10.0), 0.0);
+    }
+
+    #[test]
+    fn test_fit() {
+        let empirical = Empirical::fit(&[2.0, 2.0, 5.0, 10.0]).unwrap();
+        assert_eq!(empirical, Empirical::from_vec(vec![2.0, 2.0, 5.0, 10.0]));
+        assert!(Empirical::fit(&[]).is_err());
+    }
 }
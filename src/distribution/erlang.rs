@@ -1,4 +1,5 @@
-use crate::distribution::{Continuous, ContinuousCDF, Gamma};
+use crate::distribution::{Continuous, ContinuousCDF, Fit, Gamma};
+use crate::function::gamma;
 use crate::statistics::*;
 use crate::Result;
 use rand::Rng;
@@ -100,6 +101,56 @@ impl ContinuousCDF<f64, f64> for Erlang {
     fn cdf(&self, x: f64) -> f64 {
         self.g.cdf(x)
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// erlang distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P^-1(k, x) / λ
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the rate, and `P^-1` is the inverse
+    /// of the lower regularized incomplete gamma function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        self.g.inverse_cdf(x)
+    }
+    /// Calculates the survival function for the erlang
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Γ(k, λx) / (k - 1)!
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the rate, and `Γ` is the upper
+    /// incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        self.g.sf(x)
+    }
+    /// Calculates the inverse survival function for the
+    /// erlang distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q^-1(k, x) / λ
+    /// ```
+    ///
+    /// where `k` is the shape, `λ` is the rate, and `Q^-1` is the inverse
+    /// of the upper regularized incomplete gamma function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        self.g.inverse_sf(x)
+    }
 }
 
 impl Min<f64> for Erlang {
@@ -250,6 +301,35 @@ impl Continuous<f64, f64> for Erlang {
     }
 }
 
+impl Fit<f64> for Erlang {
+    /// Fits an erlang distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ℓ(k) = k * ln(k / x̄) - ln(Γ(k)) + (k - 1) * (1 / n) * Σ ln(x_i) - k
+    /// λ = k / x̄
+    /// ```
+    ///
+    /// where `x̄` is the sample mean and `ℓ(k)` is the log-likelihood per
+    /// sample once the rate has been maximized out. `ℓ` is concave, so the
+    /// shape `k` is whichever of the integers adjacent to the gamma shape
+    /// estimate gives the larger value
+    fn fit(data: &[f64]) -> Result<Erlang> {
+        let shape = Gamma::fit(data)?.shape();
+        let mean = data.mean();
+        let mean_ln = data.iter().map(|x| x.ln()).mean();
+        let profile = |k: f64| k * (k / mean).ln() - gamma::ln_gamma(k) + (k - 1.0) * mean_ln - k;
+        let lo = shape.floor().max(1.0);
+        let k = if profile(lo) >= profile(lo + 1.0) {
+            lo
+        } else {
+            lo + 1.0
+        };
+        Erlang::new(k as u64, k / mean)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -297,4 +377,35 @@ mod tests {
         test::check_continuous_distribution(&try_create(2, 1.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(3, 0.5), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        use crate::distribution::ContinuousCDF;
+        let n = try_create(3, 1.0);
+        assert_almost_eq!(n.inverse_cdf(0.1), 1.1020653282493211016, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.5), 2.6740603137235603179, 1e-13);
+        let n = try_create(3, 2.0);
+        assert_almost_eq!(n.inverse_cdf(0.5), 2.6740603137235603179 / 2.0, 1e-13);
+    }
+
+    #[test]
+    fn test_sf() {
+        use crate::distribution::ContinuousCDF;
+        let n = try_create(3, 1.0);
+        assert_almost_eq!(n.sf(30.0), 4.501016648012123985e-11, 1e-24);
+        assert_almost_eq!(n.inverse_sf(1e-12), 34.052374190075691311, 1e-12);
+    }
+    #[test]
+    fn test_fit() {
+        use crate::distribution::Fit;
+        let n = Erlang::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_eq!(n.shape(), 3);
+        assert_almost_eq!(n.rate(), 1.8320610687022900763, 1e-15);
+        let n = Erlang::fit(&[0.1, 2.0, 0.05, 7.0]).unwrap();
+        assert_eq!(n.shape(), 1);
+        assert_almost_eq!(n.rate(), 1.0 / 2.2875, 1e-15);
+        assert!(Erlang::fit(&[]).is_err());
+        assert!(Erlang::fit(&[1.0, 0.0]).is_err());
+        assert!(Erlang::fit(&[2.0, 2.0]).is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
+use crate::distribution::{ziggurat, Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -91,6 +91,65 @@ impl ContinuousCDF<f64, f64> for Exp {
             1.0 - (-self.rate * x).exp()
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(1 - x) / λ
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            -(-x).ln_1p() / self.rate
+        }
+    }
+    /// Calculates the survival function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-λ * x)
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-self.rate * x).exp()
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// exponential distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(x) / λ
+    /// ```
+    ///
+    /// where `λ` is the rate
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            -x.ln() / self.rate
+        }
+    }
 }
 
 impl Min<f64> for Exp {
@@ -236,12 +295,31 @@ impl Continuous<f64, f64> for Exp {
     }
 }
 
+impl Fit<f64> for Exp {
+    /// Fits an exponential distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ = n / Σ x_i
+    /// ```
+    fn fit(data: &[f64]) -> Result<Exp> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x < 0.0) {
+            return Err(StatsError::ArgNotNegative("data"));
+        }
+        Exp::new(1.0 / data.mean())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::f64;
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Exp};
+    use crate::distribution::{ContinuousCDF, Continuous, Exp, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -451,4 +529,41 @@ mod tests {
         test::check_continuous_distribution(&try_create(1.5), 0.0, 20.0);
         test::check_continuous_distribution(&try_create(2.5), 0.0, 50.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Exp| x.inverse_cdf(arg);
+        test_case(1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 2f64.ln(), 1e-15, inverse_cdf(0.5));
+        test_almost(0.1, 10.0 * 2f64.ln(), 1e-14, inverse_cdf(0.5));
+        test_almost(1.0, 1e-12, 1e-24, inverse_cdf(1e-12));
+        test_case(1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Exp| x.sf(arg);
+        test_case(1.0, 1.0, sf(-1.0));
+        test_case(1.0, 1.0, sf(0.0));
+        test_almost(1.0, 1.9287498479639177830e-22, 1e-36, sf(50.0));
+        test_almost(0.1, 0.36787944117144232160, 1e-15, sf(10.0));
+        test_case(1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Exp| x.inverse_sf(arg);
+        test_case(1.0, f64::INFINITY, inverse_sf(0.0));
+        test_almost(2.0, 10.0 * 10f64.ln(), 1e-13, inverse_sf(1e-20));
+        test_almost(1.0, 2f64.ln(), 1e-15, inverse_sf(0.5));
+        test_case(1.0, 0.0, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Exp::fit(&[0.5, 1.5, 2.0, 4.0]).unwrap();
+        assert_eq!(n.rate(), 0.5);
+        assert!(Exp::fit(&[]).is_err());
+        assert!(Exp::fit(&[1.0, -1.0]).is_err());
+    }
 }
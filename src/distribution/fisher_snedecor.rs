@@ -1,5 +1,6 @@
-use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::beta;
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
+use crate::function::{beta, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -123,6 +124,93 @@ impl ContinuousCDF<f64, f64> for FisherSnedecor {
             )
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (d2 * y) / (d1 * (1 - y))
+    /// ```
+    ///
+    /// where `y = I^-1_x(d1 / 2, d2 / 2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, and `I^-1` is the
+    /// inverse of the regularized incomplete beta function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        let a = self.freedom_1 / 2.0;
+        let b = self.freedom_2 / 2.0;
+        // solve for whichever of `y` and `1 - y` is smaller so that the
+        // ratio does not lose precision to cancellation
+        let y = beta::inv_beta_reg(a, b, x);
+        if y <= 0.5 {
+            self.freedom_2 * y / (self.freedom_1 * (1.0 - y))
+        } else {
+            let z = beta::inv_beta_reg(b, a, 1.0 - x);
+            self.freedom_2 * (1.0 - z) / (self.freedom_1 * z)
+        }
+    }
+    /// Calculates the survival function for the fisher-snedecor
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(d2 / (d1 * x + d2))(d2 / 2, d1 / 2)
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is
+    /// the second degree of freedom, and `I` is the regularized incomplete
+    /// beta function
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            beta::beta_reg(
+                self.freedom_2 / 2.0,
+                self.freedom_1 / 2.0,
+                self.freedom_2 / (self.freedom_1 * x + self.freedom_2),
+            )
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (d2 * (1 - z)) / (d1 * z)
+    /// ```
+    ///
+    /// where `z = I^-1_x(d2 / 2, d1 / 2)`, `d1` is the first degree of
+    /// freedom, `d2` is the second degree of freedom, and `I^-1` is the
+    /// inverse of the regularized incomplete beta function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        let a = self.freedom_1 / 2.0;
+        let b = self.freedom_2 / 2.0;
+        let z = beta::inv_beta_reg(b, a, x);
+        if z <= 0.5 {
+            self.freedom_2 * (1.0 - z) / (self.freedom_1 * z)
+        } else {
+            let y = beta::inv_beta_reg(a, b, 1.0 - x);
+            self.freedom_2 * y / (self.freedom_1 * (1.0 - y))
+        }
+    }
 }
 
 impl Min<f64> for FisherSnedecor {
@@ -326,6 +414,76 @@ impl Continuous<f64, f64> for FisherSnedecor {
     }
 }
 
+impl Fit<f64> for FisherSnedecor {
+    /// Fits a fisher-snedecor distribution to `data` by maximum likelihood
+    ///
+    /// The freedoms are estimated with the EM algorithm, which treats each
+    /// sample as gamma distributed with shape `a = d1 / 2` and rate `a * w_i`
+    /// given an unobserved weight `w_i` that is itself gamma distributed with
+    /// shape and rate `b = d2 / 2`. Each iteration computes
+    ///
+    /// ```ignore
+    /// w_i = (a + b) / (b + a * x_i)
+    /// l_i = ψ(a + b) - ln(b + a * x_i)
+    /// ```
+    ///
+    /// and updates the freedoms by solving
+    ///
+    /// ```ignore
+    /// ln(a) - ψ(a) = (1 / n) * Σ (w_i * x_i - l_i - ln(x_i)) - 1
+    /// ln(b) - ψ(b) = (1 / n) * Σ (w_i - l_i) - 1
+    /// ```
+    ///
+    /// where `ψ` is the digamma function
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if any sample is not positive, if
+    /// the samples are all equal, or if the iteration does not converge, which
+    /// is expected when the data are close to a scaled chi-squared
+    /// distribution so that `d2` grows without bound
+    fn fit(data: &[f64]) -> Result<FisherSnedecor> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        if data.iter().all(|&x| x == data[0]) {
+            return Err(StatsError::BadParams);
+        }
+        let eps = 1e-12;
+        let mean_ln = data.iter().map(|x| x.ln()).mean();
+        let mut a = 1.0;
+        let mut b = 1.0;
+        for _ in 0..10_000 {
+            let psi = gamma::digamma(a + b);
+            let (mut wx, mut w, mut l) = (0.0, 0.0, 0.0);
+            for &x in data {
+                let d = b + a * x;
+                wx += (a + b) * x / d;
+                w += (a + b) / d;
+                l += psi - d.ln();
+            }
+            let n = data.len() as f64;
+            let ca = (wx - l) / n - mean_ln - 1.0;
+            let cb = (w - l) / n - 1.0;
+            let next_a = solve_decreasing(|z| z.ln() - gamma::digamma(z) - ca, a)
+                .ok_or(StatsError::ComputationFailedToConverge)?;
+            let next_b = solve_decreasing(|z| z.ln() - gamma::digamma(z) - cb, b)
+                .ok_or(StatsError::ComputationFailedToConverge)?;
+            let converged =
+                (next_a - a).abs() <= eps * next_a && (next_b - b).abs() <= eps * next_b;
+            a = next_a;
+            b = next_b;
+            if converged {
+                return FisherSnedecor::new(2.0 * a, 2.0 * b);
+            }
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -540,4 +698,49 @@ mod tests {
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(10.0, 10.0), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: FisherSnedecor| x.inverse_cdf(arg);
+        test_case(5.0, 10.0, 0.0, inverse_cdf(0.0));
+        test_almost(5.0, 10.0, 0.21119042878234493804, 1e-14, inverse_cdf(0.05));
+        test_almost(5.0, 10.0, 0.9319331608510479452, 1e-14, inverse_cdf(0.5));
+        test_almost(5.0, 10.0, 3.3258345304130109171, 1e-13, inverse_cdf(0.95));
+        test_almost(1.0, 1.0, 1.0, 1e-14, inverse_cdf(0.5));
+        test_almost(5.0, 10.0, 5.6609829993291447531e-121, 1e-132, inverse_cdf(1e-300));
+        test_almost(1.0, 1.0, 2.4674011002723396547e-200, 1e-211, inverse_cdf(1e-100));
+        test_almost(3.0, 7.0, 3.5046465860430221066e-34, 1e-45, inverse_cdf(1e-50));
+        test_case(5.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: FisherSnedecor| x.sf(arg);
+        test_case(5.0, 10.0, 1.0, sf(0.0));
+        test_almost(5.0, 10.0, 3.3169075992100938717e-8, 1e-21, sf(100.0));
+        test_almost(5.0, 10.0, 0.05, 1e-15, sf(3.3258345304130109171));
+        test_case(5.0, 10.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: FisherSnedecor| x.inverse_sf(arg);
+        test_almost(5.0, 10.0, 324.75846197134442987, 1e-9, inverse_sf(1e-10));
+        test_almost(5.0, 10.0, 3.3258345304130109171, 1e-13, inverse_sf(0.05));
+        test_almost(5.0, 10.0, 0.21119042878234493804, 1e-14, inverse_sf(0.95));
+        test_almost(5.0, 10.0, 3.2726010774973155258e60, 1e48, inverse_sf(1e-300));
+        test_almost(1.0, 1.0, 4.0528473456935108578e199, 1e187, inverse_sf(1e-100));
+    }
+
+    #[test]
+    fn test_fit() {
+        use crate::distribution::Fit;
+        let data = [0.3, 0.8, 1.1, 0.5, 2.7, 1.4, 0.2, 4.1, 0.9, 1.6];
+        let n = FisherSnedecor::fit(&data).unwrap();
+        assert_almost_eq!(n.freedom_1(), 4.9080763895439156657, 1e-9);
+        assert_almost_eq!(n.freedom_2(), 7.4811233742378638633, 1e-9);
+        assert!(FisherSnedecor::fit(&[]).is_err());
+        assert!(FisherSnedecor::fit(&[1.0, 0.0]).is_err());
+        assert!(FisherSnedecor::fit(&[2.0, 2.0]).is_err());
+    }
 }
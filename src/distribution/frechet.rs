@@ -0,0 +1,452 @@
+use crate::consts;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [Fréchet](https://en.wikipedia.org/wiki/Fr%C3%A9chet_distribution)
+/// distribution, the limiting distribution of the maximum of samples from
+/// heavy-tailed distributions
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Frechet, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Frechet::new(0.5, 2.0, 3.5).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 3.051985350986888117, 1e-14));
+/// assert!(prec::almost_eq(n.pdf(2.5), 0.64378902205002406279, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Frechet {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl Frechet {
+    /// Constructs a new fréchet distribution with location `location`,
+    /// scale `scale` and shape `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, or if
+    /// `scale <= 0.0` or `shape <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let mut result = Frechet::new(0.0, 1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Frechet::new(0.0, 1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Frechet> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        if shape <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("shape"));
+        }
+        Ok(Frechet {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Returns the location of the fréchet distribution, i.e. the lower
+    /// bound of its support
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let n = Frechet::new(0.5, 2.0, 3.5).unwrap();
+    /// assert_eq!(n.location(), 0.5);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the fréchet distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let n = Frechet::new(0.5, 2.0, 3.5).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the fréchet distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Frechet;
+    ///
+    /// let n = Frechet::new(0.5, 2.0, 3.5).unwrap();
+    /// assert_eq!(n.shape(), 3.5);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `Γ(1 - k / α)`, the `k`-th raw moment of the standardized
+    /// distribution, which exists only if `α > k`
+    fn gamma_moment(&self, k: f64) -> Option<f64> {
+        if self.shape <= k {
+            None
+        } else {
+            Some(gamma::gamma(1.0 - k / self.shape))
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Frechet {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let e = super::ziggurat::sample_exp_1(rng);
+        self.location + self.scale * e.powf(-1.0 / self.shape)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Frechet {
+    /// Calculates the cumulative distribution function for the fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-((x - m) / s)^(-α))
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            0.0
+        } else {
+            (-((x - self.location) / self.scale).powf(-self.shape)).exp()
+        }
+    }
+
+    /// Calculates the survival function for the fréchet distribution at
+    /// `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-((x - m) / s)^(-α))
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.location {
+            1.0
+        } else {
+            -(-((x - self.location) / self.scale).powf(-self.shape)).exp_m1()
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// fréchet distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m + s (-ln(x))^(-1 / α)
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.location + self.scale * (-x.ln()).powf(-1.0 / self.shape)
+    }
+
+    /// Calculates the inverse survival function for the fréchet
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m + s (-ln(1 - x))^(-1 / α)
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.location + self.scale * (-(-x).ln_1p()).powf(-1.0 / self.shape)
+    }
+}
+
+impl Min<f64> for Frechet {
+    /// Returns the minimum value in the domain of the fréchet distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m
+    /// ```
+    ///
+    /// where `m` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for Frechet {
+    /// Returns the maximum value in the domain of the fréchet distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Frechet {
+    /// Returns the mean of the fréchet distribution, which exists only if
+    /// `α > 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m + s Γ(1 - 1 / α)
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale, `α` is the shape and
+    /// `Γ` is the gamma function
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * self.gamma_moment(1.0)?)
+    }
+
+    /// Returns the variance of the fréchet distribution, which exists only
+    /// if `α > 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// s^2 (Γ(1 - 2 / α) - Γ(1 - 1 / α)^2)
+    /// ```
+    ///
+    /// where `s` is the scale, `α` is the shape and `Γ` is the gamma
+    /// function
+    fn variance(&self) -> Option<f64> {
+        let g2 = self.gamma_moment(2.0)?;
+        let g1 = self.gamma_moment(1.0)?;
+        Some(self.scale * self.scale * (g2 - g1 * g1))
+    }
+
+    /// Returns the entropy of the fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 + γ / α + γ + ln(s / α)
+    /// ```
+    ///
+    /// where `s` is the scale, `α` is the shape and `γ` is the
+    /// Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        let entr = 1.0
+            + consts::EULER_MASCHERONI / self.shape
+            + consts::EULER_MASCHERONI
+            + (self.scale / self.shape).ln();
+        Some(entr)
+    }
+
+    /// Returns the skewness of the fréchet distribution, which exists only
+    /// if `α > 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (g3 - 3 g2 g1 + 2 g1^3) / (g2 - g1^2)^(3/2)
+    /// ```
+    ///
+    /// where `gk = Γ(1 - k / α)`, `α` is the shape and `Γ` is the gamma
+    /// function
+    fn skewness(&self) -> Option<f64> {
+        let g3 = self.gamma_moment(3.0)?;
+        let g2 = self.gamma_moment(2.0)?;
+        let g1 = self.gamma_moment(1.0)?;
+        Some((g3 - 3.0 * g2 * g1 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5))
+    }
+}
+
+impl Median<f64> for Frechet {
+    /// Returns the median of the fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m + s ln(2)^(-1 / α)
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn median(&self) -> f64 {
+        self.location + self.scale * f64::consts::LN_2.powf(-1.0 / self.shape)
+    }
+}
+
+impl Mode<Option<f64>> for Frechet {
+    /// Returns the mode of the fréchet distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// m + s (α / (1 + α))^(1 / α)
+    /// ```
+    ///
+    /// where `m` is the location, `s` is the scale and `α` is the shape
+    fn mode(&self) -> Option<f64> {
+        let alpha = self.shape;
+        Some(self.location + self.scale * (alpha / (1.0 + alpha)).powf(1.0 / alpha))
+    }
+}
+
+impl Continuous<f64, f64> for Frechet {
+    /// Calculates the probability density function for the fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (α / s) z^(-1 - α) e^(-z^(-α))
+    /// ```
+    ///
+    /// where `z = (x - m) / s`, `m` is the location, `s` is the scale and
+    /// `α` is the shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the fréchet
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(α / s) - (1 + α) ln(z) - z^(-α)
+    /// ```
+    ///
+    /// where `z = (x - m) / s`, `m` is the location, `s` is the scale and
+    /// `α` is the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x <= self.location || x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let ln_z = ((x - self.location) / self.scale).ln();
+        (self.shape / self.scale).ln() - (1.0 + self.shape) * ln_z - (-self.shape * ln_z).exp()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Frechet};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> Frechet {
+        let n = Frechet::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(expected: f64, acc: f64, eval: F)
+        where F: Fn(Frechet) -> f64
+    {
+        let x = eval(try_create(0.5, 2.0, 3.5));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(0.5, 2.0, 3.5);
+        try_create(-1.0, 0.1, 0.5);
+        assert!(Frechet::new(0.0, 0.0, 1.0).is_err());
+        assert!(Frechet::new(0.0, 1.0, -1.0).is_err());
+        assert!(Frechet::new(f64::NAN, 1.0, 1.0).is_err());
+        assert!(Frechet::new(0.0, 1.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(3.051985350986888117, 1e-14, |x| x.mean().unwrap());
+        test_almost(1.7574176745892468779, 1e-13, |x| x.variance().unwrap());
+        test_almost(9.5759176266367311974, 1e-11, |x| x.skewness().unwrap());
+        test_almost(1.1825186383665481345, 1e-15, |x| x.entropy().unwrap());
+        test_almost(2.7207948178550666478, 1e-15, |x| x.median());
+        test_almost(2.3614263677079170571, 1e-15, |x| x.mode().unwrap());
+        assert!(try_create(0.0, 1.0, 1.0).mean().is_none());
+        assert!(try_create(0.0, 1.0, 2.0).variance().is_none());
+        assert!(try_create(0.0, 1.0, 3.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(0.00048327224999278119954, 1e-18, |x| x.pdf(1.5));
+        test_almost(0.64378902205002406279, 1e-15, |x| x.pdf(2.5));
+        test_almost(0.017924547329444134463, 1e-16, |x| x.pdf(6.0));
+        test_almost(0.017924547329444134463f64.ln(), 1e-14, |x| x.ln_pdf(6.0));
+        test_almost(0.0, 1e-15, |x| x.pdf(0.5));
+        test_almost(0.0, 1e-15, |x| x.pdf(-1.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.000012204467326042001039, 1e-19, |x| x.cdf(1.5));
+        test_almost(0.3678794411714423216, 1e-15, |x| x.cdf(2.5));
+        test_almost(0.97142051354763531186, 1e-15, |x| x.cdf(6.0));
+        test_almost(0.028579486452364688139, 1e-16, |x| x.sf(6.0));
+        test_almost(0.0, 1e-15, |x| x.cdf(0.0));
+        test_almost(1.0, 1e-15, |x| x.sf(0.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(2.0759404358395215549, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(4.3042135222096018972, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(4.3042135222096018972, 1e-14, |x| x.inverse_sf(0.1));
+        test_almost(0.5, 1e-15, |x| x.inverse_cdf(0.0));
+        assert_eq!(try_create(0.5, 2.0, 3.5).inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(0.5, 2.0, 3.5), 1.0, 40.0);
+    }
+}
@@ -1,4 +1,5 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -118,6 +119,132 @@ impl ContinuousCDF<f64, f64> for Gamma {
             gamma::gamma_lr(self.shape, x * self.rate)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P^-1(α, x) / β
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `P^-1` is the inverse
+    /// of the lower regularized incomplete gamma function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.rate.is_infinite() {
+            self.shape
+        } else if self.shape.is_infinite() {
+            f64::INFINITY
+        } else {
+            gamma::inv_gamma_lr(self.shape, x) / self.rate
+        }
+    }
+    /// Calculates the survival function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / Γ(α)) * Γ(α, β * x)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, `Γ` is the gamma function,
+    /// and `Γ(a, x)` is the upper incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if ulps_eq!(x, self.shape) && self.rate.is_infinite() {
+            0.0
+        } else if self.rate.is_infinite() {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            gamma::gamma_ur(self.shape, x * self.rate)
+        }
+    }
+    /// Calculates the inverse survival function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Q^-1(α, x) / β
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `Q^-1` is the inverse
+    /// of the upper regularized incomplete gamma function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else if self.rate.is_infinite() {
+            self.shape
+        } else if self.shape.is_infinite() {
+            f64::INFINITY
+        } else {
+            gamma::inv_gamma_ur(self.shape, x) / self.rate
+        }
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// gamma distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(P(α, β * x))
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `P` is the lower
+    /// regularized incomplete gamma function, evaluated in log space so that
+    /// it stays finite where the cdf underflows
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            f64::NEG_INFINITY
+        } else if ulps_eq!(x, self.shape) && self.rate.is_infinite() {
+            0.0
+        } else if self.rate.is_infinite() {
+            f64::NEG_INFINITY
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            gamma::ln_gamma_lr(self.shape, x * self.rate)
+        }
+    }
+    /// Calculates the log of the survival function for the gamma
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Q(α, β * x))
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `Q` is the upper
+    /// regularized incomplete gamma function, evaluated in log space so that
+    /// it stays finite where the survival function underflows
+    fn ln_sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if ulps_eq!(x, self.shape) && self.rate.is_infinite() {
+            f64::NEG_INFINITY
+        } else if self.rate.is_infinite() {
+            0.0
+        } else if x.is_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            gamma::ln_gamma_ur(self.shape, x * self.rate)
+        }
+    }
 }
 
 impl Min<f64> for Gamma {
@@ -322,6 +449,37 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, shape: f64, rate: f64) ->
     }
 }
 
+impl Fit<f64> for Gamma {
+    /// Fits a gamma distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(α) - ψ(α) = ln(x̄) - (1 / n) * Σ ln(x_i)
+    /// β = α / x̄
+    /// ```
+    ///
+    /// where `ψ` is the digamma function and `x̄` is the sample mean. The
+    /// left-hand side is decreasing in `α`, so the shape is found by
+    /// bisection
+    fn fit(data: &[f64]) -> Result<Gamma> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let mean = data.mean();
+        let s = mean.ln() - data.iter().map(|x| x.ln()).mean();
+        if s <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let shape = solve_decreasing(|a| a.ln() - gamma::digamma(a) - s, 1.0 / s)
+            .ok_or(StatsError::ComputationFailedToConverge)?;
+        Gamma::new(shape, shape / mean)
+    }
+}
+
 #[cfg(all(test, feature = "nightly"))]
 mod tests {
     use super::*;
@@ -539,4 +697,73 @@ mod tests {
         test::check_continuous_distribution(&try_create((1.0, 0.5)), 0.0, 20.0);
         test::check_continuous_distribution(&try_create((9.0, 2.0)), 0.0, 20.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let f = |arg: f64| move |x: Gamma| x.inverse_cdf(arg);
+        let test = [
+            ((3.0, 1.0), 0.1, 1.1020653282493211016),
+            ((3.0, 1.0), 0.5, 2.6740603137235603179),
+            ((3.0, 1.0), 0.999, 11.22887224241266157),
+            ((0.5, 2.0), 0.01, 0.00003927196447742549601),
+            ((0.5, 2.0), 0.9, 0.6763858635238537303),
+        ];
+        for &(arg, x, res) in test.iter() {
+            test_case_special(arg, res, 1e-13, f(x));
+        }
+        test_case((3.0, 1.0), 0.0, f(0.0));
+        test_case((3.0, 1.0), INF, f(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let f = |arg: f64| move |x: Gamma| x.sf(arg);
+        test_case((3.0, 1.0), 1.0, f(0.0));
+        test_case_special((3.0, 1.0), 4.501016648012123985e-11, 1e-24, f(30.0));
+        test_case_special((1.0, 1.0), 0.367879441171442321595523, 1e-15, f(1.0));
+        test_case((3.0, 1.0), 0.0, f(INF));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let f = |arg: f64| move |x: Gamma| x.inverse_sf(arg);
+        test_case_special((3.0, 1.0), 34.052374190075691311, 1e-12, f(1e-12));
+        test_case_special((3.0, 1.0), 2.6740603137235603179, 1e-13, f(0.5));
+        test_case_special((3.0, 2.0), 17.026187095037845656, 1e-12, f(1e-12));
+        test_case_special((3.0, 1.0), 240.53944484409911513, 1e-11, f(1e-100));
+        test_case((3.0, 1.0), INF, f(0.0));
+        test_case((3.0, 1.0), 0.0, f(1.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let f = |arg: f64| move |x: Gamma| x.ln_cdf(arg);
+        test_case((3.0, 1.0), f64::NEG_INFINITY, f(0.0));
+        test_case_special((3.0, 1.0), -1383.3428152656554655, 1e-10, f(1e-200));
+        test_case_special((3.0, 1.0), -2.5219682600313997855, 1e-14, f(1.0));
+        test_case_special((3.0, 1.0), -2.5093035522010585192e-19, 1e-32, f(50.0));
+        test_case((3.0, 1.0), 0.0, f(INF));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let f = |arg: f64| move |x: Gamma| x.ln_sf(arg);
+        test_case((3.0, 1.0), 0.0, f(0.0));
+        test_case_special((3.0, 1.0), -986.87563662392700614, 1e-11, f(1000.0));
+        test_case_special((3.0, 1.0), -0.083709268125844934816, 1e-15, f(1.0));
+        test_case_special((3.0, 0.5), -986.87563662392700614, 1e-11, f(2000.0));
+        test_case((3.0, 1.0), f64::NEG_INFINITY, f(INF));
+        test_case_special((1.5, 1.0), -1e155, 1e140, f(1e155));
+    }
+
+    #[test]
+    fn test_fit() {
+        let data = [0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6];
+        let n = Gamma::fit(&data).unwrap();
+        assert_abs_diff_eq!(n.shape(), 3.15480971721502416559882090877, epsilon = 1e-12);
+        assert_abs_diff_eq!(n.rate(), 1.92660135402444223853363108933, epsilon = 1e-12);
+        assert!(Gamma::fit(&[]).is_err());
+        assert!(Gamma::fit(&[1.0, 0.0]).is_err());
+        assert!(Gamma::fit(&[2.0, 2.0]).is_err());
+    }
 }
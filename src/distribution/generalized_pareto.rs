@@ -0,0 +1,504 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [generalized
+/// Pareto](https://en.wikipedia.org/wiki/Generalized_Pareto_distribution)
+/// distribution, the limiting distribution of exceedances over a high
+/// threshold
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{GeneralizedPareto, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = GeneralizedPareto::new(1.0, 2.0, 0.3).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 1.0 + 2.0 / 0.7, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(3.0), 0.16040410473621003675, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeneralizedPareto {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl GeneralizedPareto {
+    /// Constructs a new generalized pareto distribution with location
+    /// `location`, scale `scale` and shape `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let mut result = GeneralizedPareto::new(1.0, 2.0, -0.4);
+    /// assert!(result.is_ok());
+    ///
+    /// result = GeneralizedPareto::new(1.0, 0.0, 0.3);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<GeneralizedPareto> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Ok(GeneralizedPareto {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Returns the location of the generalized pareto distribution, i.e.
+    /// the threshold at which its support starts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape ξ of the generalized pareto distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::GeneralizedPareto;
+    ///
+    /// let n = GeneralizedPareto::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.shape(), 0.3);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns the cumulative hazard `-ln(S(x))` at `x`, which is zero below
+    /// the support and infinite above it
+    fn hazard(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z <= 0.0 {
+            return 0.0;
+        }
+        if self.shape == 0.0 {
+            return z;
+        }
+        let s = self.shape * z;
+        if s <= -1.0 {
+            f64::INFINITY
+        } else {
+            s.ln_1p() / self.shape
+        }
+    }
+
+    /// Returns the `x` at which the cumulative hazard equals `hazard`
+    fn invert_hazard(&self, hazard: f64) -> f64 {
+        if self.shape == 0.0 {
+            self.location + self.scale * hazard
+        } else {
+            self.location + self.scale * (self.shape * hazard).exp_m1() / self.shape
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for GeneralizedPareto {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.invert_hazard(super::ziggurat::sample_exp_1(rng))
+    }
+}
+
+impl ContinuousCDF<f64, f64> for GeneralizedPareto {
+    /// Calculates the cumulative distribution function for the generalized
+    /// pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - (1 + ξ (x - μ) / σ)^(-1 / ξ) if ξ != 0
+    /// 1 - e^(-(x - μ) / σ)             if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn cdf(&self, x: f64) -> f64 {
+        -(-self.hazard(x)).exp_m1()
+    }
+
+    /// Calculates the survival function for the generalized pareto
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 + ξ (x - μ) / σ)^(-1 / ξ) if ξ != 0
+    /// e^(-(x - μ) / σ)             if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn sf(&self, x: f64) -> f64 {
+        (-self.hazard(x)).exp()
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized pareto distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ ((1 - x)^(-ξ) - 1) / ξ if ξ != 0
+    /// μ - σ ln(1 - x)              if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.invert_hazard(-(-x).ln_1p())
+    }
+
+    /// Calculates the inverse survival function for the generalized pareto
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ (x^(-ξ) - 1) / ξ if ξ != 0
+    /// μ - σ ln(x)            if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.invert_hazard(-x.ln())
+    }
+}
+
+impl Min<f64> for GeneralizedPareto {
+    /// Returns the minimum value in the domain of the generalized pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn min(&self) -> f64 {
+        self.location
+    }
+}
+
+impl Max<f64> for GeneralizedPareto {
+    /// Returns the maximum value in the domain of the generalized pareto
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - σ / ξ if ξ < 0
+    /// INF       otherwise
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for GeneralizedPareto {
+    /// Returns the mean of the generalized pareto distribution, which
+    /// exists only if `ξ < 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ / (1 - ξ)
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mean(&self) -> Option<f64> {
+        if self.shape >= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale / (1.0 - self.shape))
+        }
+    }
+
+    /// Returns the variance of the generalized pareto distribution, which
+    /// exists only if `ξ < 1 / 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 / ((1 - ξ)^2 (1 - 2ξ))
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn variance(&self) -> Option<f64> {
+        let xi = self.shape;
+        if xi >= 0.5 {
+            None
+        } else {
+            Some(self.scale * self.scale / ((1.0 - xi) * (1.0 - xi) * (1.0 - 2.0 * xi)))
+        }
+    }
+
+    /// Returns the entropy of the generalized pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + ξ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale and `ξ` is the shape
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + self.shape + 1.0)
+    }
+
+    /// Returns the skewness of the generalized pareto distribution, which
+    /// exists only if `ξ < 1 / 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 (1 + ξ) sqrt(1 - 2ξ) / (1 - 3ξ)
+    /// ```
+    ///
+    /// where `ξ` is the shape
+    fn skewness(&self) -> Option<f64> {
+        let xi = self.shape;
+        if 3.0 * xi >= 1.0 {
+            None
+        } else {
+            Some(2.0 * (1.0 + xi) * (1.0 - 2.0 * xi).sqrt() / (1.0 - 3.0 * xi))
+        }
+    }
+}
+
+impl Median<f64> for GeneralizedPareto {
+    /// Returns the median of the generalized pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ (2^ξ - 1) / ξ if ξ != 0
+    /// μ + σ ln(2)         if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn median(&self) -> f64 {
+        self.invert_hazard(f64::consts::LN_2)
+    }
+}
+
+impl Mode<Option<f64>> for GeneralizedPareto {
+    /// Returns the mode of the generalized pareto distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ         if ξ >= -1
+    /// μ - σ / ξ if ξ < -1
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mode(&self) -> Option<f64> {
+        if self.shape < -1.0 {
+            Some(self.max())
+        } else {
+            Some(self.location)
+        }
+    }
+}
+
+impl Continuous<f64, f64> for GeneralizedPareto {
+    /// Calculates the probability density function for the generalized
+    /// pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) (1 + ξ (x - μ) / σ)^(-1 / ξ - 1) if ξ != 0
+    /// (1 / σ) e^(-(x - μ) / σ)                 if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// pareto distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(σ) - (1 / ξ + 1) ln(1 + ξ (x - μ) / σ) if ξ != 0
+    /// -ln(σ) - (x - μ) / σ                       if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.location || x >= self.max() {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        if self.shape == 0.0 {
+            -self.scale.ln() - z
+        } else {
+            -self.scale.ln() - (1.0 / self.shape + 1.0) * (self.shape * z).ln_1p()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, GeneralizedPareto};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> GeneralizedPareto {
+        let n = GeneralizedPareto::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(GeneralizedPareto) -> f64
+    {
+        let x = eval(try_create(1.0, 2.0, shape));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(1.0, 2.0, 0.3);
+        try_create(1.0, 2.0, 0.0);
+        try_create(1.0, 2.0, -5.0);
+        assert!(GeneralizedPareto::new(1.0, 0.0, 0.3).is_err());
+        assert!(GeneralizedPareto::new(1.0, -2.0, 0.3).is_err());
+        assert!(GeneralizedPareto::new(1.0, 2.0, f64::NAN).is_err());
+        assert!(GeneralizedPareto::new(f64::NEG_INFINITY, 2.0, 0.3).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(0.3, 3.8571428571428570975, 1e-15, |x| x.mean().unwrap());
+        test_almost(0.3, 20.408163265306120669, 1e-13, |x| x.variance().unwrap());
+        test_almost(0.3, 16.443843832875567365, 1e-12, |x| x.skewness().unwrap());
+        test_almost(0.3, 1.9931471805599452983, 1e-15, |x| x.entropy().unwrap());
+        test_almost(-0.4, 2.4285714285714285488, 1e-15, |x| x.mean().unwrap());
+        test_almost(-0.4, 1.1337868480725622943, 1e-15, |x| x.variance().unwrap());
+        test_almost(-0.4, 0.73180406536356749679, 1e-15, |x| x.skewness().unwrap());
+        test_almost(0.0, 3.0, 1e-15, |x| x.mean().unwrap());
+        test_almost(0.0, 4.0, 1e-15, |x| x.variance().unwrap());
+        test_almost(0.0, 2.0, 1e-15, |x| x.skewness().unwrap());
+        assert!(try_create(1.0, 2.0, 1.0).mean().is_none());
+        assert!(try_create(1.0, 2.0, 0.5).variance().is_none());
+        assert!(try_create(1.0, 2.0, 0.4).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(0.3, 2.5409627556327752239, 1e-15, |x| x.median());
+        test_almost(-0.4, 2.2107085837240047852, 1e-15, |x| x.median());
+        test_almost(0.0, 2.3862943611198906188, 1e-15, |x| x.median());
+        test_almost(0.3, 1.0, 1e-15, |x| x.mode().unwrap());
+        test_almost(-2.0, 2.0, 1e-15, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_almost(0.3, 1.0, 1e-15, |x| x.min());
+        test_almost(-0.4, 6.0, 1e-15, |x| x.max());
+        assert_eq!(try_create(1.0, 2.0, 0.0).max(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(0.3, 0.36548256084694749834, 1e-15, |x| x.pdf(1.5));
+        test_almost(0.3, 0.065230405680721168253, 1e-16, |x| x.pdf(5.0));
+        test_almost(-0.4, 0.42690748412273121211, 1e-15, |x| x.pdf(1.5));
+        test_almost(-0.4, 0.044721359549995789022, 1e-15, |x| x.pdf(5.0));
+        test_almost(0.0, 0.1839397205857211608, 1e-15, |x| x.pdf(3.0));
+        test_almost(0.3, 0.0, 1e-15, |x| x.pdf(0.5));
+        test_almost(-0.4, 0.0, 1e-15, |x| x.pdf(7.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.3, 0.21421249417906288061, 1e-15, |x| x.cdf(1.5));
+        test_almost(0.3, 0.20873729817830773551, 1e-15, |x| x.sf(5.0));
+        test_almost(-0.4, 0.7211451990730659903, 1e-15, |x| x.cdf(3.0));
+        test_almost(-0.4, 0.017888543819998311637, 1e-16, |x| x.sf(5.0));
+        test_almost(0.0, 0.86466471676338730811, 1e-15, |x| x.cdf(5.0));
+        test_almost(0.3, 0.0, 1e-15, |x| x.cdf(0.5));
+        test_almost(-0.4, 1.0, 1e-15, |x| x.cdf(7.0));
+        test_almost(-0.4, 0.0, 1e-15, |x| x.sf(7.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(0.3, 1.2140866495212668995, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(0.3, 7.6350820997925305812, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(-0.4, 4.0094641472325136809, 1e-15, |x| x.inverse_sf(0.1));
+        test_almost(0.0, 5.605170185988091368, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(0.3, 1.0, 1e-15, |x| x.inverse_cdf(0.0));
+        test_almost(-0.4, 6.0, 1e-15, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 2.0, 0.3), 1.0, 400.0);
+        test::check_continuous_distribution(&try_create(1.0, 2.0, -0.4), 1.0, 6.0);
+        test::check_continuous_distribution(&try_create(1.0, 2.0, 0.0), 1.0, 40.0);
+    }
+}
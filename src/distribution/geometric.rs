@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{Discrete, DiscreteCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::OpenClosed01;
@@ -95,6 +95,21 @@ impl DiscreteCDF<u64, f64> for Geometric {
             1.0 - (1.0 - self.p).powf(x as f64)
         }
     }
+    /// Calculates the survival function for the geometric
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 - p) ^ x
+    /// ```
+    fn sf(&self, x: u64) -> f64 {
+        if x == 0 {
+            1.0
+        } else {
+            (x as f64 * (-self.p).ln_1p()).exp()
+        }
+    }
 }
 
 impl Min<u64> for Geometric {
@@ -240,12 +255,32 @@ impl Discrete<u64, f64> for Geometric {
     }
 }
 
+impl Fit<u64> for Geometric {
+    /// Fits a geometric distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p = n / Σ x_i
+    /// ```
+    fn fit(data: &[u64]) -> Result<Geometric> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.contains(&0) {
+            return Err(StatsError::ArgGte("data", 1.0));
+        }
+        let sum: u64 = data.iter().sum();
+        Geometric::new(data.len() as f64 / sum as f64)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{DiscreteCDF, Discrete, Geometric};
+    use crate::distribution::{DiscreteCDF, Discrete, Geometric, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -416,4 +451,22 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.6), 100);
         test::check_discrete_distribution(&try_create(1.0), 1);
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Geometric| x.sf(arg);
+        test_case(1.0, 1.0, sf(0));
+        test_case(1.0, 0.0, sf(1));
+        test_almost(0.5, 0.25, 1e-16, sf(2));
+        test_almost(0.5, 2f64.powi(-100), 1e-44, sf(100));
+        test_almost(0.1, 0.9f64.powi(5), 1e-15, sf(5));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Geometric::fit(&[1, 2, 3, 4, 5]).unwrap();
+        assert_almost_eq!(n.p(), 1.0 / 3.0, 1e-15);
+        assert!(Geometric::fit(&[]).is_err());
+        assert!(Geometric::fit(&[0, 1]).is_err());
+    }
 }
@@ -0,0 +1,538 @@
+use crate::consts;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [generalized extreme
+/// value](https://en.wikipedia.org/wiki/Generalized_extreme_value_distribution)
+/// distribution, which unifies the gumbel (`ξ = 0`), fréchet (`ξ > 0`) and
+/// reversed weibull (`ξ < 0`) families of limiting distributions of block
+/// maxima
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gev, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Gev::new(1.0, 2.0, 0.3).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 2.9870355509837185275, 1e-14));
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.14567616655276340082, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gev {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl Gev {
+    /// Constructs a new generalized extreme value distribution with
+    /// location `location`, scale `scale` and shape `shape`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gev;
+    ///
+    /// let mut result = Gev::new(1.0, 2.0, -0.4);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Gev::new(1.0, 0.0, 0.3);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<Gev> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Ok(Gev {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Returns the location of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gev;
+    ///
+    /// let n = Gev::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gev;
+    ///
+    /// let n = Gev::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape ξ of the generalized extreme value distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gev;
+    ///
+    /// let n = Gev::new(1.0, 2.0, 0.3).unwrap();
+    /// assert_eq!(n.shape(), 0.3);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `ln(t(x))` where `t(x) = (1 + ξz)^(-1 / ξ)`, or `e^(-z)` if
+    /// `ξ = 0`, for `z = (x - μ) / σ`. Returns `None` if `x` lies outside
+    /// the support.
+    fn ln_t(&self, x: f64) -> Option<f64> {
+        let z = (x - self.location) / self.scale;
+        if self.shape == 0.0 {
+            return Some(-z);
+        }
+        let s = self.shape * z;
+        if s <= -1.0 {
+            None
+        } else {
+            Some(-s.ln_1p() / self.shape)
+        }
+    }
+
+    /// Returns the `x` at which `ln(t(x))` equals `ln_t`, the inverse of
+    /// `ln_t`
+    fn invert_ln_t(&self, ln_t: f64) -> f64 {
+        if self.shape == 0.0 {
+            self.location - self.scale * ln_t
+        } else {
+            self.location + self.scale * (-self.shape * ln_t).exp_m1() / self.shape
+        }
+    }
+
+    /// Returns `Γ(1 - kξ)`, which is finite only if `kξ < 1`
+    fn gamma_moment(&self, k: f64) -> Option<f64> {
+        if k * self.shape >= 1.0 {
+            None
+        } else {
+            Some(gamma::gamma(1.0 - k * self.shape))
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Gev {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.invert_ln_t(super::ziggurat::sample_exp_1(rng).ln())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Gev {
+    /// Calculates the cumulative distribution function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-t(x))
+    /// t(x) = (1 + ξ (x - μ) / σ)^(-1 / ξ) if ξ != 0
+    /// t(x) = e^(-(x - μ) / σ)             if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn cdf(&self, x: f64) -> f64 {
+        match self.ln_t(x) {
+            Some(ln_t) => (-ln_t.exp()).exp(),
+            None if self.shape > 0.0 => 0.0,
+            None => 1.0,
+        }
+    }
+
+    /// Calculates the survival function for the generalized extreme value
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-t(x))
+    /// ```
+    ///
+    /// where `t` is as in the cdf
+    fn sf(&self, x: f64) -> f64 {
+        match self.ln_t(x) {
+            Some(ln_t) => -(-ln_t.exp()).exp_m1(),
+            None if self.shape > 0.0 => 1.0,
+            None => 0.0,
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// generalized extreme value distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ ((-ln(x))^(-ξ) - 1) / ξ if ξ != 0
+    /// μ - σ ln(-ln(x))              if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.invert_ln_t((-x.ln()).ln())
+    }
+
+    /// Calculates the inverse survival function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ ((-ln(1 - x))^(-ξ) - 1) / ξ if ξ != 0
+    /// μ - σ ln(-ln(1 - x))              if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.invert_ln_t((-(-x).ln_1p()).ln())
+    }
+}
+
+impl Min<f64> for Gev {
+    /// Returns the minimum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - σ / ξ if ξ > 0
+    /// -INF      otherwise
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn min(&self) -> f64 {
+        if self.shape > 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::NEG_INFINITY
+        }
+    }
+}
+
+impl Max<f64> for Gev {
+    /// Returns the maximum value in the domain of the generalized extreme
+    /// value distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - σ / ξ if ξ < 0
+    /// INF       otherwise
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn max(&self) -> f64 {
+        if self.shape < 0.0 {
+            self.location - self.scale / self.shape
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+impl Distribution<f64> for Gev {
+    /// Returns the mean of the generalized extreme value distribution,
+    /// which exists only if `ξ < 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ (g1 - 1) / ξ if ξ != 0
+    /// μ + σγ             if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, `ξ` is the shape,
+    /// `gk = Γ(1 - kξ)` and `γ` is the Euler-Mascheroni constant
+    fn mean(&self) -> Option<f64> {
+        if self.shape == 0.0 {
+            return Some(self.location + self.scale * consts::EULER_MASCHERONI);
+        }
+        let g1 = self.gamma_moment(1.0)?;
+        Some(self.location + self.scale * (g1 - 1.0) / self.shape)
+    }
+
+    /// Returns the variance of the generalized extreme value distribution,
+    /// which exists only if `ξ < 1 / 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// σ^2 (g2 - g1^2) / ξ^2 if ξ != 0
+    /// σ^2 π^2 / 6           if ξ == 0
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `gk = Γ(1 - kξ)`
+    fn variance(&self) -> Option<f64> {
+        if self.shape == 0.0 {
+            return Some(f64::consts::PI * f64::consts::PI * self.scale * self.scale / 6.0);
+        }
+        let g2 = self.gamma_moment(2.0)?;
+        let g1 = self.gamma_moment(1.0)?;
+        Some(self.scale * self.scale * (g2 - g1 * g1) / (self.shape * self.shape))
+    }
+
+    /// Returns the entropy of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(σ) + γξ + γ + 1
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `γ` is the
+    /// Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + consts::EULER_MASCHERONI * (self.shape + 1.0) + 1.0)
+    }
+
+    /// Returns the skewness of the generalized extreme value distribution,
+    /// which exists only if `ξ < 1 / 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sgn(ξ) (g3 - 3 g1 g2 + 2 g1^3) / (g2 - g1^2)^(3/2) if ξ != 0
+    /// 12 sqrt(6) ζ(3) / π^3                              if ξ == 0
+    /// ```
+    ///
+    /// where `ξ` is the shape, `gk = Γ(1 - kξ)` and `ζ` is the Riemann zeta
+    /// function
+    fn skewness(&self) -> Option<f64> {
+        if self.shape == 0.0 {
+            return Some(super::gumbel::SKEWNESS);
+        }
+        let g3 = self.gamma_moment(3.0)?;
+        let g2 = self.gamma_moment(2.0)?;
+        let g1 = self.gamma_moment(1.0)?;
+        let skew = (g3 - 3.0 * g1 * g2 + 2.0 * g1 * g1 * g1) / (g2 - g1 * g1).powf(1.5);
+        Some(skew.copysign(self.shape))
+    }
+}
+
+impl Median<f64> for Gev {
+    /// Returns the median of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ (ln(2)^(-ξ) - 1) / ξ if ξ != 0
+    /// μ - σ ln(ln(2))            if ξ == 0
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn median(&self) -> f64 {
+        self.invert_ln_t(f64::consts::LN_2.ln())
+    }
+}
+
+impl Mode<Option<f64>> for Gev {
+    /// Returns the mode of the generalized extreme value distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + σ ((1 + ξ)^(-ξ) - 1) / ξ if ξ > -1, ξ != 0
+    /// μ                            if ξ == 0
+    /// μ - σ / ξ                    if ξ <= -1
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale and `ξ` is the shape
+    fn mode(&self) -> Option<f64> {
+        if self.shape <= -1.0 {
+            Some(self.max())
+        } else {
+            Some(self.invert_ln_t((1.0 + self.shape).ln()))
+        }
+    }
+}
+
+impl Continuous<f64, f64> for Gev {
+    /// Calculates the probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / σ) t(x)^(ξ + 1) e^(-t(x))
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `t` is as in the cdf
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the generalized
+    /// extreme value distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(σ) + (ξ + 1) ln(t(x)) - t(x)
+    /// ```
+    ///
+    /// where `σ` is the scale, `ξ` is the shape and `t` is as in the cdf
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        match self.ln_t(x) {
+            Some(ln_t) => -self.scale.ln() + (self.shape + 1.0) * ln_t - ln_t.exp(),
+            None => f64::NEG_INFINITY,
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Gev};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> Gev {
+        let n = Gev::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Gev) -> f64
+    {
+        let x = eval(try_create(1.0, 2.0, shape));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(1.0, 2.0, 0.3);
+        try_create(1.0, 2.0, 0.0);
+        try_create(1.0, 2.0, -5.0);
+        assert!(Gev::new(1.0, 0.0, 0.3).is_err());
+        assert!(Gev::new(1.0, -2.0, 0.3).is_err());
+        assert!(Gev::new(1.0, 2.0, f64::NAN).is_err());
+        assert!(Gev::new(f64::INFINITY, 2.0, 0.3).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(0.3, 2.9870355509837185275, 1e-14, |x| x.mean().unwrap());
+        test_almost(0.3, 23.69830653968561265, 1e-12, |x| x.variance().unwrap());
+        test_almost(0.3, 13.483552403221148984, 1e-10, |x| x.skewness().unwrap());
+        test_almost(0.3, 2.4435275449319380218, 1e-15, |x| x.entropy().unwrap());
+        test_almost(-0.4, 1.5636809124846235286, 1e-14, |x| x.mean().unwrap());
+        test_almost(-0.4, 3.6036672282528051219, 1e-13, |x| x.variance().unwrap());
+        test_almost(-0.4, -0.35863184235012706675, 1e-12, |x| x.skewness().unwrap());
+        test_almost(-0.4, 2.039476579500865013, 1e-15, |x| x.entropy().unwrap());
+        test_almost(0.0, 2.1544313298030657212, 1e-15, |x| x.mean().unwrap());
+        test_almost(0.0, 6.5797362673929057459, 1e-14, |x| x.variance().unwrap());
+        test_almost(0.0, 1.1395470994046486575, 1e-15, |x| x.skewness().unwrap());
+        assert!(try_create(1.0, 2.0, 1.0).mean().is_none());
+        assert!(try_create(1.0, 2.0, 0.5).variance().is_none());
+        assert!(try_create(1.0, 2.0, 0.4).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(0.3, 1.7748438975420665118, 1e-15, |x| x.median());
+        test_almost(-0.4, 1.6818254969881258086, 1e-15, |x| x.median());
+        test_almost(0.0, 1.733025841163328654, 1e-15, |x| x.median());
+        test_almost(0.3, 0.49539067329719216705, 1e-15, |x| x.mode().unwrap());
+        test_almost(-0.4, 1.9240344519703863294, 1e-15, |x| x.mode().unwrap());
+        test_almost(0.0, 1.0, 1e-15, |x| x.mode().unwrap());
+        test_almost(-2.0, 2.0, 1e-15, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_min_max() {
+        test_almost(0.5, -3.0, 1e-15, |x| x.min());
+        test_almost(-0.5, 5.0, 1e-15, |x| x.max());
+        assert_eq!(try_create(1.0, 2.0, 0.5).max(), f64::INFINITY);
+        assert_eq!(try_create(1.0, 2.0, 0.0).min(), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(0.3, 0.1916513062382621998, 1e-15, |x| x.pdf(0.5));
+        test_almost(0.3, 0.074790721259622356143, 1e-16, |x| x.pdf(4.0));
+        test_almost(-0.4, 0.20183678368062777597, 1e-15, |x| x.pdf(2.0));
+        test_almost(-0.4, 0.11431743718741464653, 1e-15, |x| x.pdf(4.0));
+        test_almost(0.0, 0.16535214944520903387, 1e-15, |x| x.pdf(2.0));
+        test_almost(0.3, 0.0, 1e-15, |x| x.pdf(-6.0));
+        test_almost(-0.4, 0.0, 1e-15, |x| x.pdf(7.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(0.3, 0.27341517889313142779, 1e-15, |x| x.cdf(0.5));
+        test_almost(0.3, 0.25159089891012845311, 1e-15, |x| x.sf(4.0));
+        test_almost(-0.4, 0.5641509608372548817, 1e-15, |x| x.cdf(2.0));
+        test_almost(-0.4, 0.096241305536341843109, 1e-15, |x| x.sf(4.0));
+        test_almost(0.0, 0.80001071300435359003, 1e-15, |x| x.cdf(4.0));
+        test_almost(0.3, 0.0, 1e-15, |x| x.cdf(-6.0));
+        test_almost(0.3, 1.0, 1e-15, |x| x.sf(-6.0));
+        test_almost(-0.4, 1.0, 1e-15, |x| x.cdf(7.0));
+        test_almost(-0.4, 0.0, 1e-15, |x| x.sf(7.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(0.3, -0.47575026981833233327, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(0.3, 7.4283294718795125437, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(-0.4, -0.98001377685142720493, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(-0.4, 3.9674503676356749766, 1e-14, |x| x.inverse_sf(0.1));
+        test_almost(0.0, 5.5007346546248905727, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(0.5, -3.0, 1e-15, |x| x.inverse_cdf(0.0));
+        test_almost(-0.5, 5.0, 1e-15, |x| x.inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 2.0, 0.3), -3.0, 200.0);
+        test::check_continuous_distribution(&try_create(1.0, 2.0, -0.4), -20.0, 6.0);
+        test::check_continuous_distribution(&try_create(1.0, 2.0, 0.0), -10.0, 40.0);
+    }
+}
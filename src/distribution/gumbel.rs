@@ -0,0 +1,387 @@
+use crate::consts;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// The skewness `12 sqrt(6) ζ(3) / π^3` of the gumbel distribution, where
+/// `ζ` is the Riemann zeta function
+pub const SKEWNESS: f64 = 1.1395470994046486574927930193898461120875997958366;
+
+/// Implements the [Gumbel](https://en.wikipedia.org/wiki/Gumbel_distribution)
+/// distribution, the limiting distribution of the maximum of samples from
+/// light-tailed distributions
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Gumbel, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = Gumbel::new(1.0, 2.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 2.1544313298030657212, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(3.0), 0.12732319002179124791, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Gumbel {
+    location: f64,
+    scale: f64,
+}
+
+impl Gumbel {
+    /// Constructs a new gumbel distribution with location `location` and
+    /// scale `scale`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `location` or `scale` are `NaN` or infinite, or
+    /// if `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let mut result = Gumbel::new(1.0, 2.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Gumbel::new(1.0, 0.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64) -> Result<Gumbel> {
+        if !location.is_finite() || !scale.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Ok(Gumbel { location, scale })
+    }
+
+    /// Returns the location of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the gumbel distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Gumbel;
+    ///
+    /// let n = Gumbel::new(1.0, 2.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Gumbel {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.location - self.scale * super::ziggurat::sample_exp_1(rng).ln()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Gumbel {
+    /// Calculates the cumulative distribution function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(-e^(-(x - μ) / β))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn cdf(&self, x: f64) -> f64 {
+        (-(-(x - self.location) / self.scale).exp()).exp()
+    }
+
+    /// Calculates the survival function for the gumbel distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - e^(-e^(-(x - μ) / β))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        -(-(-(x - self.location) / self.scale).exp()).exp_m1()
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// gumbel distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β ln(-ln(x))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.location - self.scale * (-x.ln()).ln()
+    }
+
+    /// Calculates the inverse survival function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β ln(-ln(1 - x))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        self.location - self.scale * (-(-x).ln_1p()).ln()
+    }
+}
+
+impl Min<f64> for Gumbel {
+    /// Returns the minimum value in the domain of the gumbel distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for Gumbel {
+    /// Returns the maximum value in the domain of the gumbel distribution
+    /// representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for Gumbel {
+    /// Returns the mean of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + βγ
+    /// ```
+    ///
+    /// where `μ` is the location, `β` is the scale and `γ` is the
+    /// Euler-Mascheroni constant
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * consts::EULER_MASCHERONI)
+    }
+
+    /// Returns the variance of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// π^2 β^2 / 6
+    /// ```
+    ///
+    /// where `β` is the scale
+    fn variance(&self) -> Option<f64> {
+        Some(f64::consts::PI * f64::consts::PI * self.scale * self.scale / 6.0)
+    }
+
+    /// Returns the entropy of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(β) + γ + 1
+    /// ```
+    ///
+    /// where `β` is the scale and `γ` is the Euler-Mascheroni constant
+    fn entropy(&self) -> Option<f64> {
+        Some(self.scale.ln() + consts::EULER_MASCHERONI + 1.0)
+    }
+
+    /// Returns the skewness of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 12 sqrt(6) ζ(3) / π^3
+    /// ```
+    ///
+    /// where `ζ` is the Riemann zeta function
+    fn skewness(&self) -> Option<f64> {
+        Some(SKEWNESS)
+    }
+}
+
+impl Median<f64> for Gumbel {
+    /// Returns the median of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - β ln(ln(2))
+    /// ```
+    ///
+    /// where `μ` is the location and `β` is the scale
+    fn median(&self) -> f64 {
+        self.location - self.scale * f64::consts::LN_2.ln()
+    }
+}
+
+impl Mode<Option<f64>> for Gumbel {
+    /// Returns the mode of the gumbel distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn mode(&self) -> Option<f64> {
+        Some(self.location)
+    }
+}
+
+impl Continuous<f64, f64> for Gumbel {
+    /// Calculates the probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / β) e^(-(z + e^(-z)))
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the gumbel
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -ln(β) - z - e^(-z)
+    /// ```
+    ///
+    /// where `z = (x - μ) / β`, `μ` is the location and `β` is the scale
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        -self.scale.ln() - z - (-z).exp()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Gumbel};
+    use crate::distribution::internal::*;
+
+    fn try_create(location: f64, scale: f64) -> Gumbel {
+        let n = Gumbel::new(location, scale);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(location: f64, scale: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(Gumbel) -> f64
+    {
+        let x = eval(try_create(location, scale));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(1.0, 2.0);
+        try_create(-10.0, 0.1);
+        assert!(Gumbel::new(1.0, 0.0).is_err());
+        assert!(Gumbel::new(1.0, -2.0).is_err());
+        assert!(Gumbel::new(f64::NAN, 2.0).is_err());
+        assert!(Gumbel::new(1.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(1.0, 2.0, 2.1544313298030657212, 1e-15, |x| x.mean().unwrap());
+        test_almost(1.0, 2.0, 6.5797362673929057459, 1e-14, |x| x.variance().unwrap());
+        test_almost(1.0, 2.0, 2.27036284546147817, 1e-15, |x| x.entropy().unwrap());
+        test_almost(1.0, 2.0, 1.1395470994046486575, 1e-15, |x| x.skewness().unwrap());
+        test_almost(1.0, 2.0, 1.733025841163328654, 1e-15, |x| x.median());
+        test_almost(1.0, 2.0, 1.0, 1e-15, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(1.0, 2.0, 0.089687039367008590981, 1e-16, |x| x.pdf(-1.0));
+        test_almost(1.0, 2.0, 0.12732319002179124791, 1e-16, |x| x.pdf(3.0));
+        test_almost(1.0, 2.0, 0.0054931348412014012139, 1e-17, |x| x.pdf(10.0));
+        test_almost(1.0, 2.0, 0.0054931348412014012139f64.ln(), 1e-14, |x| x.ln_pdf(10.0));
+        test_almost(1.0, 2.0, 0.0, 1e-15, |x| x.pdf(-100.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(1.0, 2.0, 0.065988035845312537077, 1e-16, |x| x.cdf(-1.0));
+        test_almost(1.0, 2.0, 0.69220062755534635387, 1e-15, |x| x.cdf(3.0));
+        test_almost(1.0, 2.0, 0.93401196415468746292, 1e-15, |x| x.sf(-1.0));
+        test_almost(1.0, 2.0, 0.011047519496204849988, 1e-17, |x| x.sf(10.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(1.0, 2.0, -0.66806489049591159961, 1e-15, |x| x.inverse_cdf(0.1));
+        test_almost(1.0, 2.0, 5.5007346546248905727, 1e-14, |x| x.inverse_cdf(0.9));
+        test_almost(1.0, 2.0, 5.5007346546248905727, 1e-14, |x| x.inverse_sf(0.1));
+        test_almost(1.0, 2.0, -0.66806489049591159961, 1e-14, |x| x.inverse_sf(0.9));
+        assert_eq!(try_create(1.0, 2.0).inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(try_create(1.0, 2.0).inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 2.0), -10.0, 40.0);
+    }
+}
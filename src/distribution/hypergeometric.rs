@@ -99,6 +99,74 @@ impl Hypergeometric {
         self.draws
     }
 
+    /// Fits a hypergeometric distribution with a known `population` and
+    /// number of `draws` to `data` by maximum likelihood, estimating the
+    /// number of successes
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ℓ(K + 1) - ℓ(K) = Σ ln((K + 1) * (N - K - n + x_i) / ((K + 1 - x_i) * (N - K)))
+    /// ```
+    ///
+    /// where `ℓ` is the log-likelihood, `N` is population, `K` is successes,
+    /// and `n` is draws. The increments decrease in `K`, so the estimate is
+    /// the first feasible `K` at which the increment turns negative and is
+    /// found by bisection. For a single sample `x` this is
+    /// `floor((N + 1) * x / n)`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if `draws > population`, if any
+    /// sample is greater than `draws`, or if no number of successes is
+    /// consistent with every sample
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Hypergeometric;
+    ///
+    /// let n = Hypergeometric::fit_with_draws(50, 10, &[3]).unwrap();
+    /// assert_eq!(n.successes(), 15);
+    /// ```
+    pub fn fit_with_draws(population: u64, draws: u64, data: &[u64]) -> Result<Hypergeometric> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if draws > population {
+            return Err(StatsError::BadParams);
+        }
+        if data.iter().any(|&x| x > draws) {
+            return Err(StatsError::ArgLte("data", draws as f64));
+        }
+        let x_max = *data.iter().max().unwrap();
+        let x_min = *data.iter().min().unwrap();
+        let mut lo = x_max;
+        let mut hi = population - (draws - x_min);
+        if lo > hi {
+            return Err(StatsError::BadParams);
+        }
+        let (pop, n) = (population as f64, draws as f64);
+        let increment = |k: u64| {
+            let k = k as f64;
+            data.iter()
+                .map(|&x| {
+                    let x = x as f64;
+                    (-(n - x) / (pop - k)).ln_1p() - (-x / (k + 1.0)).ln_1p()
+                })
+                .sum::<f64>()
+        };
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if increment(mid) < 0.0 {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        Hypergeometric::new(population, lo, draws)
+    }
+
     /// Returns population, successes, and draws in that order
     /// as a tuple of doubles
     fn values_f64(&self) -> (f64, f64, f64) {
@@ -164,6 +232,31 @@ impl DiscreteCDF<u64, f64> for Hypergeometric {
             })
         }
     }
+    /// Calculates the survival function for the hypergeometric
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sum((K choose i) * (N-K choose n-i) / (N choose n)) for i in x+1..=max
+    /// ```
+    ///
+    /// where `N` is population, `K` is successes, and `n` is draws
+    fn sf(&self, x: u64) -> f64 {
+        if x < self.min() {
+            1.0
+        } else if x >= self.max() {
+            0.0
+        } else {
+            let ln_denom = factorial::ln_binomial(self.population, self.draws);
+            (x + 1..self.max() + 1).fold(0.0, |acc, i| {
+                acc + (factorial::ln_binomial(self.successes, i)
+                    + factorial::ln_binomial(self.population - self.successes, self.draws - i)
+                    - ln_denom)
+                    .exp()
+            })
+        }
+    }
 }
 
 impl Min<u64> for Hypergeometric {
@@ -530,4 +623,28 @@ mod tests {
         test::check_discrete_distribution(&try_create(5, 4, 3), 4);
         test::check_discrete_distribution(&try_create(3, 2, 1), 2);
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Hypergeometric| x.sf(arg);
+        test_almost(50, 10, 20, 0.00050850998323383604399, 1e-17, sf(8));
+        test_case(50, 10, 20, 0.0, sf(10));
+        test_almost(10, 5, 3, 0.5, 1e-15, sf(1));
+    }
+
+    #[test]
+    fn test_fit_with_draws() {
+        let fit = |population, draws, data: &[u64]| {
+            Hypergeometric::fit_with_draws(population, draws, data).unwrap().successes()
+        };
+        assert_eq!(fit(50, 10, &[3]), 15);
+        assert_eq!(fit(50, 10, &[3, 5, 2, 4, 1]), 15);
+        assert_eq!(fit(1000, 40, &[7, 12, 9, 10]), 237);
+        assert_eq!(fit(20, 5, &[0, 0]), 0);
+        assert_eq!(fit(20, 5, &[5, 5]), 20);
+        assert!(Hypergeometric::fit_with_draws(50, 10, &[]).is_err());
+        assert!(Hypergeometric::fit_with_draws(5, 10, &[3]).is_err());
+        assert!(Hypergeometric::fit_with_draws(50, 10, &[11]).is_err());
+        assert!(Hypergeometric::fit_with_draws(5, 4, &[0, 4]).is_err());
+    }
 }
@@ -12,9 +12,216 @@ pub fn is_valid_multinomial(arr: &[f64], incl_zero: bool) -> bool {
     sum != 0.0
 }
 
+/// Finds a root of the function `f`, which must be positive for small
+/// positive arguments and negative for large ones, such as a likelihood
+/// equation in a positive parameter. Starting from `x0 > 0` the root is
+/// bracketed by repeated halving and doubling, then located by bisection on a
+/// logarithmic scale to full precision.
+///
+/// Returns `None` if no sign change is found in `(0, +inf)`
+pub fn solve_decreasing<F: Fn(f64) -> f64>(f: F, x0: f64) -> Option<f64> {
+    let mut lo = x0;
+    let mut hi = x0;
+    while f(lo) <= 0.0 {
+        lo /= 2.0;
+        if lo == 0.0 {
+            return None;
+        }
+    }
+    while f(hi) >= 0.0 {
+        hi *= 2.0;
+        if hi.is_infinite() {
+            return None;
+        }
+    }
+    loop {
+        let mid = lo.sqrt() * hi.sqrt();
+        if mid <= lo || mid >= hi {
+            return Some(mid);
+        }
+        if f(mid) > 0.0 {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+/// Finds the point in `[min, max]` at which the monotone predicate `below`
+/// changes from true to false, such as `|x| dist.cdf(x) < p` when inverting
+/// a cdf. Infinite bounds are first replaced by finite ones found by repeated
+/// doubling, then the point is located by bisection to full precision.
+pub fn bisect_support<F: Fn(f64) -> bool>(below: F, min: f64, max: f64) -> f64 {
+    let mut lo = min;
+    if lo.is_infinite() {
+        lo = -1.0;
+        while !below(lo) {
+            lo *= 2.0;
+            if lo.is_infinite() {
+                return lo;
+            }
+        }
+    }
+    let mut hi = max;
+    if hi.is_infinite() {
+        hi = lo.abs().max(1.0);
+        while below(hi) {
+            hi *= 2.0;
+            if hi.is_infinite() {
+                return hi;
+            }
+        }
+    }
+    loop {
+        let mid = lo + 0.5 * (hi - lo);
+        if mid <= lo || mid >= hi {
+            return mid;
+        }
+        if below(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+/// Finds the point in `[min, max]` at which the increasing function `f`
+/// changes sign, such as a log tail probability less its target when a cdf
+/// is inverted in log space. Starting from the estimate `x0`, the root is
+/// bracketed by steps of doubling length and then located to full precision
+/// by regula falsi with the Illinois modification, falling back to
+/// bisection whenever the interpolation leaves the bracket.
+///
+/// Returns the bound of the interval if `f` does not change sign in it
+pub fn solve_increasing<F: Fn(f64) -> f64>(f: F, min: f64, max: f64, x0: f64) -> f64 {
+    let x0 = x0.max(min).min(max);
+    let f0 = f(x0);
+    if f0 == 0.0 {
+        return x0;
+    }
+    let up = f0 < 0.0;
+    let mut step = x0.abs().max(1.0) * 1e-6;
+    let (mut near, mut f_near) = (x0, f0);
+    let (far, f_far) = loop {
+        let x = if up {
+            (near + step).min(max)
+        } else {
+            (near - step).max(min)
+        };
+        let fx = f(x);
+        if fx == 0.0 || (fx < 0.0) != up {
+            break (x, fx);
+        }
+        if x == min || x == max {
+            return x;
+        }
+        near = x;
+        f_near = fx;
+        step *= 2.0;
+    };
+    let (mut lo, mut hi, mut f_lo, mut f_hi) = if up {
+        (near, far, f_near, f_far)
+    } else {
+        (far, near, f_far, f_near)
+    };
+    let (mut g_lo, mut g_hi) = (f_lo, f_hi);
+    let mut side = 0;
+    loop {
+        if g_lo == 0.0 {
+            return lo;
+        }
+        if g_hi == 0.0 {
+            return hi;
+        }
+        let mut x = lo - f_lo * (hi - lo) / (f_hi - f_lo);
+        if !(x > lo && x < hi) {
+            x = lo + 0.5 * (hi - lo);
+        }
+        if x <= lo || x >= hi {
+            return if -g_lo < g_hi { lo } else { hi };
+        }
+        let fx = f(x);
+        if fx < 0.0 {
+            lo = x;
+            f_lo = fx;
+            g_lo = fx;
+            if side < 0 {
+                f_hi *= 0.5;
+            }
+            side = -1;
+        } else {
+            hi = x;
+            f_hi = fx;
+            g_hi = fx;
+            if side > 0 {
+                f_lo *= 0.5;
+            }
+            side = 1;
+        }
+    }
+}
+
+/// Finds the point in `[min, max]` at which the increasing function `f`
+/// changes sign by Newton's method from the estimate `x0`, where `f` returns
+/// its value together with its derivative, such as a log tail probability
+/// whose derivative follows from the density. A step that leaves the bracket
+/// formed by the iterates so far is replaced by bisection, or by a step of
+/// doubling length while the root is bracketed on one side only. Once the
+/// root is bracketed on both sides, the iteration also stops when a Newton
+/// step fails to shrink, which happens when rounding in `f` dominates.
+///
+/// Converges to the bound of the interval if `f` does not change sign in it
+pub fn newton_increasing<F: Fn(f64) -> (f64, f64)>(f: F, min: f64, max: f64, x0: f64) -> f64 {
+    let (mut lo, mut hi) = (min, max);
+    let (mut below, mut above) = (false, false);
+    let mut x = x0.max(min).min(max);
+    let mut step = x.abs().max(1.0);
+    let mut last = f64::INFINITY;
+    for _ in 0..100 {
+        let (fx, dfx) = f(x);
+        if fx == 0.0 {
+            return x;
+        }
+        if fx < 0.0 {
+            lo = x;
+            below = true;
+        } else {
+            hi = x;
+            above = true;
+        }
+        let mut next = x - fx / dfx;
+        if (next - x).abs() <= 2.0 * f64::EPSILON * x.abs() {
+            return next;
+        }
+        if next > lo && next < hi {
+            let delta = (next - x).abs();
+            if below && above && delta >= last {
+                return next;
+            }
+            last = delta;
+        } else {
+            next = if lo.is_finite() && hi.is_finite() {
+                lo + 0.5 * (hi - lo)
+            } else if fx < 0.0 {
+                (x + step).min(hi)
+            } else {
+                (x - step).max(lo)
+            };
+            step *= 2.0;
+        }
+        if next.is_infinite() || (next - x).abs() <= 2.0 * f64::EPSILON * next.abs() {
+            return next;
+        }
+        x = next;
+    }
+    x
+}
+
 #[cfg(test)]
 pub mod test {
-    use super::is_valid_multinomial;
+    use super::{
+        bisect_support, is_valid_multinomial, newton_increasing, solve_decreasing, solve_increasing,
+    };
     use crate::consts::ACC;
     use crate::distribution::{Continuous, ContinuousCDF, Discrete, DiscreteCDF};
 
@@ -195,4 +402,81 @@ pub mod test {
         let invalid = [5.2, 0.0, 1e-15, 1000000.12];
         assert!(!is_valid_multinomial(&invalid, false));
     }
+
+    #[test]
+    fn test_solve_decreasing() {
+        let root = solve_decreasing(|x| 2.0 - x * x, 1.0).unwrap();
+        assert_almost_eq!(root, 2f64.sqrt(), 1e-15);
+        let root = solve_decreasing(|x| 1e-200 - x, 1.0).unwrap();
+        assert_almost_eq!(root, 1e-200, 1e-215);
+        let root = solve_decreasing(|x| 1e200 - x, 1.0).unwrap();
+        assert_almost_eq!(root, 1e200, 1e185);
+        assert!(solve_decreasing(|x| 1.0 / x, 1.0).is_none());
+        assert!(solve_decreasing(|x| -x, 1.0).is_none());
+    }
+
+    #[test]
+    fn test_bisect_support() {
+        let x = bisect_support(|x| x * x < 2.0, 0.0, 2.0);
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        let x = bisect_support(|x| x < -1e10, f64::NEG_INFINITY, f64::INFINITY);
+        assert_almost_eq!(x, -1e10, 1e-5);
+        let x = bisect_support(|x| x < 3.5e5, 0.0, f64::INFINITY);
+        assert_almost_eq!(x, 3.5e5, 1e-10);
+        assert_eq!(bisect_support(|_| true, 0.0, f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_solve_increasing() {
+        let inf = f64::INFINITY;
+        let x = solve_increasing(|x| x * x - 2.0, 0.0, inf, 1.0);
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        let x = solve_increasing(|x| x * x - 2.0, 0.0, inf, 1e10);
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        let x = solve_increasing(|x| x.ln() + 700.0, 0.0, inf, 1.0);
+        assert_almost_eq!(x, (-700f64).exp(), 1e-318);
+        let x = solve_increasing(|x| x.powi(3) - 1e-30, -inf, inf, -5.0);
+        assert_almost_eq!(x, 1e-10, 1e-24);
+        let x = solve_increasing(|x| (x - 3.0).signum(), -inf, inf, 0.0);
+        assert_almost_eq!(x, 3.0, 1e-15);
+        assert_eq!(solve_increasing(|x| x - 5.0, 0.0, 2.0, 1.0), 2.0);
+        assert_eq!(solve_increasing(|x| x + 5.0, 0.0, 2.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_newton_increasing() {
+        let inf = f64::INFINITY;
+        let x = newton_increasing(|x| (x * x - 2.0, 2.0 * x), 0.0, inf, 1.0);
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        let x = newton_increasing(|x| (x * x - 2.0, 2.0 * x), 0.0, inf, 1e10);
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        let x = newton_increasing(|x| (x.ln() + 7.0, 1.0 / x), 0.0, inf, 1.0);
+        assert_almost_eq!(x, (-7f64).exp(), 1e-17);
+        let x = newton_increasing(|x| (x.atan() - 1.5, 1.0 / (1.0 + x * x)), -inf, inf, -5.0);
+        assert_almost_eq!(x, 1.5f64.tan(), 1e-13);
+        // the iterates approach the root from above, so a final step below
+        // the rounding of `x` must end the iteration rather than bisect
+        let calls = std::cell::Cell::new(0);
+        let x = newton_increasing(
+            |x| {
+                calls.set(calls.get() + 1);
+                (x * x - 2.0, 2.0 * x)
+            },
+            0.0,
+            inf,
+            2.0,
+        );
+        assert_almost_eq!(x, 2f64.sqrt(), 1e-15);
+        assert!(calls.get() <= 8);
+        assert_almost_eq!(
+            newton_increasing(|x| (x - 5.0, 1.0), 0.0, 2.0, 1.0),
+            2.0,
+            1e-15
+        );
+        assert_almost_eq!(
+            newton_increasing(|x| (x + 5.0, 1.0), 0.0, 2.0, 1.0),
+            0.0,
+            1e-15
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit, Gamma};
 use crate::function::gamma;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -115,6 +115,71 @@ impl ContinuousCDF<f64, f64> for InverseGamma {
             gamma::gamma_ur(self.shape, self.rate / x)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// inverse gamma distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// β / Q^-1(α, x)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `Q^-1` is the inverse
+    /// of the upper regularized incomplete gamma function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.rate / gamma::inv_gamma_ur(self.shape, x)
+        }
+    }
+    /// Calculates the survival function for the inverse gamma
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// γ(α, β / x) / Γ(α)
+    /// ```
+    ///
+    /// where the numerator is the lower incomplete gamma function,
+    /// the denominator is the gamma function, `α` is the shape,
+    /// and `β` is the rate
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            gamma::gamma_lr(self.shape, self.rate / x)
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// inverse gamma distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// β / P^-1(α, x)
+    /// ```
+    ///
+    /// where `α` is the shape, `β` is the rate, and `P^-1` is the inverse
+    /// of the lower regularized incomplete gamma function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.rate / gamma::inv_gamma_lr(self.shape, x)
+        }
+    }
 }
 
 impl Min<f64> for InverseGamma {
@@ -279,11 +344,25 @@ impl Continuous<f64, f64> for InverseGamma {
     }
 }
 
+impl Fit<f64> for InverseGamma {
+    /// Fits an inverse gamma distribution to `data` by maximum likelihood,
+    /// using that the reciprocals `1 / x_i` follow a gamma distribution with
+    /// the same shape and rate
+    fn fit(data: &[f64]) -> Result<InverseGamma> {
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let recip: Vec<f64> = data.iter().map(|x| x.recip()).collect();
+        let g = Gamma::fit(&recip)?;
+        InverseGamma::new(g.shape(), g.rate())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, InverseGamma};
+    use crate::distribution::{ContinuousCDF, Continuous, InverseGamma, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -445,4 +524,44 @@ mod tests {
         test::check_continuous_distribution(&try_create(1.0, 0.5), 0.0, 100.0);
         test::check_continuous_distribution(&try_create(9.0, 2.0), 0.0, 100.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: InverseGamma| x.inverse_cdf(arg);
+        test_case(3.0, 2.0, 0.0, inverse_cdf(0.0));
+        test_almost(3.0, 2.0, 0.37577595354094223283, 1e-14, inverse_cdf(0.1));
+        test_almost(3.0, 2.0, 0.74792628638022429618, 1e-14, inverse_cdf(0.5));
+        test_almost(3.0, 2.0, 1.8147744500565021719, 1e-13, inverse_cdf(0.9));
+        test_almost(3.0, 2.0, 0.0083146446159641735809, 1e-17, inverse_cdf(1e-100));
+        test_case(3.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: InverseGamma| x.sf(arg);
+        test_case(3.0, 2.0, 1.0, sf(0.0));
+        test_almost(3.0, 2.0, 0.9, 1e-15, sf(0.37577595354094223283));
+        test_almost(3.0, 2.0, 0.5, 1e-14, sf(0.74792628638022429618));
+        test_case(3.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: InverseGamma| x.inverse_sf(arg);
+        test_almost(3.0, 2.0, 2370.7621608118587591, 1e-9, inverse_sf(1e-10));
+        test_almost(3.0, 2.0, 0.37577595354094223283, 1e-14, inverse_sf(0.9));
+    }
+
+    #[test]
+    fn test_fit() {
+        let data = [1.0 / 0.5, 1.0 / 1.2, 1.0 / 2.3, 1.0 / 0.8, 1.0 / 3.1, 1.0 / 1.7, 1.0 / 0.9, 1.0 / 2.6];
+        let n = InverseGamma::fit(&data).unwrap();
+        assert_almost_eq!(n.shape(), 3.15480971721502416559882090877, 1e-12);
+        assert_almost_eq!(n.rate(), 1.92660135402444223853363108933, 1e-12);
+        let n = InverseGamma::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_almost_eq!(n.shape(), 2.90262516987000633350537203879, 1e-12);
+        assert_almost_eq!(n.rate(), 3.35337862095440553454592328647, 1e-12);
+        assert!(InverseGamma::fit(&[]).is_err());
+        assert!(InverseGamma::fit(&[1.0, -1.0]).is_err());
+    }
 }
@@ -0,0 +1,346 @@
+use crate::distribution::ContinuousCDF;
+use crate::function::kolmogorov;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use nalgebra::DMatrix;
+use rand::Rng;
+use std::f64;
+
+/// Implements the distribution of the two-sided one-sample
+/// [Kolmogorov-Smirnov](https://en.wikipedia.org/wiki/Kolmogorov%E2%80%93Smirnov_test)
+/// statistic `D_n = sup |F_n(x) - F(x)|` for a sample of size `n` drawn
+/// from a continuous distribution `F`
+///
+/// The cdf is computed exactly with the algorithm of Marsaglia, Tsang and
+/// Wang (2003) for `n <= 500` and from the limiting Kolmogorov distribution
+/// of `√n D_n` otherwise. Upper tail probabilities use the exact one-sided
+/// distribution of Birnbaum and Tingey where it dominates the result.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ContinuousCDF, Kolmogorov};
+/// use statrs::prec;
+///
+/// let n = Kolmogorov::new(10).unwrap();
+/// assert!(prec::almost_eq(n.cdf(0.274), 0.6284796154565043, 1e-14));
+/// // the critical value of the test at the 5% level
+/// assert!(prec::almost_eq(n.inverse_sf(0.05), 0.40925, 1e-5));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Kolmogorov {
+    n: u64,
+}
+
+/// The largest sample size for which the cdf is computed exactly
+const EXACT_MAX_N: u64 = 500;
+
+impl Kolmogorov {
+    /// Constructs a new Kolmogorov-Smirnov statistic distribution for
+    /// samples of size `n`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `n` is zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Kolmogorov;
+    ///
+    /// let mut result = Kolmogorov::new(10);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Kolmogorov::new(0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(n: u64) -> Result<Kolmogorov> {
+        if n == 0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(Kolmogorov { n })
+        }
+    }
+
+    /// Returns the sample size `n` of the Kolmogorov-Smirnov statistic
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::Kolmogorov;
+    ///
+    /// let n = Kolmogorov::new(10).unwrap();
+    /// assert_eq!(n.n(), 10);
+    /// ```
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns `(cdf, sf)` at `x` in the interior of the support, each
+    /// computed in the way that is accurate for it
+    fn cdf_sf(&self, x: f64) -> (f64, f64) {
+        let n = self.n as f64;
+        if x >= 0.5 || n * x * x >= 6.0 {
+            // the events D_n^+ >= x and D_n^- >= x are (nearly) disjoint,
+            // their joint probability being below the rounding of the sf
+            // once n x^2 >= 6, and computing the tail directly avoids the
+            // cancellation in 1 - cdf
+            let sf = (2.0 * kolmogorov::smirnov_sf(self.n, x)).min(1.0);
+            (1.0 - sf, sf)
+        } else if self.n <= EXACT_MAX_N {
+            let cdf = mtw_cdf(self.n, x);
+            (cdf, 1.0 - cdf)
+        } else {
+            let x = n.sqrt() * x;
+            (kolmogorov::kolmogorov_cdf(x), kolmogorov::kolmogorov_sf(x))
+        }
+    }
+
+    /// Finds the point in the support at which `below` switches from true
+    /// to false by bisection
+    fn bisect<F: Fn(f64) -> bool>(&self, below: F) -> f64 {
+        let mut lo = self.min();
+        let mut hi = self.max();
+        loop {
+            let mid = 0.5 * (lo + hi);
+            if mid <= lo || mid >= hi {
+                return mid;
+            }
+            if below(mid) {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for Kolmogorov {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.inverse_cdf(rng.gen())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for Kolmogorov {
+    /// Calculates the cumulative distribution function for the
+    /// Kolmogorov-Smirnov statistic distribution at `x`
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            0.0
+        } else if x >= 1.0 {
+            1.0
+        } else {
+            self.cdf_sf(x).0
+        }
+    }
+
+    /// Calculates the survival function for the Kolmogorov-Smirnov
+    /// statistic distribution at `x`, i.e. the p-value of the two-sided
+    /// one-sample Kolmogorov-Smirnov test
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min() {
+            1.0
+        } else if x >= 1.0 {
+            0.0
+        } else {
+            self.cdf_sf(x).1
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// Kolmogorov-Smirnov statistic distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        self.bisect(|t| self.cdf(t) < x)
+    }
+
+    /// Calculates the inverse survival function for the Kolmogorov-Smirnov
+    /// statistic distribution at `x` by bisection, i.e. the critical value
+    /// of the two-sided test at level `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        self.bisect(|t| self.sf(t) > x)
+    }
+}
+
+impl Min<f64> for Kolmogorov {
+    /// Returns the minimum value in the domain of the Kolmogorov-Smirnov
+    /// statistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 / (2n)
+    /// ```
+    fn min(&self) -> f64 {
+        0.5 / self.n as f64
+    }
+}
+
+impl Max<f64> for Kolmogorov {
+    /// Returns the maximum value in the domain of the Kolmogorov-Smirnov
+    /// statistic distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn max(&self) -> f64 {
+        1.0
+    }
+}
+
+/// Computes `P(D_n < d)` with the matrix power algorithm of Marsaglia,
+/// Tsang and Wang, "Evaluating Kolmogorov's Distribution" (2003), keeping
+/// track of a decimal exponent to avoid overflow
+fn mtw_cdf(n: u64, d: f64) -> f64 {
+    let nd = n as f64 * d;
+    let k = nd.floor() as usize + 1;
+    let m = 2 * k - 1;
+    let h = k as f64 - nd;
+    let mut mat = DMatrix::from_fn(m, m, |i, j| if i + 1 >= j { 1.0 } else { 0.0 });
+    for i in 0..m {
+        mat[(i, 0)] -= h.powi(i as i32 + 1);
+        mat[(m - 1, i)] -= h.powi((m - i) as i32);
+    }
+    if 2.0 * h - 1.0 > 0.0 {
+        mat[(m - 1, 0)] += (2.0 * h - 1.0).powi(m as i32);
+    }
+    for i in 0..m {
+        for j in 0..=i {
+            for g in 1..=(i + 1 - j) {
+                mat[(i, j)] /= g as f64;
+            }
+        }
+    }
+    let (q, mut exp) = mtw_pow(&mat, n);
+    let mut s = q[(k - 1, k - 1)];
+    for i in 1..=n {
+        s *= i as f64 / n as f64;
+        if s < 1e-140 {
+            s *= 1e140;
+            exp -= 140;
+        }
+    }
+    s * 10f64.powi(exp)
+}
+
+/// Raises `mat` to the power `e`, returning the result scaled by
+/// `10^-exp` together with `exp`
+fn mtw_pow(mat: &DMatrix<f64>, e: u64) -> (DMatrix<f64>, i32) {
+    if e == 1 {
+        return (mat.clone(), 0);
+    }
+    let (half, exp) = mtw_pow(mat, e / 2);
+    let mut res = &half * &half;
+    let mut exp = 2 * exp;
+    if e % 2 == 1 {
+        res = mat * res;
+    }
+    let c = mat.nrows() / 2;
+    if res[(c, c)] > 1e140 {
+        res /= 1e140;
+        exp += 140;
+    }
+    (res, exp)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create(n: u64) -> Kolmogorov {
+        Kolmogorov::new(n).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(create(1).n(), 1);
+        assert!(Kolmogorov::new(0).is_err());
+    }
+
+    #[test]
+    fn test_cdf() {
+        assert_almost_eq!(create(10).cdf(0.274), 0.62847961545650436734, 1e-14);
+        assert_almost_eq!(create(10).cdf(0.1), 0.00036288000000000036259, 1e-17);
+        assert_almost_eq!(create(50).cdf(0.1), 0.33768872953418152491, 1e-13);
+        assert_almost_eq!(create(50).cdf(0.2), 0.96856122223046550795, 1e-13);
+        assert_almost_eq!(create(100).cdf(0.05), 0.046784028936427518524, 1e-14);
+        assert_almost_eq!(create(3).cdf(0.3), 0.11377777777777774936, 1e-15);
+        assert_almost_eq!(create(200).cdf(0.06), 0.54984062543066259334, 1e-12);
+        assert_eq!(create(10).cdf(0.05), 0.0);
+        assert_eq!(create(10).cdf(1.0), 1.0);
+        // falls back to the limiting distribution for large n
+        assert_almost_eq!(create(10_000).cdf(0.01), 0.7300003283226454788, 1e-15);
+    }
+
+    #[test]
+    fn test_sf() {
+        assert_almost_eq!(create(10).sf(0.274), 0.37152038454349563266, 1e-14);
+        assert_almost_eq!(create(50).sf(0.2), 0.031438777769534492054, 1e-13);
+        assert_almost_eq!(create(10).sf(0.6), 0.0005681672000000003732, 1e-17);
+        assert_almost_eq!(create(50).sf(0.7), 4.4054510556710563408e-25, 1e-37);
+        assert_almost_eq!(create(10_000).sf(0.02), 0.00067092525577969534654, 1e-18);
+        assert_eq!(create(10).sf(0.05), 1.0);
+        assert_eq!(create(10).sf(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = create(10);
+        assert_almost_eq!(n.inverse_cdf(0.62847961545650436734), 0.274, 1e-12);
+        assert_eq!(n.inverse_cdf(0.0), 0.05);
+        assert_eq!(n.inverse_cdf(1.0), 1.0);
+        assert_almost_eq!(create(50).inverse_cdf(0.33768872953418152491), 0.1, 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        assert_almost_eq!(create(10).inverse_sf(0.0005681672000000003732), 0.6, 1e-12);
+        assert_almost_eq!(create(50).inverse_sf(0.031438777769534492054), 0.2, 1e-12);
+        assert_almost_eq!(create(10_000).inverse_sf(0.00067092525577969534654), 0.02, 1e-12);
+    }
+
+    #[test]
+    fn test_tail() {
+        // below the cutoff n x^2 >= 18 of the exact cdf, where 1 - cdf
+        // would cancel
+        let n = create(500);
+        assert_almost_eq!(n.sf(0.2), 5.256835361945892635753733009914112729526e-18, 1e-30);
+        assert_almost_eq!(n.inverse_sf(1e-15), 0.186673378576178296924240460246513524136, 1e-14);
+        assert_almost_eq!(n.sf(n.inverse_sf(1e-15)) / 1e-15, 1.0, 1e-13);
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(create(10).min(), 0.05);
+        assert_eq!(create(10).max(), 1.0);
+    }
+}
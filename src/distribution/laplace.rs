@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::Rng;
@@ -121,15 +121,42 @@ impl ContinuousCDF<f64, f64> for Laplace {
     ///
     /// where `μ` is the location, `b` is the scale
     fn inverse_cdf(&self, p: f64) -> f64 {
-        if p <= 0. || 1. <= p {
+        if !(0.0..=1.0).contains(&p) {
             panic!("p must be in [0, 1]");
         };
         if p <= 0.5 {
-            self.location + self.scale * (2. * p)
+            self.location + self.scale * (2. * p).ln()
         } else {
-            self.location - self.scale * (2. - 2. * p)
+            self.location - self.scale * (2. - 2. * p).ln()
         }
     }
+    /// Calculates the survival function for the
+    /// laplace distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x >= μ {
+    ///     (1 / 2) * exp(-(x - μ) / b)
+    /// } else {
+    ///     1 - (1 / 2) * exp((x - μ) / b)
+    /// }
+    /// ```
+    ///
+    /// where `μ` is the location, `b` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        // the distribution is symmetric about the location
+        self.cdf(2.0 * self.location - x)
+    }
+    /// Calculates the inverse survival function for the
+    /// laplace distribution at `p`
+    ///
+    /// # Panics
+    ///
+    /// If `p < 0.0` or `p > 1.0`
+    fn inverse_sf(&self, p: f64) -> f64 {
+        2.0 * self.location - self.inverse_cdf(p)
+    }
 }
 
 impl Min<f64> for Laplace {
@@ -268,6 +295,25 @@ impl Continuous<f64, f64> for Laplace {
     }
 }
 
+impl Fit<f64> for Laplace {
+    /// Fits a laplace distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = median(x)
+    /// b = (1 / n) * Σ |x_i - μ|
+    /// ```
+    fn fit(data: &[f64]) -> Result<Laplace> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        let location = Data::new(data.to_vec()).median();
+        let scale = data.iter().map(|x| (x - location).abs()).mean();
+        Laplace::new(location, scale)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -458,4 +504,40 @@ mod tests {
         let l = try_create(0.1, 0.5);
         l.sample(&mut thread_rng());
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Laplace| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, -2f64.ln(), 1e-15, inverse_cdf(0.25));
+        test_case(1.0, 2.0, 1.0, inverse_cdf(0.5));
+        test_almost(1.0, 2.0, 1.0 + 2.0 * 2f64.ln(), 1e-15, inverse_cdf(0.75));
+        test_almost(0.0, 1.0, 5.0, 1e-13, inverse_cdf(1.0 - 0.5 * (-5f64).exp()));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Laplace| x.sf(arg);
+        test_case(0.0, 1.0, 0.5, sf(0.0));
+        test_almost(0.0, 1.0, 0.5 * (-40f64).exp(), 1e-32, sf(40.0));
+        test_almost(0.0, 1.0, 1.0 - 0.5 * (-1f64).exp(), 1e-15, sf(-1.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Laplace| x.inverse_sf(arg);
+        test_almost(0.0, 1.0, 40.0, 1e-13, inverse_sf(0.5 * (-40f64).exp()));
+        test_almost(1.0, 2.0, 1.0 - 2.0 * 2f64.ln(), 1e-15, inverse_sf(0.75));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_sf(0.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Laplace::fit(&[1.0, 2.0, 3.0, 4.0, 10.0]).unwrap();
+        assert_eq!(n.location(), 3.0);
+        assert_almost_eq!(n.scale(), 2.2, 1e-15);
+        assert!(Laplace::fit(&[]).is_err());
+        assert!(Laplace::fit(&[1.0, 1.0]).is_err());
+    }
 }
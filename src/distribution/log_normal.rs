@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::function::erf;
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
@@ -83,6 +83,70 @@ impl ContinuousCDF<f64, f64> for LogNormal {
             0.5 * erf::erfc((self.location - x.ln()) / (self.scale * f64::consts::SQRT_2))
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// log-normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ - sqrt(2) * σ * erfc_inv(2x))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc_inv` is the
+    /// inverse of the complementary error function
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            (self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x)).exp()
+        }
+    }
+    /// Calculates the survival function for the log-normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((ln(x) - μ) / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc` is the
+    /// complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x.is_infinite() {
+            0.0
+        } else {
+            0.5 * erf::erfc((x.ln() - self.location) / (self.scale * f64::consts::SQRT_2))
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// log-normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(μ + sqrt(2) * σ * erfc_inv(2x))
+    /// ```
+    ///
+    /// where `μ` is the location, `σ` is the scale, and `erfc_inv` is the
+    /// inverse of the complementary error function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            (self.location + self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x)).exp()
+        }
+    }
 }
 
 impl Min<f64> for LogNormal {
@@ -236,11 +300,32 @@ impl Continuous<f64, f64> for LogNormal {
     }
 }
 
+impl Fit<f64> for LogNormal {
+    /// Fits a log-normal distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = (1 / n) * Σ ln(x_i)
+    /// σ = sqrt((1 / n) * Σ (ln(x_i) - μ)^2)
+    /// ```
+    fn fit(data: &[f64]) -> Result<LogNormal> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let ln_data = data.iter().map(|x| x.ln());
+        LogNormal::new(ln_data.clone().mean(), ln_data.population_std_dev())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, LogNormal};
+    use crate::distribution::{ContinuousCDF, Continuous, LogNormal, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -601,4 +686,41 @@ mod tests {
         test::check_continuous_distribution(&try_create(0.0, 0.25), 0.0, 10.0);
         test::check_continuous_distribution(&try_create(0.0, 0.5), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: LogNormal| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_almost(0.0, 1.0, 1.0, 1e-15, inverse_cdf(0.5));
+        test_almost(2.0, 1.0, 2f64.exp(), 1e-14, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 0.27760624185200981, 1e-15, inverse_cdf(0.1));
+        test_almost(0.0, 0.5, 1.8979527073347107, 1e-14, inverse_cdf(0.9));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: LogNormal| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(0.0));
+        test_almost(0.0, 1.0, 0.5, 1e-15, sf(1.0));
+        test_almost(0.0, 1.0, 7.619853024160526066e-24, 1e-33, sf(10f64.exp()));
+        test_case(0.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: LogNormal| x.inverse_sf(arg);
+        test_almost(0.0, 1.0, 7.0344838253011319298f64.exp(), 1e-10, inverse_sf(1e-12));
+        test_almost(0.0, 0.5, 1.8979527073347107, 1e-14, inverse_sf(0.1));
+        test_case(0.0, 1.0, f64::INFINITY, inverse_sf(0.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = LogNormal::fit(&[1f64.exp(), 2f64.exp(), 3f64.exp()]).unwrap();
+        assert_almost_eq!(n.location, 2.0, 1e-15);
+        assert_almost_eq!(n.scale, (2.0f64 / 3.0).sqrt(), 1e-15);
+        assert!(LogNormal::fit(&[]).is_err());
+        assert!(LogNormal::fit(&[1.0, 0.0]).is_err());
+    }
 }
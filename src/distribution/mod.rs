@@ -18,21 +18,35 @@ pub use self::empirical::Empirical;
 pub use self::erlang::Erlang;
 pub use self::exponential::Exp;
 pub use self::fisher_snedecor::FisherSnedecor;
+pub use self::frechet::Frechet;
 pub use self::gamma::Gamma;
+pub use self::generalized_pareto::GeneralizedPareto;
 pub use self::geometric::Geometric;
+pub use self::gev::Gev;
+pub use self::gumbel::Gumbel;
 pub use self::hypergeometric::Hypergeometric;
 pub use self::inverse_gamma::InverseGamma;
+pub use self::kolmogorov::Kolmogorov;
 pub use self::laplace::Laplace;
 pub use self::log_normal::LogNormal;
 pub use self::multinomial::Multinomial;
 pub use self::multivariate_normal::MultivariateNormal;
 pub use self::negative_binomial::NegativeBinomial;
+pub use self::noncentral_beta::NoncentralBeta;
+pub use self::noncentral_chi_squared::NoncentralChiSquared;
+pub use self::noncentral_fisher_snedecor::NoncentralFisherSnedecor;
+pub use self::noncentral_students_t::NoncentralStudentsT;
 pub use self::normal::Normal;
 pub use self::pareto::Pareto;
 pub use self::poisson::Poisson;
+pub use self::skew_normal::SkewNormal;
+pub use self::skew_students_t::SkewStudentsT;
+pub use self::studentized_range::StudentizedRange;
 pub use self::students_t::StudentsT;
 pub use self::triangular::Triangular;
+pub use self::truncated::{SampleTruncated, Truncated, TruncatedDiscrete};
 pub use self::uniform::Uniform;
+pub use self::von_mises::VonMises;
 pub use self::weibull::Weibull;
 
 mod bernoulli;
@@ -49,22 +63,36 @@ mod empirical;
 mod erlang;
 mod exponential;
 mod fisher_snedecor;
+mod frechet;
 mod gamma;
+mod generalized_pareto;
 mod geometric;
+mod gev;
+mod gumbel;
 mod hypergeometric;
 mod internal;
 mod inverse_gamma;
+mod kolmogorov;
 mod laplace;
 mod log_normal;
 mod multinomial;
 mod multivariate_normal;
 mod negative_binomial;
+mod noncentral_beta;
+mod noncentral_chi_squared;
+mod noncentral_fisher_snedecor;
+mod noncentral_students_t;
 mod normal;
 mod pareto;
 mod poisson;
+mod skew_normal;
+mod skew_students_t;
+mod studentized_range;
 mod students_t;
 mod triangular;
+mod truncated;
 mod uniform;
+mod von_mises;
 mod weibull;
 mod ziggurat;
 mod ziggurat_tables;
@@ -121,6 +149,88 @@ pub trait ContinuousCDF<K: Float, T: Float>: Min<K> + Max<K> {
         }
         (high + low) / two
     }
+
+    /// Returns the survival function calculated
+    /// at `x` for a given distribution. May panic depending
+    /// on the implementor.
+    ///
+    /// The default implementation computes `1 - cdf(x)`, which loses
+    /// precision in the upper tail. Implementors should override it with a
+    /// formula based on the complementary special functions whenever
+    /// possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.5, n.sf(0.5));
+    /// ```
+    fn sf(&self, x: K) -> T {
+        T::one() - self.cdf(x)
+    }
+
+    /// Returns the inverse of the survival function, i.e. the `x` such
+    /// that `sf(x) = p`. May panic depending on the implementor.
+    ///
+    /// The default implementation computes `inverse_cdf(1 - p)`, which
+    /// loses precision for small `p`. Specialized inverse survival functions
+    /// should be used whenever possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Uniform};
+    ///
+    /// let n = Uniform::new(0.0, 1.0).unwrap();
+    /// assert_eq!(0.25, n.inverse_sf(0.75));
+    /// ```
+    fn inverse_sf(&self, p: T) -> K {
+        self.inverse_cdf(T::one() - p)
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x` for a given distribution. May panic depending on
+    /// the implementor.
+    ///
+    /// The default implementation computes `ln(cdf(x))`, which becomes
+    /// `-inf` once the cdf underflows in the far lower tail. Implementors
+    /// should override it with an evaluation in log space whenever possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert!(n.cdf(-40.0) == 0.0);
+    /// assert!((n.ln_cdf(-40.0) + 804.6084420137538).abs() < 1e-9);
+    /// ```
+    fn ln_cdf(&self, x: K) -> T {
+        self.cdf(x).ln()
+    }
+
+    /// Returns the natural logarithm of the survival function calculated at
+    /// `x` for a given distribution. May panic depending on the implementor.
+    ///
+    /// The default implementation computes `ln(sf(x))`, which becomes `-inf`
+    /// once the survival function underflows in the far upper tail.
+    /// Implementors should override it with an evaluation in log space
+    /// whenever possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{ContinuousCDF, Normal};
+    ///
+    /// let n = Normal::new(0.0, 1.0).unwrap();
+    /// assert!(n.sf(40.0) == 0.0);
+    /// assert!((n.ln_sf(40.0) + 804.6084420137538).abs() < 1e-9);
+    /// ```
+    fn ln_sf(&self, x: K) -> T {
+        self.sf(x).ln()
+    }
 }
 
 /// The `DiscreteCDF` trait is used to specify an interface for univariate
@@ -165,6 +275,100 @@ pub trait DiscreteCDF<K: Bounded + Clone + Num, T: Float>: Min<K> + Max<K> {
         }
         high
     }
+
+    /// Returns the survival function calculated
+    /// at `x` for a given distribution. May panic depending
+    /// on the implementor.
+    ///
+    /// The default implementation computes `1 - cdf(x)`, which loses
+    /// precision in the upper tail. Implementors should override it with a
+    /// formula based on the complementary special functions whenever
+    /// possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Binomial};
+    /// use statrs::prec;
+    ///
+    /// let n = Binomial::new(0.5, 10).unwrap();
+    /// assert!(prec::almost_eq(n.sf(4), 0.623046875, 1e-14));
+    /// ```
+    fn sf(&self, x: K) -> T {
+        T::one() - self.cdf(x)
+    }
+
+    /// Returns the inverse of the survival function, i.e. the smallest `x`
+    /// such that `sf(x) <= p`. May panic depending on the implementor.
+    ///
+    /// Performs a binary search on the domain of `sf`.
+    /// Specialized inverse survival functions should be used whenever
+    /// possible.
+    fn inverse_sf(&self, p: T) -> K {
+        if p == T::zero() {
+            return self.max();
+        };
+        if p == T::one() {
+            return self.min();
+        };
+        let two = K::one() + K::one();
+        let mut high = two.clone();
+        let mut low = self.min();
+        while self.sf(high.clone()) > p {
+            high = high.clone() + high.clone();
+        }
+        while high != low {
+            let mid = low.clone() + (high.clone() - low.clone()) / two.clone();
+            if self.sf(mid.clone()) <= p {
+                high = mid;
+            } else {
+                low = mid + K::one();
+            }
+        }
+        high
+    }
+
+    /// Returns the natural logarithm of the cumulative distribution function
+    /// calculated at `x` for a given distribution. May panic depending on
+    /// the implementor.
+    ///
+    /// The default implementation computes `ln(cdf(x))`, which becomes
+    /// `-inf` once the cdf underflows in the far lower tail. Implementors
+    /// should override it with an evaluation in log space whenever possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Poisson};
+    ///
+    /// let n = Poisson::new(1000.0).unwrap();
+    /// assert!(n.cdf(10) == 0.0);
+    /// assert!((n.ln_cdf(10) + 946.0168196296328).abs() < 1e-9);
+    /// ```
+    fn ln_cdf(&self, x: K) -> T {
+        self.cdf(x).ln()
+    }
+
+    /// Returns the natural logarithm of the survival function calculated at
+    /// `x` for a given distribution. May panic depending on the implementor.
+    ///
+    /// The default implementation computes `ln(sf(x))`, which becomes `-inf`
+    /// once the survival function underflows in the far upper tail.
+    /// Implementors should override it with an evaluation in log space
+    /// whenever possible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{DiscreteCDF, Poisson};
+    ///
+    /// let n = Poisson::new(1.0).unwrap();
+    /// assert!(n.sf(200) == 0.0);
+    /// assert!((n.ln_sf(200) + 869.5303294330408).abs() < 1e-9);
+    /// ```
+    fn ln_sf(&self, x: K) -> T {
+        self.sf(x).ln()
+    }
 }
 
 /// The `Continuous` trait  provides an interface for interacting with
@@ -244,3 +448,40 @@ pub trait Discrete<K, T> {
     /// ```
     fn ln_pmf(&self, x: K) -> T;
 }
+
+/// The `Fit` trait provides an interface for estimating the parameters of a
+/// distribution from observed samples by maximum likelihood.
+///
+/// # Remarks
+///
+/// Closed-form estimators are used wherever they exist. Otherwise the
+/// likelihood equations are solved iteratively, in which case `fit` returns
+/// `StatsError::ComputationFailedToConverge` if the solver does not settle.
+///
+/// Distributions with a parameter that must be known in advance, such as
+/// the number of trials of `Binomial` or the population and draws of
+/// `Hypergeometric`, do not implement `Fit` and are fitted with their
+/// `fit_with_*` constructors instead.
+pub trait Fit<T>: Sized {
+    /// Returns the distribution whose parameters maximize the likelihood of
+    /// `data`.
+    ///
+    /// # Errors
+    ///
+    /// If `data` is empty or contains values outside the support of the
+    /// distribution, if the estimated parameters are invalid (for example a
+    /// zero standard deviation for constant data), or if an iterative solver
+    /// fails to converge
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Fit, Normal};
+    /// use statrs::statistics::Distribution;
+    ///
+    /// let n = Normal::fit(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+    /// assert_eq!(n.mean().unwrap(), 3.0);
+    /// assert_eq!(n.std_dev().unwrap(), 2f64.sqrt());
+    /// ```
+    fn fit(data: &[T]) -> Result<Self>;
+}
@@ -1,4 +1,4 @@
-use crate::distribution::Discrete;
+use crate::distribution::{Discrete, Fit};
 use crate::function::factorial;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -238,6 +238,52 @@ impl<'a> Discrete<&'a [u64], f64> for Multinomial {
     }
 }
 
+impl Fit<Vec<u64>> for Multinomial {
+    /// Fits a multinomial distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p_k = Σ_i x_ik / (m * n)
+    /// ```
+    ///
+    /// where `m` is the number of samples and the number of trials `n` is the
+    /// common sum of every sample
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the samples have different
+    /// lengths, or if they do not all sum to the same positive `n`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Fit, Multinomial};
+    ///
+    /// let n = Multinomial::fit(&[vec![1, 3, 0], vec![2, 1, 1]]).unwrap();
+    /// assert_eq!(n.n(), 4);
+    /// assert_eq!(n.p(), [0.375, 0.5, 0.125]);
+    /// assert!(Multinomial::fit(&[vec![1, 3], vec![2, 1]]).is_err());
+    /// ```
+    fn fit(data: &[Vec<u64>]) -> Result<Multinomial> {
+        let (dim, n) = match data.first() {
+            Some(x) => (x.len(), x.iter().sum::<u64>()),
+            None => return Err(StatsError::ArgGte("data.len()", 1.0)),
+        };
+        if data.iter().any(|x| x.len() != dim) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        if n == 0 || data.iter().any(|x| x.iter().sum::<u64>() != n) {
+            return Err(StatsError::BadParams);
+        }
+        let total = data.len() as f64 * n as f64;
+        let p: Vec<f64> = (0..dim)
+            .map(|k| data.iter().map(|x| x[k]).sum::<u64>() as f64 / total)
+            .collect();
+        Multinomial::new(&p, n)
+    }
+}
+
 // TODO: fix tests
 // #[rustfmt::skip]
 // #[cfg(test)]
@@ -1,4 +1,5 @@
 use crate::distribution::Continuous;
+use crate::distribution::Fit;
 use crate::distribution::Normal;
 use crate::statistics::{Max, MeanN, Min, Mode, VarianceN};
 use crate::{Result, StatsError};
@@ -202,6 +203,45 @@ impl<'a> Continuous<&'a DVector<f64>, f64> for MultivariateNormal {
     }
 }
 
+impl Fit<DVector<f64>> for MultivariateNormal {
+    /// Fits a multivariate normal distribution to `data` by maximum
+    /// likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = (1 / n) * Σ x_i
+    /// Σ = (1 / n) * Σ (x_i - μ) * (x_i - μ)^T
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the samples have different
+    /// dimensions, or if the estimated covariance matrix is singular
+    fn fit(data: &[DVector<f64>]) -> Result<Self> {
+        let dim = match data.first() {
+            Some(x) => x.len(),
+            None => return Err(StatsError::ArgGte("data.len()", 1.0)),
+        };
+        if data.iter().any(|x| x.len() != dim) {
+            return Err(StatsError::ContainersMustBeSameLength);
+        }
+        let n = data.len() as f64;
+        let mean = data
+            .iter()
+            .fold(DVector::zeros(dim), |acc, x| acc + x)
+            .unscale(n);
+        let cov = data
+            .iter()
+            .fold(DMatrix::zeros(dim, dim), |acc, x| {
+                let d = x - &mean;
+                acc + &d * d.transpose()
+            })
+            .unscale(n);
+        MultivariateNormal::new(mean.as_slice().to_vec(), cov.as_slice().to_vec())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests  {
@@ -353,4 +393,20 @@ mod tests  {
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![10., 10.]));
         test_case(vec![0., 0.], vec![f64::INFINITY, 0., 0., f64::INFINITY], f64::NEG_INFINITY, ln_pdf(dvec![100., 100.]));
     }
+
+    #[test]
+    fn test_fit() {
+        let data = [
+            DVector::from_vec(vec![1.0, 2.0]),
+            DVector::from_vec(vec![3.0, 0.0]),
+            DVector::from_vec(vec![2.0, 4.0]),
+            DVector::from_vec(vec![6.0, 2.0]),
+        ];
+        let mvn = MultivariateNormal::fit(&data).unwrap();
+        assert_eq!(mvn.mean().unwrap(), DVector::from_vec(vec![3.0, 2.0]));
+        assert_eq!(mvn.variance().unwrap(), DMatrix::from_vec(2, 2, vec![3.5, -0.5, -0.5, 2.0]));
+        assert!(MultivariateNormal::fit(&[]).is_err());
+        assert!(MultivariateNormal::fit(&data[..1]).is_err());
+        assert!(MultivariateNormal::fit(&[data[0].clone(), DVector::from_vec(vec![1.0])]).is_err());
+    }
 }
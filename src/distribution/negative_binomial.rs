@@ -1,4 +1,5 @@
-use crate::distribution::{self, poisson, Discrete, DiscreteCDF};
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{self, poisson, Discrete, DiscreteCDF, Fit};
 use crate::function::{beta, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -113,6 +114,19 @@ impl DiscreteCDF<u64, f64> for NegativeBinomial {
     fn cdf(&self, x: u64) -> f64 {
         1.0 - beta::beta_reg(x as f64 + 1.0, self.r, 1.0 - self.p)
     }
+    /// Calculates the survival function for the
+    /// negative binomial distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_(1-p)(x+1, r)
+    /// ```
+    ///
+    /// where `I_(x)(a, b)` is the regularized incomplete beta function
+    fn sf(&self, x: u64) -> f64 {
+        beta::beta_reg(x as f64 + 1.0, self.r, 1.0 - self.p)
+    }
 }
 
 impl Min<u64> for NegativeBinomial {
@@ -228,12 +242,55 @@ impl Discrete<u64, f64> for NegativeBinomial {
     }
 }
 
+impl Fit<u64> for NegativeBinomial {
+    /// Fits a negative binomial distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ ψ(x_i + r) - n * ψ(r) - n * ln(1 + x̄ / r) = 0
+    /// p = r / (r + x̄)
+    /// ```
+    ///
+    /// where `ψ` is the digamma function and `x̄` is the sample mean. The
+    /// equation for `r` is solved by bisection and has a finite root only if
+    /// the sample variance exceeds the sample mean
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty or if the data are not
+    /// overdispersed, in which case the likelihood increases towards the
+    /// poisson limit `r -> inf`
+    fn fit(data: &[u64]) -> Result<NegativeBinomial> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        let n = data.len() as f64;
+        let mean = data.iter().map(|&x| x as f64).mean();
+        if data.iter().map(|&x| x as f64).population_variance() <= mean {
+            return Err(StatsError::BadParams);
+        }
+        let r = solve_decreasing(
+            |r| {
+                data.iter()
+                    .map(|&x| gamma::digamma(x as f64 + r))
+                    .sum::<f64>()
+                    - n * gamma::digamma(r)
+                    - n * (mean / r).ln_1p()
+            },
+            1.0,
+        )
+        .ok_or(StatsError::ComputationFailedToConverge)?;
+        NegativeBinomial::new(r, r / (r + mean))
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{DiscreteCDF, Discrete, NegativeBinomial};
+    use crate::distribution::{DiscreteCDF, Discrete, NegativeBinomial, Fit};
     use crate::consts::ACC;
 
     fn try_create(r: f64, p: f64) -> NegativeBinomial {
@@ -433,4 +490,21 @@ mod tests {
     //     test::check_discrete_distribution(&try_create(5.0, 0.3), 35);
     //     test::check_discrete_distribution(&try_create(10.0, 0.7), 21);
     // }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: NegativeBinomial| x.sf(arg);
+        test_almost(4.0, 0.5, 8.0916606748360209167e-10, 1e-21, sf(40));
+        test_almost(1.0, 0.5, 0.25, 1e-15, sf(1));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = NegativeBinomial::fit(&[0, 3, 1, 7, 2, 0, 12, 4, 1, 5]).unwrap();
+        assert_almost_eq!(n.r(), 1.15975894036584600953672126709, 1e-12);
+        assert_almost_eq!(n.p(), 0.248888183961462877865862022888, 1e-12);
+        assert!(NegativeBinomial::fit(&[]).is_err());
+        // underdispersed data has no finite maximum likelihood estimate
+        assert!(NegativeBinomial::fit(&[2, 3, 2, 3]).is_err());
+    }
 }
@@ -0,0 +1,390 @@
+use crate::distribution::internal::bisect_support;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::noncentral;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// Beta](https://en.wikipedia.org/wiki/Noncentral_beta_distribution)
+/// distribution, the distribution of `X / (X + Y)` for a noncentral
+/// chi-squared variable `X` with `2α` degrees of freedom and an independent
+/// chi-squared variable `Y` with `2β` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralBeta, Continuous};
+/// use statrs::prec;
+///
+/// let n = NoncentralBeta::new(2.0, 3.0, 4.0).unwrap();
+/// assert!(prec::almost_eq(n.pdf(0.3), 0.908708825736964055, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralBeta {
+    shape_a: f64,
+    shape_b: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralBeta {
+    /// Constructs a new noncentral beta distribution with shape parameters
+    /// `shape_a` and `shape_b` and noncentrality parameter `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, if
+    /// `shape_a <= 0.0` or `shape_b <= 0.0`, or if `noncentrality < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let mut result = NoncentralBeta::new(2.0, 3.0, 4.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralBeta::new(0.0, 3.0, 4.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(shape_a: f64, shape_b: f64, noncentrality: f64) -> Result<NoncentralBeta> {
+        if !shape_a.is_finite() || !shape_b.is_finite() || !noncentrality.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if shape_a <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("shape_a"));
+        }
+        if shape_b <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("shape_b"));
+        }
+        if noncentrality < 0.0 {
+            return Err(StatsError::ArgNotNegative("noncentrality"));
+        }
+        Ok(NoncentralBeta {
+            shape_a,
+            shape_b,
+            noncentrality,
+        })
+    }
+
+    /// Returns the shape parameter α of the noncentral beta distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.shape_a(), 2.0);
+    /// ```
+    pub fn shape_a(&self) -> f64 {
+        self.shape_a
+    }
+
+    /// Returns the shape parameter β of the noncentral beta distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.shape_b(), 3.0);
+    /// ```
+    pub fn shape_b(&self) -> f64 {
+        self.shape_b
+    }
+
+    /// Returns the noncentrality parameter of the noncentral beta
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralBeta;
+    ///
+    /// let n = NoncentralBeta::new(2.0, 3.0, 4.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 4.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns the `k`-th raw moment as a Poisson mixture of the raw
+    /// moments of central beta distributions
+    fn raw_moment(&self, k: usize) -> f64 {
+        let a = self.shape_a;
+        let b = self.shape_b;
+        let beta_moment = |j: f64| {
+            (0..k).fold(1.0, |acc, i| {
+                acc * (a + j + i as f64) / (a + b + j + i as f64)
+            })
+        };
+        if self.noncentrality == 0.0 {
+            return beta_moment(0.0);
+        }
+        let mu = self.noncentrality / 2.0;
+        noncentral::mixture_sum(mu.floor(), |j| {
+            let w = noncentral::poisson_weight(mu, j);
+            (w, w * beta_moment(j))
+        })
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralBeta {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let x = super::noncentral_chi_squared::sample_unchecked(
+            rng,
+            2.0 * self.shape_a,
+            self.noncentrality,
+        );
+        let y = super::gamma::sample_unchecked(rng, self.shape_b, 0.5);
+        x / (x + y)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralBeta {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// beta distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * I_x(α + j, β)
+    /// ```
+    ///
+    /// where `α` and `β` are the shape parameters, `λ` is the noncentrality
+    /// and `I` is the regularized incomplete beta function
+    fn cdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_beta_cdf(self.shape_a, self.shape_b, self.noncentrality, x)
+    }
+
+    /// Calculates the survival function for the noncentral beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * I_(1 - x)(β, α + j)
+    /// ```
+    ///
+    /// where `α` and `β` are the shape parameters, `λ` is the noncentrality
+    /// and `I` is the regularized incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        noncentral::noncentral_beta_sf(self.shape_a, self.shape_b, self.noncentrality, x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral beta distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        bisect_support(|t| self.cdf(t) < x, self.min(), self.max())
+    }
+
+    /// Calculates the inverse survival function for the noncentral beta
+    /// distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        bisect_support(|t| self.sf(t) > x, self.min(), self.max())
+    }
+}
+
+impl Min<f64> for NoncentralBeta {
+    /// Returns the minimum value in the domain of the noncentral beta
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralBeta {
+    /// Returns the maximum value in the domain of the noncentral beta
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1
+    /// ```
+    fn max(&self) -> f64 {
+        1.0
+    }
+}
+
+impl Distribution<f64> for NoncentralBeta {
+    /// Returns the mean of the noncentral beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * (α + j) / (α + β + j)
+    /// ```
+    ///
+    /// where `α` and `β` are the shape parameters and `λ` is the
+    /// noncentrality
+    fn mean(&self) -> Option<f64> {
+        Some(self.raw_moment(1))
+    }
+
+    /// Returns the variance of the noncentral beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// E[X^2] - μ^2
+    /// E[X^2] = Σ e^(-λ/2) (λ/2)^j / j! * (α + j)(α + j + 1) / ((α + β + j)(α + β + j + 1))
+    /// ```
+    ///
+    /// where `α` and `β` are the shape parameters, `λ` is the noncentrality
+    /// and `μ` is the mean
+    fn variance(&self) -> Option<f64> {
+        let mean = self.raw_moment(1);
+        Some(self.raw_moment(2) - mean * mean)
+    }
+
+    /// Returns the skewness of the noncentral beta distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (E[X^3] - 3μσ^2 - μ^3) / σ^3
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ^2` the variance and the raw moment
+    /// `E[X^3]` is the Poisson mixture of the third raw moments of central
+    /// beta distributions
+    fn skewness(&self) -> Option<f64> {
+        let mean = self.raw_moment(1);
+        let variance = self.raw_moment(2) - mean * mean;
+        let third = self.raw_moment(3);
+        Some((third - 3.0 * mean * variance - mean * mean * mean) / variance.powf(1.5))
+    }
+}
+
+impl Median<f64> for NoncentralBeta {
+    /// Returns the median of the noncentral beta distribution, computed by
+    /// inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralBeta {
+    /// Calculates the probability density function for the noncentral beta
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * x^(α + j - 1) (1 - x)^(β - 1) / B(α + j, β)
+    /// ```
+    ///
+    /// where `α` and `β` are the shape parameters, `λ` is the noncentrality
+    /// and `B` is the beta function
+    fn pdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_beta_pdf(self.shape_a, self.shape_b, self.noncentrality, x)
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// beta distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x))
+    /// ```
+    ///
+    /// where `f` is the density of the noncentral beta distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, NoncentralBeta};
+    use crate::statistics::*;
+
+    fn try_create(shape_a: f64, shape_b: f64, noncentrality: f64) -> NoncentralBeta {
+        let n = NoncentralBeta::new(shape_a, shape_b, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(2.0, 3.0, 4.0);
+        try_create(0.5, 0.5, 0.0);
+        assert!(NoncentralBeta::new(0.0, 3.0, 4.0).is_err());
+        assert!(NoncentralBeta::new(2.0, -3.0, 4.0).is_err());
+        assert!(NoncentralBeta::new(2.0, 3.0, -4.0).is_err());
+        assert!(NoncentralBeta::new(f64::NAN, 3.0, 4.0).is_err());
+    }
+    #[test]
+    fn test_moments() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.mean().unwrap(), 0.55450438728237855676, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 0.038472237096051252744, 1e-15);
+        assert_almost_eq!(n.skewness().unwrap(), -0.27487057740332012079, 1e-13);
+        let n = try_create(2.0, 3.0, 0.0);
+        assert_almost_eq!(n.mean().unwrap(), 0.4, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 0.04, 1e-15);
+    }
+
+    #[test]
+    fn test_cdf_pdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.cdf(0.3), 0.11674344147530744501, 1e-14);
+        assert_almost_eq!(n.sf(0.3), 0.88325655852469255499, 1e-14);
+        assert_almost_eq!(n.pdf(0.3), 0.90870882573696405540, 1e-12);
+        assert_eq!(n.pdf(1.5), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(2.0, 3.0, 4.0);
+        assert_almost_eq!(n.inverse_cdf(0.05), 0.20801568097842693531, 1e-14);
+        assert_almost_eq!(n.median(), 0.56844826457868457153, 1e-14);
+        assert_almost_eq!(n.inverse_cdf(0.95), 0.85315479950022642506, 1e-14);
+        assert_almost_eq!(n.inverse_sf(0.05), 0.85315479950022642506, 1e-14);
+        assert_eq!(n.inverse_cdf(1.0), 1.0);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(2.0, 3.0, 4.0), 0.0, 1.0);
+    }
+}
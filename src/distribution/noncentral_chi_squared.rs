@@ -0,0 +1,379 @@
+use crate::distribution::internal::bisect_support;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::noncentral;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// chi-squared](https://en.wikipedia.org/wiki/Noncentral_chi-squared_distribution)
+/// distribution, the distribution of the sum of squares of independent
+/// normal variables with unit variance and nonzero means
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralChiSquared, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralChiSquared::new(3.0, 2.5).unwrap();
+/// assert_eq!(n.mean().unwrap(), 5.5);
+/// assert!(prec::almost_eq(n.pdf(4.0), 0.115354326267663823, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralChiSquared {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralChiSquared {
+    /// Constructs a new noncentral chi-squared distribution with `freedom`
+    /// degrees of freedom and noncentrality parameter `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` or `noncentrality` are `NaN` or
+    /// infinite, if `freedom <= 0.0` or if `noncentrality < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let mut result = NoncentralChiSquared::new(3.0, 2.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralChiSquared::new(3.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralChiSquared> {
+        if !freedom.is_finite() || !noncentrality.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if freedom <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("freedom"));
+        }
+        if noncentrality < 0.0 {
+            return Err(StatsError::ArgNotNegative("noncentrality"));
+        }
+        Ok(NoncentralChiSquared {
+            freedom,
+            noncentrality,
+        })
+    }
+
+    /// Returns the degrees of freedom of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.5).unwrap();
+    /// assert_eq!(n.freedom(), 3.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality parameter of the noncentral chi-squared
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralChiSquared;
+    ///
+    /// let n = NoncentralChiSquared::new(3.0, 2.5).unwrap();
+    /// assert_eq!(n.noncentrality(), 2.5);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralChiSquared {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        sample_unchecked(rng, self.freedom, self.noncentrality)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralChiSquared {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * P(k/2 + j, x/2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `P` is the lower regularized incomplete gamma function
+    fn cdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_chi_squared_cdf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the survival function for the noncentral chi-squared
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * Q(k/2 + j, x/2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `Q` is the upper regularized incomplete gamma function
+    fn sf(&self, x: f64) -> f64 {
+        noncentral::noncentral_chi_squared_sf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral chi-squared distribution at `x` by bisection. Upper
+    /// quantiles are located with the survival function, whose bracket is
+    /// bounded where the cdf would round to one before reaching `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        if x > 0.5 {
+            return bisect_support(|t| self.sf(t) > 1.0 - x, self.min(), self.max());
+        }
+        bisect_support(|t| self.cdf(t) < x, self.min(), self.max())
+    }
+
+    /// Calculates the inverse survival function for the noncentral
+    /// chi-squared distribution at `x` by bisection, locating lower
+    /// quantiles with the cdf
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        if x > 0.5 {
+            return bisect_support(|t| self.cdf(t) < 1.0 - x, self.min(), self.max());
+        }
+        bisect_support(|t| self.sf(t) > x, self.min(), self.max())
+    }
+}
+
+impl Min<f64> for NoncentralChiSquared {
+    /// Returns the minimum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralChiSquared {
+    /// Returns the maximum value in the domain of the noncentral
+    /// chi-squared distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralChiSquared {
+    /// Returns the mean of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// k + λ
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        Some(self.freedom + self.noncentrality)
+    }
+
+    /// Returns the variance of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 * (k + 2λ)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        Some(2.0 * (self.freedom + 2.0 * self.noncentrality))
+    }
+
+    /// Returns the skewness of the noncentral chi-squared distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2^(3/2) * (k + 3λ) / (k + 2λ)^(3/2)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom and `λ` is the noncentrality
+    fn skewness(&self) -> Option<f64> {
+        let k = self.freedom;
+        let lambda = self.noncentrality;
+        Some((8.0f64).sqrt() * (k + 3.0 * lambda) / (k + 2.0 * lambda).powf(1.5))
+    }
+}
+
+impl Median<f64> for NoncentralChiSquared {
+    /// Returns the median of the noncentral chi-squared distribution,
+    /// computed by inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralChiSquared {
+    /// Calculates the probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * f_(k + 2j)(x)
+    /// ```
+    ///
+    /// where `k` is the degrees of freedom, `λ` is the noncentrality and
+    /// `f_n` is the density of the chi-squared distribution with `n` degrees
+    /// of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_chi_squared_pdf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// chi-squared distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x))
+    /// ```
+    ///
+    /// where `f` is the density of the noncentral chi-squared distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// Draws a sample from the noncentral chi-squared distribution as a
+/// chi-squared variable whose degrees of freedom are increased by twice a
+/// Poisson variable with mean `noncentrality / 2`
+pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, freedom: f64, noncentrality: f64) -> f64 {
+    let j = if noncentrality > 0.0 {
+        super::poisson::sample_unchecked(rng, noncentrality / 2.0)
+    } else {
+        0.0
+    };
+    super::gamma::sample_unchecked(rng, freedom / 2.0 + j, 0.5)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, NoncentralChiSquared};
+    use crate::statistics::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralChiSquared {
+        let n = NoncentralChiSquared::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(3.0, 2.5);
+        try_create(0.5, 0.0);
+        assert!(NoncentralChiSquared::new(0.0, 1.0).is_err());
+        assert!(NoncentralChiSquared::new(3.0, -1.0).is_err());
+        assert!(NoncentralChiSquared::new(f64::NAN, 1.0).is_err());
+        assert!(NoncentralChiSquared::new(3.0, f64::INFINITY).is_err());
+    }
+    #[test]
+    fn test_moments() {
+        let n = try_create(3.0, 2.5);
+        assert_eq!(n.mean().unwrap(), 5.5);
+        assert_eq!(n.variance().unwrap(), 16.0);
+        assert_almost_eq!(n.skewness().unwrap(), 1.3125, 1e-15);
+        let n = try_create(4.0, 0.0);
+        assert_almost_eq!(n.skewness().unwrap(), 2f64.sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_cdf_pdf() {
+        let n = try_create(3.0, 2.5);
+        assert_almost_eq!(n.cdf(4.0), 0.43146149918696317272, 1e-14);
+        assert_almost_eq!(n.sf(4.0), 0.56853850081303682728, 1e-14);
+        assert_almost_eq!(n.pdf(4.0), 0.11535432626766382273, 1e-12);
+        assert_eq!(n.pdf(-1.0), 0.0);
+        assert_eq!(n.ln_pdf(-1.0), f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3.0, 2.5);
+        assert_almost_eq!(n.inverse_cdf(0.05), 0.7791278795089799710, 1e-12);
+        assert_almost_eq!(n.median(), 4.615053032229891423, 1e-12);
+        assert_almost_eq!(n.inverse_cdf(0.95), 13.253274705261484491, 1e-12);
+        assert_almost_eq!(n.inverse_sf(0.05), 13.253274705261484491, 1e-12);
+        assert_eq!(n.inverse_cdf(0.0), 0.0);
+        assert_eq!(n.inverse_sf(0.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_cdf_extreme() {
+        assert_eq!(try_create(0.5, 0.0).inverse_cdf(1e-100), 5e-324);
+        assert_eq!(try_create(0.5, 0.0).cdf(5e-324), 0.0);
+        // 1 - 1e-15 rounds to 1 - 9.992e-16
+        let n = try_create(3.0, 2000.0);
+        assert_almost_eq!(n.inverse_cdf(1.0 - 1e-15), 2775.538973828093, 1e-9);
+        assert_almost_eq!(n.sf(n.inverse_cdf(1.0 - 1e-15)), 9.9920072216334135308e-16, 1e-26);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inverse_cdf_out_of_range() {
+        try_create(3.0, 2.5).inverse_cdf(1.5);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(3.0, 2.5), 0.0, 40.0);
+    }
+}
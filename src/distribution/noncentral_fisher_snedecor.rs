@@ -0,0 +1,407 @@
+use crate::distribution::internal::bisect_support;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::noncentral;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral
+/// Fisher-Snedecor](https://en.wikipedia.org/wiki/Noncentral_F-distribution)
+/// distribution, the distribution of the ratio of a noncentral chi-squared
+/// variable to an independent central one, each divided by its degrees of
+/// freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralFisherSnedecor, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = NoncentralFisherSnedecor::new(3.0, 20.0, 5.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 80.0 / 27.0, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(2.5), 0.211273081739843240, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralFisherSnedecor {
+    freedom_1: f64,
+    freedom_2: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralFisherSnedecor {
+    /// Constructs a new noncentral fisher-snedecor distribution with
+    /// `freedom_1` degrees of freedom in the numerator, `freedom_2` degrees
+    /// of freedom in the denominator and noncentrality parameter
+    /// `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, if
+    /// `freedom_1 <= 0.0` or `freedom_2 <= 0.0`, or if `noncentrality < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let mut result = NoncentralFisherSnedecor::new(3.0, 20.0, 5.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralFisherSnedecor::new(3.0, 0.0, 5.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(
+        freedom_1: f64,
+        freedom_2: f64,
+        noncentrality: f64,
+    ) -> Result<NoncentralFisherSnedecor> {
+        if !freedom_1.is_finite() || !freedom_2.is_finite() || !noncentrality.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if freedom_1 <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("freedom_1"));
+        }
+        if freedom_2 <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("freedom_2"));
+        }
+        if noncentrality < 0.0 {
+            return Err(StatsError::ArgNotNegative("noncentrality"));
+        }
+        Ok(NoncentralFisherSnedecor {
+            freedom_1,
+            freedom_2,
+            noncentrality,
+        })
+    }
+
+    /// Returns the numerator degrees of freedom of the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(3.0, 20.0, 5.0).unwrap();
+    /// assert_eq!(n.freedom_1(), 3.0);
+    /// ```
+    pub fn freedom_1(&self) -> f64 {
+        self.freedom_1
+    }
+
+    /// Returns the denominator degrees of freedom of the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(3.0, 20.0, 5.0).unwrap();
+    /// assert_eq!(n.freedom_2(), 20.0);
+    /// ```
+    pub fn freedom_2(&self) -> f64 {
+        self.freedom_2
+    }
+
+    /// Returns the noncentrality parameter of the noncentral
+    /// fisher-snedecor distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralFisherSnedecor;
+    ///
+    /// let n = NoncentralFisherSnedecor::new(3.0, 20.0, 5.0).unwrap();
+    /// assert_eq!(n.noncentrality(), 5.0);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns the `k`-th raw moment for `k` in `1..=3`, which exists only
+    /// if `d2 > 2k`
+    fn raw_moment(&self, k: usize) -> Option<f64> {
+        let d1 = self.freedom_1;
+        let d2 = self.freedom_2;
+        let lambda = self.noncentrality;
+        if d2 <= 2.0 * k as f64 {
+            return None;
+        }
+        let k1 = d1 + lambda;
+        let k2 = 2.0 * (d1 + 2.0 * lambda);
+        let k3 = 8.0 * (d1 + 3.0 * lambda);
+        let numerator = match k {
+            1 => k1,
+            2 => k2 + k1 * k1,
+            _ => k3 + 3.0 * k2 * k1 + k1 * k1 * k1,
+        };
+        let denominator = (1..=k).fold(1.0, |acc, i| acc * (d2 - 2.0 * i as f64));
+        Some((d2 / d1).powi(k as i32) * numerator / denominator)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralFisherSnedecor {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        (super::noncentral_chi_squared::sample_unchecked(rng, self.freedom_1, self.noncentrality)
+            * self.freedom_2)
+            / (super::gamma::sample_unchecked(rng, self.freedom_2 / 2.0, 0.5) * self.freedom_1)
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralFisherSnedecor {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * I_((d1 * x) / (d1 * x + d2))(d1 / 2 + j, d2 / 2)
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom, `λ` is the noncentrality and `I` is the regularized
+    /// incomplete beta function
+    fn cdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_f_cdf(self.freedom_1, self.freedom_2, self.noncentrality, x)
+    }
+
+    /// Calculates the survival function for the noncentral fisher-snedecor
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ e^(-λ/2) (λ/2)^j / j! * I_(d2 / (d1 * x + d2))(d2 / 2, d1 / 2 + j)
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom, `λ` is the noncentrality and `I` is the regularized
+    /// incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        noncentral::noncentral_f_sf(self.freedom_1, self.freedom_2, self.noncentrality, x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral fisher-snedecor distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        bisect_support(|t| self.cdf(t) < x, self.min(), self.max())
+    }
+
+    /// Calculates the inverse survival function for the noncentral
+    /// fisher-snedecor distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        bisect_support(|t| self.sf(t) > x, self.min(), self.max())
+    }
+}
+
+impl Min<f64> for NoncentralFisherSnedecor {
+    /// Returns the minimum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for NoncentralFisherSnedecor {
+    /// Returns the maximum value in the domain of the noncentral
+    /// fisher-snedecor distribution representable by a double precision
+    /// float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralFisherSnedecor {
+    /// Returns the mean of the noncentral fisher-snedecor distribution,
+    /// which exists only if `d2 > 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d2 (d1 + λ) / (d1 (d2 - 2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1)
+    }
+
+    /// Returns the variance of the noncentral fisher-snedecor distribution,
+    /// which exists only if `d2 > 4`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 2 (d2 / d1)^2 ((d1 + λ)^2 + (d1 + 2λ)(d2 - 2)) / ((d2 - 2)^2 (d2 - 4))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `λ` is the noncentrality
+    fn variance(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        Some(self.raw_moment(2)? - mean * mean)
+    }
+
+    /// Returns the skewness of the noncentral fisher-snedecor distribution,
+    /// which exists only if `d2 > 6`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (E[F^3] - 3μσ^2 - μ^3) / σ^3
+    /// E[F^3] = (d2 / d1)^3 E[X^3] / ((d2 - 2)(d2 - 4)(d2 - 6))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom, `μ` the mean, `σ^2` the variance and `E[X^3]` the third
+    /// raw moment of the noncentral chi-squared distribution in the
+    /// numerator
+    fn skewness(&self) -> Option<f64> {
+        let mean = self.mean()?;
+        let variance = self.variance()?;
+        let third = self.raw_moment(3)?;
+        Some((third - 3.0 * mean * variance - mean * mean * mean) / variance.powf(1.5))
+    }
+}
+
+impl Median<f64> for NoncentralFisherSnedecor {
+    /// Returns the median of the noncentral fisher-snedecor distribution,
+    /// computed by inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralFisherSnedecor {
+    /// Calculates the probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// d1 d2 / (d1 x + d2)^2 * g(d1 x / (d1 x + d2))
+    /// ```
+    ///
+    /// where `d1` is the first degree of freedom, `d2` is the second degree
+    /// of freedom and `g` is the density of the noncentral beta
+    /// distribution with shapes `d1 / 2`, `d2 / 2` and the same
+    /// noncentrality
+    fn pdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_f_pdf(self.freedom_1, self.freedom_2, self.noncentrality, x)
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// fisher-snedecor distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x))
+    /// ```
+    ///
+    /// where `f` is the density of the noncentral fisher-snedecor
+    /// distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, NoncentralFisherSnedecor};
+    use crate::statistics::*;
+
+    fn try_create(freedom_1: f64, freedom_2: f64, noncentrality: f64) -> NoncentralFisherSnedecor {
+        let n = NoncentralFisherSnedecor::new(freedom_1, freedom_2, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(3.0, 20.0, 5.0);
+        try_create(0.5, 0.5, 0.0);
+        assert!(NoncentralFisherSnedecor::new(0.0, 20.0, 5.0).is_err());
+        assert!(NoncentralFisherSnedecor::new(3.0, -1.0, 5.0).is_err());
+        assert!(NoncentralFisherSnedecor::new(3.0, 20.0, -5.0).is_err());
+        assert!(NoncentralFisherSnedecor::new(3.0, f64::INFINITY, 5.0).is_err());
+    }
+    #[test]
+    fn test_moments() {
+        let n = try_create(3.0, 20.0, 5.0);
+        assert_almost_eq!(n.mean().unwrap(), 2.9629629629629629630, 1e-15);
+        assert_almost_eq!(n.variance().unwrap(), 5.1097393689986282579, 1e-14);
+        assert_almost_eq!(n.skewness().unwrap(), 1.9592445853717109682, 1e-14);
+        assert!(try_create(3.0, 2.0, 5.0).mean().is_none());
+        assert!(try_create(3.0, 4.0, 5.0).variance().is_none());
+        assert!(try_create(3.0, 6.0, 5.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_cdf_pdf() {
+        let n = try_create(3.0, 20.0, 5.0);
+        assert_almost_eq!(n.cdf(2.5), 0.51635499925526642438, 1e-14);
+        assert_almost_eq!(n.sf(2.5), 0.48364500074473357562, 1e-14);
+        assert_almost_eq!(n.pdf(2.5), 0.21127308173984323985, 1e-12);
+        assert_eq!(n.pdf(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(3.0, 20.0, 5.0);
+        assert_almost_eq!(n.inverse_cdf(0.05), 0.49058618341194274391, 1e-12);
+        assert_almost_eq!(n.median(), 2.4234834710663885321, 1e-12);
+        assert_almost_eq!(n.inverse_cdf(0.95), 7.2491563539448100818, 1e-12);
+        assert_almost_eq!(n.inverse_sf(0.05), 7.2491563539448100818, 1e-12);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(3.0, 20.0, 5.0), 0.0, 50.0);
+    }
+}
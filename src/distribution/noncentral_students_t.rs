@@ -0,0 +1,398 @@
+use crate::distribution::internal::bisect_support;
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::{gamma, noncentral};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [noncentral Student's
+/// T](https://en.wikipedia.org/wiki/Noncentral_t-distribution) distribution,
+/// the distribution of `(Z + δ) / sqrt(V / ν)` for a standard normal `Z`
+/// and an independent chi-squared variable `V` with `ν` degrees of freedom
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{NoncentralStudentsT, Continuous};
+/// use statrs::prec;
+///
+/// let n = NoncentralStudentsT::new(10.0, 1.5).unwrap();
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.314605918450196143, 1e-12));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NoncentralStudentsT {
+    freedom: f64,
+    noncentrality: f64,
+}
+
+impl NoncentralStudentsT {
+    /// Constructs a new noncentral student's t-distribution with `freedom`
+    /// degrees of freedom and noncentrality parameter `noncentrality`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `freedom` or `noncentrality` are `NaN` or
+    /// infinite, or if `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let mut result = NoncentralStudentsT::new(10.0, -1.5);
+    /// assert!(result.is_ok());
+    ///
+    /// result = NoncentralStudentsT::new(0.0, 1.5);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(freedom: f64, noncentrality: f64) -> Result<NoncentralStudentsT> {
+        if !freedom.is_finite() || !noncentrality.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if freedom <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("freedom"));
+        }
+        Ok(NoncentralStudentsT {
+            freedom,
+            noncentrality,
+        })
+    }
+
+    /// Returns the degrees of freedom of the noncentral student's
+    /// t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let n = NoncentralStudentsT::new(10.0, 1.5).unwrap();
+    /// assert_eq!(n.freedom(), 10.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the noncentrality parameter of the noncentral student's
+    /// t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::NoncentralStudentsT;
+    ///
+    /// let n = NoncentralStudentsT::new(10.0, 1.5).unwrap();
+    /// assert_eq!(n.noncentrality(), 1.5);
+    /// ```
+    pub fn noncentrality(&self) -> f64 {
+        self.noncentrality
+    }
+
+    /// Returns the `k`-th raw moment, which exists for `k < ν`
+    fn raw_moment(&self, k: f64, normal_moment: f64) -> Option<f64> {
+        let nu = self.freedom;
+        if nu <= k {
+            return None;
+        }
+        let scale = (0.5 * k) * (0.5 * nu).ln() + gamma::ln_gamma(0.5 * (nu - k))
+            - gamma::ln_gamma(0.5 * nu);
+        Some(scale.exp() * normal_moment)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for NoncentralStudentsT {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = super::normal::sample_unchecked(rng, self.noncentrality, 1.0);
+        let v = super::gamma::sample_unchecked(rng, 0.5 * self.freedom, 0.5);
+        z / (v / self.freedom).sqrt()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for NoncentralStudentsT {
+    /// Calculates the cumulative distribution function for the noncentral
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(-δ) + 1/2 Σ (p_j I_y(j + 1/2, ν/2) + q_j I_y(j + 1, ν/2))
+    /// p_j = e^(-δ^2/2) (δ^2/2)^j / j!
+    /// q_j = δ e^(-δ^2/2) (δ^2/2)^j / (sqrt(2) Γ(j + 3/2))
+    /// ```
+    ///
+    /// for `x >= 0`, where `δ` is the noncentrality, `ν` the degrees of
+    /// freedom, `y = x^2 / (x^2 + ν)`, `Φ` is the standard normal cdf and
+    /// `I` is the regularized incomplete beta function. Negative `x` is
+    /// handled by reflection.
+    fn cdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_t_cdf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the survival function for the noncentral student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - F(x)
+    /// ```
+    ///
+    /// where `F` is the cdf, evaluated with complementary incomplete beta
+    /// functions to retain precision in the upper tail
+    fn sf(&self, x: f64) -> f64 {
+        noncentral::noncentral_t_sf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// noncentral student's t-distribution at `x` by bisection, locating
+    /// upper quantiles with the survival function
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        if x > 0.5 {
+            return bisect_support(|t| self.sf(t) > 1.0 - x, self.min(), self.max());
+        }
+        bisect_support(|t| self.cdf(t) < x, self.min(), self.max())
+    }
+
+    /// Calculates the inverse survival function for the noncentral
+    /// student's t-distribution at `x` by bisection, locating lower
+    /// quantiles with the cdf
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        if x > 0.5 {
+            return bisect_support(|t| self.cdf(t) < 1.0 - x, self.min(), self.max());
+        }
+        bisect_support(|t| self.sf(t) > x, self.min(), self.max())
+    }
+}
+
+impl Min<f64> for NoncentralStudentsT {
+    /// Returns the minimum value in the domain of the noncentral student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for NoncentralStudentsT {
+    /// Returns the maximum value in the domain of the noncentral student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for NoncentralStudentsT {
+    /// Returns the mean of the noncentral student's t-distribution, which
+    /// exists only if `ν > 1`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// δ * sqrt(ν / 2) * Γ((ν - 1) / 2) / Γ(ν / 2)
+    /// ```
+    ///
+    /// where `δ` is the noncentrality, `ν` the degrees of freedom and `Γ`
+    /// is the gamma function
+    fn mean(&self) -> Option<f64> {
+        self.raw_moment(1.0, self.noncentrality)
+    }
+
+    /// Returns the variance of the noncentral student's t-distribution,
+    /// which exists only if `ν > 2`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ν (1 + δ^2) / (ν - 2) - μ^2
+    /// ```
+    ///
+    /// where `δ` is the noncentrality, `ν` the degrees of freedom and `μ`
+    /// the mean
+    fn variance(&self) -> Option<f64> {
+        let delta = self.noncentrality;
+        let mean = self.mean()?;
+        let second = self.raw_moment(2.0, 1.0 + delta * delta)?;
+        Some(second - mean * mean)
+    }
+
+    /// Returns the skewness of the noncentral student's t-distribution,
+    /// which exists only if `ν > 3`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (E[T^3] - 3μσ^2 - μ^3) / σ^3
+    /// E[T^3] = (ν / 2)^(3/2) * Γ((ν - 3) / 2) / Γ(ν / 2) * (δ^3 + 3δ)
+    /// ```
+    ///
+    /// where `δ` is the noncentrality, `ν` the degrees of freedom, `μ` the
+    /// mean, `σ^2` the variance and `Γ` is the gamma function
+    fn skewness(&self) -> Option<f64> {
+        let delta = self.noncentrality;
+        let mean = self.mean()?;
+        let variance = self.variance()?;
+        let third = self.raw_moment(3.0, delta * (delta * delta + 3.0))?;
+        Some((third - 3.0 * mean * variance - mean * mean * mean) / variance.powf(1.5))
+    }
+}
+
+impl Median<f64> for NoncentralStudentsT {
+    /// Returns the median of the noncentral student's t-distribution,
+    /// computed by inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Continuous<f64, f64> for NoncentralStudentsT {
+    /// Calculates the probability density function for the noncentral
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ φ(x sqrt(v / ν) - δ) sqrt(v / ν) f_ν(v) dv
+    /// ```
+    ///
+    /// where `δ` is the noncentrality, `ν` the degrees of freedom, `φ` the
+    /// standard normal density and `f_ν` the chi-squared density, evaluated
+    /// as a Poisson mixture of beta densities
+    fn pdf(&self, x: f64) -> f64 {
+        noncentral::noncentral_t_pdf(self.freedom, self.noncentrality, x)
+    }
+
+    /// Calculates the log probability density function for the noncentral
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x))
+    /// ```
+    ///
+    /// where `f` is the density of the noncentral student's t-distribution
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::distribution::internal::*;
+    use crate::distribution::{Continuous, ContinuousCDF, NoncentralStudentsT};
+    use crate::statistics::*;
+
+    fn try_create(freedom: f64, noncentrality: f64) -> NoncentralStudentsT {
+        let n = NoncentralStudentsT::new(freedom, noncentrality);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(10.0, 1.5);
+        try_create(0.5, -3.0);
+        assert!(NoncentralStudentsT::new(0.0, 1.0).is_err());
+        assert!(NoncentralStudentsT::new(f64::INFINITY, 1.0).is_err());
+        assert!(NoncentralStudentsT::new(10.0, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(10.0, 1.5);
+        assert_almost_eq!(n.mean().unwrap(), 1.6255834619087154547, 1e-14);
+        assert_almost_eq!(n.variance().unwrap(), 1.4199784083688758504, 1e-13);
+        assert_almost_eq!(n.skewness().unwrap(), 0.57405824314791091691, 1e-13);
+        assert_eq!(try_create(10.0, 0.0).mean().unwrap(), 0.0);
+        assert!(try_create(1.0, 1.5).mean().is_none());
+        assert!(try_create(2.0, 1.5).variance().is_none());
+        assert!(try_create(3.0, 1.5).skewness().is_none());
+    }
+
+    #[test]
+    fn test_cdf_pdf() {
+        let n = try_create(10.0, 1.5);
+        assert_almost_eq!(n.cdf(2.0), 0.65915407244219081540, 1e-11);
+        assert_almost_eq!(n.sf(2.0), 0.34084592755780918460, 1e-13);
+        assert_almost_eq!(n.cdf(-1.0), 0.0077790953543366217351, 1e-14);
+        assert_almost_eq!(n.pdf(2.0), 0.31460591845019614340, 1e-12);
+        assert_almost_eq!(n.pdf(-1.0), 0.018120743153011294142, 1e-13);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(10.0, 1.5);
+        assert_almost_eq!(n.inverse_cdf(0.05), -0.14942854713197461692, 1e-10);
+        assert_almost_eq!(n.median(), 1.5393384473884732568, 1e-10);
+        assert_almost_eq!(n.inverse_cdf(0.95), 3.6897891894112774896, 1e-10);
+        assert_almost_eq!(n.inverse_sf(0.05), 3.6897891894112774896, 1e-10);
+        assert_eq!(n.inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(n.inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_cdf_far_tail() {
+        let n = try_create(1.0, 1.0);
+        assert_almost_eq!(n.inverse_cdf(1e-300), -6.6476127657940128547e298, 1e287);
+        assert_almost_eq!(n.inverse_sf(1e-300), 8.6436068846080548443e299, 1e287);
+        for (freedom, delta) in [(5.0, 2.0), (5.0, -2.0), (5.0, 40.0), (1.0, 1.0)] {
+            let n = try_create(freedom, delta);
+            assert_almost_eq!(n.sf(n.inverse_cdf(1.0 - 1e-15)), 1.0 - (1.0 - 1e-15), 1e-26);
+        }
+    }
+
+    #[test]
+    fn test_cdf_sf_sum_to_one() {
+        for (freedom, delta) in [(5.0, 2.0), (5.0, -2.0), (5.0, 40.0), (1.0, 1.0)] {
+            let n = try_create(freedom, delta);
+            for x in [-1e6, -3.0, 0.0, 1.0, 40.0, 1e6] {
+                assert_eq!(n.cdf(x) + n.sf(x), 1.0);
+            }
+        }
+        assert_almost_eq!(try_create(5.0, 2.0).sf(1e6), 4.2226928871118096182e-28, 1e-40);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(10.0, 1.5), -10.0, 20.0);
+    }
+}
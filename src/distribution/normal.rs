@@ -1,4 +1,4 @@
-use crate::distribution::{ziggurat, Continuous, ContinuousCDF};
+use crate::distribution::{ziggurat, Continuous, ContinuousCDF, Fit};
 use crate::function::erf;
 use crate::statistics::*;
 use crate::{consts, Result, StatsError};
@@ -51,6 +51,17 @@ impl Normal {
             Ok(Normal { mean, std_dev })
         }
     }
+
+    /// Draws an exact sample from the distribution truncated to
+    /// `[lower, upper]`, see `sample_truncated_unchecked`
+    pub(crate) fn sample_truncated<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        lower: f64,
+        upper: f64,
+    ) -> f64 {
+        sample_truncated_unchecked(rng, self.mean, self.std_dev, lower, upper)
+    }
 }
 
 impl ::rand::distributions::Distribution<f64> for Normal {
@@ -96,6 +107,72 @@ impl ContinuousCDF<f64, f64> for Normal {
             self.mean - (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
         }
     }
+    /// Calculates the survival function for the
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (1 / 2) * erfc((x - μ) / (σ * sqrt(2)))
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation, and
+    /// `erfc` is the complementary error function
+    fn sf(&self, x: f64) -> f64 {
+        sf_unchecked(x, self.mean, self.std_dev)
+    }
+    /// Calculates the inverse survival function for the
+    /// normal distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + sqrt(2) * σ * erfc_inv(2x)
+    /// ```
+    ///
+    /// where `μ` is the mean, `σ` is the standard deviation and `erfc_inv` is
+    /// the inverse of the complementary error function
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.mean + (self.std_dev * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x))
+        }
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln((1 / 2) * erfc(-z / sqrt(2)))                          if z > -20
+    /// -z^2 / 2 - ln(-z) - ln(sqrt(2π)) + ln(sum_k (-1)^k (2k - 1)!! / z^(2k)) otherwise
+    /// ```
+    ///
+    /// where `z = (x - μ) / σ`, `μ` is the mean, `σ` is the standard
+    /// deviation, and `erfc` is the complementary error function. The second
+    /// branch is the asymptotic expansion of the Mills ratio, which stays
+    /// finite where the cdf underflows
+    fn ln_cdf(&self, x: f64) -> f64 {
+        ln_cdf_unchecked(x, self.mean, self.std_dev)
+    }
+    /// Calculates the log of the survival function for the
+    /// normal distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln_cdf(2μ - x)
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn ln_sf(&self, x: f64) -> f64 {
+        ln_sf_unchecked(x, self.mean, self.std_dev)
+    }
 }
 
 impl Min<f64> for Normal {
@@ -238,6 +315,46 @@ pub fn cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
     0.5 * erf::erfc((mean - x) / (std_dev * f64::consts::SQRT_2))
 }
 
+/// performs an unchecked sf calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    0.5 * erf::erfc((x - mean) / (std_dev * f64::consts::SQRT_2))
+}
+
+/// performs an unchecked log(cdf) calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn ln_cdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    ln_std_cdf((x - mean) / std_dev)
+}
+
+/// performs an unchecked log(sf) calculation for a normal distribution
+/// with the given mean and standard deviation at x
+pub fn ln_sf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
+    ln_std_cdf((mean - x) / std_dev)
+}
+
+/// Computes the log of the standard normal cdf, switching to the asymptotic
+/// expansion of the Mills ratio once `erfc` would underflow
+fn ln_std_cdf(z: f64) -> f64 {
+    if z > 0.0 {
+        (-0.5 * erf::erfc(z / f64::consts::SQRT_2)).ln_1p()
+    } else if z > -20.0 {
+        (0.5 * erf::erfc(-z / f64::consts::SQRT_2)).ln()
+    } else {
+        let z2 = z * z;
+        let mut term = 1.0;
+        let mut sum = 1.0;
+        for k in 1..100 {
+            term *= -f64::from(2 * k - 1) / z2;
+            if term.abs() <= f64::EPSILON * sum {
+                break;
+            }
+            sum += term;
+        }
+        -0.5 * z2 - (-z).ln() - consts::LN_SQRT_2PI + sum.ln()
+    }
+}
+
 /// performs an unchecked pdf calculation for a normal distribution
 /// with the given mean and standard deviation at x
 pub fn pdf_unchecked(x: f64, mean: f64, std_dev: f64) -> f64 {
@@ -257,11 +374,91 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, mean: f64, std_dev: f64) -
     mean + std_dev * ziggurat::sample_std_normal(rng)
 }
 
+/// draws an exact sample from a normal distribution truncated to
+/// `[lower, upper]` using the rejection samplers of Robert (1995), whose
+/// acceptance rate does not degrade however far out in a tail the interval
+/// lies
+pub fn sample_truncated_unchecked<R: Rng + ?Sized>(
+    rng: &mut R,
+    mean: f64,
+    std_dev: f64,
+    lower: f64,
+    upper: f64,
+) -> f64 {
+    let z = sample_std_truncated(rng, (lower - mean) / std_dev, (upper - mean) / std_dev);
+    (mean + std_dev * z).max(lower).min(upper)
+}
+
+/// draws a standard normal sample truncated to `[a, b]`
+fn sample_std_truncated<R: Rng + ?Sized>(rng: &mut R, a: f64, b: f64) -> f64 {
+    if a >= 0.0 {
+        sample_std_tail(rng, a, b)
+    } else if b <= 0.0 {
+        -sample_std_tail(rng, -b, -a)
+    } else if b - a >= consts::SQRT_2PI {
+        loop {
+            let z = ziggurat::sample_std_normal(rng);
+            if a <= z && z <= b {
+                return z;
+            }
+        }
+    } else {
+        loop {
+            let z = a + (b - a) * rng.gen::<f64>();
+            if rng.gen::<f64>() <= (-0.5 * z * z).exp() {
+                return z;
+            }
+        }
+    }
+}
+
+/// draws a standard normal sample truncated to `[a, b]` for `0 <= a < b`,
+/// proposing from a uniform on short intervals and otherwise from the
+/// optimally scaled exponential shifted to `a`
+fn sample_std_tail<R: Rng + ?Sized>(rng: &mut R, a: f64, b: f64) -> f64 {
+    let root = (a * a + 4.0).sqrt();
+    let uniform_width = 2.0 / (a + root) * (0.5 + 0.25 * (a * a - a * root)).exp();
+    if b - a < uniform_width {
+        loop {
+            let z = a + (b - a) * rng.gen::<f64>();
+            if rng.gen::<f64>() <= (0.5 * (a * a - z * z)).exp() {
+                return z;
+            }
+        }
+    } else {
+        let rate = 0.5 * (a + root);
+        loop {
+            let z = a + ziggurat::sample_exp_1(rng) / rate;
+            let d = z - rate;
+            if z <= b && rng.gen::<f64>() <= (-0.5 * d * d).exp() {
+                return z;
+            }
+        }
+    }
+}
+
+impl Fit<f64> for Normal {
+    /// Fits a normal distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ = (1 / n) * Σ x_i
+    /// σ = sqrt((1 / n) * Σ (x_i - μ)^2)
+    /// ```
+    fn fit(data: &[f64]) -> Result<Normal> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        Normal::new(data.mean(), data.population_std_dev())
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Normal};
+    use crate::distribution::{ContinuousCDF, Continuous, Normal, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -464,4 +661,63 @@ mod tests {
         test_almost(5.0, 2.0, 10.0, 1e-14, inverse_cdf(0.9937903346742238648330218954258077788721022530769078));
         test_case(5.0, 2.0, f64::INFINITY, inverse_cdf(1.0));
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Normal| x.sf(arg);
+        test_case(5.0, 2.0, 1.0, sf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, 0.84134474606854294859, 1e-10, sf(-1.0));
+        test_case(5.0, 2.0, 0.5, sf(5.0));
+        test_almost(0.0, 1.0, 7.619853024160526066e-24, 1e-33, sf(10.0));
+        test_almost(5.0, 2.0, 7.619853024160526066e-24, 1e-33, sf(25.0));
+        test_case(5.0, 2.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Normal| x.inverse_sf(arg);
+        test_case(5.0, 2.0, f64::INFINITY, inverse_sf(0.0));
+        test_almost(0.0, 1.0, 7.0344838253011319298, 1e-13, inverse_sf(1e-12));
+        test_almost(5.0, 2.0, 5.0, 1e-14, inverse_sf(0.5));
+        test_almost(5.0, 2.0, 4.0, 1e-14, inverse_sf(0.69146246127401310363770461060833773988360217555457859));
+        test_case(5.0, 2.0, f64::NEG_INFINITY, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: f64| move |x: Normal| x.ln_cdf(arg);
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_cdf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, -1.8410216450092635058, 1e-10, ln_cdf(-1.0));
+        test_almost(0.0, 1.0, -53.231285150512470578, 1e-9, ln_cdf(-10.0));
+        test_almost(0.0, 1.0, -201.91716770733259757, 1e-9, ln_cdf(-19.9));
+        test_almost(0.0, 1.0, -203.91715537109726394, 1e-12, ln_cdf(-20.0));
+        test_almost(0.0, 1.0, -205.92711840194824576, 1e-12, ln_cdf(-20.1));
+        test_almost(0.0, 1.0, -804.60844201375378817, 1e-12, ln_cdf(-40.0));
+        test_almost(5.0, 2.0, -804.60844201375378817, 1e-12, ln_cdf(-75.0));
+        test_almost(0.0, 1.0, -500007.82669481218431, 1e-9, ln_cdf(-1000.0));
+        test_almost(0.0, 1.0, -0.17275377902344988953, 1e-10, ln_cdf(1.0));
+        test_almost(0.0, 1.0, -7.619853024160526066e-24, 1e-33, ln_cdf(10.0));
+        test_case(0.0, 1.0, 0.0, ln_cdf(40.0));
+        test_case(0.0, 1.0, 0.0, ln_cdf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: f64| move |x: Normal| x.ln_sf(arg);
+        test_case(0.0, 1.0, 0.0, ln_sf(f64::NEG_INFINITY));
+        test_almost(0.0, 1.0, -2.8665161296376359338e-7, 1e-16, ln_sf(-5.0));
+        test_almost(0.0, 1.0, -1.8410216450092635058, 1e-10, ln_sf(1.0));
+        test_almost(0.0, 1.0, -804.60844201375378817, 1e-12, ln_sf(40.0));
+        test_almost(5.0, 2.0, -804.60844201375378817, 1e-12, ln_sf(85.0));
+        test_case(0.0, 1.0, f64::NEG_INFINITY, ln_sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Normal::fit(&[1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+        assert_eq!(n.mean().unwrap(), 3.0);
+        assert_almost_eq!(n.std_dev().unwrap(), 2f64.sqrt(), 1e-15);
+        assert!(Normal::fit(&[]).is_err());
+        assert!(Normal::fit(&[1.0, 1.0]).is_err());
+    }
 }
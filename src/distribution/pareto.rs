@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::OpenClosed01;
@@ -113,6 +113,69 @@ impl ContinuousCDF<f64, f64> for Pareto {
             1.0 - (self.scale / x).powf(self.shape)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// Pareto distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_m * (1 - x)^(-1 / α)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.scale * (-(-x).ln_1p() / self.shape).exp()
+        }
+    }
+    /// Calculates the survival function for the Pareto
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < x_m {
+    ///     1
+    /// } else {
+    ///     (x_m/x)^α
+    /// }
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn sf(&self, x: f64) -> f64 {
+        if x < self.scale {
+            1.0
+        } else {
+            (self.scale / x).powf(self.shape)
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// Pareto distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_m * x^(-1 / α)
+    /// ```
+    ///
+    /// where `x_m` is the scale and `α` is the shape
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.scale * x.powf(-1.0 / self.shape)
+        }
+    }
 }
 
 impl Min<f64> for Pareto {
@@ -302,11 +365,33 @@ impl Continuous<f64, f64> for Pareto {
     }
 }
 
+impl Fit<f64> for Pareto {
+    /// Fits a pareto distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// x_m = min_i x_i
+    /// α = n / Σ ln(x_i / x_m)
+    /// ```
+    fn fit(data: &[f64]) -> Result<Pareto> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        let scale = Statistics::min(data);
+        let shape = 1.0 / data.iter().map(|x| (x / scale).ln()).mean();
+        Pareto::new(scale, shape)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Pareto};
+    use crate::distribution::{ContinuousCDF, Continuous, Pareto, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -492,4 +577,40 @@ mod tests {
         test::check_continuous_distribution(&try_create(1.0, 10.0), 1.0, 10.0);
         test::check_continuous_distribution(&try_create(0.1, 2.0), 0.1, 100.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Pareto| x.inverse_cdf(arg);
+        test_case(1.0, 1.0, 1.0, inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 2.0, 1e-15, inverse_cdf(0.5));
+        test_almost(3.0, 2.0, 3.0 * 2f64.sqrt(), 1e-14, inverse_cdf(0.5));
+        test_almost(1.0, 1.0, 4.0, 1e-15, inverse_cdf(0.75));
+        test_case(1.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Pareto| x.sf(arg);
+        test_case(1.0, 1.0, 1.0, sf(0.5));
+        test_case(1.0, 1.0, 1.0, sf(1.0));
+        test_almost(1.0, 1.0, 1e-10, 1e-25, sf(1e10));
+        test_almost(3.0, 2.0, 0.25, 1e-15, sf(6.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Pareto| x.inverse_sf(arg);
+        test_almost(1.0, 1.0, 1e10, 1e-5, inverse_sf(1e-10));
+        test_almost(3.0, 2.0, 6.0, 1e-14, inverse_sf(0.25));
+        test_case(1.0, 1.0, 1.0, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Pareto::fit(&[1.0, 2.0, 4.0]).unwrap();
+        assert_eq!(n.scale(), 1.0);
+        assert_almost_eq!(n.shape(), 1.0 / 2f64.ln(), 1e-15);
+        assert!(Pareto::fit(&[]).is_err());
+        assert!(Pareto::fit(&[1.0, -1.0]).is_err());
+    }
 }
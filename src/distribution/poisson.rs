@@ -1,4 +1,4 @@
-use crate::distribution::{Discrete, DiscreteCDF};
+use crate::distribution::{Discrete, DiscreteCDF, Fit};
 use crate::function::{factorial, gamma};
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -92,6 +92,49 @@ impl DiscreteCDF<u64, f64> for Poisson {
     fn cdf(&self, x: u64) -> f64 {
         1.0 - gamma::gamma_lr(x as f64 + 1.0, self.lambda)
     }
+    /// Calculates the survival function for the poisson
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// P(x + 1, λ)
+    /// ```
+    ///
+    /// where `λ` is the rate and `P` is the lower regularized gamma function
+    fn sf(&self, x: u64) -> f64 {
+        gamma::gamma_lr(x as f64 + 1.0, self.lambda)
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// poisson distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(Q(x + 1, λ))
+    /// ```
+    ///
+    /// where `λ` is the rate and `Q` is the upper regularized incomplete
+    /// gamma function, evaluated in log space so that it stays finite where
+    /// the cdf underflows
+    fn ln_cdf(&self, x: u64) -> f64 {
+        gamma::ln_gamma_ur(x as f64 + 1.0, self.lambda)
+    }
+    /// Calculates the log of the survival function for the poisson
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(P(x + 1, λ))
+    /// ```
+    ///
+    /// where `λ` is the rate and `P` is the lower regularized incomplete
+    /// gamma function, evaluated in log space so that it stays finite where
+    /// the survival function underflows
+    fn ln_sf(&self, x: u64) -> f64 {
+        gamma::ln_gamma_lr(x as f64 + 1.0, self.lambda)
+    }
 }
 
 impl Min<u64> for Poisson {
@@ -279,12 +322,29 @@ pub fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, lambda: f64) -> f64 {
     }
 }
 
+impl Fit<u64> for Poisson {
+    /// Fits a poisson distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ = (1 / n) * Σ x_i
+    /// ```
+    fn fit(data: &[u64]) -> Result<Poisson> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        let sum: u64 = data.iter().sum();
+        Poisson::new(sum as f64 / data.len() as f64)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{DiscreteCDF, Discrete, Poisson};
+    use crate::distribution::{DiscreteCDF, Discrete, Poisson, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -448,4 +508,46 @@ mod tests {
         test::check_discrete_distribution(&try_create(0.3), 10);
         test::check_discrete_distribution(&try_create(4.5), 30);
     }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: u64| move |x: Poisson| x.sf(arg);
+        test_almost(1.0, 7.542625077205278476e-21, 1e-34, sf(20));
+        test_almost(1.0, 1.0 - (-1f64).exp(), 1e-15, sf(0));
+        test_almost(5.5, 0.99591322856153597, 1e-15, sf(0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Poisson| x.inverse_sf(arg);
+        test_case(1.0, 20, inverse_sf(1e-20));
+        test_case(1.0, 0, inverse_sf(0.7));
+        test_case(1.0, 1, inverse_sf(0.6));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: u64| move |x: Poisson| x.ln_cdf(arg);
+        test_almost(1000.0, -946.01681962963279419, 1e-10, ln_cdf(10));
+        test_almost(1.0, -1.0, 1e-14, ln_cdf(0));
+        test_almost(5.5, -0.63692057061002553706, 1e-14, ln_cdf(5));
+        test_almost(1e155, -1e155, 1e140, ln_cdf(3));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: u64| move |x: Poisson| x.ln_sf(arg);
+        test_almost(1.0, -869.53032943304084829, 1e-10, ln_sf(200));
+        test_almost(1.0, -0.45867514538708189102, 1e-15, ln_sf(0));
+        test_almost(5.5, -0.75272455979382207254, 1e-14, ln_sf(5));
+        test_almost(1e155, 0.0, 1e-300, ln_sf(3));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Poisson::fit(&[0, 1, 2, 3, 4]).unwrap();
+        assert_eq!(n.lambda(), 2.0);
+        assert!(Poisson::fit(&[]).is_err());
+        assert!(Poisson::fit(&[0, 0]).is_err());
+    }
 }
@@ -0,0 +1,481 @@
+use crate::distribution::internal::bisect_support;
+use crate::distribution::{normal, ziggurat, Continuous, ContinuousCDF};
+use crate::function::owens_t::{owens_t, owens_t_complement};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the [skew
+/// normal](https://en.wikipedia.org/wiki/Skew_normal_distribution)
+/// distribution of Azzalini, which extends the normal distribution by a
+/// shape parameter controlling its skewness
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{SkewNormal, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 2.5138795132120960289, 1e-15));
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.32854482761937064008, 1e-10));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkewNormal {
+    location: f64,
+    scale: f64,
+    shape: f64,
+}
+
+impl SkewNormal {
+    /// Constructs a new skew normal distribution with location `location`,
+    /// scale `scale` and shape `shape`. A shape of zero gives the normal
+    /// distribution, positive shapes skew the distribution to the right and
+    /// negative shapes to the left.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN` or infinite, or if
+    /// `scale <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let mut result = SkewNormal::new(1.0, 2.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = SkewNormal::new(1.0, 0.0, 3.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, shape: f64) -> Result<SkewNormal> {
+        if !location.is_finite() || !scale.is_finite() || !shape.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        Ok(SkewNormal {
+            location,
+            scale,
+            shape,
+        })
+    }
+
+    /// Returns the location of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the shape of the skew normal distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewNormal;
+    ///
+    /// let n = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `δ = α / sqrt(1 + α^2)` for the shape `α`
+    fn delta(&self) -> f64 {
+        self.shape / self.shape.hypot(1.0)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for SkewNormal {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        self.location + self.scale * sample_std_unchecked(rng, self.delta())
+    }
+}
+
+impl ContinuousCDF<f64, f64> for SkewNormal {
+    /// Calculates the cumulative distribution function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Φ(z) - 2 T(z, α)
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape, `Φ` is the standard normal cdf and `T` is Owen's T
+    /// function. In the short tail `z < 0, α > 0` this is evaluated as
+    /// `2 (T(z, ∞) - T(z, α))` to avoid cancellation.
+    fn cdf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z < 0.0 && self.shape > 0.0 {
+            // Φ(z) = 2 T(z, ∞) for z <= 0
+            return 2.0 * owens_t_complement(z, self.shape);
+        }
+        (normal::cdf_unchecked(z, 0.0, 1.0) - 2.0 * owens_t(z, self.shape)).clamp(0.0, 1.0)
+    }
+
+    /// Calculates the survival function for the skew normal distribution at
+    /// `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - Φ(z) + 2 T(z, α)
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape, `Φ` is the standard normal cdf and `T` is Owen's T
+    /// function. In the short tail `z > 0, α < 0` this is evaluated as
+    /// `2 (T(z, ∞) - T(z, -α))` to avoid cancellation.
+    fn sf(&self, x: f64) -> f64 {
+        let z = (x - self.location) / self.scale;
+        if z > 0.0 && self.shape < 0.0 {
+            return 2.0 * owens_t_complement(z, -self.shape);
+        }
+        (normal::sf_unchecked(z, 0.0, 1.0) + 2.0 * owens_t(z, self.shape)).clamp(0.0, 1.0)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the skew
+    /// normal distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        bisect_support(|t| self.cdf(t) < x, self.min(), self.max())
+    }
+
+    /// Calculates the inverse survival function for the skew normal
+    /// distribution at `x` by bisection
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        bisect_support(|t| self.sf(t) > x, self.min(), self.max())
+    }
+}
+
+impl Min<f64> for SkewNormal {
+    /// Returns the minimum value in the domain of the skew normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for SkewNormal {
+    /// Returns the maximum value in the domain of the skew normal
+    /// distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for SkewNormal {
+    /// Returns the mean of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ + ω δ sqrt(2 / π)
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `δ = α / sqrt(1 + α^2)`
+    /// and `α` is the shape
+    fn mean(&self) -> Option<f64> {
+        Some(self.location + self.scale * self.delta() * f64::consts::FRAC_2_PI.sqrt())
+    }
+
+    /// Returns the variance of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ω^2 (1 - 2 δ^2 / π)
+    /// ```
+    ///
+    /// where `ω` is the scale, `δ = α / sqrt(1 + α^2)` and `α` is the shape
+    fn variance(&self) -> Option<f64> {
+        let delta = self.delta();
+        Some(self.scale * self.scale * (1.0 - f64::consts::FRAC_2_PI * delta * delta))
+    }
+
+    /// Returns the skewness of the skew normal distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (4 - π) / 2 * (δ sqrt(2 / π))^3 / (1 - 2 δ^2 / π)^(3 / 2)
+    /// ```
+    ///
+    /// where `δ = α / sqrt(1 + α^2)` and `α` is the shape
+    fn skewness(&self) -> Option<f64> {
+        let m = self.delta() * f64::consts::FRAC_2_PI.sqrt();
+        Some(0.5 * (4.0 - f64::consts::PI) * m.powi(3) / (1.0 - m * m).powf(1.5))
+    }
+}
+
+impl Median<f64> for SkewNormal {
+    /// Returns the median of the skew normal distribution, found by
+    /// inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for SkewNormal {
+    /// Returns the mode of the skew normal distribution, found by bisection
+    /// on the derivative of the log density
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ + ω z
+    /// ```
+    ///
+    /// where `z` solves `α φ(α z) / Φ(α z) = z`, `ξ` is the location, `ω`
+    /// is the scale, `α` is the shape and `φ` and `Φ` are the standard
+    /// normal pdf and cdf
+    fn mode(&self) -> Option<f64> {
+        let alpha = self.shape;
+        let z = bisect_support(
+            |z| {
+                let ln_mills = normal::ln_pdf_unchecked(alpha * z, 0.0, 1.0)
+                    - normal::ln_cdf_unchecked(alpha * z, 0.0, 1.0);
+                alpha * ln_mills.exp() > z
+            },
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+        );
+        Some(self.location + self.scale * z)
+    }
+}
+
+impl Continuous<f64, f64> for SkewNormal {
+    /// Calculates the probability density function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / ω) φ(z) Φ(α z)
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape and `φ` and `Φ` are the standard normal pdf and cdf
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the skew normal
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / ω) + ln(φ(z)) + ln(Φ(α z))
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `α`
+    /// is the shape and `φ` and `Φ` are the standard normal pdf and cdf
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        f64::consts::LN_2 - self.scale.ln()
+            + normal::ln_pdf_unchecked(z, 0.0, 1.0)
+            + normal::ln_cdf_unchecked(self.shape * z, 0.0, 1.0)
+    }
+}
+
+/// draws a sample from the standard skew normal distribution with
+/// `δ = α / sqrt(1 + α^2)` for the shape `α`, by reflecting a normal
+/// variable correlated with `δ` on the sign of another
+pub fn sample_std_unchecked<R: Rng + ?Sized>(rng: &mut R, delta: f64) -> f64 {
+    let u0 = ziggurat::sample_std_normal(rng);
+    let v = ziggurat::sample_std_normal(rng);
+    let u1 = delta * u0 + (1.0 - delta * delta).sqrt() * v;
+    if u0 >= 0.0 {
+        u1
+    } else {
+        -u1
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, Normal, SkewNormal};
+    use crate::distribution::internal::*;
+    use rand::distributions::Distribution as RandDistribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(location: f64, scale: f64, shape: f64) -> SkewNormal {
+        let n = SkewNormal::new(location, scale, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(SkewNormal) -> f64
+    {
+        let x = eval(try_create(1.0, 2.0, shape));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(1.0, 2.0, 3.0);
+        try_create(-1.0, 0.1, -30.0);
+        try_create(0.0, 1.0, 0.0);
+        assert!(SkewNormal::new(1.0, 0.0, 3.0).is_err());
+        assert!(SkewNormal::new(1.0, -2.0, 3.0).is_err());
+        assert!(SkewNormal::new(f64::NAN, 2.0, 3.0).is_err());
+        assert!(SkewNormal::new(1.0, 2.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(3.0, 2.5138795132120960289, 1e-15, |x| x.mean().unwrap());
+        test_almost(3.0, 1.7081688194767071649, 1e-14, |x| x.variance().unwrap());
+        test_almost(3.0, 0.66702357015240795331, 1e-14, |x| x.skewness().unwrap());
+        test_almost(-3.0, -0.51387951321209602895, 1e-15, |x| x.mean().unwrap());
+        test_almost(-3.0, -0.66702357015240795331, 1e-14, |x| x.skewness().unwrap());
+        test_almost(0.0, 0.0, 1e-15, |x| x.skewness().unwrap());
+        assert!(try_create(1.0, 2.0, 3.0).entropy().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(3.0, 2.3439879582878360061, 1e-9, |x| x.median());
+        test_almost(-3.0, -0.34398795828783600613, 1e-9, |x| x.median());
+        test_almost(3.0, 1.9467912587336272536, 1e-10, |x| x.mode().unwrap());
+        test_almost(-3.0, 0.053208741266372746379, 1e-10, |x| x.mode().unwrap());
+        test_almost(0.0, 1.0, 1e-15, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(3.0, 0.00032663580474049945883, 1e-13, |x| x.pdf(-1.0));
+        test_almost(3.0, 0.32854482761937064008, 1e-10, |x| x.pdf(2.0));
+        test_almost(3.0, 0.053990966459921231445, 1e-11, |x| x.pdf(5.0));
+        test_almost(-3.0, 0.24164408871440285034, 1e-10, |x| x.pdf(-1.0));
+        test_almost(-3.0, 5.3266820505555420668e-11, 1e-20, |x| x.pdf(5.0));
+        test_almost(-3.0, 5.3266820505555420668e-11f64.ln(), 1e-10, |x| x.ln_pdf(5.0));
+        let normal = Normal::new(1.0, 2.0).unwrap();
+        test_almost(0.0, normal.pdf(2.5), 1e-15, |x| x.pdf(2.5));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(3.0, 0.000056244433711877094156, 1e-13, |x| x.cdf(-1.0));
+        test_almost(3.0, 0.38929437512197628151, 1e-10, |x| x.cdf(2.0));
+        test_almost(3.0, 0.045500263891269288425, 1e-10, |x| x.sf(5.0));
+        test_almost(-3.0, 0.31725426342920222574, 1e-10, |x| x.cdf(-1.0));
+        test_almost(-3.0, 0.68274573657079777426, 1e-10, |x| x.sf(-1.0));
+        test_almost(-3.0, 0.0063694525739500742321, 1e-10, |x| x.sf(2.0));
+    }
+
+    #[test]
+    fn test_cdf_short_tail() {
+        test_almost(3.0, 3.2642743096552352041e-23, 1e-36, |x| x.cdf(-5.0));
+        test_almost(3.0, 2.1659607561811265895e-58, 1e-71, |x| x.cdf(-9.0));
+        test_almost(3.0, 7.5360133756552132367e-222, 1e-235, |x| x.cdf(-19.0));
+        test_almost(10.0, 1.4647900657554495214e-92, 1e-105, |x| x.cdf(-3.0));
+        test_almost(3.0, 0.041519856588776702134, 1e-15, |x| x.cdf(0.6));
+        test_almost(-3.0, 2.1659607561811265895e-58, 1e-71, |x| x.sf(11.0));
+        test_almost(-3.0, 7.5360133756552132367e-222, 1e-235, |x| x.sf(21.0));
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(3.0, 0.98779686938951061938, 1e-9, |x| x.inverse_cdf(0.1));
+        test_almost(3.0, 4.2897072081362529931, 1e-9, |x| x.inverse_cdf(0.9));
+        test_almost(3.0, 4.2897072081362529931, 1e-9, |x| x.inverse_sf(0.1));
+        test_almost(-3.0, -2.2897072081362529931, 1e-9, |x| x.inverse_cdf(0.1));
+        test_almost(-3.0, 1.0122031306104893806, 1e-9, |x| x.inverse_sf(0.1));
+        test_almost(3.0, -3.7506319584799212209, 1e-13, |x| x.inverse_cdf(1e-15));
+        test_almost(3.0, -12.324046901620780211, 1e-12, |x| x.inverse_cdf(1e-100));
+        test_almost(3.0, -22.346310029504919645, 1e-12, |x| x.inverse_cdf(1e-300));
+        test_almost(-3.0, 14.324046901620780211, 1e-12, |x| x.inverse_sf(1e-100));
+        assert_eq!(try_create(1.0, 2.0, 3.0).inverse_cdf(0.0), f64::NEG_INFINITY);
+        assert_eq!(try_create(1.0, 2.0, 3.0).inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 2.0, 3.0), -3.0, 12.0);
+        test::check_continuous_distribution(&try_create(1.0, 2.0, -3.0), -10.0, 5.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(9);
+        let n = try_create(1.0, 2.0, 3.0);
+        let median = n.median();
+        let below = (0..4000).filter(|_| n.sample(&mut rng) <= median).count();
+        assert!((below as f64 / 4000.0 - 0.5).abs() < 0.03);
+    }
+}
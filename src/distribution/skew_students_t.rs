@@ -0,0 +1,692 @@
+use crate::distribution::internal::{bisect_support, newton_increasing};
+use crate::distribution::{skew_normal, Continuous, ContinuousCDF, SkewNormal, StudentsT};
+use crate::function::evaluate::gauss_legendre;
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the skew [Student's
+/// T](https://en.wikipedia.org/wiki/Student%27s_t-distribution)
+/// distribution of Azzalini and Capitanio, the distribution of a skew normal
+/// variable divided by an independent estimate of its scale. It combines
+/// the heavy tails of the student's t-distribution with the skewness of the
+/// skew normal distribution.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{SkewStudentsT, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0).unwrap();
+/// assert!(prec::almost_eq(n.mean().unwrap(), 2.8006326323142121391, 1e-14));
+/// assert!(prec::almost_eq(n.pdf(2.0), 0.30169643948906941338, 1e-10));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SkewStudentsT {
+    location: f64,
+    scale: f64,
+    freedom: f64,
+    shape: f64,
+    /// The standard student's t-distribution with `freedom` degrees of
+    /// freedom
+    students_t: StudentsT,
+    /// The standard student's t-distribution with `freedom + 1` degrees of
+    /// freedom, whose cdf skews the density
+    skewing_t: StudentsT,
+    /// The standard skew normal distribution with the same shape
+    skew_normal: SkewNormal,
+}
+
+impl SkewStudentsT {
+    /// Constructs a new skew student's t-distribution with location
+    /// `location`, scale `scale`, `freedom` degrees of freedom and shape
+    /// `shape`. A shape of zero gives the student's t-distribution and
+    /// infinite `freedom` the skew normal distribution.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is `NaN`, if `location`, `scale` or
+    /// `shape` are infinite, or if `scale <= 0.0` or `freedom <= 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let mut result = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = SkewStudentsT::new(1.0, 2.0, 0.0, 3.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(location: f64, scale: f64, freedom: f64, shape: f64) -> Result<SkewStudentsT> {
+        if !location.is_finite() || !scale.is_finite() || freedom.is_nan() || !shape.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if scale <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("scale"));
+        }
+        if freedom <= 0.0 {
+            return Err(StatsError::ArgMustBePositive("freedom"));
+        }
+        Ok(SkewStudentsT {
+            location,
+            scale,
+            freedom,
+            shape,
+            students_t: StudentsT::new(0.0, 1.0, freedom)?,
+            skewing_t: StudentsT::new(0.0, 1.0, freedom + 1.0)?,
+            skew_normal: SkewNormal::new(0.0, 1.0, shape)?,
+        })
+    }
+
+    /// Returns the location of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0).unwrap();
+    /// assert_eq!(n.location(), 1.0);
+    /// ```
+    pub fn location(&self) -> f64 {
+        self.location
+    }
+
+    /// Returns the scale of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0).unwrap();
+    /// assert_eq!(n.scale(), 2.0);
+    /// ```
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    /// Returns the degrees of freedom of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0).unwrap();
+    /// assert_eq!(n.freedom(), 5.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Returns the shape of the skew student's t-distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::SkewStudentsT;
+    ///
+    /// let n = SkewStudentsT::new(1.0, 2.0, 5.0, 3.0).unwrap();
+    /// assert_eq!(n.shape(), 3.0);
+    /// ```
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Returns `δ = α / sqrt(1 + α^2)` for the shape `α`
+    fn delta(&self) -> f64 {
+        self.shape / self.shape.hypot(1.0)
+    }
+
+    /// Returns `ν / (ν - k)` for the degrees of freedom `ν`, which tends to
+    /// one for infinite `ν`
+    fn freedom_ratio(&self, k: f64) -> f64 {
+        if self.freedom.is_infinite() {
+            1.0
+        } else {
+            self.freedom / (self.freedom - k)
+        }
+    }
+
+    /// Returns the mean `δ b_ν` of the standard distribution, where
+    /// `b_ν = sqrt(ν / π) Γ((ν - 1) / 2) / Γ(ν / 2)` is the mean of the
+    /// absolute value of a standard student's t variable
+    fn std_mean(&self) -> f64 {
+        let nu = self.freedom;
+        let b = if nu.is_infinite() {
+            f64::consts::FRAC_2_PI.sqrt()
+        } else {
+            (nu / f64::consts::PI).sqrt()
+                * (gamma::ln_gamma(0.5 * (nu - 1.0)) - gamma::ln_gamma(0.5 * nu)).exp()
+        };
+        self.delta() * b
+    }
+
+    /// Returns the argument `α z sqrt((ν + 1) / (ν + z^2))` of the skewing
+    /// cdf at the standardized point `z`
+    fn skewing_arg(&self, z: f64) -> f64 {
+        if self.freedom.is_infinite() {
+            self.shape * z
+        } else {
+            // sqrt(ν + z^2) as a hypotenuse so that z^2 cannot overflow
+            self.shape * z * (self.freedom + 1.0).sqrt() / self.freedom.sqrt().hypot(z)
+        }
+    }
+
+    /// Inverts the lower tail probability `p` if `lower`, and the upper tail
+    /// probability otherwise, by Newton's method on the log tail
+    /// probability with the density as its derivative. The iteration starts
+    /// from the heavy tail asymptotic `F(x) ~ 2 T_(ν + 1)(-α sqrt(ν + 1)) F_ν(z)`
+    /// of the cdf, or its mirror image for the survival function, whose
+    /// relative error vanishes like `1 / z^2`.
+    fn invert_tail(&self, p: f64, lower: bool) -> f64 {
+        if self.freedom.is_infinite() {
+            let z = if lower {
+                self.skew_normal.inverse_cdf(p)
+            } else {
+                self.skew_normal.inverse_sf(p)
+            };
+            return self.location + self.scale * z;
+        }
+        let ln_p = p.ln();
+        let sign = if lower { -1.0 } else { 1.0 };
+        let ln_weight = f64::consts::LN_2
+            + self
+                .skewing_t
+                .ln_cdf(sign * self.shape * (self.freedom + 1.0).sqrt());
+        let tail = (ln_p - ln_weight).exp().min(0.5);
+        let z0 = if lower {
+            self.students_t.inverse_cdf(tail)
+        } else {
+            self.students_t.inverse_sf(tail)
+        };
+        let x0 = self.location + self.scale * z0;
+        if x0.is_infinite() {
+            return x0;
+        }
+        newton_increasing(
+            |x| {
+                let ln_tail = if lower { self.cdf(x) } else { self.sf(x) }.ln();
+                let slope = (self.ln_pdf(x) - ln_tail).exp();
+                if lower {
+                    (ln_tail - ln_p, slope)
+                } else {
+                    (ln_p - ln_tail, slope)
+                }
+            },
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            x0,
+        )
+    }
+
+    /// Integrates `f(z s)` against the density of the scale estimate `s`,
+    /// which follows a chi distribution with `freedom` degrees of freedom
+    /// scaled by `1 / √freedom`. The integration runs over `ln(s)`, on which
+    /// the density is smooth and decays exponentially in both directions,
+    /// and reaches down to `s ≈ 1 / |z|` where the heavy tails of the
+    /// distribution originate.
+    fn integrate_scale<F: Fn(f64) -> f64>(&self, z: f64, f: F) -> f64 {
+        let nu = self.freedom;
+        if nu.is_infinite() {
+            return f(z);
+        }
+        let sd = (0.5 / nu).sqrt();
+        let lo = -(40.0 / nu + 9.0 * sd + z.abs().ln().max(0.0));
+        let hi = 9.0 * sd;
+        let ln_norm = f64::consts::LN_2 + 0.5 * nu * (0.5 * nu).ln() - gamma::ln_gamma(0.5 * nu);
+        gauss_legendre(lo, hi, sd.min(1.0), |u| {
+            let s = u.exp();
+            (ln_norm + nu * u - 0.5 * nu * s * s).exp() * f(z * s)
+        })
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for SkewStudentsT {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let z = skew_normal::sample_std_unchecked(rng, self.delta());
+        let z = if self.freedom.is_infinite() {
+            z
+        } else {
+            let half = 0.5 * self.freedom;
+            z / super::gamma::sample_unchecked(rng, half, half).sqrt()
+        };
+        self.location + self.scale * z
+    }
+}
+
+impl ContinuousCDF<f64, f64> for SkewStudentsT {
+    /// Calculates the cumulative distribution function for the skew
+    /// student's t-distribution at `x` by numerical integration
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ F_SN(z s) g(s) ds
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale,
+    /// `F_SN` is the cdf of the standard skew normal distribution with the
+    /// same shape and `g` is the density of `sqrt(W / ν)` for a chi-squared
+    /// variable `W` with `ν` degrees of freedom
+    fn cdf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            return 0.0;
+        }
+        if x == f64::INFINITY {
+            return 1.0;
+        }
+        let z = (x - self.location) / self.scale;
+        self.integrate_scale(z, |t| self.skew_normal.cdf(t))
+            .clamp(0.0, 1.0)
+    }
+
+    /// Calculates the survival function for the skew student's
+    /// t-distribution at `x` by numerical integration
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ S_SN(z s) g(s) ds
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale,
+    /// `S_SN` is the survival function of the standard skew normal
+    /// distribution with the same shape and `g` is the density of
+    /// `sqrt(W / ν)` for a chi-squared variable `W` with `ν` degrees of
+    /// freedom
+    fn sf(&self, x: f64) -> f64 {
+        if x == f64::NEG_INFINITY {
+            return 1.0;
+        }
+        if x == f64::INFINITY {
+            return 0.0;
+        }
+        let z = (x - self.location) / self.scale;
+        self.integrate_scale(z, |t| self.skew_normal.sf(t))
+            .clamp(0.0, 1.0)
+    }
+
+    /// Calculates the inverse cumulative distribution function for the skew
+    /// student's t-distribution at `x` by Newton's method on the logarithm of
+    /// the smaller tail, starting from its heavy tail asymptotic
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x == 1.0 {
+            return self.max();
+        }
+        if x <= 0.5 {
+            self.invert_tail(x, true)
+        } else {
+            self.invert_tail(1.0 - x, false)
+        }
+    }
+
+    /// Calculates the inverse survival function for the skew student's
+    /// t-distribution at `x` by Newton's method on the logarithm of the
+    /// smaller tail, starting from its heavy tail asymptotic
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x == 1.0 {
+            return self.min();
+        }
+        if x <= 0.5 {
+            self.invert_tail(x, false)
+        } else {
+            self.invert_tail(1.0 - x, true)
+        }
+    }
+}
+
+impl Min<f64> for SkewStudentsT {
+    /// Returns the minimum value in the domain of the skew student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// -INF
+    /// ```
+    fn min(&self) -> f64 {
+        f64::NEG_INFINITY
+    }
+}
+
+impl Max<f64> for SkewStudentsT {
+    /// Returns the maximum value in the domain of the skew student's
+    /// t-distribution representable by a double precision float
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Distribution<f64> for SkewStudentsT {
+    /// Returns the mean of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ξ + ω δ b_ν
+    /// ```
+    ///
+    /// where `ξ` is the location, `ω` is the scale, `δ = α / sqrt(1 + α^2)`,
+    /// `α` is the shape and `b_ν = sqrt(ν / π) Γ((ν - 1) / 2) / Γ(ν / 2)`
+    fn mean(&self) -> Option<f64> {
+        if self.freedom <= 1.0 {
+            None
+        } else {
+            Some(self.location + self.scale * self.std_mean())
+        }
+    }
+
+    /// Returns the variance of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 2.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ω^2 (ν / (ν - 2) - (δ b_ν)^2)
+    /// ```
+    ///
+    /// where `ω` is the scale, `ν` the degrees of freedom,
+    /// `δ = α / sqrt(1 + α^2)`, `α` is the shape and
+    /// `b_ν = sqrt(ν / π) Γ((ν - 1) / 2) / Γ(ν / 2)`
+    fn variance(&self) -> Option<f64> {
+        if self.freedom <= 2.0 {
+            None
+        } else {
+            let mu = self.std_mean();
+            Some(self.scale * self.scale * (self.freedom_ratio(2.0) - mu * mu))
+        }
+    }
+
+    /// Returns the skewness of the skew student's t-distribution
+    ///
+    /// # Remarks
+    ///
+    /// Returns `None` if `freedom <= 3.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ (ν (3 - δ^2) / (ν - 3) - 3ν / (ν - 2) + 2μ^2) / (ν / (ν - 2) - μ^2)^(3 / 2)
+    /// ```
+    ///
+    /// where `ν` is the degrees of freedom, `μ = δ b_ν`,
+    /// `δ = α / sqrt(1 + α^2)`, `α` is the shape and
+    /// `b_ν = sqrt(ν / π) Γ((ν - 1) / 2) / Γ(ν / 2)`
+    fn skewness(&self) -> Option<f64> {
+        if self.freedom <= 3.0 {
+            return None;
+        }
+        let delta = self.delta();
+        let mu = self.std_mean();
+        let var = self.freedom_ratio(2.0) - mu * mu;
+        let third = (3.0 - delta * delta) * self.freedom_ratio(3.0) - 3.0 * self.freedom_ratio(2.0)
+            + 2.0 * mu * mu;
+        Some(mu * third / var.powf(1.5))
+    }
+}
+
+impl Median<f64> for SkewStudentsT {
+    /// Returns the median of the skew student's t-distribution, found by
+    /// inverting the cdf
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl Mode<Option<f64>> for SkewStudentsT {
+    /// Returns the mode of the skew student's t-distribution, found by
+    /// bisection on the derivative of the log density, which changes sign
+    /// once as the distribution is unimodal
+    fn mode(&self) -> Option<f64> {
+        if self.freedom.is_infinite() {
+            return self
+                .skew_normal
+                .mode()
+                .map(|z| self.location + self.scale * z);
+        }
+        let nu = self.freedom;
+        let z = bisect_support(
+            |z| {
+                let r = nu + z * z;
+                let w = self.skewing_arg(z);
+                let ln_ratio = self.skewing_t.ln_pdf(w) - self.skewing_t.ln_cdf(w);
+                let dw = self.shape * (nu + 1.0).sqrt() * nu / (r * r.sqrt());
+                ln_ratio.exp() * dw > (nu + 1.0) * z / r
+            },
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+        );
+        Some(self.location + self.scale * z)
+    }
+}
+
+impl Continuous<f64, f64> for SkewStudentsT {
+    /// Calculates the probability density function for the skew student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (2 / ω) t_ν(z) T_(ν + 1)(α z sqrt((ν + 1) / (ν + z^2)))
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `ν`
+    /// the degrees of freedom, `α` is the shape and `t_k` and `T_k` are the
+    /// pdf and cdf of the standard student's t-distribution with `k` degrees
+    /// of freedom
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the skew
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2 / ω) + ln(t_ν(z)) + ln(T_(ν + 1)(α z sqrt((ν + 1) / (ν + z^2))))
+    /// ```
+    ///
+    /// where `z = (x - ξ) / ω`, `ξ` is the location, `ω` is the scale, `ν`
+    /// the degrees of freedom, `α` is the shape and `t_k` and `T_k` are the
+    /// pdf and cdf of the standard student's t-distribution with `k` degrees
+    /// of freedom
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x.is_infinite() {
+            return f64::NEG_INFINITY;
+        }
+        let z = (x - self.location) / self.scale;
+        f64::consts::LN_2 - self.scale.ln()
+            + self.students_t.ln_pdf(z)
+            + self.skewing_t.ln_cdf(self.skewing_arg(z))
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, SkewNormal, SkewStudentsT, StudentsT};
+    use rand::distributions::Distribution as RandDistribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn try_create(location: f64, scale: f64, freedom: f64, shape: f64) -> SkewStudentsT {
+        let n = SkewStudentsT::new(location, scale, freedom, shape);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn test_almost<F>(shape: f64, expected: f64, acc: f64, eval: F)
+        where F: Fn(SkewStudentsT) -> f64
+    {
+        let x = eval(try_create(1.0, 2.0, 5.0, shape));
+        assert_almost_eq!(expected, x, acc);
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(1.0, 2.0, 5.0, 3.0);
+        try_create(0.0, 1.0, 0.5, -2.0);
+        try_create(0.0, 1.0, f64::INFINITY, 2.0);
+        assert!(SkewStudentsT::new(1.0, 0.0, 5.0, 3.0).is_err());
+        assert!(SkewStudentsT::new(1.0, 2.0, 0.0, 3.0).is_err());
+        assert!(SkewStudentsT::new(1.0, 2.0, -1.0, 3.0).is_err());
+        assert!(SkewStudentsT::new(1.0, 2.0, f64::NAN, 3.0).is_err());
+        assert!(SkewStudentsT::new(1.0, 2.0, 5.0, f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn test_moments() {
+        test_almost(3.0, 2.8006326323142121391, 1e-14, |x| x.mean().unwrap());
+        test_almost(3.0, 3.4243887901118579805, 1e-13, |x| x.variance().unwrap());
+        test_almost(3.0, 2.1267505032824032734, 1e-12, |x| x.skewness().unwrap());
+        test_almost(-3.0, -0.80063263231421213911, 1e-14, |x| x.mean().unwrap());
+        test_almost(-3.0, -2.1267505032824032734, 1e-12, |x| x.skewness().unwrap());
+        assert!(try_create(1.0, 2.0, 1.0, 3.0).mean().is_none());
+        assert!(try_create(1.0, 2.0, 2.0, 3.0).variance().is_none());
+        assert!(try_create(1.0, 2.0, 3.0, 3.0).skewness().is_none());
+    }
+
+    #[test]
+    fn test_median_mode() {
+        test_almost(3.0, 2.4379174659343031390, 1e-9, |x| x.median());
+        test_almost(3.0, 1.8636003280381404970, 1e-9, |x| x.mode().unwrap());
+        test_almost(-3.0, 0.13639967196185950304, 1e-9, |x| x.mode().unwrap());
+    }
+
+    #[test]
+    fn test_pdf() {
+        test_almost(3.0, 0.0026370578990307216192, 1e-12, |x| x.pdf(-1.0));
+        test_almost(3.0, 0.30169643948906941338, 1e-10, |x| x.pdf(2.0));
+        test_almost(3.0, 4.7570655179472451913e-6, 1e-15, |x| x.pdf(30.0));
+        test_almost(-3.0, 0.21704273945194985198, 1e-10, |x| x.pdf(-1.0));
+        test_almost(-3.0, 8.2440417731063637507e-10, 1e-18, |x| x.pdf(30.0));
+    }
+
+    #[test]
+    fn test_cdf() {
+        test_almost(3.0, 0.0014223158861702154421, 1e-11, |x| x.cdf(-1.0));
+        test_almost(3.0, 0.37317919973435355232, 1e-10, |x| x.cdf(2.0));
+        test_almost(3.0, 0.10186232089667328482, 1e-10, |x| x.sf(5.0));
+        test_almost(3.0, 0.000028152003812840830356, 1e-12, |x| x.sf(30.0));
+        test_almost(-3.0, 0.36179515176295241016, 1e-10, |x| x.cdf(-1.0));
+        test_almost(-3.0, 0.011478071375282559026, 1e-10, |x| x.sf(2.0));
+    }
+
+    #[test]
+    fn test_heavy_tail() {
+        let n = try_create(0.0, 1.0, 1.0, 2.0);
+        assert_almost_eq!(n.sf(100.0), 0.0060299385530857296085, 1e-12);
+        assert_almost_eq!(n.cdf(-50.0), 0.00067208368524564864212, 1e-13);
+        let n = try_create(0.0, 1.0, 0.5, -1.0);
+        assert_almost_eq!(n.cdf(-50.0), 0.073526022429492462084, 1e-11);
+        let n = try_create(0.0, 1.0, 30.0, 5.0);
+        assert_almost_eq!(n.sf(100.0) / 1.9846115225744686243e-39, 1.0, 1e-6);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        test_almost(3.0, 0.98716532448400334584, 1e-9, |x| x.inverse_cdf(0.1));
+        test_almost(3.0, 5.0289270435039275577, 1e-9, |x| x.inverse_sf(0.1));
+        test_almost(-3.0, -3.0289270435039275577, 1e-9, |x| x.inverse_cdf(0.1));
+    }
+
+    #[test]
+    fn test_inverse_cdf_far_tail() {
+        // the heavy tail asymptotic is exact to double precision this far out
+        let n = try_create(1.0, 1.0, 1.0, -3.0);
+        assert_almost_eq!(n.inverse_cdf(1e-300) / -6.2028515881071288176e299, 1.0, 1e-10);
+        assert_almost_eq!(n.inverse_sf(1e-300) / 1.6334613556868461320e298, 1.0, 1e-10);
+        let n = try_create(0.0, 1.0, 4.0, 3.0);
+        assert_almost_eq!(n.inverse_cdf(1e-300) / -2.4046077752775171015e74, 1.0, 1e-10);
+        assert_almost_eq!(n.inverse_sf(n.sf(50.0)), 50.0, 1e-9);
+        assert_almost_eq!(n.inverse_cdf(n.cdf(-5.0)), -5.0, 1e-9);
+    }
+
+    #[test]
+    fn test_special_cases() {
+        // zero shape gives the student's t-distribution
+        let n = try_create(1.0, 2.0, 5.0, 0.0);
+        let t = StudentsT::new(1.0, 2.0, 5.0).unwrap();
+        for &x in &[-3.0, 0.5, 4.0] {
+            assert_almost_eq!(n.pdf(x), t.pdf(x), 1e-15);
+            assert_almost_eq!(n.cdf(x), t.cdf(x), 1e-10);
+        }
+        // infinite freedom gives the skew normal distribution
+        let n = try_create(1.0, 2.0, f64::INFINITY, 3.0);
+        let sn = SkewNormal::new(1.0, 2.0, 3.0).unwrap();
+        for &x in &[-1.0, 2.0, 5.0] {
+            assert_almost_eq!(n.pdf(x), sn.pdf(x), 1e-15);
+            assert_eq!(n.cdf(x), sn.cdf(x));
+        }
+        assert_almost_eq!(n.mean().unwrap(), sn.mean().unwrap(), 1e-15);
+        assert_almost_eq!(n.skewness().unwrap(), sn.skewness().unwrap(), 1e-15);
+        assert_eq!(n.mode(), sn.mode());
+    }
+
+    #[test]
+    fn test_integrate_pdf_is_cdf() {
+        let n = try_create(1.0, 2.0, 5.0, 3.0);
+        let step = 0.001;
+        let mut sum = n.cdf(-2.0);
+        let mut x = -2.0;
+        while x < 8.0 {
+            sum += 0.5 * step * (n.pdf(x) + n.pdf(x + step));
+            x += step;
+            if (x * 10.0).round() == x * 10.0 {
+                assert_almost_eq!(sum, n.cdf(x), 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(13);
+        let n = try_create(1.0, 2.0, 5.0, 3.0);
+        let median = n.median();
+        let below = (0..4000).filter(|_| n.sample(&mut rng) <= median).count();
+        assert!((below as f64 / 4000.0 - 0.5).abs() < 0.03);
+    }
+}
@@ -0,0 +1,567 @@
+use crate::distribution::internal::newton_increasing;
+use crate::distribution::{normal, Continuous, ContinuousCDF};
+use crate::function::evaluate::{
+    self, adaptive_gauss_legendre, gauss_legendre, gauss_legendre_rule,
+};
+use crate::function::gamma;
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// Implements the
+/// [Studentized range](https://en.wikipedia.org/wiki/Studentized_range_distribution)
+/// distribution, i.e. the distribution of the range of `k` independent
+/// standard normal variables divided by an independent estimate of their
+/// standard deviation with `freedom` degrees of freedom. It is the null
+/// distribution of Tukey's HSD test.
+///
+/// The cdf is evaluated by Gauss-Legendre quadrature of the double
+/// integral over the normal range distribution and the chi distribution of
+/// the standard deviation estimate, refining the outer integral adaptively.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{ContinuousCDF, StudentizedRange};
+/// use statrs::prec;
+///
+/// let n = StudentizedRange::new(3, 10.0).unwrap();
+/// // the critical value of Tukey's HSD test at the 5% level
+/// assert!(prec::almost_eq(n.inverse_sf(0.05), 3.877, 1e-3));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StudentizedRange {
+    k: u64,
+    freedom: f64,
+}
+
+impl StudentizedRange {
+    /// Constructs a new studentized range distribution for the range of
+    /// `k` normal variables studentized by a standard deviation estimate
+    /// with `freedom` degrees of freedom
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `k < 2`, or if `freedom` is `NaN` or less
+    /// than `1.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentizedRange;
+    ///
+    /// let mut result = StudentizedRange::new(3, 10.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = StudentizedRange::new(1, 10.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(k: u64, freedom: f64) -> Result<StudentizedRange> {
+        if k < 2 || freedom.is_nan() || freedom < 1.0 {
+            Err(StatsError::BadParams)
+        } else {
+            Ok(StudentizedRange { k, freedom })
+        }
+    }
+
+    /// Returns the number of normal variables `k` of the studentized range
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentizedRange;
+    ///
+    /// let n = StudentizedRange::new(3, 10.0).unwrap();
+    /// assert_eq!(n.k(), 3);
+    /// ```
+    pub fn k(&self) -> u64 {
+        self.k
+    }
+
+    /// Returns the degrees of freedom of the studentized range
+    /// distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::StudentizedRange;
+    ///
+    /// let n = StudentizedRange::new(3, 10.0).unwrap();
+    /// assert_eq!(n.freedom(), 10.0);
+    /// ```
+    pub fn freedom(&self) -> f64 {
+        self.freedom
+    }
+
+    /// Integrates `f(q s)` against the density of the standard deviation
+    /// estimate `s`, which follows a chi distribution with `freedom`
+    /// degrees of freedom scaled by `1 / √freedom`
+    ///
+    /// The range is split at the mode of the density and at the point
+    /// beyond which `f(q s)` no longer varies, which for small `freedom`
+    /// lies far inside the bulk of the density, and each piece is refined
+    /// adaptively.
+    fn integrate_scale<F: Fn(f64) -> f64>(&self, x: f64, f: F) -> f64 {
+        let nu = self.freedom;
+        if nu.is_infinite() {
+            return f(x);
+        }
+        let sd = (0.5 / nu).sqrt();
+        let lo = (1.0 - 12.0 * sd).max(0.0);
+        let hi = 1.0 + 12.0 * sd;
+        let ln_norm = f64::consts::LN_2 + 0.5 * nu * (0.5 * nu).ln() - gamma::ln_gamma(0.5 * nu);
+        let integrand = |s: f64| {
+            if s <= 0.0 {
+                return 0.0;
+            }
+            let ln_density = ln_norm + (nu - 1.0) * s.ln() - 0.5 * nu * s * s;
+            ln_density.exp() * f(x * s)
+        };
+        let mode = ((nu - 1.0) / nu).sqrt();
+        let mut breaks = vec![lo, hi];
+        for &b in &[mode, 2.0 * Z_MAX / x] {
+            if b > lo && b < hi {
+                breaks.push(b);
+            }
+        }
+        breaks.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        breaks
+            .windows(2)
+            .map(|w| adaptive_gauss_legendre(w[0], w[1], 4.0 * sd, SCALE_TOL, integrand))
+            .sum()
+    }
+
+    /// Finds the quantile with lower tail probability `p` if `lower`, or
+    /// upper tail probability `p` otherwise, by Newton's method on the log
+    /// tail probability as a function of `ln(q)`, whose slope is
+    /// `q f(q) / tail(q)` for the density `f`
+    fn invert_tail(&self, p: f64, lower: bool) -> f64 {
+        let ln_p = p.ln();
+        let start = self.initial_quantile(if lower { 1.0 - p } else { p });
+        let u0 = if start > 0.0 && start.is_finite() {
+            start.ln()
+        } else {
+            0.0
+        };
+        let u = newton_increasing(
+            |u| {
+                let q = u.exp();
+                let ln_tail = if lower { self.cdf(q) } else { self.sf(q) }.ln();
+                let slope = q * (self.ln_pdf(q) - ln_tail).exp();
+                if lower {
+                    (ln_tail - ln_p, slope)
+                } else {
+                    (ln_p - ln_tail, slope)
+                }
+            },
+            f64::NEG_INFINITY,
+            f64::INFINITY,
+            u0,
+        );
+        u.exp()
+    }
+
+    /// Approximates the quantile with upper tail probability `p` following
+    /// Copenhaver and Holland (1988), as in algorithm AS 190 and R's
+    /// `qtukey`, from a rational approximation of the normal quantile
+    fn initial_quantile(&self, p: f64) -> f64 {
+        let (k, nu) = (self.k as f64, self.freedom);
+        let yi = (-2.0 * (0.5 * p).ln()).sqrt();
+        let mut t = yi
+            + evaluate::polynomial(
+                yi,
+                &[
+                    -0.322232431088,
+                    -1.0,
+                    -0.342242088547,
+                    -0.0204231210245,
+                    -0.453642210148e-4,
+                ],
+            ) / evaluate::polynomial(
+                yi,
+                &[
+                    0.0993484626060,
+                    0.588581570495,
+                    0.531103462366,
+                    0.103537752850,
+                    0.38560700634e-2,
+                ],
+            );
+        if nu < 120.0 {
+            t += (t * t * t + t) / nu / 4.0;
+        }
+        let mut qq = 0.8832 - 0.2368 * t;
+        if nu < 120.0 {
+            qq += (1.208 * t - 1.214) / nu;
+        }
+        t * (qq * (k - 1.0).ln() + f64::consts::SQRT_2)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for StudentizedRange {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let (min, max) = (0..self.k).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), _| {
+            let z = super::normal::sample_unchecked(rng, 0.0, 1.0);
+            (lo.min(z), hi.max(z))
+        });
+        if self.freedom.is_infinite() {
+            return max - min;
+        }
+        let chi_sq = super::gamma::sample_unchecked(rng, 0.5 * self.freedom, 0.5);
+        (max - min) / (chi_sq / self.freedom).sqrt()
+    }
+}
+
+impl ContinuousCDF<f64, f64> for StudentizedRange {
+    /// Calculates the cumulative distribution function for the
+    /// studentized range distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ f(s) k ∫ φ(z) (Φ(z) - Φ(z - x s))^(k - 1) dz ds
+    /// ```
+    ///
+    /// where `f` is the density of the standard deviation estimate, and `φ`
+    /// and `Φ` are the standard normal density and distribution functions
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            0.0
+        } else if x == f64::INFINITY {
+            1.0
+        } else {
+            let cdf = self.integrate_scale(x, |w| range_cdf(w, self.k));
+            // the survival function is more accurate in the upper half
+            if cdf > 0.5 {
+                1.0 - self.sf(x)
+            } else {
+                cdf.clamp(0.0, 1.0)
+            }
+        }
+    }
+
+    /// Calculates the survival function for the studentized range
+    /// distribution at `x`, i.e. the p-value of Tukey's HSD test
+    fn sf(&self, x: f64) -> f64 {
+        if x <= 0.0 {
+            1.0
+        } else if x == f64::INFINITY {
+            0.0
+        } else {
+            let nodes = range_sf_nodes(self.k);
+            self.integrate_scale(x, |w| range_sf(w, self.k, &nodes))
+                .clamp(0.0, 1.0)
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// studentized range distribution at `x` by Newton's method from the
+    /// starting value of Copenhaver and Holland
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return 0.0;
+        }
+        if x == 1.0 {
+            return f64::INFINITY;
+        }
+        if x > 0.5 {
+            return self.inverse_sf(1.0 - x);
+        }
+        self.invert_tail(x, true)
+    }
+
+    /// Calculates the inverse survival function for the studentized range
+    /// distribution at `x` by Newton's method from the starting value of
+    /// Copenhaver and Holland, i.e. the critical value of Tukey's HSD test
+    /// at level `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return f64::INFINITY;
+        }
+        if x == 1.0 {
+            return 0.0;
+        }
+        if x > 0.5 {
+            return self.inverse_cdf(1.0 - x);
+        }
+        self.invert_tail(x, false)
+    }
+}
+
+impl Min<f64> for StudentizedRange {
+    /// Returns the minimum value in the domain of the studentized range
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn min(&self) -> f64 {
+        0.0
+    }
+}
+
+impl Max<f64> for StudentizedRange {
+    /// Returns the maximum value in the domain of the studentized range
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// INF
+    /// ```
+    fn max(&self) -> f64 {
+        f64::INFINITY
+    }
+}
+
+impl Continuous<f64, f64> for StudentizedRange {
+    /// Calculates the probability density function for the studentized
+    /// range distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ f(s) s k (k - 1) ∫ φ(z) φ(z - x s) (Φ(z) - Φ(z - x s))^(k - 2) dz ds
+    /// ```
+    fn pdf(&self, x: f64) -> f64 {
+        if x <= 0.0 || x == f64::INFINITY {
+            0.0
+        } else {
+            self.integrate_scale(x, |w| range_pdf(w, self.k) * w / x)
+        }
+    }
+
+    /// Calculates the log probability density function for the studentized
+    /// range distribution at `x`
+    fn ln_pdf(&self, x: f64) -> f64 {
+        self.pdf(x).ln()
+    }
+}
+
+/// The half-width of the integration range of the normal variables
+const Z_MAX: f64 = 8.8;
+
+/// The relative tolerance of the adaptive integral over the scale
+const SCALE_TOL: f64 = 1e-11;
+
+/// Computes `P(z - w < Z <= z)` for a standard normal `Z`, taking the
+/// difference in the tail where it is accurate and using Simpson's rule
+/// when `w` is so small that the difference would cancel
+fn normal_mass(z: f64, w: f64) -> f64 {
+    if w < 1e-3 {
+        let phi = |t: f64| normal::pdf_unchecked(t, 0.0, 1.0);
+        w / 6.0 * (phi(z) + 4.0 * phi(z - 0.5 * w) + phi(z - w))
+    } else if z > w {
+        normal::sf_unchecked(z - w, 0.0, 1.0) - normal::sf_unchecked(z, 0.0, 1.0)
+    } else {
+        normal::cdf_unchecked(z, 0.0, 1.0) - normal::cdf_unchecked(z - w, 0.0, 1.0)
+    }
+}
+
+/// Computes `P(R <= w)` for the range `R` of `k` standard normal variables,
+/// conditioning on their maximum `z`
+fn range_cdf(w: f64, k: u64) -> f64 {
+    if w <= 0.0 {
+        return 0.0;
+    }
+    let k = k as f64;
+    gauss_legendre(-Z_MAX, Z_MAX, 1.0, |z| {
+        k * normal::pdf_unchecked(z, 0.0, 1.0) * normal_mass(z, w).powf(k - 1.0)
+    })
+}
+
+/// Tabulates the nodes `z` of the rule used by `range_sf` together with
+/// `Φ(z)` and the weighted factor `k φ(z) Φ(z)^(k - 1)`, which do not
+/// depend on the range `w`
+fn range_sf_nodes(k: u64) -> Vec<(f64, f64, f64)> {
+    let k = k as f64;
+    gauss_legendre_rule(-Z_MAX, Z_MAX, 1.0)
+        .into_iter()
+        .filter_map(|(z, weight)| {
+            let upper = normal::cdf_unchecked(z, 0.0, 1.0);
+            if upper == 0.0 {
+                return None;
+            }
+            let factor = weight * k * normal::pdf_unchecked(z, 0.0, 1.0) * upper.powf(k - 1.0);
+            Some((z, upper, factor))
+        })
+        .collect()
+}
+
+/// Computes `P(R > w)` for the range `R` of `k` standard normal variables
+/// as `k ∫ φ(z) (Φ(z)^(k - 1) - (Φ(z) - Φ(z - w))^(k - 1)) dz` over the
+/// `nodes` tabulated by `range_sf_nodes`
+fn range_sf(w: f64, k: u64, nodes: &[(f64, f64, f64)]) -> f64 {
+    if w <= 0.0 {
+        return 1.0;
+    }
+    let k = k as f64;
+    nodes
+        .iter()
+        .map(|&(z, upper, factor)| {
+            let ratio = normal::cdf_unchecked(z - w, 0.0, 1.0) / upper;
+            factor * -((k - 1.0) * (-ratio).ln_1p()).exp_m1()
+        })
+        .sum()
+}
+
+/// Computes the density of the range of `k` standard normal variables at
+/// `w`
+fn range_pdf(w: f64, k: u64) -> f64 {
+    if w <= 0.0 {
+        return 0.0;
+    }
+    let k = k as f64;
+    gauss_legendre(-Z_MAX, Z_MAX, 1.0, |z| {
+        k * (k - 1.0)
+            * normal::pdf_unchecked(z, 0.0, 1.0)
+            * normal::pdf_unchecked(z - w, 0.0, 1.0)
+            * normal_mass(z, w).powf(k - 2.0)
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::StudentsT;
+
+    fn create(k: u64, freedom: f64) -> StudentizedRange {
+        StudentizedRange::new(k, freedom).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        assert_eq!(create(2, 1.0).k(), 2);
+        assert_eq!(create(3, f64::INFINITY).freedom(), f64::INFINITY);
+        assert!(StudentizedRange::new(1, 10.0).is_err());
+        assert!(StudentizedRange::new(3, 0.5).is_err());
+        assert!(StudentizedRange::new(3, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_cdf() {
+        assert_almost_eq!(create(3, 10.0).cdf(3.5), 0.922896689161588291, 1e-10);
+        assert_almost_eq!(create(3, 10.0).cdf(1.0), 0.235109189421280618, 1e-10);
+        assert_almost_eq!(create(5, 20.0).cdf(5.0), 0.984214322804550013, 1e-10);
+        assert_almost_eq!(create(4, f64::INFINITY).cdf(3.0), 0.853728518952340254, 1e-10);
+        assert_almost_eq!(create(6, 3.0).cdf(0.5), 0.00158964069159348974, 1e-12);
+        assert_almost_eq!(create(3, 1.0).cdf(4.0), 0.680197725771317763, 1e-10);
+        assert_almost_eq!(create(3, 2.0).cdf(2.0), 0.523439431626138601, 1e-10);
+        assert_almost_eq!(create(4, 1.5).cdf(0.3), 0.00464410636240628087, 1e-12);
+        assert_eq!(create(3, 10.0).cdf(0.0), 0.0);
+        assert_eq!(create(3, 10.0).cdf(f64::INFINITY), 1.0);
+    }
+
+    #[test]
+    fn test_sf() {
+        assert_almost_eq!(create(3, 10.0).sf(3.5), 0.0771033108384118482, 1e-11);
+        assert_almost_eq!(create(10, 30.0).sf(8.0), 0.000139799175513589279, 1e-13);
+        assert_almost_eq!(create(20, 60.0).sf(6.0), 0.0103350576187617397, 1e-12);
+        assert_almost_eq!(create(3, 12.0).sf(4.4129598898795805), 0.0223404071385320084, 1e-12);
+        // small freedom puts the transition of the range distribution deep
+        // inside the bulk of the scale density
+        assert_almost_eq!(create(10, 1.0).sf(20.0), 0.122192469588926, 1e-10);
+        assert_almost_eq!(create(10, 1.0).sf(48.17), 0.0509337309955798, 1e-10);
+        assert_almost_eq!(create(10, 1.0).sf(1000.0), 0.00245548941090251695, 1e-12);
+        assert_almost_eq!(create(5, 3.0).sf(10.0), 0.0226861051122526781, 1e-11);
+        assert_almost_eq!(create(2, 1.0).sf(100.0), 0.00900256302270864019, 1e-12);
+        assert_eq!(create(3, 10.0).sf(0.0), 1.0);
+        assert_eq!(create(3, 10.0).sf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_pdf() {
+        assert_almost_eq!(create(3, 10.0).pdf(3.5), 0.0879074429145214054, 1e-11);
+        assert_almost_eq!(create(3, 10.0).pdf(1.0), 0.398772020275752148, 1e-11);
+        assert_almost_eq!(create(4, f64::INFINITY).pdf(3.0), 0.221010296000636236, 1e-11);
+        assert_almost_eq!(create(10, 30.0).pdf(8.0), 0.000271712268478503769, 1e-13);
+        assert_almost_eq!(create(3, 10.0).ln_pdf(3.5), 0.0879074429145214054f64.ln(), 1e-10);
+        assert_eq!(create(3, 10.0).pdf(0.0), 0.0);
+        assert_eq!(create(3, 10.0).pdf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_two_groups_is_t() {
+        // the range of two normals is √2 times the absolute value of a t variable
+        for &freedom in &[1.0, 5.0, 30.0] {
+            let t = StudentsT::new(0.0, 1.0, freedom).unwrap();
+            for &q in &[0.5, 2.0, 4.0] {
+                let expected = 2.0 * t.sf(q / 2f64.sqrt());
+                assert_almost_eq!(create(2, freedom).sf(q), expected, 1e-10);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        assert_almost_eq!(create(3, 10.0).inverse_cdf(0.235109189421280618), 1.0, 1e-9);
+        assert_almost_eq!(create(3, 10.0).inverse_cdf(0.922896689161588291), 3.5, 1e-9);
+        let n = create(3, 10.0);
+        assert_almost_eq!(n.cdf(n.inverse_cdf(1e-100)) / 1e-100, 1.0, 1e-9);
+        let n = create(3, 1.0);
+        assert_almost_eq!(n.cdf(n.inverse_cdf(1e-20)) / 1e-20, 1.0, 1e-9);
+        assert_eq!(create(3, 10.0).inverse_cdf(0.0), 0.0);
+        assert_eq!(create(3, 10.0).inverse_cdf(1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        // tabulated critical values of Tukey's HSD test
+        assert_almost_eq!(create(3, 10.0).inverse_sf(0.05), 3.877, 1e-3);
+        assert_almost_eq!(create(5, 20.0).inverse_sf(0.05), 4.232, 1e-3);
+        assert_almost_eq!(create(3, f64::INFINITY).inverse_sf(0.05), 3.314, 1e-3);
+        assert_almost_eq!(create(10, 1.0).inverse_sf(0.05), 49.07102242, 1e-7);
+        assert_almost_eq!(create(10, 30.0).inverse_sf(0.000139799175513589279), 8.0, 1e-9);
+        let n = create(10, 2.0);
+        assert_almost_eq!(n.sf(n.inverse_sf(0.05)) / 0.05, 1.0, 1e-14);
+        let n = create(5, 20.0);
+        assert_almost_eq!(n.sf(n.inverse_sf(1e-10)) / 1e-10, 1.0, 1e-14);
+        assert_eq!(create(3, 10.0).inverse_sf(0.0), f64::INFINITY);
+        assert_eq!(create(3, 10.0).inverse_sf(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_initial_quantile() {
+        // the starting value of Copenhaver and Holland is within a few
+        // percent of the critical value
+        assert_almost_eq!(create(3, 10.0).initial_quantile(0.05), 3.877, 0.01);
+        assert_almost_eq!(create(10, 72.0).initial_quantile(0.05), 4.617, 0.1);
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(create(3, 10.0).min(), 0.0);
+        assert_eq!(create(3, 10.0).max(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_sample() {
+        use rand::distributions::Distribution;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut r = StdRng::seed_from_u64(42);
+        let n = create(3, 10.0);
+        let below = (0..10_000).filter(|_| n.sample(&mut r) <= 3.5).count();
+        assert_almost_eq!(below as f64 / 10_000.0, 0.922896689161588291, 0.01);
+    }
+}
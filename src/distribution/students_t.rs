@@ -1,5 +1,6 @@
-use crate::distribution::{Continuous, ContinuousCDF};
-use crate::function::{beta, gamma};
+use crate::distribution::internal::solve_decreasing;
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
+use crate::function::{beta, erf, gamma};
 use crate::is_zero;
 use crate::statistics::*;
 use crate::{Result, StatsError};
@@ -153,15 +154,43 @@ impl ContinuousCDF<f64, f64> for StudentsT {
 
     /// Calculates the inverse cumulative distribution function for the
     /// Student's T-distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ ± σ * sqrt(v * (1 - y) / y)
+    /// ```
+    ///
+    /// where `y = I^-1_p(v / 2, 1 / 2)`, `p = 2 * min(x, 1 - x)`, the sign is
+    /// that of `x - 1 / 2`, `μ` is the location, `σ` is the scale, `v` is the
+    /// freedom, and `I^-1` is the inverse of the regularized incomplete beta
+    /// function
     fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if self.freedom.is_infinite() {
+            return self.location - self.scale * f64::consts::SQRT_2 * erf::erfc_inv(2.0 * x);
+        }
         // first calculate inverse_cdf for normal Student's T
-        assert!((0.0..=1.0).contains(&x));
-        let x = 2. * x.min(1. - x);
+        let p = 2.0 * x.min(1.0 - x);
         let a = 0.5 * self.freedom;
-        let b = 0.5;
-        let mut y = beta::inv_beta_reg(a, b, x);
-        y = (self.freedom * (1. - y) / y).sqrt();
-        y = if x <= 0.5 { y } else { -y };
+        let y = if p < 0.5 {
+            // `y` underflows in the far tail long before the quantile
+            // overflows, so work with `ln(y)`
+            let ln_y = beta::ln_inv_beta_reg(a, 0.5, p);
+            (-self.freedom * ln_y.exp_m1()).sqrt() * (-0.5 * ln_y).exp()
+        } else {
+            // near the median `y` is close to one, so solve for `1 - y`
+            // directly to avoid cancellation
+            let z = beta::inv_beta_reg(0.5, a, 1.0 - p);
+            (self.freedom * z / (1.0 - z)).sqrt()
+        };
+        let y = if x < 0.5 { -y } else { y };
         // generalised Student's T is related to normal Student's T by `Y = μ + σ X`
         // where `X` is distributed as Student's T, so this result has to be scaled and shifted back
         // formally: F_Y(t) = P(Y <= t) = P(X <= (t - μ) / σ) = F_X((t - μ) / σ)
@@ -170,6 +199,95 @@ impl ContinuousCDF<f64, f64> for StudentsT {
         // = μ + σ inf { t | F_X(t) >= p } = μ + σ F_X^{-1}(p)
         self.location + self.scale * y
     }
+    /// Calculates the survival function for the student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < μ {
+    ///     1 - (1 / 2) * I(t, v / 2, 1 / 2)
+    /// } else {
+    ///     (1 / 2) * I(t, v / 2, 1 / 2)
+    /// }
+    /// ```
+    ///
+    /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
+    /// `σ` is the scale, `v` is the freedom, and `I` is the regularized
+    /// incomplete beta function
+    fn sf(&self, x: f64) -> f64 {
+        // the distribution is symmetric about the location
+        self.cdf(2.0 * self.location - x)
+    }
+    /// Calculates the inverse survival function for the
+    /// Student's T-distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> f64 {
+        2.0 * self.location - self.inverse_cdf(x)
+    }
+    /// Calculates the log of the cumulative distribution function for the
+    /// student's t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < μ {
+    ///     ln(I(t, v / 2, 1 / 2)) - ln(2)
+    /// } else {
+    ///     ln(1 - (1 / 2) * I(t, v / 2, 1 / 2))
+    /// }
+    /// ```
+    ///
+    /// where `t = v / (v + k^2)`, `k = (x - μ) / σ`, `μ` is the location,
+    /// `σ` is the scale, `v` is the freedom, and `I` is the regularized
+    /// incomplete beta function. The lower tail is evaluated in log space
+    /// so that it stays finite where the cdf underflows
+    fn ln_cdf(&self, x: f64) -> f64 {
+        if self.freedom.is_infinite() {
+            super::normal::ln_cdf_unchecked(x, self.location, self.scale)
+        } else {
+            let k = (x - self.location) / self.scale;
+            // h = v / (v + k^2), scaled once |k| exceeds √v so that k^2
+            // cannot overflow, while small or subnormal k is used directly
+            let h = if k.abs() <= self.freedom.sqrt() {
+                self.freedom / (self.freedom + k * k)
+            } else {
+                let r = self.freedom / k;
+                (r / (r + k)).abs()
+            };
+            if x <= self.location {
+                let a = self.freedom / 2.0;
+                if h < f64::MIN_POSITIVE {
+                    // far tail where h underflows, I(h, a, 1 / 2) equals its
+                    // leading term h^a / (a B(a, 1 / 2)) to double precision
+                    a * (self.freedom.ln() - 2.0 * k.abs().ln())
+                        - a.ln()
+                        - beta::ln_beta(a, 0.5)
+                        - f64::consts::LN_2
+                } else {
+                    beta::ln_beta_reg(a, 0.5, h) - f64::consts::LN_2
+                }
+            } else {
+                (-0.5 * beta::beta_reg(self.freedom / 2.0, 0.5, h)).ln_1p()
+            }
+        }
+    }
+    /// Calculates the log of the survival function for the student's
+    /// t-distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln_cdf(2μ - x)
+    /// ```
+    ///
+    /// where `μ` is the location
+    fn ln_sf(&self, x: f64) -> f64 {
+        self.ln_cdf(2.0 * self.location - x)
+    }
 }
 
 impl Min<f64> for StudentsT {
@@ -371,8 +489,15 @@ impl Continuous<f64, f64> for StudentsT {
             super::normal::ln_pdf_unchecked(x, self.location, self.scale)
         } else {
             let d = (x - self.location) / self.scale;
+            let q = d * d / self.freedom;
+            // ln(1 + k^2 / v), where k^2 / v has overflowed the one is negligible
+            let ln_1pq = if q.is_finite() {
+                q.ln_1p()
+            } else {
+                2.0 * d.abs().ln() - self.freedom.ln()
+            };
             gamma::ln_gamma((self.freedom + 1.0) / 2.0)
-                - 0.5 * ((self.freedom + 1.0) * (1.0 + d * d / self.freedom).ln())
+                - 0.5 * ((self.freedom + 1.0) * ln_1pq)
                 - gamma::ln_gamma(self.freedom / 2.0)
                 - 0.5 * (self.freedom * f64::consts::PI).ln()
                 - self.scale.ln()
@@ -380,12 +505,85 @@ impl Continuous<f64, f64> for StudentsT {
     }
 }
 
+impl Fit<f64> for StudentsT {
+    /// Fits a student's t-distribution to `data` by maximum likelihood
+    ///
+    /// The location, scale and freedom are estimated with the ECM algorithm
+    /// of Liu and Rubin (1995), which treats the data as normal samples with
+    /// unobserved gamma distributed precision weights. Each iteration updates
+    ///
+    /// ```ignore
+    /// w_i = (v + 1) / (v + ((x_i - μ) / σ)^2)
+    /// μ = Σ w_i * x_i / Σ w_i
+    /// σ^2 = (1 / n) * Σ w_i * (x_i - μ)^2
+    /// ```
+    ///
+    /// and the freedom `v` solves
+    ///
+    /// ```ignore
+    /// ln(v / 2) - ψ(v / 2) + 1 + (1 / n) * Σ (ln(w_i) - w_i) + ψ((v' + 1) / 2) - ln((v' + 1) / 2) = 0
+    /// ```
+    ///
+    /// where `v'` is the previous freedom and `ψ` is the digamma function
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `data` is empty, if the samples are all equal, or
+    /// if the iteration does not converge, which is expected when the data
+    /// are close to normal so that the freedom grows without bound
+    fn fit(data: &[f64]) -> Result<StudentsT> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        let eps = 1e-12;
+        let n = data.len() as f64;
+        let mut location = Data::new(data.to_vec()).median();
+        let mut scale = data.population_std_dev();
+        let mut freedom = 1.0;
+        if scale.is_nan() || scale <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let mut weights = vec![0.0; data.len()];
+        for _ in 0..10_000 {
+            for (w, x) in weights.iter_mut().zip(data) {
+                let k = (x - location) / scale;
+                *w = (freedom + 1.0) / (freedom + k * k);
+            }
+            let w_sum: f64 = weights.iter().sum();
+            let next_location = weights.iter().zip(data).map(|(w, x)| w * x).sum::<f64>() / w_sum;
+            let next_scale = (weights
+                .iter()
+                .zip(data)
+                .map(|(w, x)| w * (x - next_location) * (x - next_location))
+                .sum::<f64>()
+                / n)
+                .sqrt();
+            let half = 0.5 * (freedom + 1.0);
+            let c =
+                1.0 + weights.iter().map(|w| w.ln() - w).mean() + gamma::digamma(half) - half.ln();
+            let next_freedom = 2.0
+                * solve_decreasing(|z| z.ln() - gamma::digamma(z) + c, 0.5 * freedom)
+                    .ok_or(StatsError::ComputationFailedToConverge)?;
+            let converged = (next_location - location).abs() <= eps * next_scale
+                && (next_scale - scale).abs() <= eps * next_scale
+                && (next_freedom - freedom).abs() <= eps * next_freedom;
+            location = next_location;
+            scale = next_scale;
+            freedom = next_freedom;
+            if converged {
+                return StudentsT::new(location, scale, freedom);
+            }
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use std::panic;
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, StudentsT};
+    use crate::distribution::{ContinuousCDF, Continuous, StudentsT, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -586,6 +784,8 @@ mod tests {
         test_almost(0.0, 1.0, f64::INFINITY, -0.918938533204672, 1e-14, ln_pdf(0.0));
         test_almost(0.0, 1.0, f64::INFINITY, -1.418938533204674, 1e-14, ln_pdf(1.0));
         test_almost(0.0, 1.0, f64::INFINITY, -2.918938533204674, 1e-14, ln_pdf(2.0));
+        test_almost(0.0, 1.0, 1.0, -922.17876708346767378, 1e-12, ln_pdf(1e200));
+        test_almost(0.0, 1.0, 1.0, -922.17876708346767378, 1e-12, ln_pdf(-1e200));
     }
 
     #[test]
@@ -1060,4 +1260,87 @@ mod tests {
         test(0.999, 120.0, 3.160);
         test(0.9995, 120.0, 3.373);
     }
+
+    #[test]
+    fn test_inverse_cdf_full_precision() {
+        let inverse_cdf = |arg: f64| move |x: StudentsT| x.inverse_cdf(arg);
+        test_almost(0.0, 1.0, 10.0, -2.2281388519862744, 1e-13, inverse_cdf(0.025));
+        test_almost(0.0, 1.0, 10.0, -0.54152803875501569144, 1e-14, inverse_cdf(0.3));
+        test_almost(0.0, 1.0, 10.0, 0.0, 1e-15, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 10.0, 0.26018482949208017615, 1e-14, inverse_cdf(0.6));
+        test_almost(0.0, 1.0, 10.0, 2.2281388519862744, 1e-13, inverse_cdf(0.975));
+        test_almost(0.0, 1.0, 2.5, -13.822193110865964523, 1e-11, inverse_cdf(0.001));
+        test_almost(0.0, 1.0, 2.5, 0.59730773825231730106, 1e-14, inverse_cdf(0.7));
+        test_almost(0.0, 1.0, 1.0, -318309886183.79067154, 1e-2, inverse_cdf(1e-12));
+        test_almost(0.0, 1.0, 0.5, -1028491156.3163400117, 1e-5, inverse_cdf(1e-5));
+        test_almost(0.0, 1.0, 0.5, -1.0284911563163400118e199, 1e186, inverse_cdf(1e-100));
+        test_almost(0.0, 1.0, 30.0, -10810.645001143975872, 1e-9, inverse_cdf(1e-100));
+        test_almost(0.0, 1.0, 5.0, -1.568392559099337809e60, 1e47, inverse_cdf(1e-300));
+        test_almost(0.0, 1.0, 1.0, -3.1830988618379067154e299, 1e287, inverse_cdf(1e-300));
+        test_almost(3.0, 2.0, 10.0, 3.0 + 2.0 * 2.2281388519862744, 1e-13, inverse_cdf(0.975));
+        test_almost(0.0, 1.0, f64::INFINITY, 1.2815515655446004, 1e-14, inverse_cdf(0.9));
+        test_case(0.0, 1.0, 10.0, f64::NEG_INFINITY, inverse_cdf(0.0));
+        test_case(0.0, 1.0, 10.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: StudentsT| x.sf(arg);
+        test_almost(0.0, 1.0, 5.0, 3.0238788133006126158e-8, 1e-21, sf(50.0));
+        test_almost(0.0, 1.0, 5.0, 1.0 - 3.0238788133006126158e-8, 1e-15, sf(-50.0));
+        test_case(0.0, 1.0, 5.0, 0.5, sf(0.0));
+        test_almost(0.0, 1.0, 2.0, 0.211324865405187, 1e-15, sf(1.0));
+        test_almost(1.0, 2.0, 5.0, 3.0238788133006126158e-8, 1e-21, sf(101.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: StudentsT| x.inverse_sf(arg);
+        test_almost(0.0, 1.0, 5.0, 156.82559270889431749, 1e-9, inverse_sf(1e-10));
+        test_almost(0.0, 1.0, 10.0, 2.2281388519862744, 1e-13, inverse_sf(0.025));
+        test_almost(0.0, 1.0, 10.0, -2.2281388519862744, 1e-13, inverse_sf(0.975));
+    }
+
+    #[test]
+    fn test_ln_cdf() {
+        let ln_cdf = |arg: f64| move |x: StudentsT| x.ln_cdf(arg);
+        test_almost(0.0, 1.0, 5.0, -17.314140361404830473, 1e-12, ln_cdf(-50.0));
+        test_almost(0.0, 1.0, 5.0, -228.00825306359109178, 1e-11, ln_cdf(-1e20));
+        test_almost(0.0, 1.0, 1.0, -231.40323918525396859, 1e-11, ln_cdf(-1e100));
+        test_almost(0.0, 1.0, 5.0, -1782.2531908345719285, 1e-10, ln_cdf(-1e155));
+        test_almost(0.0, 1.0, 2.5, -921.36345874217310734, 1e-10, ln_cdf(-1e160));
+        test_almost(0.0, 1.0, 5.0, -2300.3348367582322072, 1e-10, ln_cdf(-1e200));
+        test_almost(0.0, 1.0, 1.0, -691.92025778406310543, 1e-10, ln_cdf(-1e300));
+        assert_eq!(StudentsT::new(0.0, 1.0, 5.0).unwrap().ln_cdf(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        test_almost(0.0, 1.0, 2.0, -0.23740078615161914609, 1e-15, ln_cdf(1.0));
+        test_almost(0.0, 1.0, 10.0, -0.0066941836454523511645, 1e-15, ln_cdf(3.0));
+        test_almost(1.0, 2.0, 5.0, -17.314140361404830473, 1e-12, ln_cdf(-99.0));
+        test_almost(0.0, 1.0, f64::INFINITY, -804.60844201375378817, 1e-12, ln_cdf(-40.0));
+        test_almost(0.0, 1.0, 5.0, -0.69314718055994530942, 1e-15, ln_cdf(-1e-310));
+        test_almost(0.0, 1.0, 5.0, -0.69314718055994530942, 1e-15, ln_cdf(1e-310));
+        test_almost(0.0, 1.0, 1e-10, -0.69314718055994530942, 1e-15, ln_cdf(5e-324));
+    }
+
+    #[test]
+    fn test_ln_sf() {
+        let ln_sf = |arg: f64| move |x: StudentsT| x.ln_sf(arg);
+        test_almost(0.0, 1.0, 5.0, -17.314140361404830473, 1e-12, ln_sf(50.0));
+        test_almost(0.0, 1.0, 5.0, -228.00825306359109178, 1e-11, ln_sf(1e20));
+        test_almost(0.0, 1.0, 5.0, -1782.2531908345719285, 1e-10, ln_sf(1e155));
+        test_almost(0.0, 1.0, 2.0, -0.23740078615161914609, 1e-15, ln_sf(-1.0));
+        test_almost(0.0, 1.0, f64::INFINITY, -804.60844201375378817, 1e-12, ln_sf(40.0));
+        test_almost(0.0, 1.0, 5.0, -0.69314718055994530942, 1e-15, ln_sf(1e-310));
+        test_almost(0.0, 1.0, 5.0, -0.69314718055994530942, 1e-15, ln_sf(-1e-310));
+    }
+
+    #[test]
+    fn test_fit() {
+        let data = [-3.1, -0.4, 0.2, 0.5, 0.9, 1.1, 1.3, 2.0, 2.4, 9.5];
+        let n = StudentsT::fit(&data).unwrap();
+        assert_almost_eq!(n.location(), 0.925703383461754992418099073135, 1e-9);
+        assert_almost_eq!(n.scale(), 0.868893930026277408842558387562, 1e-9);
+        assert_almost_eq!(n.freedom(), 1.18367826665739246664561726305, 1e-9);
+        assert!(StudentsT::fit(&[]).is_err());
+        assert!(StudentsT::fit(&[1.0, 1.0]).is_err());
+    }
 }
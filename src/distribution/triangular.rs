@@ -1,6 +1,7 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::internal::solve_increasing;
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
-use crate::{Result, StatsError};
+use crate::{prec, Result, StatsError};
 use rand::Rng;
 use std::f64;
 
@@ -97,6 +98,94 @@ impl ContinuousCDF<f64, f64> for Triangular {
             1.0
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// triangular distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x < (mode - min) / (max - min) {
+    ///     min + sqrt(x * (max - min) * (mode - min))
+    /// } else {
+    ///     max - sqrt((1 - x) * (max - min) * (max - mode))
+    /// }
+    /// ```
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if x < (c - a) / (b - a) {
+            a + (x * (b - a) * (c - a)).sqrt()
+        } else {
+            b - ((1.0 - x) * (b - a) * (b - c)).sqrt()
+        }
+    }
+    /// Calculates the survival function for the triangular
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x <= min {
+    ///     1
+    /// } if min < x <= mode {
+    ///     1 - (x - min)^2 / ((max - min) * (mode - min))
+    /// } else if mode < x < max {
+    ///     (max - x)^2 / ((max - min) * (max - mode))
+    /// } else {
+    ///     0
+    /// }
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if x <= a {
+            1.0
+        } else if x <= c {
+            1.0 - (x - a) * (x - a) / ((b - a) * (c - a))
+        } else if x < b {
+            (b - x) * (b - x) / ((b - a) * (b - c))
+        } else {
+            0.0
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// triangular distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// if x > (max - mode) / (max - min) {
+    ///     min + sqrt((1 - x) * (max - min) * (mode - min))
+    /// } else {
+    ///     max - sqrt(x * (max - min) * (max - mode))
+    /// }
+    /// ```
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        let a = self.min;
+        let b = self.max;
+        let c = self.mode;
+        if x > (b - c) / (b - a) {
+            a + ((1.0 - x) * (b - a) * (c - a)).sqrt()
+        } else {
+            b - (x * (b - a) * (b - c)).sqrt()
+        }
+    }
 }
 
 impl Min<f64> for Triangular {
@@ -236,7 +325,10 @@ impl Continuous<f64, f64> for Triangular {
         let a = self.min;
         let b = self.max;
         let c = self.mode;
-        if a <= x && x <= c {
+        if x == c {
+            // the peak, also when the mode coincides with a bound
+            2.0 / (b - a)
+        } else if a <= x && x < c {
             2.0 * (x - a) / ((b - a) * (c - a))
         } else if c < x && x <= b {
             2.0 * (b - x) / ((b - a) * (b - c))
@@ -267,6 +359,118 @@ impl Continuous<f64, f64> for Triangular {
     }
 }
 
+impl Fit<f64> for Triangular {
+    /// Fits a triangular distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln L = n ln(2 / (b - a)) + Σ_{x_i < c} ln((x_i - a) / (c - a))
+    ///        + Σ_{x_i > c} ln((b - x_i) / (b - c))
+    /// ```
+    ///
+    /// where `a`, `b` and `c` are the minimum, maximum and mode. For fixed
+    /// bounds the likelihood is largest with the mode at one of the samples
+    /// (Oliver, 1972), so the fit alternates between profiling the mode over
+    /// the samples and solving the likelihood equations of the bounds,
+    /// until the mode stays put and the bounds settle
+    fn fit(data: &[f64]) -> Result<Triangular> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|x| !x.is_finite()) {
+            return Err(StatsError::BadParams);
+        }
+        let mut x = data.to_vec();
+        x.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        let n = x.len();
+        let (lo, hi) = (x[0], x[n - 1]);
+        if lo == hi {
+            return Err(StatsError::BadParams);
+        }
+        let nf = n as f64;
+        let (mut a, mut b) = (lo - (hi - lo) / nf, hi + (hi - lo) / nf);
+        let mut mode = n;
+        for _ in 0..1000 {
+            let next = fit_mode(&x, a, b);
+            let c = x[next];
+            // the samples below and above the mode
+            let below = &x[..x.partition_point(|&v| v < c)];
+            let above = &x[x.partition_point(|&v| v <= c)..];
+            let (m, k) = (below.len() as f64, above.len() as f64);
+            let next_a = if below.is_empty() {
+                c
+            } else {
+                solve_increasing(
+                    |a| {
+                        below.iter().map(|v| 1.0 / (v - a)).sum::<f64>()
+                            - m / (c - a)
+                            - nf / (b - a)
+                    },
+                    f64::NEG_INFINITY,
+                    lo,
+                    a.min(lo),
+                )
+            };
+            let next_b = if above.is_empty() {
+                c
+            } else {
+                solve_increasing(
+                    |b| {
+                        nf / (b - next_a) + k / (b - c)
+                            - above.iter().map(|v| 1.0 / (b - v)).sum::<f64>()
+                    },
+                    hi,
+                    f64::INFINITY,
+                    b.max(hi),
+                )
+            };
+            let tol = prec::DEFAULT_F64_ACC * (next_b - next_a);
+            let settled = (next_a - a).abs() <= tol && (next_b - b).abs() <= tol;
+            a = next_a;
+            b = next_b;
+            if next == mode && settled {
+                return Triangular::new(a, b, c);
+            }
+            mode = next;
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
+/// Returns the index of the sample that maximizes the likelihood of the
+/// sorted samples `x` as the mode of a triangular distribution on `[a, b]`,
+/// evaluating every candidate at once from cumulative sums of the log
+/// factors `ln(x_i - a)` and `ln(b - x_i)`
+fn fit_mode(x: &[f64], a: f64, b: f64) -> usize {
+    let n = x.len();
+    let mut lower = vec![0.0; n + 1];
+    let mut upper = vec![0.0; n + 1];
+    for i in 0..n {
+        lower[i + 1] = lower[i] + (x[i] - a).ln();
+        upper[n - i - 1] = upper[n - i] + (b - x[n - i - 1]).ln();
+    }
+    let mut best = (f64::NEG_INFINITY, 0);
+    let mut i = 0;
+    while i < n {
+        let c = x[i];
+        // samples tied with the mode contribute nothing
+        let j = i + x[i..].partition_point(|&v| v <= c);
+        let mut ln_l = 0.0;
+        if i > 0 {
+            ln_l += lower[i] - i as f64 * (c - a).ln();
+        }
+        if j < n {
+            ln_l += upper[j] - (n - j) as f64 * (b - c).ln();
+        }
+        if ln_l > best.0 {
+            best = (ln_l, i);
+        }
+        i = j;
+    }
+    best.1
+}
+
 fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, min: f64, max: f64, mode: f64) -> f64 {
     let f: f64 = rng.gen();
     if f < (mode - min) / (max - min) {
@@ -281,7 +485,7 @@ fn sample_unchecked<R: Rng + ?Sized>(rng: &mut R, min: f64, max: f64, mode: f64)
 mod tests {
     use std::fmt::Debug;
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Triangular};
+    use crate::distribution::{ContinuousCDF, Continuous, Triangular, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -424,6 +628,8 @@ mod tests {
         test_case(-5.0, -3.0, -4.0, 0.5, pdf(-4.5));
         test_case(-5.0, -3.0, -4.0, 1.0, pdf(-4.0));
         test_case(-5.0, -3.0, -4.0, 0.5, pdf(-3.5));
+        test_case(1.0, 2.0, 1.0, 2.0, pdf(1.0));
+        test_case(1.0, 2.0, 2.0, 2.0, pdf(2.0));
     }
 
     #[test]
@@ -477,4 +683,50 @@ mod tests {
         test::check_continuous_distribution(&try_create(-5.0, 5.0, 0.0), -5.0, 5.0);
         test::check_continuous_distribution(&try_create(-15.0, -2.0, -3.0), -15.0, -2.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Triangular| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, 0.5, 0.0, inverse_cdf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.5, inverse_cdf(0.5));
+        test_almost(0.0, 1.0, 0.5, 0.25, 1e-15, inverse_cdf(0.125));
+        test_almost(-5.0, 8.0, -3.5, 0.0, 1e-14, inverse_cdf(0.571906354515050167224080));
+        test_almost(-5.0, 8.0, -3.5, -4.0, 1e-14, inverse_cdf(0.051282051282051282051282));
+        test_case(0.0, 1.0, 0.5, 1.0, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Triangular| x.sf(arg);
+        test_case(0.0, 1.0, 0.5, 1.0, sf(0.0));
+        test_case(0.0, 1.0, 0.5, 0.5, sf(0.5));
+        test_almost(0.0, 1.0, 0.5, 0.125, 1e-15, sf(0.75));
+        test_almost(-5.0, 8.0, -3.5, 1.0 - 0.051282051282051282051282, 1e-15, sf(-4.0));
+        test_case(0.0, 1.0, 0.5, 0.0, sf(1.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Triangular| x.inverse_sf(arg);
+        test_case(0.0, 1.0, 0.5, 1.0, inverse_sf(0.0));
+        test_almost(0.0, 1.0, 0.5, 0.75, 1e-15, inverse_sf(0.125));
+        test_almost(-5.0, 8.0, -3.5, -4.0, 1e-14, inverse_sf(1.0 - 0.051282051282051282051282));
+        test_case(0.0, 1.0, 0.5, 0.0, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Triangular::fit(&[1.0, 2.0, 2.6, 3.0, 3.2, 3.3, 3.5, 3.9, 4.4, 5.0, 6.1]).unwrap();
+        assert_almost_eq!(n.min(), 0.408315720953110793650238320520882, 1e-14);
+        assert_almost_eq!(n.max(), 6.77535116962688243804436495510103, 1e-14);
+        assert_eq!(n.mode().unwrap(), 3.2);
+        // the likelihood is largest with the mode on the smallest sample
+        let n = Triangular::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_eq!(n.min(), 0.5);
+        assert_eq!(n.mode().unwrap(), 0.5);
+        assert_almost_eq!(n.max(), 3.76912298697669281861332163287, 1e-14);
+        assert!(Triangular::fit(&[]).is_err());
+        assert!(Triangular::fit(&[1.0, f64::NAN]).is_err());
+        assert!(Triangular::fit(&[1.0, 1.0]).is_err());
+    }
 }
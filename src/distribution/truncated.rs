@@ -0,0 +1,981 @@
+use crate::distribution::internal::solve_increasing;
+use crate::distribution::{
+    Beta, Cauchy, Chi, ChiSquared, Continuous, ContinuousCDF, Dirac, Discrete, DiscreteCDF,
+    Empirical, Erlang, Exp, FisherSnedecor, Frechet, Gamma, GeneralizedPareto, Gev, Gumbel,
+    InverseGamma, Kolmogorov, Laplace, LogNormal, NoncentralBeta, NoncentralChiSquared,
+    NoncentralFisherSnedecor, NoncentralStudentsT, Normal, Pareto, SkewNormal, SkewStudentsT,
+    StudentizedRange, StudentsT, Triangular, Uniform, VonMises, Weibull,
+};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+
+/// The share of the parent's probability mass inside the truncation
+/// interval from which on samples are drawn by rejection from the parent
+/// rather than by inverting the cdf
+const REJECTION_MIN_MASS: f64 = 0.25;
+
+/// The probability mass a parent distribution assigns to a truncation
+/// interval.
+///
+/// The mass is measured through the parent's cdf or, if the interval lies
+/// in the upper tail, through its survival function, and is kept in log
+/// space relative to the outer bound so that intervals far out in a tail
+/// neither cancel nor underflow.
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct Mass {
+    /// whether the mass is measured through the survival function
+    upper_tail: bool,
+    /// log tail probability at the outer bound, i.e. `ln(cdf(upper))` or
+    /// `ln(sf(lower))`
+    ln_outer: f64,
+    /// log ratio of the tail probability at the inner bound to the one at
+    /// the outer bound
+    ln_ratio: f64,
+    /// log of the mass itself
+    ln_mass: f64,
+}
+
+impl Mass {
+    fn new(upper_tail: bool, ln_outer: f64, ln_inner: f64) -> Result<Mass> {
+        let ln_ratio = ln_inner - ln_outer;
+        if ln_outer.is_infinite() || ln_ratio.is_nan() || ln_ratio >= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        Ok(Mass {
+            upper_tail,
+            ln_outer,
+            ln_ratio,
+            ln_mass: ln_outer + (-ln_ratio.exp_m1()).ln(),
+        })
+    }
+
+    /// The normalized mass between the inner bound and a point whose log
+    /// tail probability is `ln_tail`
+    fn inner(&self, ln_tail: f64) -> f64 {
+        let num = (ln_tail - self.ln_outer).exp() - self.ln_ratio.exp();
+        (num / -self.ln_ratio.exp_m1()).clamp(0.0, 1.0)
+    }
+
+    /// The normalized mass between a point whose log tail probability is
+    /// `ln_tail` and the outer bound
+    fn outer(&self, ln_tail: f64) -> f64 {
+        ((ln_tail - self.ln_outer).exp_m1() / self.ln_ratio.exp_m1()).clamp(0.0, 1.0)
+    }
+
+    /// The parent's log tail probability at the point that leaves a
+    /// normalized mass of `outer` towards the outer bound and of
+    /// `inner = 1 - outer` towards the inner bound. Whichever of the two is
+    /// smaller enters the formula, so that neither loses precision.
+    fn ln_parent_tail(&self, outer: f64, inner: f64) -> f64 {
+        if outer <= inner {
+            self.ln_outer + (outer * self.ln_ratio.exp_m1()).ln_1p()
+        } else {
+            let a = self.ln_ratio;
+            let b = inner.ln() + (-self.ln_ratio.exp_m1()).ln();
+            self.ln_outer + a.max(b) + (-(a - b).abs()).exp().ln_1p()
+        }
+    }
+
+    fn ln(&self) -> f64 {
+        self.ln_mass
+    }
+}
+
+/// Implements a continuous distribution `D` truncated to an interval
+/// `[lower, upper]`, with its density renormalized to the probability mass
+/// the parent assigns to that interval
+///
+/// Samples are drawn through the parent's `SampleTruncated` implementation:
+/// an exact tail sampler for `Normal`, and for other parents rejection when
+/// the interval carries most of their mass and inversion of the cdf
+/// otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Continuous, ContinuousCDF, Normal, Truncated};
+/// use statrs::prec;
+///
+/// let n = Truncated::new(Normal::new(0.0, 1.0).unwrap(), -1.0, 2.0).unwrap();
+/// assert!(prec::almost_eq(n.pdf(0.0), 0.48735023846953063372, 1e-10));
+/// assert!(prec::almost_eq(n.cdf(0.5), 0.65088042133662712997, 1e-10));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Truncated<D> {
+    dist: D,
+    lower: f64,
+    upper: f64,
+    mass: Mass,
+}
+
+impl<D: ContinuousCDF<f64, f64>> Truncated<D> {
+    /// Constructs a new distribution by truncating `dist` to
+    /// `[lower, upper]`. Bounds outside the support of `dist` are clamped
+    /// to it, so infinite bounds leave that side untruncated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower` or `upper` are `NaN`, if
+    /// `lower >= upper`, or if `dist` assigns no probability mass to the
+    /// interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Exp, Truncated};
+    /// use std::f64;
+    ///
+    /// let mut result = Truncated::new(Exp::new(1.0).unwrap(), 1.0, f64::INFINITY);
+    /// assert!(result.is_ok());
+    ///
+    /// result = Truncated::new(Exp::new(1.0).unwrap(), -2.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, lower: f64, upper: f64) -> Result<Truncated<D>> {
+        if lower.is_nan() || upper.is_nan() {
+            return Err(StatsError::BadParams);
+        }
+        if lower >= upper {
+            return Err(StatsError::ArgLtArg("lower", "upper"));
+        }
+        let lower = lower.max(dist.min());
+        let upper = upper.min(dist.max());
+        if lower >= upper {
+            return Err(StatsError::BadParams);
+        }
+        let ln_sf_lower = dist.ln_sf(lower);
+        let ln_cdf_upper = dist.ln_cdf(upper);
+        let mass = if ln_sf_lower < ln_cdf_upper {
+            Mass::new(true, ln_sf_lower, dist.ln_sf(upper))?
+        } else {
+            Mass::new(false, ln_cdf_upper, dist.ln_cdf(lower))?
+        };
+        Ok(Truncated {
+            dist,
+            lower,
+            upper,
+            mass,
+        })
+    }
+
+    /// Returns the point in the truncation interval at which the parent's
+    /// log tail probability, measured on the side of the outer bound, equals
+    /// `ln_tail`. The parent's own quantile function provides the starting
+    /// point whenever the tail probability does not underflow.
+    fn invert(&self, ln_tail: f64) -> f64 {
+        let p = ln_tail.exp();
+        let x0 = if self.mass.upper_tail {
+            self.dist.inverse_sf(p)
+        } else {
+            self.dist.inverse_cdf(p)
+        };
+        let x0 = if x0.is_finite() {
+            x0
+        } else if self.lower.is_finite() && (self.mass.upper_tail || self.upper.is_infinite()) {
+            self.lower
+        } else if self.upper.is_finite() {
+            self.upper
+        } else {
+            0.0
+        };
+        if self.mass.upper_tail {
+            solve_increasing(|x| ln_tail - self.dist.ln_sf(x), self.lower, self.upper, x0)
+        } else {
+            solve_increasing(
+                |x| self.dist.ln_cdf(x) - ln_tail,
+                self.lower,
+                self.upper,
+                x0,
+            )
+        }
+    }
+}
+
+impl<D> Truncated<D> {
+    /// Returns the untruncated parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Normal, Truncated};
+    ///
+    /// let n = Truncated::new(Normal::new(0.0, 1.0).unwrap(), -1.0, 2.0).unwrap();
+    /// assert_eq!(*n.parent(), Normal::new(0.0, 1.0).unwrap());
+    /// ```
+    pub fn parent(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the probability mass the parent distribution assigns to the
+    /// truncation interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Uniform, Truncated};
+    ///
+    /// let n = Truncated::new(Uniform::new(0.0, 4.0).unwrap(), 1.0, 2.0).unwrap();
+    /// assert_eq!(n.mass(), 0.25);
+    /// ```
+    pub fn mass(&self) -> f64 {
+        self.mass.ln().exp()
+    }
+}
+
+/// The `SampleTruncated` trait is the hook through which `Truncated` draws
+/// its samples, so that a parent distribution can supply an exact sampler
+/// for its truncations.
+///
+/// # Remarks
+///
+/// The provided method draws by rejection from the parent when the interval
+/// carries most of its mass and by inverting the truncated cdf otherwise.
+/// `Normal` overrides it with an exact tail sampler. Other parents implement
+/// the trait with an empty `impl` block.
+pub trait SampleTruncated:
+    ContinuousCDF<f64, f64> + ::rand::distributions::Distribution<f64> + Sized
+{
+    /// Draws a sample from `dist`, a truncation of this distribution
+    fn sample_truncated<R: Rng + ?Sized>(dist: &Truncated<Self>, rng: &mut R) -> f64 {
+        if dist.mass() >= REJECTION_MIN_MASS {
+            loop {
+                let x = dist.dist.sample(rng);
+                if dist.lower <= x && x <= dist.upper {
+                    return x;
+                }
+            }
+        }
+        dist.inverse_cdf(rng.gen::<f64>())
+    }
+}
+
+impl SampleTruncated for Normal {
+    fn sample_truncated<R: Rng + ?Sized>(dist: &Truncated<Normal>, rng: &mut R) -> f64 {
+        dist.dist.sample_truncated(rng, dist.lower, dist.upper)
+    }
+}
+
+impl SampleTruncated for Beta {}
+impl SampleTruncated for Cauchy {}
+impl SampleTruncated for Chi {}
+impl SampleTruncated for ChiSquared {}
+impl SampleTruncated for Dirac {}
+impl SampleTruncated for Empirical {}
+impl SampleTruncated for Erlang {}
+impl SampleTruncated for Exp {}
+impl SampleTruncated for FisherSnedecor {}
+impl SampleTruncated for Frechet {}
+impl SampleTruncated for Gamma {}
+impl SampleTruncated for GeneralizedPareto {}
+impl SampleTruncated for Gev {}
+impl SampleTruncated for Gumbel {}
+impl SampleTruncated for InverseGamma {}
+impl SampleTruncated for Kolmogorov {}
+impl SampleTruncated for Laplace {}
+impl SampleTruncated for LogNormal {}
+impl SampleTruncated for NoncentralBeta {}
+impl SampleTruncated for NoncentralChiSquared {}
+impl SampleTruncated for NoncentralFisherSnedecor {}
+impl SampleTruncated for NoncentralStudentsT {}
+impl SampleTruncated for Pareto {}
+impl SampleTruncated for SkewNormal {}
+impl SampleTruncated for SkewStudentsT {}
+impl SampleTruncated for StudentizedRange {}
+impl SampleTruncated for StudentsT {}
+impl SampleTruncated for Triangular {}
+impl SampleTruncated for Uniform {}
+impl SampleTruncated for VonMises {}
+impl SampleTruncated for Weibull {}
+
+impl<D: SampleTruncated> ::rand::distributions::Distribution<f64> for Truncated<D> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        D::sample_truncated(self, rng)
+    }
+}
+
+impl<D: ContinuousCDF<f64, f64>> ContinuousCDF<f64, f64> for Truncated<D> {
+    /// Calculates the cumulative distribution function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (F(x) - F(a)) / (F(b) - F(a))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `F` is the cdf of the parent distribution
+    /// and `[a, b]` is the truncation interval. The differences are taken
+    /// between survival functions when the interval lies in the upper tail.
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            0.0
+        } else if x >= self.upper {
+            1.0
+        } else if self.mass.upper_tail {
+            self.mass.outer(self.dist.ln_sf(x))
+        } else {
+            self.mass.inner(self.dist.ln_cdf(x))
+        }
+    }
+
+    /// Calculates the survival function for the truncated distribution at
+    /// `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (S(x) - S(b)) / (S(a) - S(b))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `S` is the survival function of the parent
+    /// distribution and `[a, b]` is the truncation interval. The differences
+    /// are taken between cdfs when the interval lies in the lower tail.
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.lower {
+            1.0
+        } else if x >= self.upper {
+            0.0
+        } else if self.mass.upper_tail {
+            self.mass.inner(self.dist.ln_sf(x))
+        } else {
+            self.mass.outer(self.dist.ln_cdf(x))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(F(a) + x * (F(b) - F(a)))
+    /// ```
+    ///
+    /// where `F` is the cdf of the parent distribution and `[a, b]` is the
+    /// truncation interval. The equation is solved for the log of the
+    /// parent's tail probability, starting from the parent's quantile
+    /// function, so that intervals far out in a tail do not underflow.
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.lower;
+        }
+        if x == 1.0 {
+            return self.upper;
+        }
+        if self.mass.upper_tail {
+            self.invert(self.mass.ln_parent_tail(x, 1.0 - x))
+        } else {
+            self.invert(self.mass.ln_parent_tail(1.0 - x, x))
+        }
+    }
+
+    /// Calculates the inverse survival function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// S^-1(S(b) + x * (S(a) - S(b)))
+    /// ```
+    ///
+    /// where `S` is the survival function of the parent distribution and
+    /// `[a, b]` is the truncation interval. The equation is solved for the
+    /// log of the parent's tail probability, starting from the parent's
+    /// quantile function, so that intervals far out in a tail do not
+    /// underflow.
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.upper;
+        }
+        if x == 1.0 {
+            return self.lower;
+        }
+        if self.mass.upper_tail {
+            self.invert(self.mass.ln_parent_tail(1.0 - x, x))
+        } else {
+            self.invert(self.mass.ln_parent_tail(x, 1.0 - x))
+        }
+    }
+}
+
+impl<D> Min<f64> for Truncated<D> {
+    /// Returns the lower bound of the truncation interval, clamped to the
+    /// support of the parent distribution
+    fn min(&self) -> f64 {
+        self.lower
+    }
+}
+
+impl<D> Max<f64> for Truncated<D> {
+    /// Returns the upper bound of the truncation interval, clamped to the
+    /// support of the parent distribution
+    fn max(&self) -> f64 {
+        self.upper
+    }
+}
+
+impl<D: ContinuousCDF<f64, f64>> Median<f64> for Truncated<D> {
+    /// Returns the median of the truncated distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// F^-1(1 / 2)
+    /// ```
+    ///
+    /// where `F^-1` is the inverse cdf of the truncated distribution
+    fn median(&self) -> f64 {
+        self.inverse_cdf(0.5)
+    }
+}
+
+impl<D: Continuous<f64, f64>> Continuous<f64, f64> for Truncated<D> {
+    /// Calculates the probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// f(x) / (F(b) - F(a))
+    /// ```
+    ///
+    /// for `a <= x <= b` and zero otherwise, where `f` and `F` are the pdf
+    /// and cdf of the parent distribution and `[a, b]` is the truncation
+    /// interval
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(f(x)) - ln(F(b) - F(a))
+    /// ```
+    ///
+    /// for `a <= x <= b` and `-INF` otherwise, where `f` and `F` are the pdf
+    /// and cdf of the parent distribution and `[a, b]` is the truncation
+    /// interval
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.lower || x > self.upper {
+            f64::NEG_INFINITY
+        } else {
+            self.dist.ln_pdf(x) - self.mass.ln()
+        }
+    }
+}
+
+/// Implements a discrete distribution `D` truncated to the integers in
+/// `[lower, upper]`, with its probability mass function renormalized to the
+/// mass the parent assigns to that range
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{Discrete, DiscreteCDF, Poisson, TruncatedDiscrete};
+/// use statrs::prec;
+///
+/// let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 2, 6).unwrap();
+/// assert!(prec::almost_eq(n.pmf(3), 0.29197080291970802920, 1e-15));
+/// assert!(prec::almost_eq(n.cdf(4), 0.80291970802919708029, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TruncatedDiscrete<D> {
+    dist: D,
+    lower: u64,
+    upper: u64,
+    mass: Mass,
+}
+
+impl<D: DiscreteCDF<u64, f64>> TruncatedDiscrete<D> {
+    /// Constructs a new distribution by truncating `dist` to the integers
+    /// in `[lower, upper]`. Bounds outside the support of `dist` are
+    /// clamped to it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `lower > upper` or if `dist` assigns no
+    /// probability mass to the range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Binomial, TruncatedDiscrete};
+    ///
+    /// let mut result = TruncatedDiscrete::new(Binomial::new(0.5, 20).unwrap(), 15, 20);
+    /// assert!(result.is_ok());
+    ///
+    /// result = TruncatedDiscrete::new(Binomial::new(0.5, 20).unwrap(), 21, 30);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(dist: D, lower: u64, upper: u64) -> Result<TruncatedDiscrete<D>> {
+        if lower > upper {
+            return Err(StatsError::ArgLteArg("lower", "upper"));
+        }
+        let lower = lower.max(dist.min());
+        let upper = upper.min(dist.max());
+        if lower > upper {
+            return Err(StatsError::BadParams);
+        }
+        let (ln_cdf_below, ln_sf_below) = if lower == 0 {
+            (f64::NEG_INFINITY, 0.0)
+        } else {
+            (dist.ln_cdf(lower - 1), dist.ln_sf(lower - 1))
+        };
+        let ln_cdf_upper = dist.ln_cdf(upper);
+        let mass = if ln_sf_below < ln_cdf_upper {
+            Mass::new(true, ln_sf_below, dist.ln_sf(upper))?
+        } else {
+            Mass::new(false, ln_cdf_upper, ln_cdf_below)?
+        };
+        Ok(TruncatedDiscrete {
+            dist,
+            lower,
+            upper,
+            mass,
+        })
+    }
+
+    /// Returns the smallest integer in the truncation range whose log tail
+    /// probability, measured on the side of the outer bound, has passed
+    /// `ln_tail`, found by bisection
+    fn invert(&self, ln_tail: f64) -> u64 {
+        let passed = |k: u64| {
+            if self.mass.upper_tail {
+                self.dist.ln_sf(k) <= ln_tail
+            } else {
+                self.dist.ln_cdf(k) >= ln_tail
+            }
+        };
+        let (mut lo, mut hi) = (self.lower, self.upper);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if passed(mid) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo
+    }
+}
+
+impl<D> TruncatedDiscrete<D> {
+    /// Returns the untruncated parent distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Poisson, TruncatedDiscrete};
+    ///
+    /// let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 2, 6).unwrap();
+    /// assert_eq!(*n.parent(), Poisson::new(3.0).unwrap());
+    /// ```
+    pub fn parent(&self) -> &D {
+        &self.dist
+    }
+
+    /// Returns the probability mass the parent distribution assigns to the
+    /// truncation range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::{Binomial, TruncatedDiscrete};
+    ///
+    /// let n = TruncatedDiscrete::new(Binomial::new(0.5, 4).unwrap(), 1, 4).unwrap();
+    /// assert!((n.mass() - 0.9375).abs() < 1e-15);
+    /// ```
+    pub fn mass(&self) -> f64 {
+        self.mass.ln().exp()
+    }
+}
+
+impl<D> ::rand::distributions::Distribution<f64> for TruncatedDiscrete<D>
+where
+    D: ::rand::distributions::Distribution<f64> + DiscreteCDF<u64, f64>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        if self.mass() >= REJECTION_MIN_MASS {
+            let (lower, upper) = (self.lower as f64, self.upper as f64);
+            loop {
+                let x = self.dist.sample(rng);
+                if lower <= x && x <= upper {
+                    return x;
+                }
+            }
+        }
+        self.inverse_cdf(rng.gen::<f64>()) as f64
+    }
+}
+
+impl<D: DiscreteCDF<u64, f64>> DiscreteCDF<u64, f64> for TruncatedDiscrete<D> {
+    /// Calculates the cumulative distribution function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (F(x) - F(a - 1)) / (F(b) - F(a - 1))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `F` is the cdf of the parent distribution
+    /// and `[a, b]` is the truncation range. The differences are taken
+    /// between survival functions when the range lies in the upper tail.
+    fn cdf(&self, x: u64) -> f64 {
+        if x < self.lower {
+            0.0
+        } else if x >= self.upper {
+            1.0
+        } else if self.mass.upper_tail {
+            self.mass.outer(self.dist.ln_sf(x))
+        } else {
+            self.mass.inner(self.dist.ln_cdf(x))
+        }
+    }
+
+    /// Calculates the survival function for the truncated distribution at
+    /// `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (S(x) - S(b)) / (S(a - 1) - S(b))
+    /// ```
+    ///
+    /// for `a <= x <= b`, where `S` is the survival function of the parent
+    /// distribution and `[a, b]` is the truncation range. The differences
+    /// are taken between cdfs when the range lies in the lower tail.
+    fn sf(&self, x: u64) -> f64 {
+        if x < self.lower {
+            1.0
+        } else if x >= self.upper {
+            0.0
+        } else if self.mass.upper_tail {
+            self.mass.inner(self.dist.ln_sf(x))
+        } else {
+            self.mass.outer(self.dist.ln_cdf(x))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the
+    /// truncated distribution at `x`, i.e. the smallest `k` with
+    /// `cdf(k) >= x`, found by bisection on the log of the parent's tail
+    /// probability
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_cdf(&self, x: f64) -> u64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.lower;
+        }
+        if x == 1.0 {
+            return self.upper;
+        }
+        if self.mass.upper_tail {
+            self.invert(self.mass.ln_parent_tail(x, 1.0 - x))
+        } else {
+            self.invert(self.mass.ln_parent_tail(1.0 - x, x))
+        }
+    }
+
+    /// Calculates the inverse survival function for the truncated
+    /// distribution at `x`, i.e. the smallest `k` with `sf(k) <= x`, found by
+    /// bisection on the log of the parent's tail probability
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    fn inverse_sf(&self, x: f64) -> u64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.upper;
+        }
+        if x == 1.0 {
+            return self.lower;
+        }
+        if self.mass.upper_tail {
+            self.invert(self.mass.ln_parent_tail(1.0 - x, x))
+        } else {
+            self.invert(self.mass.ln_parent_tail(x, 1.0 - x))
+        }
+    }
+}
+
+impl<D> Min<u64> for TruncatedDiscrete<D> {
+    /// Returns the lower bound of the truncation range, clamped to the
+    /// support of the parent distribution
+    fn min(&self) -> u64 {
+        self.lower
+    }
+}
+
+impl<D> Max<u64> for TruncatedDiscrete<D> {
+    /// Returns the upper bound of the truncation range, clamped to the
+    /// support of the parent distribution
+    fn max(&self) -> u64 {
+        self.upper
+    }
+}
+
+impl<D: Discrete<u64, f64>> Discrete<u64, f64> for TruncatedDiscrete<D> {
+    /// Calculates the probability mass function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// p(x) / (F(b) - F(a - 1))
+    /// ```
+    ///
+    /// for `a <= x <= b` and zero otherwise, where `p` and `F` are the pmf
+    /// and cdf of the parent distribution and `[a, b]` is the truncation
+    /// range
+    fn pmf(&self, x: u64) -> f64 {
+        self.ln_pmf(x).exp()
+    }
+
+    /// Calculates the log probability mass function for the truncated
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(p(x)) - ln(F(b) - F(a - 1))
+    /// ```
+    ///
+    /// for `a <= x <= b` and `-INF` otherwise, where `p` and `F` are the pmf
+    /// and cdf of the parent distribution and `[a, b]` is the truncation
+    /// range
+    fn ln_pmf(&self, x: u64) -> f64 {
+        if x < self.lower || x > self.upper {
+            f64::NEG_INFINITY
+        } else {
+            self.dist.ln_pmf(x) - self.mass.ln()
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{Binomial, Continuous, ContinuousCDF, Discrete, DiscreteCDF, Exp,
+                              Gamma, Gumbel, Normal, Poisson, Truncated, TruncatedDiscrete, Uniform};
+    use crate::distribution::internal::*;
+    use rand::distributions::Distribution;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use std::f64;
+
+    fn normal(lower: f64, upper: f64) -> Truncated<Normal> {
+        Truncated::new(Normal::new(0.0, 1.0).unwrap(), lower, upper).unwrap()
+    }
+
+    #[test]
+    fn test_create() {
+        let n = Truncated::new(Exp::new(1.0).unwrap(), -5.0, f64::INFINITY).unwrap();
+        assert_eq!(n.min(), 0.0);
+        assert_eq!(n.max(), f64::INFINITY);
+        assert_eq!(n.mass(), 1.0);
+        assert!(Truncated::new(Normal::new(0.0, 1.0).unwrap(), f64::NEG_INFINITY, 0.0).is_ok());
+        assert!(TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 0, 0).is_ok());
+    }
+
+    #[test]
+    fn test_bad_create() {
+        let n = Normal::new(0.0, 1.0).unwrap();
+        assert!(Truncated::new(n, 1.0, 1.0).is_err());
+        assert!(Truncated::new(n, 2.0, 1.0).is_err());
+        assert!(Truncated::new(n, f64::NAN, 1.0).is_err());
+        assert!(Truncated::new(n, 0.0, f64::NAN).is_err());
+        assert!(Truncated::new(Uniform::new(0.0, 1.0).unwrap(), 2.0, 3.0).is_err());
+        assert!(TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 6, 2).is_err());
+        assert!(TruncatedDiscrete::new(Binomial::new(0.5, 10).unwrap(), 11, 12).is_err());
+    }
+
+    #[test]
+    fn test_pdf_cdf() {
+        let n = normal(-1.0, 2.0);
+        assert_almost_eq!(n.pdf(0.0), 0.48735023846953063372, 1e-10);
+        assert_almost_eq!(n.cdf(0.5), 0.65088042133662712997, 1e-10);
+        assert_almost_eq!(n.sf(1.5), 0.053820375263549971360, 1e-10);
+        assert_eq!(n.pdf(-1.5), 0.0);
+        assert_eq!(n.ln_pdf(2.5), f64::NEG_INFINITY);
+        assert_eq!(n.cdf(-1.0), 0.0);
+        assert_eq!(n.cdf(2.0), 1.0);
+        assert_eq!(n.sf(-3.0), 1.0);
+        assert_eq!(n.sf(3.0), 0.0);
+    }
+
+    #[test]
+    fn test_far_tail() {
+        // the parent's mass beyond 40 underflows, its log does not
+        let n = normal(40.0, f64::INFINITY);
+        assert_almost_eq!(n.ln_pdf(40.0), 3.6895034805491154248, 1e-12);
+        assert_almost_eq!(n.cdf(40.01), 0.32988079019633785043, 1e-12);
+        assert_almost_eq!(n.sf(40.01), 1.0 - 0.32988079019633785043, 1e-12);
+        let n = normal(f64::NEG_INFINITY, -40.0);
+        assert_almost_eq!(n.ln_pdf(-40.0), 3.6895034805491154248, 1e-12);
+        assert_almost_eq!(n.sf(-40.01), 0.32988079019633785043, 1e-12);
+    }
+
+    #[test]
+    fn test_far_tail_inverse_cdf() {
+        let n = normal(40.0, f64::INFINITY);
+        assert_almost_eq!(n.inverse_cdf(0.5), 40.017314126764651106, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.9), 40.057487458036021659, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(1e-10), 40.000000000002498440, 1e-13);
+        assert_almost_eq!(n.inverse_sf(1e-6), 40.343697534942505672, 1e-13);
+        assert_almost_eq!(n.cdf(n.inverse_cdf(0.3)), 0.3, 1e-12);
+        let n = normal(f64::NEG_INFINITY, -40.0);
+        assert_almost_eq!(n.inverse_sf(0.5), -40.017314126764651106, 1e-13);
+        assert_almost_eq!(n.inverse_cdf(0.1), -40.057487458036021659, 1e-13);
+        let n = normal(40.0, 41.0);
+        assert_almost_eq!(n.inverse_cdf(0.5), 40.017314126764651106, 1e-13);
+        let n = normal(5.0, 8.0);
+        assert_almost_eq!(n.inverse_cdf(1e-8), 5.0000000019280810523, 1e-15);
+        // limited by the accuracy of the parent's tail at 7.8
+        assert_almost_eq!(n.inverse_sf(1e-8), 7.7848929931886600740, 1e-11);
+
+        let n = Truncated::new(Gamma::new(1.0, 1.0).unwrap(), 800.0, 801.0).unwrap();
+        assert_almost_eq!(n.inverse_cdf(0.5), 800.0 + (2.0 / (1.0 + (-1f64).exp())).ln(), 1e-12);
+        let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 200, 205).unwrap();
+        assert_eq!(n.inverse_cdf(0.5), 200);
+        assert_eq!(n.inverse_cdf(0.99), 201);
+        assert_eq!(n.inverse_sf(1e-3), 201);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = normal(-1.0, 2.0);
+        assert_almost_eq!(n.inverse_cdf(0.3), -0.24240381788922680277, 1e-10);
+        assert_eq!(n.inverse_cdf(0.0), -1.0);
+        assert_eq!(n.inverse_cdf(1.0), 2.0);
+        for &p in &[0.01, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-10);
+            assert_almost_eq!(n.sf(n.inverse_sf(p)), p, 1e-10);
+        }
+        assert_almost_eq!(n.median(), n.inverse_cdf(0.5), 1e-15);
+
+        let n = normal(2.0, 3.0);
+        for &p in &[0.01, 0.2, 0.5, 0.9, 0.999] {
+            assert_almost_eq!(n.cdf(n.inverse_cdf(p)), p, 1e-10);
+            assert_almost_eq!(n.sf(n.inverse_sf(p)), p, 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&normal(-1.0, 2.0), -1.0, 2.0);
+        test::check_continuous_distribution(&normal(2.0, f64::INFINITY), 2.0, 6.0);
+        let n = Truncated::new(Gumbel::new(1.0, 2.0).unwrap(), -1.0, 4.0).unwrap();
+        test::check_continuous_distribution(&n, -1.0, 4.0);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let cases = [(-1.0, 2.0), (40.0, f64::INFINITY), (f64::NEG_INFINITY, -40.0),
+                     (8.0, 8.01), (-0.5, 0.5), (-0.01, f64::INFINITY)];
+        for &(lower, upper) in &cases {
+            let n = normal(lower, upper);
+            let mean = (0..2000).map(|_| n.sample(&mut rng)).inspect(|&x| {
+                assert!(lower <= x && x <= upper);
+            }).sum::<f64>() / 2000.0;
+            assert!((n.cdf(mean) - 0.5).abs() < 0.15);
+        }
+
+        // inversion of the cdf far beyond the range of the parent's cdf
+        let n = Truncated::new(Gamma::new(1.0, 1.0).unwrap(), 800.0, 801.0).unwrap();
+        let mean = (0..2000).map(|_| n.sample(&mut rng)).inspect(|&x| {
+            assert!((800.0..=801.0).contains(&x));
+        }).sum::<f64>() / 2000.0;
+        assert!((mean - 800.0 - (1.0 - 1.0 / 1f64.exp_m1())).abs() < 0.03);
+
+        // rejection from the parent and inversion of the cdf
+        for &(lower, upper) in &[(-1.0, 4.0), (8.0, 12.0)] {
+            let n = Truncated::new(Gumbel::new(1.0, 2.0).unwrap(), lower, upper).unwrap();
+            let below = (0..2000).map(|_| n.sample(&mut rng)).filter(|&x| {
+                assert!(lower <= x && x <= upper);
+                x <= n.median()
+            }).count();
+            assert!((below as f64 / 2000.0 - 0.5).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_discrete() {
+        let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 2, 6).unwrap();
+        assert_almost_eq!(n.pmf(3), 0.29197080291970802920, 1e-15);
+        assert_almost_eq!(n.cdf(4), 0.80291970802919708029, 1e-15);
+        assert_almost_eq!(n.sf(4), 0.19708029197080291971, 1e-15);
+        assert_eq!(n.pmf(1), 0.0);
+        assert_eq!(n.pmf(7), 0.0);
+        assert_eq!(n.cdf(1), 0.0);
+        assert_eq!(n.cdf(6), 1.0);
+        test::check_discrete_distribution(&n, 6);
+
+        let n = TruncatedDiscrete::new(Binomial::new(0.5, 20).unwrap(), 15, 30).unwrap();
+        assert_eq!(n.max(), 20);
+        assert_almost_eq!(n.pmf(16), 0.22327188940092165899, 1e-12);
+        assert_almost_eq!(n.cdf(16), 0.93774193548387096774, 1e-12);
+        test::check_discrete_distribution(&n, 20);
+    }
+
+    #[test]
+    fn test_discrete_inverse_cdf() {
+        let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), 2, 6).unwrap();
+        assert_eq!(n.inverse_cdf(0.0), 2);
+        assert_eq!(n.inverse_cdf(0.5), 3);
+        assert_eq!(n.inverse_cdf(0.81), 5);
+        assert_eq!(n.inverse_cdf(1.0), 6);
+        assert_eq!(n.inverse_sf(0.19), 5);
+
+        let n = TruncatedDiscrete::new(Binomial::new(0.5, 20).unwrap(), 15, 20).unwrap();
+        assert_eq!(n.inverse_cdf(0.5), 15);
+        assert_eq!(n.inverse_cdf(0.9), 16);
+        assert_eq!(n.inverse_cdf(0.95), 17);
+    }
+
+    #[test]
+    fn test_discrete_sample() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for &(lower, upper) in &[(2, 6), (10, 12)] {
+            let n = TruncatedDiscrete::new(Poisson::new(3.0).unwrap(), lower, upper).unwrap();
+            let mut counts = [0usize; 13];
+            for _ in 0..4000 {
+                let x = n.sample(&mut rng);
+                assert!(lower as f64 <= x && x <= upper as f64);
+                counts[x as usize] += 1;
+            }
+            for k in lower..=upper {
+                assert!((counts[k as usize] as f64 / 4000.0 - n.pmf(k)).abs() < 0.03);
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::statistics::*;
 use crate::{Result, StatsError};
 use rand::distributions::Uniform as RandUniform;
@@ -80,6 +80,61 @@ impl ContinuousCDF<f64, f64> for Uniform {
             (x - self.min) / (self.max - self.min)
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// uniform distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min + x * (max - min)
+    /// ```
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.min + x * (self.max - self.min)
+        }
+    }
+    /// Calculates the survival function for the uniform
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// (max - x) / (max - min)
+    /// ```
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.min {
+            1.0
+        } else if x >= self.max {
+            0.0
+        } else {
+            (self.max - x) / (self.max - self.min)
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// uniform distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// max - x * (max - min)
+    /// ```
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.max - x * (self.max - self.min)
+        }
+    }
 }
 
 impl Min<f64> for Uniform {
@@ -211,11 +266,29 @@ impl Continuous<f64, f64> for Uniform {
     }
 }
 
+impl Fit<f64> for Uniform {
+    /// Fits a continuous uniform distribution to `data` by maximum
+    /// likelihood, i.e. to the range of the samples
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// min = min_i x_i
+    /// max = max_i x_i
+    /// ```
+    fn fit(data: &[f64]) -> Result<Uniform> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        Uniform::new(Statistics::min(data), Statistics::max(data))
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Uniform};
+    use crate::distribution::{ContinuousCDF, Continuous, Uniform, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -435,4 +508,39 @@ mod tests {
             .all(|v| (min <= v) && (v < max))
         );
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Uniform| x.inverse_cdf(arg);
+        test_case(0.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_case(0.0, 1.0, 0.25, inverse_cdf(0.25));
+        test_case(-4.0, 6.0, 1.0, inverse_cdf(0.5));
+        test_case(-4.0, 6.0, 6.0, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Uniform| x.sf(arg);
+        test_case(0.0, 1.0, 1.0, sf(-1.0));
+        test_case(0.0, 1.0, 0.75, sf(0.25));
+        test_case(-4.0, 6.0, 0.5, sf(1.0));
+        test_case(-4.0, 6.0, 0.0, sf(7.0));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Uniform| x.inverse_sf(arg);
+        test_case(0.0, 1.0, 1.0, inverse_sf(0.0));
+        test_case(-4.0, 6.0, 1.0, inverse_sf(0.5));
+        test_case(-4.0, 6.0, -4.0, inverse_sf(1.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Uniform::fit(&[0.5, -1.0, 3.0]).unwrap();
+        assert_eq!(n.min(), -1.0);
+        assert_eq!(n.max(), 3.0);
+        assert!(Uniform::fit(&[]).is_err());
+        assert!(Uniform::fit(&[1.0, f64::NAN]).is_err());
+    }
 }
@@ -0,0 +1,653 @@
+use crate::distribution::{Continuous, ContinuousCDF};
+use crate::function::evaluate::gauss_legendre;
+use crate::function::{bessel, erf};
+use crate::statistics::*;
+use crate::{Result, StatsError};
+use rand::Rng;
+use std::f64;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+/// The concentration below which the cdf is evaluated through the series of
+/// Hill's algorithm rather than its normal approximation
+const SERIES_MAX_CONCENTRATION: f64 = 200.0;
+
+/// The constant `C1` of the normal approximation of Hill's algorithm
+const HILL_C1: f64 = 50.1;
+
+/// The concentration below which samples are drawn uniformly from the circle
+const UNIFORM_MAX_CONCENTRATION: f64 = 1e-8;
+
+/// The concentration above which samples are drawn from the limiting normal
+/// distribution
+const NORMAL_MIN_CONCENTRATION: f64 = 1e6;
+
+/// Implements the [von
+/// Mises](https://en.wikipedia.org/wiki/Von_Mises_distribution) distribution,
+/// the analogue of the normal distribution for angles such as wind
+/// directions or times of day mapped onto the circle
+///
+/// The distribution is supported on the interval `[μ - π, μ + π]` of
+/// length `2π` centered at its mean direction `μ`, so that angles are
+/// measured in radians and must be wrapped into that interval before
+/// evaluating the cdf. The moments are those of the angle on this interval,
+/// see `circular_variance` for the dispersion on the circle.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::{VonMises, Continuous};
+/// use statrs::statistics::Distribution;
+/// use statrs::prec;
+///
+/// let n = VonMises::new(1.0, 2.0).unwrap();
+/// assert_eq!(n.mean().unwrap(), 1.0);
+/// assert!(prec::almost_eq(n.pdf(1.1), 0.51075652127115189654, 1e-15));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VonMises {
+    mean: f64,
+    concentration: f64,
+    ln_norm: f64,
+}
+
+impl VonMises {
+    /// Constructs a new von Mises distribution with mean direction `mean`
+    /// and concentration `concentration`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `mean` or `concentration` are `NaN` or infinite,
+    /// or if `concentration < 0.0`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let mut result = VonMises::new(0.0, 1.0);
+    /// assert!(result.is_ok());
+    ///
+    /// result = VonMises::new(0.0, -1.0);
+    /// assert!(result.is_err());
+    /// ```
+    pub fn new(mean: f64, concentration: f64) -> Result<VonMises> {
+        if !mean.is_finite() || !concentration.is_finite() {
+            return Err(StatsError::BadParams);
+        }
+        if concentration < 0.0 {
+            return Err(StatsError::ArgNotNegative("concentration"));
+        }
+        Ok(VonMises {
+            mean,
+            concentration,
+            ln_norm: (2.0 * PI * bessel::i0e(concentration)).ln(),
+        })
+    }
+
+    /// Returns the concentration of the von Mises distribution
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(0.0, 1.0).unwrap();
+    /// assert_eq!(n.concentration(), 1.0);
+    /// ```
+    pub fn concentration(&self) -> f64 {
+        self.concentration
+    }
+
+    /// Returns the mean resultant length of the von Mises distribution, the
+    /// length of the expected unit vector `E[(cos X, sin X)]`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// I_1(κ) / I_0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I_0`, `I_1` are modified Bessel
+    /// functions of the first kind
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::distribution::VonMises;
+    ///
+    /// let n = VonMises::new(0.0, 2.0).unwrap();
+    /// assert!((n.mean_resultant_length() - 0.69777465796400798201).abs() < 1e-15);
+    /// ```
+    pub fn mean_resultant_length(&self) -> f64 {
+        bessel::i1e(self.concentration) / bessel::i0e(self.concentration)
+    }
+
+    /// Returns the circular variance of the von Mises distribution, which
+    /// lies in `[0, 1]` and measures the dispersion of the angles on the
+    /// circle rather than on the interval of support
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - I_1(κ) / I_0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I_0`, `I_1` are modified Bessel
+    /// functions of the first kind
+    pub fn circular_variance(&self) -> f64 {
+        1.0 - self.mean_resultant_length()
+    }
+
+    /// Evaluates the cdf of the von Mises distribution with zero mean at
+    /// `x` in `[-π, π]` with Hill's algorithm (ACM algorithm 518)
+    fn std_cdf(&self, x: f64) -> f64 {
+        let k = self.concentration;
+        if k < SERIES_MAX_CONCENTRATION {
+            // Fourier series of the cdf in the ratios I_n(κ) / I_0(κ), which
+            // are accumulated by backward recurrence from `p` terms. Ten more
+            // terms than in Hill's choice of `p` extend the accuracy of the
+            // series to concentrations up to 50.
+            let p = (38.0 + 0.5 * k - 100.0 / (k + 5.0)).ceil();
+            let (s, c) = x.sin_cos();
+            let (mut sn, mut cn) = (p * x).sin_cos();
+            let mut r = 0.0;
+            let mut v = 0.0;
+            let mut n = p - 1.0;
+            while n >= 1.0 {
+                let next = sn * c - cn * s;
+                cn = cn * c + sn * s;
+                sn = next;
+                r = 1.0 / (2.0 * n / k + r);
+                v = r * (sn / n + v);
+                n -= 1.0;
+            }
+            (0.5 + x / (2.0 * PI) + v / PI).clamp(0.0, 1.0)
+        } else {
+            let b = (2.0 / PI).sqrt() / bessel::i0e(k);
+            let z = b * (0.5 * x).sin();
+            let z2 = z * z;
+            let c = 24.0 * k;
+            let y = (c - 2.0 * z2 - 16.0) / 3.0
+                - (z2 * z2 + 1.75 * z2 + 83.5) / (c - HILL_C1 - z2 + 3.0);
+            let chi = z - z * z2 / (y * y);
+            0.5 * erf::erfc(-chi * FRAC_1_SQRT_2)
+        }
+    }
+
+    /// Returns the angle `x - μ` clamped to `[-π, π]`
+    fn centered(&self, x: f64) -> f64 {
+        (x - self.mean).clamp(-PI, PI)
+    }
+}
+
+impl ::rand::distributions::Distribution<f64> for VonMises {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> f64 {
+        let k = self.concentration;
+        if k < UNIFORM_MAX_CONCENTRATION {
+            return self.mean + PI * rng.gen_range(-1.0..1.0);
+        }
+        if k > NORMAL_MIN_CONCENTRATION {
+            let z = super::ziggurat::sample_std_normal(rng) / k.sqrt();
+            return self.mean + z.clamp(-PI, PI);
+        }
+        // Best and Fisher (1979), rejection from a wrapped Cauchy envelope
+        // with parameter `ρ`, where `s = (1 + ρ^2) / (2ρ)`
+        let s = if k < 1e-5 {
+            1.0 / k + k
+        } else {
+            let tau = 1.0 + (1.0 + 4.0 * k * k).sqrt();
+            let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * k);
+            (1.0 + rho * rho) / (2.0 * rho)
+        };
+        let w = loop {
+            let z = (PI * rng.gen::<f64>()).cos();
+            let w = (1.0 + s * z) / (s + z);
+            let y = k * (s - w);
+            let v: f64 = rng.gen();
+            if y * (2.0 - y) - v >= 0.0 || (y / v).ln() + 1.0 - y >= 0.0 {
+                break w;
+            }
+        };
+        let theta = w.clamp(-1.0, 1.0).acos();
+        if rng.gen::<bool>() {
+            self.mean + theta
+        } else {
+            self.mean - theta
+        }
+    }
+}
+
+impl ContinuousCDF<f64, f64> for VonMises {
+    /// Calculates the cumulative distribution function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Remarks
+    ///
+    /// Uses the series of Hill's algorithm for concentrations below 200 and
+    /// its normal approximation otherwise. The series is accurate to about
+    /// `1e-14` and the normal approximation to about `1e-11` in absolute
+    /// terms, but both lose relative accuracy in the far tails.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ e^(κ cos(t - μ)) / (2π I_0(κ)) dt
+    /// ```
+    ///
+    /// integrated over `t` from `μ - π` to `x`, where `μ` is the mean and `κ`
+    /// is the concentration
+    fn cdf(&self, x: f64) -> f64 {
+        if x <= self.mean - PI {
+            0.0
+        } else if x >= self.mean + PI {
+            1.0
+        } else {
+            self.std_cdf(self.centered(x))
+        }
+    }
+
+    /// Calculates the survival function for the von Mises distribution at
+    /// `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ e^(κ cos(t - μ)) / (2π I_0(κ)) dt
+    /// ```
+    ///
+    /// integrated over `t` from `x` to `μ + π`, where `μ` is the mean and `κ`
+    /// is the concentration
+    fn sf(&self, x: f64) -> f64 {
+        if x <= self.mean - PI {
+            1.0
+        } else if x >= self.mean + PI {
+            0.0
+        } else {
+            self.std_cdf(-self.centered(x))
+        }
+    }
+
+    /// Calculates the inverse cumulative distribution function for the von
+    /// Mises distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The cdf is inverted numerically by bisection
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.min();
+        }
+        if x >= 0.5 {
+            return self.inverse_sf(1.0 - x);
+        }
+        super::internal::bisect_support(|t| self.cdf(t) < x, self.min(), self.mean)
+    }
+
+    /// Calculates the inverse survival function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Remarks
+    ///
+    /// The survival function is inverted numerically by bisection
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        }
+        if x == 0.0 {
+            return self.max();
+        }
+        if x > 0.5 {
+            return self.inverse_cdf(1.0 - x);
+        }
+        super::internal::bisect_support(|t| self.sf(t) > x, self.mean, self.max())
+    }
+}
+
+impl Min<f64> for VonMises {
+    /// Returns the minimum value in the domain of the von Mises
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ - π
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn min(&self) -> f64 {
+        self.mean - PI
+    }
+}
+
+impl Max<f64> for VonMises {
+    /// Returns the maximum value in the domain of the von Mises
+    /// distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ + π
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn max(&self) -> f64 {
+        self.mean + PI
+    }
+}
+
+impl Distribution<f64> for VonMises {
+    /// Returns the mean of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn mean(&self) -> Option<f64> {
+        Some(self.mean)
+    }
+
+    /// Returns the variance of the angle about the mean of the von Mises
+    /// distribution on the interval `[μ - π, μ + π]`
+    ///
+    /// # Remarks
+    ///
+    /// The defining integral is evaluated numerically
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ∫ t^2 e^(κ cos(t)) / (2π I_0(κ)) dt
+    /// ```
+    ///
+    /// integrated over `t` from `-π` to `π`, where `κ` is the concentration
+    fn variance(&self) -> Option<f64> {
+        // the integrand is negligible beyond twelve standard deviations of
+        // the limiting normal distribution
+        let k = self.concentration;
+        let sd = k.sqrt().recip();
+        let hi = PI.min(12.0 * sd);
+        let integral = gauss_legendre(0.0, hi, sd.min(1.0), |t| {
+            let half = (0.5 * t).sin();
+            t * t * (-2.0 * k * half * half - self.ln_norm).exp()
+        });
+        Some(2.0 * integral)
+    }
+
+    /// Returns the entropy of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// ln(2π I_0(κ)) - κ I_1(κ) / I_0(κ)
+    /// ```
+    ///
+    /// where `κ` is the concentration and `I_0`, `I_1` are modified Bessel
+    /// functions of the first kind
+    fn entropy(&self) -> Option<f64> {
+        Some(self.ln_norm + self.concentration * self.circular_variance())
+    }
+
+    /// Returns the skewness of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 0
+    /// ```
+    fn skewness(&self) -> Option<f64> {
+        Some(0.0)
+    }
+}
+
+impl Median<f64> for VonMises {
+    /// Returns the median of the von Mises distribution
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn median(&self) -> f64 {
+        self.mean
+    }
+}
+
+impl Mode<Option<f64>> for VonMises {
+    /// Returns the mode of the von Mises distribution
+    ///
+    /// # Remarks
+    ///
+    /// For a concentration of zero the distribution is uniform and the
+    /// mean is returned as the middle of the support
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// μ
+    /// ```
+    ///
+    /// where `μ` is the mean
+    fn mode(&self) -> Option<f64> {
+        Some(self.mean)
+    }
+}
+
+impl Continuous<f64, f64> for VonMises {
+    /// Calculates the probability density function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^(κ cos(x - μ)) / (2π I_0(κ))
+    /// ```
+    ///
+    /// where `μ` is the mean, `κ` is the concentration and `I_0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn pdf(&self, x: f64) -> f64 {
+        self.ln_pdf(x).exp()
+    }
+
+    /// Calculates the log probability density function for the von Mises
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// κ cos(x - μ) - ln(2π I_0(κ))
+    /// ```
+    ///
+    /// where `μ` is the mean, `κ` is the concentration and `I_0` is the
+    /// modified Bessel function of the first kind of order zero
+    fn ln_pdf(&self, x: f64) -> f64 {
+        if x < self.mean - PI || x > self.mean + PI || x.is_nan() {
+            return f64::NEG_INFINITY;
+        }
+        // κ (cos(x - μ) - 1) is evaluated as -2κ sin^2((x - μ) / 2) to keep
+        // its relative accuracy near the mean
+        let half = (0.5 * self.centered(x)).sin();
+        -2.0 * self.concentration * half * half - self.ln_norm
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use crate::statistics::*;
+    use crate::distribution::{ContinuousCDF, Continuous, VonMises};
+    use crate::distribution::internal::*;
+    use rand::distributions::Distribution as RandDistribution;
+    use rand::SeedableRng;
+    use std::f64::consts::PI;
+
+    fn try_create(mean: f64, concentration: f64) -> VonMises {
+        let n = VonMises::new(mean, concentration);
+        assert!(n.is_ok());
+        n.unwrap()
+    }
+
+    fn bad_create_case(mean: f64, concentration: f64) {
+        let n = VonMises::new(mean, concentration);
+        assert!(n.is_err());
+    }
+
+    #[test]
+    fn test_create() {
+        try_create(0.0, 0.0);
+        try_create(1.0, 2.0);
+        try_create(-10.0, 1e10);
+        bad_create_case(0.0, -1.0);
+        bad_create_case(f64::NAN, 1.0);
+        bad_create_case(0.0, f64::NAN);
+        bad_create_case(f64::INFINITY, 1.0);
+        bad_create_case(0.0, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_moments() {
+        let n = try_create(1.0, 2.0);
+        assert_eq!(n.mean().unwrap(), 1.0);
+        assert_almost_eq!(n.variance().unwrap(), 0.76446187981112690303, 1e-15);
+        assert_almost_eq!(n.entropy().unwrap(), 1.2663212919642858025, 1e-15);
+        assert_eq!(n.skewness().unwrap(), 0.0);
+        assert_eq!(n.median(), 1.0);
+        assert_eq!(n.mode().unwrap(), 1.0);
+        assert_eq!(n.min(), 1.0 - PI);
+        assert_eq!(n.max(), 1.0 + PI);
+        assert_almost_eq!(n.mean_resultant_length(), 0.69777465796400798201, 1e-15);
+        assert_almost_eq!(n.circular_variance(), 0.30222534203599201799, 1e-15);
+
+        let n = try_create(0.0, 0.5);
+        assert_almost_eq!(n.variance().unwrap(), 2.3488033436687469195, 1e-15);
+        assert_almost_eq!(n.entropy().unwrap(), 1.7781769793044258148, 1e-15);
+        let n = try_create(-2.0, 30.0);
+        assert_almost_eq!(n.variance().unwrap(), 0.03391011461486363806, 1e-16);
+        assert_almost_eq!(n.entropy().unwrap(), -0.27310816137880144714, 1e-13);
+        let n = try_create(0.0, 80.0);
+        assert_almost_eq!(n.variance().unwrap(), 0.012579204890245843095, 1e-16);
+        assert_almost_eq!(n.entropy().unwrap(), -0.7689199658344822767, 1e-13);
+        assert_almost_eq!(n.mean_resultant_length(), 0.99373021971286783654, 1e-15);
+    }
+
+    #[test]
+    fn test_uniform_limit() {
+        let n = try_create(0.0, 0.0);
+        assert_almost_eq!(n.variance().unwrap(), PI * PI / 3.0, 1e-14);
+        assert_almost_eq!(n.entropy().unwrap(), (2.0 * PI).ln(), 1e-15);
+        assert_eq!(n.mean_resultant_length(), 0.0);
+        assert_almost_eq!(n.pdf(2.0), 0.5 / PI, 1e-16);
+        assert_almost_eq!(n.cdf(-1.0), 0.5 - 0.5 / PI, 1e-15);
+        assert_almost_eq!(n.inverse_cdf(0.75), 0.5 * PI, 1e-14);
+
+        let n = try_create(0.5, 1e-3);
+        assert_almost_eq!(n.variance().unwrap(), 3.2878682589371733914, 1e-14);
+        assert_almost_eq!(n.entropy().unwrap(), 1.8378768164093923586, 1e-15);
+    }
+
+    #[test]
+    fn test_pdf() {
+        let n = try_create(1.0, 2.0);
+        assert_almost_eq!(n.pdf(-2.0), 0.0096397934099426609418, 1e-17);
+        assert_almost_eq!(n.pdf(0.0), 0.20571449951559534279, 1e-16);
+        assert_almost_eq!(n.pdf(0.8), 0.49572325965507581363, 1e-15);
+        assert_almost_eq!(n.pdf(3.0), 0.030374122063858550982, 1e-16);
+        assert_almost_eq!(n.ln_pdf(1.1), -0.67186227733625023541, 1e-15);
+        assert_eq!(n.pdf(1.0 - PI - 1e-10), 0.0);
+        assert_eq!(n.pdf(5.0), 0.0);
+        assert_eq!(n.ln_pdf(5.0), f64::NEG_INFINITY);
+
+        let n = try_create(-2.0, 30.0);
+        assert_almost_eq!(n.pdf(-1.9), 1.8730094311360370092, 1e-14);
+        assert_almost_eq!(n.ln_pdf(-5.0), -58.922353397594645052, 1e-13);
+        let n = try_create(0.0, 80.0);
+        assert_almost_eq!(n.ln_pdf(-3.0), -157.92889733923058123, 1e-12);
+        assert_almost_eq!(n.pdf(0.1), 2.388906216159390057, 1e-14);
+    }
+
+    #[test]
+    fn test_cdf() {
+        let n = try_create(1.0, 2.0);
+        assert_almost_eq!(n.cdf(-2.0), 0.0013468622889293203445, 1e-14);
+        assert_almost_eq!(n.cdf(0.0), 0.11042226304496346545, 1e-14);
+        assert_almost_eq!(n.cdf(0.8), 0.3981795881553845606, 1e-14);
+        assert_almost_eq!(n.cdf(1.1), 0.55141717940641451495, 1e-14);
+        assert_almost_eq!(n.sf(3.0), 1.0 - 0.9826902063693222049, 1e-14);
+        assert_eq!(n.cdf(1.0 - PI), 0.0);
+        assert_eq!(n.cdf(1.0 + PI), 1.0);
+        assert_eq!(n.sf(-10.0), 1.0);
+
+        let n = try_create(0.0, 0.5);
+        assert_almost_eq!(n.cdf(-3.0), 0.012873844040111326283, 1e-14);
+        assert_almost_eq!(n.cdf(-0.2), 0.45081600211939081734, 1e-14);
+        assert_almost_eq!(n.sf(2.0), 1.0 - 0.88482311008689181329, 1e-14);
+
+        let n = try_create(-2.0, 30.0);
+        assert_almost_eq!(n.cdf(-3.0), 8.6357615799431409904e-8, 1e-14);
+        assert_almost_eq!(n.cdf(-2.2), 0.13808334207034235958, 1e-13);
+        assert_almost_eq!(n.sf(-1.9), 1.0 - 0.70718365458558031923, 1e-13);
+
+        let n = try_create(0.0, 80.0);
+        assert_almost_eq!(n.cdf(-0.2), 0.037288725506775873943, 1e-14);
+        assert_almost_eq!(n.cdf(0.1), 0.81397717078671629635, 1e-14);
+        assert!(n.cdf(-1.0) < 1e-14);
+        assert!(n.sf(1.0) < 1e-14);
+
+        // normal approximation of Hill's algorithm
+        let n = try_create(0.0, 500.0);
+        assert_almost_eq!(n.cdf(-0.1), 0.012722585013892833942, 1e-10);
+        assert_almost_eq!(n.cdf(-0.03), 0.25122897005218966961, 1e-10);
+        assert_almost_eq!(n.sf(0.05), 1.0 - 0.8681391237022175817, 1e-10);
+        assert!(n.cdf(-0.5) < 1e-25);
+    }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let n = try_create(1.0, 2.0);
+        assert_almost_eq!(n.inverse_cdf(0.11042226304496346545), 0.0, 1e-13);
+        assert_almost_eq!(n.inverse_sf(1.0 - 0.9826902063693222049), 3.0, 1e-12);
+        assert_almost_eq!(n.inverse_cdf(0.5), 1.0, 1e-15);
+        assert_eq!(n.inverse_cdf(0.0), 1.0 - PI);
+        assert_eq!(n.inverse_cdf(1.0), 1.0 + PI);
+        let n = try_create(0.0, 80.0);
+        assert_almost_eq!(n.inverse_cdf(0.037288725506775873943), -0.2, 1e-13);
+    }
+
+    #[test]
+    fn test_continuous() {
+        test::check_continuous_distribution(&try_create(1.0, 2.0), 1.0 - PI, 1.0 + PI);
+        test::check_continuous_distribution(&try_create(0.0, 80.0), -PI, PI);
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        for &(mean, concentration) in &[(1.0, 2.0), (0.0, 0.5), (-2.0, 30.0), (0.0, 1e-6), (0.0, 1e7)] {
+            let n = try_create(mean, concentration);
+            let samples: Vec<f64> = (0..20_000).map(|_| n.sample(&mut rng)).collect();
+            assert!(samples.iter().all(|&x| x >= n.min() && x <= n.max()));
+            let (c, s) = samples.iter().fold((0.0, 0.0), |(c, s), x| (c + (x - mean).cos(), s + (x - mean).sin()));
+            let len = samples.len() as f64;
+            assert!((c / len - n.mean_resultant_length()).abs() < 0.01);
+            assert!((s / len).abs() < 0.01);
+            let q = n.inverse_cdf(0.2);
+            let below = samples.iter().filter(|&&x| x < q).count() as f64;
+            assert!((below / len - 0.2).abs() < 0.01);
+        }
+    }
+}
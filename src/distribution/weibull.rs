@@ -1,8 +1,8 @@
-use crate::distribution::{Continuous, ContinuousCDF};
+use crate::distribution::{Continuous, ContinuousCDF, Fit};
 use crate::function::gamma;
 use crate::is_zero;
 use crate::statistics::*;
-use crate::{consts, Result, StatsError};
+use crate::{consts, prec, Result, StatsError};
 use rand::Rng;
 use std::f64;
 
@@ -115,6 +115,65 @@ impl ContinuousCDF<f64, f64> for Weibull {
             -(-x.powf(self.shape) * self.scale_pow_shape_inv).exp_m1()
         }
     }
+    /// Calculates the inverse cumulative distribution function for the
+    /// weibull distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (-ln(1 - x))^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn inverse_cdf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.scale * (-(-x).ln_1p()).powf(1.0 / self.shape)
+        }
+    }
+    /// Calculates the survival function for the weibull
+    /// distribution at `x`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// e^-((x/λ)^k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn sf(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            1.0
+        } else {
+            (-x.powf(self.shape) * self.scale_pow_shape_inv).exp()
+        }
+    }
+    /// Calculates the inverse survival function for the
+    /// weibull distribution at `x`
+    ///
+    /// # Panics
+    ///
+    /// If `x < 0.0` or `x > 1.0`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// λ * (-ln(x))^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale
+    fn inverse_sf(&self, x: f64) -> f64 {
+        if !(0.0..=1.0).contains(&x) {
+            panic!("x must be in [0, 1]");
+        } else {
+            self.scale * (-x.ln()).powf(1.0 / self.shape)
+        }
+    }
 }
 
 impl Min<f64> for Weibull {
@@ -303,11 +362,66 @@ impl Continuous<f64, f64> for Weibull {
     }
 }
 
+impl Fit<f64> for Weibull {
+    /// Fits a weibull distribution to `data` by maximum likelihood
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// Σ x_i^k * ln(x_i) / Σ x_i^k - 1 / k - (1 / n) * Σ ln(x_i) = 0
+    /// λ = ((1 / n) * Σ x_i^k)^(1 / k)
+    /// ```
+    ///
+    /// where `k` is the shape and `λ` is the scale. The equation for the
+    /// shape is solved with Newton's method
+    fn fit(data: &[f64]) -> Result<Weibull> {
+        if data.is_empty() {
+            return Err(StatsError::ArgGte("data.len()", 1.0));
+        }
+        if data.iter().any(|&x| x <= 0.0) {
+            return Err(StatsError::ArgMustBePositive("data"));
+        }
+        // work with `x / max(x)` so that `x^k` cannot overflow
+        let x_max = Statistics::max(data);
+        let ln_x: Vec<f64> = data.iter().map(|x| (x / x_max).ln()).collect();
+        let mean_ln = ln_x.iter().mean();
+        let std_dev_ln = ln_x.iter().population_std_dev();
+        if std_dev_ln <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        // `ln(x)` follows a gumbel distribution with standard deviation
+        // `π / (k * sqrt(6))`
+        let mut shape = f64::consts::PI / (6f64.sqrt() * std_dev_ln);
+        for _ in 0..100 {
+            let (mut s0, mut s1, mut s2) = (0.0, 0.0, 0.0);
+            for &y in &ln_x {
+                let w = (shape * y).exp();
+                s0 += w;
+                s1 += w * y;
+                s2 += w * y * y;
+            }
+            let g = s1 / s0 - 1.0 / shape - mean_ln;
+            let dg = (s2 * s0 - s1 * s1) / (s0 * s0) + 1.0 / (shape * shape);
+            let mut next = shape - g / dg;
+            if next <= 0.0 {
+                next = shape / 2.0;
+            }
+            if (next - shape).abs() <= prec::DEFAULT_F64_ACC * shape {
+                let scale =
+                    x_max * (ln_x.iter().map(|y| (next * y).exp()).mean()).powf(next.recip());
+                return Weibull::new(next, scale);
+            }
+            shape = next;
+        }
+        Err(StatsError::ComputationFailedToConverge)
+    }
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
     use crate::statistics::*;
-    use crate::distribution::{ContinuousCDF, Continuous, Weibull};
+    use crate::distribution::{ContinuousCDF, Continuous, Weibull, Fit};
     use crate::distribution::internal::*;
     use crate::consts::ACC;
 
@@ -487,4 +601,43 @@ mod tests {
     fn test_continuous() {
         test::check_continuous_distribution(&try_create(1.0, 0.2), 0.0, 10.0);
     }
+
+    #[test]
+    fn test_inverse_cdf() {
+        let inverse_cdf = |arg: f64| move |x: Weibull| x.inverse_cdf(arg);
+        test_case(1.0, 1.0, 0.0, inverse_cdf(0.0));
+        test_almost(1.0, 1.0, 2f64.ln(), 1e-15, inverse_cdf(0.5));
+        test_almost(2.0, 3.0, 3.0 * 2f64.ln().sqrt(), 1e-14, inverse_cdf(0.5));
+        test_almost(0.5, 2.0, 2.0 * 1e-24, 1e-35, inverse_cdf(1e-12));
+        test_case(1.0, 1.0, f64::INFINITY, inverse_cdf(1.0));
+    }
+
+    #[test]
+    fn test_sf() {
+        let sf = |arg: f64| move |x: Weibull| x.sf(arg);
+        test_case(1.0, 1.0, 1.0, sf(0.0));
+        test_almost(1.0, 1.0, 1.9287498479639177830e-22, 1e-36, sf(50.0));
+        test_almost(2.0, 3.0, (-4f64).exp(), 1e-16, sf(6.0));
+        test_case(1.0, 1.0, 0.0, sf(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_inverse_sf() {
+        let inverse_sf = |arg: f64| move |x: Weibull| x.inverse_sf(arg);
+        test_almost(1.0, 1.0, 20.0 * 10f64.ln(), 1e-13, inverse_sf(1e-20));
+        test_almost(2.0, 3.0, 6.0, 1e-14, inverse_sf((-4f64).exp()));
+        test_case(1.0, 1.0, f64::INFINITY, inverse_sf(0.0));
+    }
+
+    #[test]
+    fn test_fit() {
+        let n = Weibull::fit(&[0.5, 1.2, 2.3, 0.8, 3.1, 1.7, 0.9, 2.6]).unwrap();
+        assert_almost_eq!(n.shape(), 1.97700712902102064615334530005, 1e-12);
+        assert_almost_eq!(n.scale(), 1.85584044857509236212026561672, 1e-12);
+        let n = Weibull::fit(&[0.5e200, 1.2e200, 2.3e200, 0.8e200, 3.1e200, 1.7e200, 0.9e200, 2.6e200]).unwrap();
+        assert_almost_eq!(n.shape(), 1.97700712902102064615334530005, 1e-12);
+        assert!(Weibull::fit(&[]).is_err());
+        assert!(Weibull::fit(&[1.0, 0.0]).is_err());
+        assert!(Weibull::fit(&[1.0, 1.0]).is_err());
+    }
 }
@@ -0,0 +1,239 @@
+//! Provides the modified Bessel functions of the first kind of orders zero
+//! and one, which appear in the density and the moments of the von Mises
+//! distribution
+
+use std::f64;
+use std::f64::consts::PI;
+
+/// The argument above which the functions are evaluated through their
+/// asymptotic expansion rather than their power series
+const ASYMPTOTIC_MIN: f64 = 20.0;
+
+/// Computes the modified Bessel function of the first kind of order zero
+///
+/// # Formula
+///
+/// ```ignore
+/// I_0(x) = Σ (x^2 / 4)^k / (k!)^2
+/// ```
+///
+/// summed over `k >= 0`. Arguments above 20 are evaluated through the
+/// asymptotic expansion of `exp(-|x|) I_0(x)` instead.
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is `NaN`. Overflows to infinity for `|x|` above
+/// roughly `713.98`, see [`i0e`] for an exponentially scaled variant.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel::i0;
+///
+/// assert_eq!(i0(0.0), 1.0);
+/// assert!((i0(1.0) - 1.2660658777520083356).abs() < 1e-15);
+/// ```
+pub fn i0(x: f64) -> f64 {
+    let x = x.abs();
+    if x <= ASYMPTOTIC_MIN || x.is_nan() {
+        series(x, 0.0)
+    } else {
+        scale_up(asymptotic(x, 0.0), x)
+    }
+}
+
+/// Computes the exponentially scaled modified Bessel function of the first
+/// kind of order zero, `exp(-|x|) I_0(x)`, which remains representable for
+/// all `x`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel::i0e;
+///
+/// assert!((i0e(1000.0) - 0.012617240455891256).abs() < 1e-16);
+/// ```
+pub fn i0e(x: f64) -> f64 {
+    let x = x.abs();
+    if x <= ASYMPTOTIC_MIN || x.is_nan() {
+        series(x, 0.0) * (-x).exp()
+    } else {
+        asymptotic(x, 0.0)
+    }
+}
+
+/// Computes the modified Bessel function of the first kind of order one
+///
+/// # Formula
+///
+/// ```ignore
+/// I_1(x) = Σ (x / 2)^(2k + 1) / (k! (k + 1)!)
+/// ```
+///
+/// summed over `k >= 0`. Arguments above 20 in magnitude are evaluated
+/// through the asymptotic expansion of `exp(-|x|) I_1(x)` instead.
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is `NaN`. Overflows to infinity for `|x|` above
+/// roughly `713.99`, see [`i1e`] for an exponentially scaled variant.
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel::i1;
+///
+/// assert_eq!(i1(0.0), 0.0);
+/// assert!((i1(-1.0) + 0.56515910399248502721).abs() < 1e-15);
+/// ```
+pub fn i1(x: f64) -> f64 {
+    let abs = x.abs();
+    let value = if abs <= ASYMPTOTIC_MIN || x.is_nan() {
+        series(abs, 1.0)
+    } else {
+        scale_up(asymptotic(abs, 1.0), abs)
+    };
+    value.copysign(x)
+}
+
+/// Computes the exponentially scaled modified Bessel function of the first
+/// kind of order one, `exp(-|x|) I_1(x)`, which remains representable for
+/// all `x`
+///
+/// # Remarks
+///
+/// Returns `NaN` if `x` is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::bessel::{i0e, i1e};
+///
+/// // the ratio I_1(x) / I_0(x) approaches one for large arguments
+/// assert!((i1e(1e6) / i0e(1e6) - 0.99999949999987499994).abs() < 1e-15);
+/// ```
+pub fn i1e(x: f64) -> f64 {
+    let abs = x.abs();
+    let value = if abs <= ASYMPTOTIC_MIN || x.is_nan() {
+        series(abs, 1.0) * (-abs).exp()
+    } else {
+        asymptotic(abs, 1.0)
+    };
+    value.copysign(x)
+}
+
+/// Sums the power series of `I_ν(x)` for `ν` zero or one and `x >= 0`.
+/// All terms are positive, so the sum is accurate to a few ulps.
+fn series(x: f64, nu: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    let q = 0.25 * x * x;
+    let mut term = if nu == 0.0 { 1.0 } else { 0.5 * x };
+    let mut sum = term;
+    let mut k = 1.0;
+    loop {
+        term *= q / (k * (k + nu));
+        sum += term;
+        if term <= sum * f64::EPSILON {
+            return sum;
+        }
+        k += 1.0;
+    }
+}
+
+/// Sums the asymptotic expansion of `exp(-x) I_ν(x)` for `x >= 20`, whose
+/// terms keep decreasing well beyond double precision at such arguments
+fn asymptotic(x: f64, nu: f64) -> f64 {
+    if x.is_infinite() {
+        return 0.0;
+    }
+    let mu = 4.0 * nu * nu;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    let mut k = 1.0;
+    loop {
+        let odd = 2.0 * k - 1.0;
+        term *= (odd * odd - mu) / (8.0 * k * x);
+        sum += term;
+        if term.abs() <= sum * f64::EPSILON {
+            return sum / (2.0 * PI * x).sqrt();
+        }
+        k += 1.0;
+    }
+}
+
+/// Multiplies the scaled value `scaled` by `exp(x)` in two halves, so that
+/// the result only overflows if it is not representable
+fn scale_up(scaled: f64, x: f64) -> f64 {
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+    let half = (0.5 * x).exp();
+    scaled * half * half
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64;
+
+    #[test]
+    fn test_i0() {
+        assert_eq!(i0(0.0), 1.0);
+        assert_almost_eq!(i0(0.5), 1.063483370741323519263, 1e-15);
+        assert_almost_eq!(i0(-2.5), 3.289839144050123035706, 1e-15);
+        assert_almost_eq!(i0(10.0), 2815.71662846625447147, 1e-11);
+        assert_almost_eq!(i0(19.9) / 39513376.52006682376167, 1.0, 1e-15);
+        assert_almost_eq!(i0(20.1) / 48017874.10713650302313, 1.0, 1e-15);
+        assert_almost_eq!(i0(100.0) / 1.07375170713107382352e42, 1.0, 1e-14);
+        assert_almost_eq!(i0(700.0) / 1.529593347671873736316e302, 1.0, 1e-13);
+        assert_eq!(i0(800.0), f64::INFINITY);
+        assert_eq!(i0(f64::NEG_INFINITY), f64::INFINITY);
+        assert!(i0(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_i0e() {
+        assert_eq!(i0e(0.0), 1.0);
+        assert_almost_eq!(i0e(1.0), 0.4657596075936404365019, 1e-16);
+        assert_almost_eq!(i0e(5.0), 0.1835408126093283530737, 1e-16);
+        assert_almost_eq!(i0e(-19.9), 0.09000858886438959729407, 1e-16);
+        assert_almost_eq!(i0e(20.1), 0.08955376362061344403534, 1e-16);
+        assert_almost_eq!(i0e(30.0), 0.07314594648223729392892, 1e-16);
+        assert_almost_eq!(i0e(700.0), 0.01508129565153135758699, 1e-17);
+        assert_eq!(i0e(f64::INFINITY), 0.0);
+        assert!(i0e(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_i1() {
+        assert_eq!(i1(0.0), 0.0);
+        assert_almost_eq!(i1(0.5), 0.2578943053908963163625, 1e-16);
+        assert_almost_eq!(i1(-2.5), -2.516716245288698441528, 1e-15);
+        assert_almost_eq!(i1(10.0), 2670.988303701254654341, 1e-11);
+        assert_almost_eq!(i1(19.9) / 38507423.8748622828384, 1.0, 1e-15);
+        assert_almost_eq!(i1(20.1) / 46807739.53302988276305, 1.0, 1e-15);
+        assert_almost_eq!(i1(-100.0) / -1.068369390338162481206e42, 1.0, 1e-14);
+        assert_eq!(i1(f64::INFINITY), f64::INFINITY);
+        assert_eq!(i1(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(i1(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_i1e() {
+        assert_eq!(i1e(0.0), 0.0);
+        assert_almost_eq!(i1e(1.0), 0.2079104153497084488694, 1e-16);
+        assert_almost_eq!(i1e(-5.0), -0.1639722669445423569261, 1e-16);
+        assert_almost_eq!(i1e(19.9), 0.0877171021317061010821, 1e-16);
+        assert_almost_eq!(i1e(20.1), 0.0872968518432015919858, 1e-16);
+        assert_almost_eq!(i1e(30.0), 0.07191633059864755470613, 1e-16);
+        assert_almost_eq!(i1e(700.0), 0.01507051944471684694926, 1e-17);
+        assert!(i1e(f64::NAN).is_nan());
+    }
+}
@@ -118,7 +118,7 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
     } else if !(0.0..=1.0).contains(&x) {
         Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
     } else {
-        let bt = if is_zero(x) || ulps_eq!(x, 1.0) {
+        let bt = if x == 0.0 || ulps_eq!(x, 1.0) {
             0.0
         } else {
             (gamma::ln_gamma(a + b) - gamma::ln_gamma(a) - gamma::ln_gamma(b)
@@ -126,84 +126,225 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
                 + b * (1.0 - x).ln())
             .exp()
         };
-        let symm_transform = x >= (a + 1.0) / (a + b + 2.0);
-        let eps = prec::F64_PREC;
-        let fpmin = f64::MIN_POSITIVE / eps;
-
-        let mut a = a;
-        let mut b = b;
-        let mut x = x;
-        if symm_transform {
-            let swap = a;
-            x = 1.0 - x;
-            a = b;
-            b = swap;
+        if x >= (a + 1.0) / (a + b + 2.0) {
+            Ok(1.0 - bt * beta_reg_cf(b, a, 1.0 - x) / b)
+        } else {
+            Ok(bt * beta_reg_cf(a, b, x) / a)
+        }
+    }
+}
+
+/// Computes the natural logarithm of the regularized lower incomplete beta
+/// function `ln(I_x(a,b))` for `a > 0`, `b > 0`, `1 >= x >= 0`.
+///
+/// Unlike `beta_reg(a, b, x).ln()` this stays finite when `I_x(a,b)`
+/// underflows, since the prefactor `x^a * (1-x)^b / Beta(a,b)` is kept in
+/// log space.
+///
+/// # Panics
+///
+/// if `a <= 0.0`, `b <= 0.0`, `x < 0.0`, or `x > 1.0`
+pub fn ln_beta_reg(a: f64, b: f64, x: f64) -> f64 {
+    checked_ln_beta_reg(a, b, x).unwrap()
+}
+
+/// Computes the natural logarithm of the regularized lower incomplete beta
+/// function `ln(I_x(a,b))` for `a > 0`, `b > 0`, `1 >= x >= 0`.
+///
+/// # Errors
+///
+/// if `a <= 0.0`, `b <= 0.0`, `x < 0.0`, or `x > 1.0`
+pub fn checked_ln_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
+    if a <= 0.0 {
+        Err(StatsError::ArgMustBePositive("a"))
+    } else if b <= 0.0 {
+        Err(StatsError::ArgMustBePositive("b"))
+    } else if !(0.0..=1.0).contains(&x) {
+        Err(StatsError::ArgIntervalIncl("x", 0.0, 1.0))
+    } else if x == 0.0 {
+        Ok(f64::NEG_INFINITY)
+    } else if ulps_eq!(x, 1.0) {
+        Ok(0.0)
+    } else {
+        let ln_bt = gamma::ln_gamma(a + b) - gamma::ln_gamma(a) - gamma::ln_gamma(b)
+            + a * x.ln()
+            + b * (-x).ln_1p();
+        if x >= (a + 1.0) / (a + b + 2.0) {
+            Ok((-ln_bt.exp() * beta_reg_cf(b, a, 1.0 - x) / b).ln_1p())
+        } else {
+            Ok(ln_bt + (beta_reg_cf(a, b, x) / a).ln())
         }
+    }
+}
+
+/// Evaluates the continued fraction for `I_x(a,b)` without the prefactor
+/// `x^a * (1-x)^b / (a * Beta(a,b))`, converging quickly for
+/// `x < (a + 1) / (a + b + 2)`
+fn beta_reg_cf(a: f64, b: f64, x: f64) -> f64 {
+    let eps = prec::F64_PREC;
+    let fpmin = f64::MIN_POSITIVE / eps;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
 
-        let qab = a + b;
-        let qap = a + 1.0;
-        let qam = a - 1.0;
-        let mut c = 1.0;
-        let mut d = 1.0 - qab * x / qap;
+    if d.abs() < fpmin {
+        d = fpmin;
+    }
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..141 {
+        let m = f64::from(m);
+        let m2 = m * 2.0;
+        let mut aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
 
         if d.abs() < fpmin {
             d = fpmin;
         }
+
+        c = 1.0 + aa / c;
+        if c.abs() < fpmin {
+            c = fpmin;
+        }
+
         d = 1.0 / d;
-        let mut h = d;
+        h = h * d * c;
+        aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
 
-        for m in 1..141 {
-            let m = f64::from(m);
-            let m2 = m * 2.0;
-            let mut aa = m * (b - m) * x / ((qam + m2) * (a + m2));
-            d = 1.0 + aa * d;
+        if d.abs() < fpmin {
+            d = fpmin;
+        }
 
-            if d.abs() < fpmin {
-                d = fpmin;
-            }
+        c = 1.0 + aa / c;
 
-            c = 1.0 + aa / c;
-            if c.abs() < fpmin {
-                c = fpmin;
-            }
+        if c.abs() < fpmin {
+            c = fpmin;
+        }
 
-            d = 1.0 / d;
-            h = h * d * c;
-            aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
-            d = 1.0 + aa * d;
+        d = 1.0 / d;
+        let del = d * c;
+        h *= del;
 
-            if d.abs() < fpmin {
-                d = fpmin;
-            }
+        if (del - 1.0).abs() <= eps {
+            break;
+        }
+    }
+    h
+}
 
-            c = 1.0 + aa / c;
+/// Computes the inverse of the regularized incomplete beta function,
+/// i.e. the `x` such that `I_x(a,b) = y` for `a > 0`, `b > 0` and
+/// `0 <= y <= 1`.
+///
+/// The starting value follows Algorithm AS 64 / AS 109 and is refined with
+/// Newton's method on `ln(I_x(a,b))` as a function of `ln(x)`. The root is
+/// solved for directly when it lies below the mean `a / (a + b)` and as
+/// `1 - x` otherwise, so that the result keeps full relative precision near
+/// whichever end of `[0, 1]` it approaches.
+pub fn inv_beta_reg(a: f64, b: f64, y: f64) -> f64 {
+    debug_assert!((0.0..=1.0).contains(&y) && a > 0.0 && b > 0.0);
+
+    if y == 0.0 {
+        return 0.0;
+    }
+    if y == 1.0 {
+        return 1.0;
+    }
+    if above_mean(a, b, y) {
+        1.0 - ln_inv_beta_reg_lower(b, a, 1.0 - y).exp()
+    } else {
+        ln_inv_beta_reg_lower(a, b, y).exp()
+    }
+}
 
-            if c.abs() < fpmin {
-                c = fpmin;
-            }
+/// Computes the natural logarithm of `inv_beta_reg(a, b, y)`, which stays
+/// finite when the inverse underflows in the lower tail
+pub(crate) fn ln_inv_beta_reg(a: f64, b: f64, y: f64) -> f64 {
+    debug_assert!((0.0..=1.0).contains(&y) && a > 0.0 && b > 0.0);
 
-            d = 1.0 / d;
-            let del = d * c;
-            h *= del;
+    if y == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if y == 1.0 {
+        return 0.0;
+    }
+    if above_mean(a, b, y) {
+        (-ln_inv_beta_reg_lower(b, a, 1.0 - y).exp()).ln_1p()
+    } else {
+        ln_inv_beta_reg_lower(a, b, y)
+    }
+}
 
-            if (del - 1.0).abs() <= eps {
-                return if symm_transform {
-                    Ok(1.0 - bt * h / a)
-                } else {
-                    Ok(bt * h / a)
-                };
-            }
-        }
+/// Returns whether the root of `I_x(a,b) = y` lies above the mean
+/// `a / (a + b)`. Comparing `y` with `1 / 2` instead would reflect roots
+/// that sit close to zero when most of the mass is piled up there.
+pub(crate) fn above_mean(a: f64, b: f64, y: f64) -> bool {
+    y > beta_reg(a, b, a / (a + b))
+}
 
-        if symm_transform {
-            Ok(1.0 - bt * h / a)
+// Solves `ln(I_x(a,b)) = ln(y)` for `t = ln(x)` where the root lies below
+// the mean `a / (a + b)`.
+// `ln(I)` is close to linear in `ln(x)` in the lower tail, so Newton's
+// method converges quickly there, and the iterates are safeguarded by a
+// bracket on the root.
+fn ln_inv_beta_reg_lower(a: f64, b: f64, y: f64) -> f64 {
+    let ln_beta = ln_beta(a, b);
+    let ln_y = y.ln();
+
+    let mut t = inv_beta_reg_guess(a, b, y, ln_beta).ln();
+    // leading term of the lower tail, I_x(a,b) ~ x^a / (a * Beta(a,b))
+    let t_tail = (ln_y + a.ln() + ln_beta) / a;
+    if t_tail < t {
+        t = t_tail;
+    }
+
+    let switch = (a + 1.0) / (a + b + 2.0);
+    let mut lo = f64::NEG_INFINITY;
+    let mut hi = 0.0;
+    for _ in 0..200 {
+        let x = t.exp();
+        // ln(I) and ln(x * dI/dx) with the `x^a` factor kept as `a * t` so
+        // that neither underflows when `x` does
+        let ln_head = a * t + b * (-x).ln_1p() - ln_beta;
+        let ln_i = if x < switch {
+            ln_head + (beta_reg_cf(a, b, x) / a).ln()
         } else {
-            Ok(bt * h / a)
+            ln_beta_reg(a, b, x)
+        };
+        let g = ln_i - ln_y;
+        if g == 0.0 {
+            break;
+        }
+        if g > 0.0 {
+            hi = t;
+        } else {
+            lo = t;
+        }
+
+        let dg = (ln_head - (-x).ln_1p() - ln_i).exp();
+        let mut next = t - g / dg;
+        if !(next > lo && next < hi) {
+            next = if lo.is_finite() {
+                0.5 * (lo + hi)
+            } else {
+                2.0 * t - 1.0
+            };
+        }
+        let step = (next - t).abs();
+        t = next;
+        if step <= 2.0 * prec::F64_PREC * t.abs().max(1.0) {
+            break;
         }
     }
+    t
 }
 
-/// Computes the inverse of the regularized incomplete beta function
+// Starting value for `inv_beta_reg`
 //
 // This code is based on the implementation in the ["special"][1] crate,
 // which in turn is based on a [C implementation][2] by John Burkardt. The
@@ -233,7 +374,7 @@ pub fn checked_beta_reg(a: f64, b: f64, x: f64) -> Result<f64> {
 // > COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER
 // > IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN
 // > CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
-pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
+fn inv_beta_reg_guess(a: f64, b: f64, x: f64, ln_beta: f64) -> f64 {
     // Algorithm AS 64
     // http://www.jstor.org/stable/2346798
     //
@@ -257,47 +398,14 @@ pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
     // Again if (4p + 2q - 2) / χ²(α) does not exceed 1, x₀ is obtained from
     //
     // x₀ = (αp B(p, q))^(1 / p).
-    //
-    // The final solution is obtained by the Newton–Raphson method from the
-    // relation
-    //
-    //                    f(x[i - 1])
-    // x[i] = x[i - 1] - ------------
-    //                   f'(x[i - 1])
-    //
-    // where
-    //
-    // f(x) = I(x, p, q) - α.
-    let ln_beta = ln_beta(a, b);
-
-    // Remark AS R83
-    // http://www.jstor.org/stable/2347779
-    const SAE: i32 = -30;
-    const FPU: f64 = 1e-30; // 10^SAE
-
-    debug_assert!((0.0..=1.0).contains(&x) && a > 0.0 && b > 0.0);
-
-    if x == 0.0 {
-        return 0.0;
-    }
-    if x == 1.0 {
-        return 1.0;
+    let mut p = (-2.0 * x.min(1.0 - x).ln()).sqrt();
+    let mut q = p - (2.30753 + 0.27061 * p) / (1.0 + (0.99229 + 0.04481 * p) * p);
+    if x > 0.5 {
+        // Hastings' approximation holds for α <= 1 / 2, beyond which the
+        // upper α point is the negated point at 1 - α
+        q = -q;
     }
 
-    let mut p;
-    let mut q;
-
-    let flip = 0.5 < x;
-    if flip {
-        p = a;
-        a = b;
-        b = p;
-        x = 1.0 - x;
-    }
-
-    p = (-(x * x).ln()).sqrt();
-    q = p - (2.30753 + 0.27061 * p) / (1.0 + (0.99229 + 0.04481 * p) * p);
-
     if 1.0 < a && 1.0 < b {
         // Remark AS R19 and Algorithm AS 109
         // http://www.jstor.org/stable/2346887
@@ -327,76 +435,7 @@ pub fn inv_beta_reg(mut a: f64, mut b: f64, mut x: f64) -> f64 {
         }
     }
 
-    if p < 0.0001 {
-        p = 0.0001;
-    } else if 0.9999 < p {
-        p = 0.9999;
-    }
-
-    // Remark AS R83
-    // http://www.jstor.org/stable/2347779
-    let e = (-5.0 / a / a - 1.0 / x.powf(0.2) - 13.0) as i32;
-    let acu = if e > SAE { f64::powi(10.0, e) } else { FPU };
-
-    let mut pnext;
-    let mut qprev = 0.0;
-    let mut sq = 1.0;
-    let mut prev = 1.0;
-
-    'outer: loop {
-        // Remark AS R19 and Algorithm AS 109
-        // http://www.jstor.org/stable/2346887
-        q = beta_reg(a, b, p);
-        q = (q - x) * (ln_beta + (1.0 - a) * p.ln() + (1.0 - b) * (1.0 - p).ln()).exp();
-
-        // Remark AS R83
-        // http://www.jstor.org/stable/2347779
-        if q * qprev <= 0.0 {
-            prev = if sq > FPU { sq } else { FPU };
-        }
-
-        // Remark AS R19 and Algorithm AS 109
-        // http://www.jstor.org/stable/2346887
-        let mut g = 1.0;
-        loop {
-            loop {
-                let adj = g * q;
-                sq = adj * adj;
-
-                if sq < prev {
-                    pnext = p - adj;
-                    if 0.0 <= pnext && pnext <= 1.0 {
-                        break;
-                    }
-                }
-                g /= 3.0;
-            }
-
-            if prev <= acu || q * q <= acu {
-                p = pnext;
-                break 'outer;
-            }
-
-            if pnext != 0.0 && pnext != 1.0 {
-                break;
-            }
-
-            g /= 3.0;
-        }
-
-        if pnext == p {
-            break;
-        }
-
-        p = pnext;
-        qprev = q;
-    }
-
-    if flip {
-        1.0 - p
-    } else {
-        p
-    }
+    p.clamp(0.0001, 0.9999)
 }
 
 #[rustfmt::skip]
@@ -603,4 +642,41 @@ mod tests {
     fn test_checked_beta_reg_x_gt_1() {
         assert!(super::checked_beta_reg(1.0, 1.0, 2.0).is_err());
     }
+
+    #[test]
+    fn test_ln_beta_reg() {
+        assert_almost_eq!(super::ln_beta_reg(0.5, 0.5, 0.5), -std::f64::consts::LN_2, 1e-15);
+        assert_almost_eq!(super::ln_beta_reg(2.5, 0.5, 0.5), -2.582473370501515916, 1e-14);
+        assert_almost_eq!(super::ln_beta_reg(1.0, 1.0, 0.25), -1.3862943611198906188, 1e-15);
+        assert_almost_eq!(super::ln_beta_reg(2.0, 3.0, 0.9), -0.003706861931326472822, 1e-15);
+        assert_almost_eq!(super::ln_beta_reg(50.0, 50.0, 0.1), -54.088884708204335123, 1e-11);
+        assert_almost_eq!(super::ln_beta_reg(5.0, 2.5, 1e-100), -1148.8303568735190741, 1e-11);
+        assert_eq!(super::ln_beta_reg(1.0, 1.0, 0.0), f64::NEG_INFINITY);
+        assert_eq!(super::ln_beta_reg(1.0, 1.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_inv_beta_reg() {
+        assert_almost_eq!(super::inv_beta_reg(0.5, 0.5, 0.25), 0.1464466094067262378, 1e-15);
+        assert_almost_eq!(super::inv_beta_reg(2.0, 5.0, 0.95), 0.58180340925202583141, 1e-15);
+        assert_almost_eq!(super::inv_beta_reg(2.0, 30.0, 1e-100), 4.6373889576016827257e-52, 1e-64);
+        assert_almost_eq!(super::inv_beta_reg(30.0, 2.0, 1.0 - 1e-10), 0.99999953625902537361, 1e-13);
+        assert_almost_eq!(super::ln_inv_beta_reg(0.5, 0.5, 1e-300), -1380.6478903858485007, 1e-10);
+        assert_almost_eq!(super::inv_beta_reg(0.01, 5.0, 0.7), 4.0560026574505144103e-17, 1e-28);
+        assert_almost_eq!(super::ln_inv_beta_reg(0.01, 5.0, 0.7), -37.743748659420140189, 1e-11);
+        assert_eq!(super::inv_beta_reg(5.0, 0.01, 0.3), 1.0);
+        assert_eq!(super::inv_beta_reg(2.0, 5.0, 0.0), 0.0);
+        assert_eq!(super::inv_beta_reg(2.0, 5.0, 1.0), 1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_beta_reg_x_gt_1() {
+        super::ln_beta_reg(1.0, 1.0, 2.0);
+    }
+
+    #[test]
+    fn test_checked_ln_beta_reg_a_lte_0() {
+        assert!(super::checked_ln_beta_reg(0.0, 1.0, 1.0).is_err());
+    }
 }
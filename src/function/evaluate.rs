@@ -23,6 +23,124 @@ pub fn polynomial(z: f64, coeff: &[f64]) -> f64 {
     sum
 }
 
+/// Nodes of the 16-point Gauss-Legendre rule on `[-1, 1]`, only the
+/// positive half of the symmetric rule
+const GL_NODES: [f64; 8] = [
+    0.0950125098376374401853193,
+    0.2816035507792589132304605,
+    0.4580167776572273863424194,
+    0.6178762444026437484466718,
+    0.7554044083550030338951012,
+    0.8656312023878317438804679,
+    0.9445750230732325760779884,
+    0.9894009349916499325961542,
+];
+
+/// Weights of the 16-point Gauss-Legendre rule matching `GL_NODES`
+const GL_WEIGHTS: [f64; 8] = [
+    0.1894506104550684962853967,
+    0.1826034150449235888667637,
+    0.1691565193950025381893121,
+    0.1495959888165767320815017,
+    0.1246289712555338720524763,
+    0.0951585116824927848099251,
+    0.0622535239386478928628438,
+    0.0271524594117540948517806,
+];
+
+/// Integrates `f` over `[lo, hi]` with the 16-point Gauss-Legendre rule
+/// on panels of width at most `width`
+pub(crate) fn gauss_legendre<F: Fn(f64) -> f64>(lo: f64, hi: f64, width: f64, f: F) -> f64 {
+    let panels = ((hi - lo) / width).ceil().max(1.0);
+    let h = (hi - lo) / panels;
+    (0..panels as usize)
+        .map(|i| gauss_legendre_panel(lo + i as f64 * h, lo + (i + 1) as f64 * h, &f))
+        .sum()
+}
+
+/// Returns the nodes and weights used by `gauss_legendre` over `[lo, hi]`
+/// with panels of width at most `width`, so that factors of the integrand
+/// that do not change between integrals can be tabulated once
+pub(crate) fn gauss_legendre_rule(lo: f64, hi: f64, width: f64) -> Vec<(f64, f64)> {
+    let panels = ((hi - lo) / width).ceil().max(1.0);
+    let h = (hi - lo) / panels;
+    let mut rule = Vec::with_capacity(16 * panels as usize);
+    for i in 0..panels as usize {
+        let center = lo + (i as f64 + 0.5) * h;
+        for (x, w) in GL_NODES.iter().zip(GL_WEIGHTS.iter()) {
+            rule.push((center - 0.5 * h * x, 0.5 * h * w));
+            rule.push((center + 0.5 * h * x, 0.5 * h * w));
+        }
+    }
+    rule
+}
+
+/// Integrates `f` over `[lo, hi]` with the 16-point Gauss-Legendre rule,
+/// starting from panels of width at most `width` and bisecting each panel
+/// until the estimates over the panel and its two halves agree to the
+/// relative tolerance `tol`, or to `tol` times its share of the initial
+/// estimate of the whole integral, so that panels contributing nothing
+/// are not refined. Intended for non-negative integrands, where the
+/// relative tolerance carries over to the whole integral.
+pub(crate) fn adaptive_gauss_legendre<F: Fn(f64) -> f64>(
+    lo: f64,
+    hi: f64,
+    width: f64,
+    tol: f64,
+    f: F,
+) -> f64 {
+    let panels = ((hi - lo) / width).ceil().max(1.0);
+    let h = (hi - lo) / panels;
+    let estimates: Vec<(f64, f64, f64)> = (0..panels as usize)
+        .map(|i| {
+            let (a, b) = (lo + i as f64 * h, lo + (i + 1) as f64 * h);
+            (a, b, gauss_legendre_panel(a, b, &f))
+        })
+        .collect();
+    let abs_tol = tol * estimates.iter().map(|e| e.2.abs()).sum::<f64>() / panels;
+    estimates
+        .into_iter()
+        .map(|(a, b, whole)| refine_panel(a, b, whole, tol, abs_tol, 0, &f))
+        .sum()
+}
+
+/// The maximum number of bisections of a single panel in
+/// `adaptive_gauss_legendre`
+const GL_MAX_DEPTH: u32 = 24;
+
+fn refine_panel<F: Fn(f64) -> f64>(
+    lo: f64,
+    hi: f64,
+    whole: f64,
+    tol: f64,
+    abs_tol: f64,
+    depth: u32,
+    f: &F,
+) -> f64 {
+    let mid = 0.5 * (lo + hi);
+    let left = gauss_legendre_panel(lo, mid, f);
+    let right = gauss_legendre_panel(mid, hi, f);
+    let sum = left + right;
+    if depth >= GL_MAX_DEPTH || (sum - whole).abs() <= (tol * sum.abs()).max(abs_tol) {
+        sum
+    } else {
+        let abs_tol = 0.5 * abs_tol;
+        refine_panel(lo, mid, left, tol, abs_tol, depth + 1, f)
+            + refine_panel(mid, hi, right, tol, abs_tol, depth + 1, f)
+    }
+}
+
+fn gauss_legendre_panel<F: Fn(f64) -> f64>(lo: f64, hi: f64, f: &F) -> f64 {
+    let center = 0.5 * (lo + hi);
+    let h = hi - lo;
+    let sum: f64 = GL_NODES
+        .iter()
+        .zip(GL_WEIGHTS.iter())
+        .map(|(x, w)| w * (f(center - 0.5 * h * x) + f(center + 0.5 * h * x)))
+        .sum();
+    0.5 * h * sum
+}
+
 #[rustfmt::skip]
 #[cfg(test)]
 mod tests {
@@ -60,4 +178,29 @@ mod tests {
         assert!(super::polynomial(2.0, &coeff).is_nan());
         assert!(super::polynomial(-2.0, &coeff).is_nan());
     }
+
+    #[test]
+    fn test_gauss_legendre() {
+        assert_almost_eq!(super::gauss_legendre(0.0, f64::consts::PI, 1.0, f64::sin), 2.0, 1e-15);
+        assert_almost_eq!(super::gauss_legendre(-1.0, 1.0, 2.0, |x| x.powi(31)), 0.0, 1e-15);
+        assert_almost_eq!(super::gauss_legendre(0.0, 1.0, 0.1, |x| x.sqrt()), 2.0 / 3.0, 1e-5);
+        assert_almost_eq!(super::gauss_legendre(0.0, 10.0, 0.5, |x| (-x).exp()), -(-10f64).exp_m1(), 1e-15);
+    }
+
+    #[test]
+    fn test_gauss_legendre_rule() {
+        let rule = super::gauss_legendre_rule(0.0, f64::consts::PI, 1.0);
+        assert_eq!(rule.len(), 64);
+        assert_almost_eq!(rule.iter().map(|&(x, w)| w * x.sin()).sum::<f64>(), 2.0, 1e-15);
+    }
+
+    #[test]
+    fn test_adaptive_gauss_legendre() {
+        assert_almost_eq!(super::adaptive_gauss_legendre(0.0, f64::consts::PI, 4.0, 1e-14, f64::sin), 2.0, 1e-15);
+        assert_almost_eq!(super::adaptive_gauss_legendre(0.0, 1.0, 1.0, 1e-14, |x| x.sqrt()), 2.0 / 3.0, 1e-14);
+        // a narrow peak that needs several bisections to resolve
+        let peak = |x: f64| (-0.5 * ((x - 0.3) / 1e-3).powi(2)).exp();
+        let expected = 1e-3 * (2.0 * f64::consts::PI).sqrt();
+        assert_almost_eq!(super::adaptive_gauss_legendre(0.0, 1.0, 0.25, 1e-12, peak), expected, 1e-15);
+    }
 }
@@ -174,59 +174,16 @@ pub fn checked_gamma_ur(a: f64, x: f64) -> Result<f64> {
         return Err(StatsError::ArgIntervalExcl("x", 0.0, f64::INFINITY));
     }
 
-    let eps = 0.000000000000001;
-    let big = 4503599627370496.0;
-    let big_inv = 2.22044604925031308085e-16;
-
     if x < 1.0 || x <= a {
         return Ok(1.0 - gamma_lr(a, x));
     }
 
-    let mut ax = a * x.ln() - x - ln_gamma(a);
+    let ax = a * x.ln() - x - ln_gamma(a);
     if ax < -709.78271289338399 {
         return if a < x { Ok(0.0) } else { Ok(1.0) };
     }
 
-    ax = ax.exp();
-    let mut y = 1.0 - a;
-    let mut z = x + y + 1.0;
-    let mut c = 0.0;
-    let mut pkm2 = 1.0;
-    let mut qkm2 = x;
-    let mut pkm1 = x + 1.0;
-    let mut qkm1 = z * x;
-    let mut ans = pkm1 / qkm1;
-    loop {
-        y += 1.0;
-        z += 2.0;
-        c += 1.0;
-        let yc = y * c;
-        let pk = pkm1 * z - pkm2 * yc;
-        let qk = qkm1 * z - qkm2 * yc;
-
-        pkm2 = pkm1;
-        pkm1 = pk;
-        qkm2 = qkm1;
-        qkm1 = qk;
-
-        if pk.abs() > big {
-            pkm2 *= big_inv;
-            pkm1 *= big_inv;
-            qkm2 *= big_inv;
-            qkm1 *= big_inv;
-        }
-
-        if !is_zero(qk) {
-            let r = pk / qk;
-            let t = ((ans - r) / r).abs();
-            ans = r;
-
-            if t <= eps {
-                break;
-            }
-        }
-    }
-    Ok(ans * ax)
+    Ok(ax.exp() * gamma_ur_cf(a, x))
 }
 
 /// Computes the lower incomplete regularized gamma function
@@ -268,16 +225,9 @@ pub fn checked_gamma_lr(a: f64, x: f64) -> Result<f64> {
         return Err(StatsError::ArgIntervalExcl("x", 0.0, f64::INFINITY));
     }
 
-    let eps = 0.000000000000001;
-    let big = 4503599627370496.0;
-    let big_inv = 2.22044604925031308085e-16;
-
     if prec::almost_eq(a, 0.0, prec::DEFAULT_F64_ACC) {
         return Ok(1.0);
     }
-    if prec::almost_eq(x, 0.0, prec::DEFAULT_F64_ACC) {
-        return Ok(0.0);
-    }
 
     let ax = a * x.ln() - x - ln_gamma(a);
     if ax < -709.78271289338399 {
@@ -287,63 +237,280 @@ pub fn checked_gamma_lr(a: f64, x: f64) -> Result<f64> {
         return Ok(0.0);
     }
     if x <= 1.0 || x <= a {
-        let mut r2 = a;
-        let mut c2 = 1.0;
-        let mut ans2 = 1.0;
-        loop {
-            r2 += 1.0;
-            c2 *= x / r2;
-            ans2 += c2;
-
-            if c2 / ans2 <= eps {
-                break;
-            }
+        return Ok(ax.exp() * gamma_lr_series(a, x));
+    }
+    Ok(1.0 - ax.exp() * gamma_ur_cf(a, x))
+}
+
+/// Computes the natural logarithm of the lower incomplete regularized gamma
+/// function `ln(P(a,x))` for `a > 0`, `x > 0`.
+///
+/// Unlike `gamma_lr(a, x).ln()` this stays finite when `P(a,x)` underflows,
+/// since the prefactor `x^a * exp(-x) / Gamma(a)` is kept in log space.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Panics
+///
+/// if `a` or `x` are not in `(0, +inf)`
+pub fn ln_gamma_lr(a: f64, x: f64) -> f64 {
+    checked_ln_gamma_lr(a, x).unwrap()
+}
+
+/// Computes the natural logarithm of the lower incomplete regularized gamma
+/// function `ln(P(a,x))` for `a > 0`, `x > 0`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Errors
+///
+/// if `a` or `x` are not in `(0, +inf)`
+pub fn checked_ln_gamma_lr(a: f64, x: f64) -> Result<f64> {
+    if a.is_nan() || x.is_nan() {
+        return Ok(f64::NAN);
+    }
+    if a <= 0.0 || a == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("a", 0.0, f64::INFINITY));
+    }
+    if x <= 0.0 || x == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("x", 0.0, f64::INFINITY));
+    }
+
+    let ax = a * x.ln() - x - ln_gamma(a);
+    if x <= 1.0 || x <= a {
+        Ok(ax + gamma_lr_series(a, x).ln())
+    } else {
+        Ok((-ax.exp() * gamma_ur_cf(a, x)).ln_1p())
+    }
+}
+
+/// Computes the natural logarithm of the upper incomplete regularized gamma
+/// function `ln(Q(a,x))` for `a > 0`, `x > 0`.
+///
+/// Unlike `gamma_ur(a, x).ln()` this stays finite when `Q(a,x)` underflows,
+/// since the prefactor `x^a * exp(-x) / Gamma(a)` is kept in log space.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Panics
+///
+/// if `a` or `x` are not in `(0, +inf)`
+pub fn ln_gamma_ur(a: f64, x: f64) -> f64 {
+    checked_ln_gamma_ur(a, x).unwrap()
+}
+
+/// Computes the natural logarithm of the upper incomplete regularized gamma
+/// function `ln(Q(a,x))` for `a > 0`, `x > 0`.
+///
+/// # Remarks
+///
+/// Returns `f64::NAN` if either argument is `f64::NAN`
+///
+/// # Errors
+///
+/// if `a` or `x` are not in `(0, +inf)`
+pub fn checked_ln_gamma_ur(a: f64, x: f64) -> Result<f64> {
+    if a.is_nan() || x.is_nan() {
+        return Ok(f64::NAN);
+    }
+    if a <= 0.0 || a == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("a", 0.0, f64::INFINITY));
+    }
+    if x <= 0.0 || x == f64::INFINITY {
+        return Err(StatsError::ArgIntervalExcl("x", 0.0, f64::INFINITY));
+    }
+
+    let ax = a * x.ln() - x - ln_gamma(a);
+    if x < 1.0 || x <= a {
+        Ok((-ax.exp() * gamma_lr_series(a, x)).ln_1p())
+    } else {
+        Ok(ax + gamma_ur_cf(a, x).ln())
+    }
+}
+
+/// Evaluates the power series for `P(a,x)` without the prefactor
+/// `x^a * exp(-x) / Gamma(a)`, converging quickly for `x <= max(1, a)`
+fn gamma_lr_series(a: f64, x: f64) -> f64 {
+    let eps = 0.000000000000001;
+
+    let mut r = a;
+    let mut c = 1.0;
+    let mut ans = 1.0;
+    loop {
+        r += 1.0;
+        c *= x / r;
+        ans += c;
+
+        if c / ans <= eps {
+            break;
         }
-        return Ok(ax.exp() * ans2 / a);
     }
+    ans / a
+}
+
+/// Evaluates the continued fraction for `Q(a,x)` without the prefactor
+/// `x^a * exp(-x) / Gamma(a)`, converging quickly for `x > max(1, a)`
+///
+/// The convergents are rescaled by the latest denominator at every step,
+/// since a single step multiplies them by about `x` and would overflow for
+/// `x` beyond `1e154` otherwise
+fn gamma_ur_cf(a: f64, x: f64) -> f64 {
+    let eps = 0.000000000000001;
 
     let mut y = 1.0 - a;
     let mut z = x + y + 1.0;
-    let mut c = 0;
-
-    let mut p3 = 1.0;
-    let mut q3 = x;
-    let mut p2 = x + 1.0;
-    let mut q2 = z * x;
-    let mut ans = p2 / q2;
-
+    let mut c = 0.0;
+    let mut pkm2 = 1.0 / x / z;
+    let mut qkm2 = 1.0 / z;
+    let mut pkm1 = (1.0 + 1.0 / x) / z;
+    let mut qkm1 = 1.0;
+    let mut ans = pkm1;
     loop {
         y += 1.0;
         z += 2.0;
-        c += 1;
-        let yc = y * f64::from(c);
-
-        let p = p2 * z - p3 * yc;
-        let q = q2 * z - q3 * yc;
-
-        p3 = p2;
-        p2 = p;
-        q3 = q2;
-        q2 = q;
-
-        if p.abs() > big {
-            p3 *= big_inv;
-            p2 *= big_inv;
-            q3 *= big_inv;
-            q2 *= big_inv;
+        c += 1.0;
+        let yc = y * c;
+        let pk = pkm1 * z - pkm2 * yc;
+        let qk = qkm1 * z - qkm2 * yc;
+
+        pkm2 = pkm1;
+        pkm1 = pk;
+        qkm2 = qkm1;
+        qkm1 = qk;
+
+        if !is_zero(qk) {
+            let scale = 1.0 / qk.abs();
+            pkm2 *= scale;
+            pkm1 *= scale;
+            qkm2 *= scale;
+            qkm1 *= scale;
+
+            let r = pk / qk;
+            let t = ((ans - r) / r).abs();
+            ans = r;
+
+            if t <= eps {
+                break;
+            }
         }
+    }
+    ans
+}
 
-        if q != 0.0 {
-            let nextans = p / q;
-            let error = ((ans - nextans) / nextans).abs();
-            ans = nextans;
+/// Computes the inverse of the lower incomplete regularized gamma function,
+/// i.e. the `x` such that `P(a,x) = p` for `a > 0` and `0 <= p <= 1`.
+/// The starting value is taken from "Numerical Recipes", 3rd Edition,
+/// section 6.2.1 and refined with Halley's method.
+///
+/// # Panics
+///
+/// if `a` is not in `(0, +inf)` or `p` is not in `[0, 1]`
+pub fn inv_gamma_lr(a: f64, p: f64) -> f64 {
+    assert!(a > 0.0 && a.is_finite(), "a must be in (0, +inf)");
+    assert!((0.0..=1.0).contains(&p), "p must be in [0, 1]");
+    inv_gamma_reg(a, p, 1.0 - p, false)
+}
 
-            if error <= eps {
+/// Computes the inverse of the upper incomplete regularized gamma function,
+/// i.e. the `x` such that `Q(a,x) = q` for `a > 0` and `0 <= q <= 1`.
+/// Solves directly against `Q` so that small values of `q` retain full
+/// precision.
+///
+/// # Panics
+///
+/// if `a` is not in `(0, +inf)` or `q` is not in `[0, 1]`
+pub fn inv_gamma_ur(a: f64, q: f64) -> f64 {
+    assert!(a > 0.0 && a.is_finite(), "a must be in (0, +inf)");
+    assert!((0.0..=1.0).contains(&q), "q must be in [0, 1]");
+    inv_gamma_reg(a, 1.0 - q, q, true)
+}
+
+// Solves `P(a,x) = p` (or equivalently `Q(a,x) = q`) where `p + q = 1`.
+// The residual is computed against whichever of the two tails is smaller
+// so that precision is not lost to cancellation. `upper` signals that `q`
+// is the exact input, in which case it is used to break ties at `p == q`.
+fn inv_gamma_reg(a: f64, p: f64, q: f64, upper: bool) -> f64 {
+    if p == 0.0 {
+        return 0.0;
+    }
+    if q == 0.0 {
+        return f64::INFINITY;
+    }
+
+    let a1 = a - 1.0;
+    let gln = ln_gamma(a);
+    let use_upper = q < p || (upper && q == p);
+
+    let mut x = if a > 1.0 {
+        let pp = if use_upper { q } else { p };
+        let t = (-2.0 * pp.ln()).sqrt();
+        let mut z = (2.30753 + t * 0.27061) / (1.0 + t * (0.99229 + t * 0.04481)) - t;
+        if !use_upper {
+            z = -z;
+        }
+        let guess = a * (1.0 - 1.0 / (9.0 * a) - z / (3.0 * a.sqrt())).powi(3);
+        if guess > 1e-3 {
+            guess
+        } else {
+            // P(a,x) <= x^a / Γ(a + 1) so this is a lower bound on the root
+            ((p.ln() + ln_gamma(a + 1.0)) / a).exp()
+        }
+    } else {
+        let t = 1.0 - a * (0.253 + a * 0.12);
+        if p < t {
+            (p / t).powf(1.0 / a)
+        } else {
+            1.0 - (q / (1.0 - t)).ln()
+        }
+    };
+
+    let ln_q = q.ln();
+    if use_upper && -ln_q > a {
+        // asymptotic upper tail Q(a,x) ~ x^(a-1) exp(-x) / Γ(a), which the
+        // guess above badly undershoots once q is far below machine epsilon
+        let l = -ln_q;
+        x = x.max(l + a1 * l.ln() - gln);
+    }
+
+    for _ in 0..100 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x.is_infinite() {
+            return x;
+        }
+        let ln_dens = a1 * x.ln() - x - gln;
+        // Halley's method on f, with `u = f / f'` and `r = f'' / f'`
+        let (u, r) = if use_upper {
+            // the residual is taken in log space, ln Q(a,x) - ln q, since
+            // the absolute residual is meaningless once q is tiny
+            let ln_qx = ln_gamma_ur(a, x);
+            let d = (ln_dens - ln_qx).exp();
+            if d == 0.0 || !d.is_finite() {
+                break;
+            }
+            ((ln_q - ln_qx) / d, a1 / x - 1.0 + d)
+        } else {
+            let dens = ln_dens.exp();
+            if dens == 0.0 {
                 break;
             }
+            ((gamma_lr(a, x) - p) / dens, a1 / x - 1.0)
+        };
+        let step = u / (1.0 - 0.5 * (u * r).min(1.0));
+        let next = x - step;
+        x = if next <= 0.0 { 0.5 * x } else { next };
+        if step.abs() <= 1e-15 * x {
+            break;
         }
     }
-    Ok(1.0 - ax.exp() * ans)
+    x
 }
 
 /// Computes the Digamma function which is defined as the derivative of
@@ -805,4 +972,92 @@ mod tests {
         assert_almost_eq!(super::inv_digamma(1.6110931485817511237336268416044190359814435699427405), 5.5, 1e-14);
         assert_almost_eq!(super::inv_digamma(2.2622143570941481235561593642219403924532310597356171), 10.1, 1e-13);
     }
+
+    #[test]
+    fn test_inv_gamma_lr() {
+        assert_eq!(super::inv_gamma_lr(1.5, 0.0), 0.0);
+        assert_eq!(super::inv_gamma_lr(1.5, 1.0), f64::INFINITY);
+        assert_almost_eq!(super::inv_gamma_lr(0.1, 0.01), 6.0730483624078993461e-21, 1e-34);
+        assert_almost_eq!(super::inv_gamma_lr(0.1, 0.5), 0.00059339110446022617358, 1e-16);
+        assert_almost_eq!(super::inv_gamma_lr(1.0, 0.5), consts::LN_2, 1e-15);
+        assert_almost_eq!(super::inv_gamma_lr(1.5, 0.001), 0.012148792907846366765, 1e-16);
+        assert_almost_eq!(super::inv_gamma_lr(2.5, 0.99), 7.5431362346944939805, 1e-13);
+        assert_almost_eq!(super::inv_gamma_lr(5.5, 0.3), 4.0739338887548175743, 1e-14);
+        assert_almost_eq!(super::inv_gamma_lr(100.0, 0.5), 99.666864919315488744, 1e-11);
+        assert_almost_eq!(super::inv_gamma_lr(100.0, 1e-10), 48.883092053795954401, 1e-11);
+        assert_almost_eq!(super::inv_gamma_lr(2.0, 1e-12), 1.4142142290401938224e-6, 1e-20);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_lr_p_upper_bound() {
+        super::inv_gamma_lr(1.0, 1.5);
+    }
+
+    #[test]
+    fn test_inv_gamma_ur() {
+        assert_eq!(super::inv_gamma_ur(1.5, 1.0), 0.0);
+        assert_eq!(super::inv_gamma_ur(1.5, 0.0), f64::INFINITY);
+        assert_almost_eq!(super::inv_gamma_ur(1.5, 1e-10), 24.771077963761833249, 1e-13);
+        assert_almost_eq!(super::inv_gamma_ur(0.1, 1e-12), 22.537026473243614025, 1e-13);
+        assert_almost_eq!(super::inv_gamma_ur(10.0, 1e-15), 58.525601560025190256, 1e-12);
+        assert_almost_eq!(super::inv_gamma_ur(2.5, 0.3), 3.0322149920774524819, 1e-14);
+        assert_almost_eq!(super::inv_gamma_ur(1.5, 1e-100), 233.10717878564567455, 1e-11);
+        assert_almost_eq!(super::inv_gamma_ur(1.5, 1e-200), 463.70850542834311766, 1e-10);
+        assert_almost_eq!(super::inv_gamma_ur(0.1, 1e-100), 223.13479066961921457, 1e-11);
+        assert_almost_eq!(super::inv_gamma_ur(10.0, 1e-200), 503.73157451671995803, 1e-10);
+        assert_almost_eq!(super::inv_gamma_ur(100.0, 1e-100), 483.21953022256192661, 1e-10);
+        assert_almost_eq!(super::inv_gamma_ur(0.5, 1e-300), 686.93631561119706857, 1e-10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inv_gamma_ur_a_lower_bound() {
+        super::inv_gamma_ur(0.0, 0.5);
+    }
+
+    #[test]
+    fn test_ln_gamma_lr() {
+        assert!(super::ln_gamma_lr(f64::NAN, f64::NAN).is_nan());
+        assert_almost_eq!(super::ln_gamma_lr(0.1, 1.0), -0.024423176211098930564, 1e-14);
+        assert_almost_eq!(super::ln_gamma_lr(1.5, 8.0), -0.0011346277364550014363, 1e-15);
+        assert_almost_eq!(super::ln_gamma_lr(5.5, 1.0), -6.4995484112118952348, 1e-13);
+        assert_almost_eq!(super::ln_gamma_lr(100.0, 90.0), -1.8437625574400939112, 1e-12);
+        assert_almost_eq!(super::ln_gamma_lr(10.0, 0.001), -84.182874450362305857, 1e-12);
+        assert_almost_eq!(super::ln_gamma_lr(3.0, 1e-300), -2074.1183431638691705, 1e-10);
+    }
+
+    #[test]
+    fn test_ln_gamma_ur() {
+        assert!(super::ln_gamma_ur(f64::NAN, f64::NAN).is_nan());
+        assert_almost_eq!(super::ln_gamma_ur(0.1, 1.0), -3.7244094871979375193, 1e-13);
+        assert_almost_eq!(super::ln_gamma_ur(1.5, 8.0), -6.7820179275773716732, 1e-13);
+        assert_almost_eq!(super::ln_gamma_ur(5.5, 1.0), -0.0015052506040605279365, 1e-15);
+        assert_almost_eq!(super::ln_gamma_ur(100.0, 110.0), -1.8433980649510160817, 1e-12);
+        assert_almost_eq!(super::ln_gamma_ur(3.0, 1000.0), -986.87563662392700614, 1e-11);
+        assert_almost_eq!(super::ln_gamma_ur(10.0, 2000.0), -1944.3891974568222936, 1e-11);
+    }
+
+    #[test]
+    fn test_ln_gamma_huge_x() {
+        // the continued fraction used to overflow to NaN and never converge
+        assert_almost_eq!(super::ln_gamma_ur(1.5, 1e155), -1e155, 1e140);
+        assert_almost_eq!(super::ln_gamma_ur(1.5, 1e300), -1e300, 1e285);
+        assert_almost_eq!(super::ln_gamma_ur(10.0, 1e200), -1e200, 1e185);
+        assert_almost_eq!(super::ln_gamma_ur(1e155, 2e155), 1e155 * (2f64.ln() - 1.0), 1e143);
+        assert_eq!(super::ln_gamma_lr(1.5, 1e155), 0.0);
+        assert_eq!(super::ln_gamma_lr(1e155, 2e155), 0.0);
+        assert_eq!(super::gamma_ur(1.5, 1e300), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_ln_gamma_lr_x_lte_0() {
+        super::ln_gamma_lr(1.0, 0.0);
+    }
+
+    #[test]
+    fn test_checked_ln_gamma_ur_a_lte_0() {
+        assert!(super::checked_ln_gamma_ur(0.0, 1.0).is_err());
+    }
 }
@@ -0,0 +1,215 @@
+//! Provides the limiting distribution function of the Kolmogorov-Smirnov
+//! statistic and the exact distribution of its one-sided variant
+
+use crate::consts;
+use crate::function::factorial;
+use std::f64::consts::PI;
+
+/// Computes the cumulative distribution function of the Kolmogorov
+/// distribution at `x`, i.e. the limiting distribution of `√n D_n` where
+/// `D_n` is the two-sided one-sample Kolmogorov-Smirnov statistic
+///
+/// # Formula
+///
+/// ```ignore
+/// K(x) = √(2π) / x * Σ exp(-(2k - 1)^2 π^2 / (8x^2))     // x < 1
+/// K(x) = 1 - 2 Σ (-1)^(k - 1) exp(-2k^2 x^2)            // x >= 1
+/// ```
+///
+/// for `k = 1, 2, ...`
+pub fn kolmogorov_cdf(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::NAN
+    } else if x <= 0.0 {
+        0.0
+    } else if x < 1.0 {
+        small_cdf(x)
+    } else {
+        1.0 - large_sf(x)
+    }
+}
+
+/// Computes the survival function `1 - K(x)` of the Kolmogorov
+/// distribution at `x`, which is the asymptotic p-value of the two-sided
+/// Kolmogorov-Smirnov test for `x = √n D_n`
+pub fn kolmogorov_sf(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::NAN
+    } else if x <= 0.0 {
+        1.0
+    } else if x < 1.0 {
+        1.0 - small_cdf(x)
+    } else {
+        large_sf(x)
+    }
+}
+
+/// Computes the probability density function of the Kolmogorov
+/// distribution at `x`
+pub fn kolmogorov_pdf(x: f64) -> f64 {
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x < 1.0 {
+        let w = PI * PI / (8.0 * x * x);
+        let mut sum = 0.0;
+        for k in 1..100 {
+            let j = (2 * k - 1) as f64;
+            let term = (2.0 * j * j * w - 1.0) / x * (-j * j * w).exp();
+            sum += term;
+            if term.abs() <= f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+        consts::SQRT_2PI / x * sum
+    } else {
+        let mut sum = 0.0;
+        for k in 1..100 {
+            let k2 = (k * k) as f64;
+            let term = k2 * (-2.0 * k2 * x * x).exp();
+            sum += if k % 2 == 1 { term } else { -term };
+            if term <= f64::EPSILON * sum.abs() {
+                break;
+            }
+        }
+        8.0 * x * sum
+    }
+}
+
+/// Computes the probability `P(D_n^+ >= d)` that the one-sided one-sample
+/// Kolmogorov-Smirnov statistic `D_n^+ = sup (F_n(x) - F(x))` for a sample
+/// of size `n` is at least `d`. By symmetry the same holds for `D_n^-`.
+///
+/// # Formula
+///
+/// ```ignore
+/// P(D_n^+ >= d) = d Σ C(n, j) (1 - d - j / n)^(n - j) (d + j / n)^(j - 1)
+/// ```
+///
+/// for `j = 0, ..., ⌊n(1 - d)⌋` (Birnbaum and Tingey, 1951)
+///
+/// # Panics
+///
+/// If `n` is zero
+pub fn smirnov_sf(n: u64, d: f64) -> f64 {
+    assert!(n > 0, "n must be positive");
+    if d.is_nan() {
+        return f64::NAN;
+    }
+    if d <= 0.0 {
+        return 1.0;
+    }
+    if d >= 1.0 {
+        return 0.0;
+    }
+    let nf = n as f64;
+    let jmax = ((1.0 - d) * nf).floor() as u64;
+    let sum: f64 = (0..=jmax.min(n))
+        .map(|j| {
+            let jf = j as f64;
+            let a = 1.0 - d - jf / nf;
+            if a <= 0.0 {
+                return 0.0;
+            }
+            (factorial::ln_binomial(n, j) + (nf - jf) * a.ln() + (jf - 1.0) * (d + jf / nf).ln())
+                .exp()
+        })
+        .sum();
+    (d * sum).min(1.0)
+}
+
+/// Sums the theta-function series of the Kolmogorov cdf, which converges
+/// quickly for small `x`
+fn small_cdf(x: f64) -> f64 {
+    let w = PI * PI / (8.0 * x * x);
+    let mut sum = 0.0;
+    for k in 1..100 {
+        let j = (2 * k - 1) as f64;
+        let term = (-j * j * w).exp();
+        sum += term;
+        if term <= f64::EPSILON * sum {
+            break;
+        }
+    }
+    consts::SQRT_2PI / x * sum
+}
+
+/// Sums the alternating series of the Kolmogorov survival function, which
+/// converges quickly for large `x`
+fn large_sf(x: f64) -> f64 {
+    let mut sum = 0.0;
+    for k in 1..100 {
+        let k2 = (k * k) as f64;
+        let term = (-2.0 * k2 * x * x).exp();
+        sum += if k % 2 == 1 { term } else { -term };
+        if term <= f64::EPSILON * sum.abs() {
+            break;
+        }
+    }
+    (2.0 * sum).min(1.0)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_kolmogorov_cdf() {
+        assert_eq!(super::kolmogorov_cdf(0.0), 0.0);
+        assert_eq!(super::kolmogorov_cdf(-1.0), 0.0);
+        assert_almost_eq!(super::kolmogorov_cdf(0.3), 9.3058013345666228456e-6, 1e-19);
+        assert_almost_eq!(super::kolmogorov_cdf(0.5), 0.036054756335124905614, 1e-16);
+        assert_almost_eq!(super::kolmogorov_cdf(0.8), 0.45585758842580192326, 1e-15);
+        assert_almost_eq!(super::kolmogorov_cdf(1.0), 0.7300003283226454788, 1e-15);
+        assert_almost_eq!(super::kolmogorov_cdf(1.36), 0.95051412324462211636, 1e-15);
+        assert_almost_eq!(super::kolmogorov_cdf(2.0), 0.99932907474422030465, 1e-15);
+        assert_eq!(super::kolmogorov_cdf(f64::INFINITY), 1.0);
+        assert!(super::kolmogorov_cdf(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_kolmogorov_sf() {
+        assert_eq!(super::kolmogorov_sf(0.0), 1.0);
+        assert_almost_eq!(super::kolmogorov_sf(0.3), 0.99999069419866543338, 1e-15);
+        assert_almost_eq!(super::kolmogorov_sf(0.8), 0.54414241157419807674, 1e-15);
+        assert_almost_eq!(super::kolmogorov_sf(1.0), 0.2699996716773545212, 1e-15);
+        assert_almost_eq!(super::kolmogorov_sf(1.36), 0.04948587675537788364, 1e-16);
+        assert_almost_eq!(super::kolmogorov_sf(2.0), 0.00067092525577969534654, 1e-18);
+        assert_almost_eq!(super::kolmogorov_sf(4.0), 2.5328331098188351446e-14, 1e-28);
+        assert_eq!(super::kolmogorov_sf(f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_kolmogorov_pdf() {
+        assert_eq!(super::kolmogorov_pdf(0.0), 0.0);
+        assert_almost_eq!(super::kolmogorov_pdf(0.3), 0.00081939341969312957099, 1e-17);
+        assert_almost_eq!(super::kolmogorov_pdf(0.5), 0.63958285094045663465, 1e-14);
+        assert_almost_eq!(super::kolmogorov_pdf(0.8), 1.6270243456365922413, 1e-14);
+        assert_almost_eq!(super::kolmogorov_pdf(1.0), 1.0719485583569417625, 1e-14);
+        assert_almost_eq!(super::kolmogorov_pdf(2.0), 0.005367402045629682826, 1e-16);
+        assert_almost_eq!(super::kolmogorov_pdf(4.0), 4.0525329757101362314e-13, 1e-26);
+    }
+
+    #[test]
+    fn test_smirnov_sf() {
+        assert_eq!(super::smirnov_sf(10, 0.0), 1.0);
+        assert_eq!(super::smirnov_sf(10, 1.0), 0.0);
+        assert_almost_eq!(super::smirnov_sf(10, 0.274), 0.1866050696914837459, 1e-14);
+        assert_almost_eq!(super::smirnov_sf(10, 0.6), 0.0002840836000000001866, 1e-17);
+        assert_almost_eq!(super::smirnov_sf(100, 0.3), 8.8599349463314592548e-9, 1e-21);
+        assert_almost_eq!(super::smirnov_sf(1000, 0.01), 0.81332377547636777636, 1e-12);
+        assert_almost_eq!(super::smirnov_sf(5, 0.05), 0.93922468749999999598, 1e-14);
+        // P(D_1^+ >= d) = 1 - d
+        assert_almost_eq!(super::smirnov_sf(1, 0.25), 0.75, 1e-15);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_smirnov_sf_n_zero() {
+        super::smirnov_sf(0, 0.5);
+    }
+}
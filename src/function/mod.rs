@@ -1,6 +1,7 @@
 //! Provides a host of special statistical functions (e.g. the beta function or
 //! the error function)
 
+pub mod bessel;
 pub mod beta;
 pub mod erf;
 pub mod evaluate;
@@ -8,4 +9,7 @@ pub mod exponential;
 pub mod factorial;
 pub mod gamma;
 pub mod harmonic;
+pub mod kolmogorov;
 pub mod logistic;
+pub mod noncentral;
+pub mod owens_t;
@@ -0,0 +1,678 @@
+//! Provides the distribution functions of the noncentral chi-squared,
+//! beta, F and Student's t distributions as Poisson mixtures of the
+//! regularized incomplete gamma and beta functions
+
+use crate::consts::LN_SQRT_2PI;
+use crate::function::beta::{beta_reg, ln_beta};
+use crate::function::erf::erfc;
+use crate::function::gamma::{gamma_lr, gamma_ur, ln_gamma};
+use std::f64::consts::{FRAC_1_SQRT_2, LN_2};
+
+/// The largest number of terms summed in either direction from the mode
+const MAX_TERMS: usize = 100_000;
+
+/// Sums the series `Σ term(j)` over `j = 0, 1, ...`, where `term(j)`
+/// returns a bound `w_j` on the magnitude of the `j`-th term together with
+/// the term itself. The bounds must be unimodal with their mode near
+/// `mode`. The summation proceeds outwards from the mode in both
+/// directions until the bounds become negligible relative to the sum.
+pub(crate) fn mixture_sum<F: FnMut(f64) -> (f64, f64)>(mode: f64, mut term: F) -> f64 {
+    let mut sum = 0.0;
+    let mut j = mode;
+    for _ in 0..MAX_TERMS {
+        let (weight, value) = term(j);
+        sum += value;
+        if weight <= 1e-17 * sum.abs() {
+            break;
+        }
+        j += 1.0;
+    }
+    let mut j = mode - 1.0;
+    while j >= 0.0 && mode - j <= MAX_TERMS as f64 {
+        let (weight, value) = term(j);
+        sum += value;
+        if weight <= 1e-17 * sum.abs() {
+            break;
+        }
+        j -= 1.0;
+    }
+    sum
+}
+
+/// Returns the Poisson probability `e^(-mu) mu^j / j!` for `mu > 0`
+pub(crate) fn poisson_weight(mu: f64, j: f64) -> f64 {
+    ln_poisson_weight(mu, j).exp()
+}
+
+fn ln_poisson_weight(mu: f64, j: f64) -> f64 {
+    -mu + j * mu.ln() - ln_gamma(j + 1.0)
+}
+
+/// Returns `j ln(y)`, taking `0 ln(0)` to be zero
+fn j_ln(j: f64, ln_y: f64) -> f64 {
+    if j == 0.0 {
+        0.0
+    } else {
+        j * ln_y
+    }
+}
+
+/// Computes the cdf of the noncentral chi-squared distribution with
+/// `freedom` degrees of freedom and noncentrality `lambda` at `x`
+///
+/// # Formula
+///
+/// ```ignore
+/// F(x) = Σ e^(-λ/2) (λ/2)^j / j! * P(k/2 + j, x/2)
+/// ```
+///
+/// where `k` is `freedom`, `λ` is `lambda` and `P` is the lower
+/// regularized incomplete gamma function
+///
+/// # Panics
+///
+/// If `freedom` is not positive or `lambda` is negative
+pub fn noncentral_chi_squared_cdf(freedom: f64, lambda: f64, x: f64) -> f64 {
+    check_chi_squared(freedom, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    // `x / 2` underflows to zero for the smallest subnormal `x`
+    if x / 2.0 <= 0.0 {
+        return 0.0;
+    }
+    if x == f64::INFINITY {
+        return 1.0;
+    }
+    if lambda == 0.0 {
+        return gamma_lr(freedom / 2.0, x / 2.0);
+    }
+    let mu = lambda / 2.0;
+    mixture_sum(mu.floor(), |j| {
+        let w = poisson_weight(mu, j);
+        (w, w * gamma_lr(freedom / 2.0 + j, x / 2.0))
+    })
+}
+
+/// Computes the survival function of the noncentral chi-squared
+/// distribution with `freedom` degrees of freedom and noncentrality
+/// `lambda` at `x`, using the upper regularized incomplete gamma function
+/// in the series of `noncentral_chi_squared_cdf`
+///
+/// # Panics
+///
+/// If `freedom` is not positive or `lambda` is negative
+pub fn noncentral_chi_squared_sf(freedom: f64, lambda: f64, x: f64) -> f64 {
+    check_chi_squared(freedom, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x / 2.0 <= 0.0 {
+        return 1.0;
+    }
+    if x == f64::INFINITY {
+        return 0.0;
+    }
+    if lambda == 0.0 {
+        return gamma_ur(freedom / 2.0, x / 2.0);
+    }
+    let mu = lambda / 2.0;
+    mixture_sum(mu.floor(), |j| {
+        let w = poisson_weight(mu, j);
+        (w, w * gamma_ur(freedom / 2.0 + j, x / 2.0))
+    })
+}
+
+/// Computes the density of the noncentral chi-squared distribution with
+/// `freedom` degrees of freedom and noncentrality `lambda` at `x`
+///
+/// # Formula
+///
+/// ```ignore
+/// f(x) = Σ e^(-λ/2) (λ/2)^j / j! * f_(k + 2j)(x)
+/// ```
+///
+/// where `k` is `freedom`, `λ` is `lambda` and `f_n` is the density of the
+/// chi-squared distribution with `n` degrees of freedom
+///
+/// # Panics
+///
+/// If `freedom` is not positive or `lambda` is negative
+pub fn noncentral_chi_squared_pdf(freedom: f64, lambda: f64, x: f64) -> f64 {
+    check_chi_squared(freedom, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x < 0.0 || x == f64::INFINITY {
+        return 0.0;
+    }
+    let mu = lambda / 2.0;
+    if x / 2.0 == 0.0 {
+        // only the leading term of the mixture is nonzero at the origin
+        return if freedom < 2.0 {
+            f64::INFINITY
+        } else if freedom == 2.0 {
+            0.5 * (-mu).exp()
+        } else {
+            0.0
+        };
+    }
+    let ln_half_x = (x / 2.0).ln();
+    let ln_density = |j: f64| {
+        (freedom / 2.0 + j - 1.0) * ln_half_x - x / 2.0 - ln_gamma(freedom / 2.0 + j) - LN_2
+    };
+    if lambda == 0.0 {
+        return ln_density(0.0).exp();
+    }
+    mixture_sum(mu.floor(), |j| {
+        let term = (ln_poisson_weight(mu, j) + ln_density(j)).exp();
+        (term, term)
+    })
+}
+
+fn check_chi_squared(freedom: f64, lambda: f64) {
+    assert!(freedom > 0.0, "freedom must be positive");
+    assert!(lambda >= 0.0, "lambda must be non-negative");
+}
+
+/// Computes `I_x(a, b; λ)` or its complement given both `x` and `y = 1 - x`
+/// to retain precision in either tail
+fn beta_mixture(a: f64, b: f64, lambda: f64, x: f64, y: f64, upper: bool) -> f64 {
+    if x <= 0.0 {
+        return if upper { 1.0 } else { 0.0 };
+    }
+    if y <= 0.0 {
+        return if upper { 0.0 } else { 1.0 };
+    }
+    let reg = |j: f64| {
+        if upper {
+            beta_reg(b, a + j, y)
+        } else {
+            beta_reg(a + j, b, x)
+        }
+    };
+    if lambda == 0.0 {
+        return reg(0.0);
+    }
+    let mu = lambda / 2.0;
+    mixture_sum(mu.floor(), |j| {
+        let w = poisson_weight(mu, j);
+        (w, w * reg(j))
+    })
+}
+
+/// Computes the noncentral beta density at `x` given both `x` and
+/// `y = 1 - x`
+fn beta_mixture_pdf(a: f64, b: f64, lambda: f64, x: f64, y: f64) -> f64 {
+    let mu = lambda / 2.0;
+    if x / 2.0 == 0.0 {
+        // only the leading term of the mixture is nonzero at zero
+        return if a < 1.0 {
+            f64::INFINITY
+        } else if a == 1.0 {
+            b * (-mu).exp()
+        } else {
+            0.0
+        };
+    }
+    if y == 0.0 {
+        return if b < 1.0 {
+            f64::INFINITY
+        } else if b == 1.0 {
+            // Σ w_j / B(a + j, 1) = Σ w_j (a + j)
+            a + mu
+        } else {
+            0.0
+        };
+    }
+    let (ln_x, ln_y) = (x.ln(), y.ln());
+    let ln_density = |j: f64| (a + j - 1.0) * ln_x + (b - 1.0) * ln_y - ln_beta(a + j, b);
+    if lambda == 0.0 {
+        return ln_density(0.0).exp();
+    }
+    mixture_sum(mu.floor(), |j| {
+        let term = (ln_poisson_weight(mu, j) + ln_density(j)).exp();
+        (term, term)
+    })
+}
+
+fn check_beta(a: f64, b: f64, lambda: f64) {
+    assert!(a > 0.0, "a must be positive");
+    assert!(b > 0.0, "b must be positive");
+    assert!(lambda >= 0.0, "lambda must be non-negative");
+}
+
+/// Computes the cdf of the noncentral beta distribution with shape
+/// parameters `a`, `b` and noncentrality `lambda` at `x`
+///
+/// # Formula
+///
+/// ```ignore
+/// F(x) = Σ e^(-λ/2) (λ/2)^j / j! * I_x(a + j, b)
+/// ```
+///
+/// where `λ` is `lambda` and `I` is the regularized incomplete beta
+/// function
+///
+/// # Panics
+///
+/// If `a` or `b` are not positive or `lambda` is negative
+pub fn noncentral_beta_cdf(a: f64, b: f64, lambda: f64, x: f64) -> f64 {
+    check_beta(a, b, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    beta_mixture(a, b, lambda, x, 1.0 - x, false)
+}
+
+/// Computes the survival function of the noncentral beta distribution with
+/// shape parameters `a`, `b` and noncentrality `lambda` at `x`, using
+/// `1 - I_x(a + j, b) = I_(1-x)(b, a + j)` in the series of
+/// `noncentral_beta_cdf`
+///
+/// # Panics
+///
+/// If `a` or `b` are not positive or `lambda` is negative
+pub fn noncentral_beta_sf(a: f64, b: f64, lambda: f64, x: f64) -> f64 {
+    check_beta(a, b, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    beta_mixture(a, b, lambda, x, 1.0 - x, true)
+}
+
+/// Computes the density of the noncentral beta distribution with shape
+/// parameters `a`, `b` and noncentrality `lambda` at `x`
+///
+/// # Formula
+///
+/// ```ignore
+/// f(x) = Σ e^(-λ/2) (λ/2)^j / j! * x^(a + j - 1) (1 - x)^(b - 1) / B(a + j, b)
+/// ```
+///
+/// where `λ` is `lambda` and `B` is the beta function
+///
+/// # Panics
+///
+/// If `a` or `b` are not positive or `lambda` is negative
+pub fn noncentral_beta_pdf(a: f64, b: f64, lambda: f64, x: f64) -> f64 {
+    check_beta(a, b, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if !(0.0..=1.0).contains(&x) {
+        return 0.0;
+    }
+    beta_mixture_pdf(a, b, lambda, x, 1.0 - x)
+}
+
+/// Computes the cdf of the noncentral F distribution with `freedom_1` and
+/// `freedom_2` degrees of freedom and noncentrality `lambda` at `x`, which
+/// is the noncentral beta cdf with shape parameters `freedom_1 / 2`,
+/// `freedom_2 / 2` at `freedom_1 x / (freedom_1 x + freedom_2)`
+///
+/// # Panics
+///
+/// If `freedom_1` or `freedom_2` are not positive or `lambda` is negative
+pub fn noncentral_f_cdf(freedom_1: f64, freedom_2: f64, lambda: f64, x: f64) -> f64 {
+    f_mixture(freedom_1, freedom_2, lambda, x, false)
+}
+
+/// Computes the survival function of the noncentral F distribution with
+/// `freedom_1` and `freedom_2` degrees of freedom and noncentrality
+/// `lambda` at `x`
+///
+/// # Panics
+///
+/// If `freedom_1` or `freedom_2` are not positive or `lambda` is negative
+pub fn noncentral_f_sf(freedom_1: f64, freedom_2: f64, lambda: f64, x: f64) -> f64 {
+    f_mixture(freedom_1, freedom_2, lambda, x, true)
+}
+
+/// Computes the density of the noncentral F distribution with `freedom_1`
+/// and `freedom_2` degrees of freedom and noncentrality `lambda` at `x`
+/// from the noncentral beta density `f_B` of `noncentral_beta_pdf`
+///
+/// # Formula
+///
+/// ```ignore
+/// f(x) = f_B(d_1 x / (d_1 x + d_2)) * d_1 d_2 / (d_1 x + d_2)^2
+/// ```
+///
+/// where `d_1` is `freedom_1` and `d_2` is `freedom_2`
+///
+/// # Panics
+///
+/// If `freedom_1` or `freedom_2` are not positive or `lambda` is negative
+pub fn noncentral_f_pdf(freedom_1: f64, freedom_2: f64, lambda: f64, x: f64) -> f64 {
+    check_f(freedom_1, freedom_2, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x < 0.0 || x == f64::INFINITY {
+        return 0.0;
+    }
+    let denom = freedom_1 * x + freedom_2;
+    let density = beta_mixture_pdf(
+        freedom_1 / 2.0,
+        freedom_2 / 2.0,
+        lambda,
+        freedom_1 * x / denom,
+        freedom_2 / denom,
+    );
+    density * freedom_1 * freedom_2 / (denom * denom)
+}
+
+fn check_f(freedom_1: f64, freedom_2: f64, lambda: f64) {
+    assert!(freedom_1 > 0.0, "freedom_1 must be positive");
+    assert!(freedom_2 > 0.0, "freedom_2 must be positive");
+    assert!(lambda >= 0.0, "lambda must be non-negative");
+}
+
+fn f_mixture(freedom_1: f64, freedom_2: f64, lambda: f64, x: f64, upper: bool) -> f64 {
+    check_f(freedom_1, freedom_2, lambda);
+    if x.is_nan() {
+        return f64::NAN;
+    }
+    if x == f64::INFINITY {
+        return if upper { 0.0 } else { 1.0 };
+    }
+    let x = x.max(0.0);
+    let denom = freedom_1 * x + freedom_2;
+    beta_mixture(
+        freedom_1 / 2.0,
+        freedom_2 / 2.0,
+        lambda,
+        freedom_1 * x / denom,
+        freedom_2 / denom,
+        upper,
+    )
+}
+
+/// Computes the cdf of the noncentral Student's t distribution with
+/// `freedom` degrees of freedom and noncentrality `delta` at `x`, i.e. the
+/// distribution of `(Z + δ) / √(V / ν)` for a standard normal `Z` and an
+/// independent chi-squared `V` with `ν` degrees of freedom
+///
+/// # Formula
+///
+/// ```ignore
+/// F(x) = Φ(-δ) + 1/2 Σ (p_j I_y(j + 1/2, ν/2) + q_j I_y(j + 1, ν/2))
+/// p_j = e^(-δ²/2) (δ²/2)^j / j!
+/// q_j = δ e^(-δ²/2) (δ²/2)^j / (√2 Γ(j + 3/2))
+/// ```
+///
+/// for `x >= 0` where `y = x² / (x² + ν)` (Lenth, 1989), and
+/// `F(x; ν, δ) = 1 - F(-x; ν, -δ)` for `x < 0`
+///
+/// # Panics
+///
+/// If `freedom` is not positive
+pub fn noncentral_t_cdf(freedom: f64, delta: f64, x: f64) -> f64 {
+    assert!(freedom > 0.0, "freedom must be positive");
+    if x.is_nan() || delta.is_nan() {
+        f64::NAN
+    } else if x < 0.0 {
+        t_tails(freedom, -delta, -x).1
+    } else {
+        t_tails(freedom, delta, x).0
+    }
+}
+
+/// Computes the survival function of the noncentral Student's t
+/// distribution with `freedom` degrees of freedom and noncentrality
+/// `delta` at `x`, using the complementary incomplete beta functions in
+/// the series of `noncentral_t_cdf`
+///
+/// # Panics
+///
+/// If `freedom` is not positive
+pub fn noncentral_t_sf(freedom: f64, delta: f64, x: f64) -> f64 {
+    assert!(freedom > 0.0, "freedom must be positive");
+    if x.is_nan() || delta.is_nan() {
+        f64::NAN
+    } else if x < 0.0 {
+        t_tails(freedom, -delta, -x).0
+    } else {
+        t_tails(freedom, delta, x).1
+    }
+}
+
+/// Computes the density of the noncentral Student's t distribution with
+/// `freedom` degrees of freedom and noncentrality `delta` at `x`, obtained
+/// by differentiating the series of `noncentral_t_cdf`
+///
+/// # Formula
+///
+/// ```ignore
+/// f(x) = Σ (p_j ν / (B(j + 1/2, ν/2) s^(3/2)) + q_j x ν / (B(j + 1, ν/2) s^2)) y^j (1 - y)^(ν/2 - 1)
+/// ```
+///
+/// for `x >= 0` where `s = x² + ν`, `y = x² / s`, `B` is the beta function
+/// and `p_j`, `q_j` are the weights of `noncentral_t_cdf`, and
+/// `f(x; ν, δ) = f(-x; ν, -δ)` for `x < 0`
+///
+/// # Panics
+///
+/// If `freedom` is not positive
+pub fn noncentral_t_pdf(freedom: f64, delta: f64, x: f64) -> f64 {
+    assert!(freedom > 0.0, "freedom must be positive");
+    if x.is_nan() || delta.is_nan() {
+        return f64::NAN;
+    }
+    if x.is_infinite() {
+        return 0.0;
+    }
+    if freedom == f64::INFINITY {
+        return (-0.5 * (x - delta) * (x - delta) - LN_SQRT_2PI).exp();
+    }
+    let (delta, x) = if x < 0.0 { (-delta, -x) } else { (delta, x) };
+    let (ln_y, ln_w) = t_ln_split(freedom, x);
+    // ln(s) = ln(ν / w) avoids forming x² + ν, which overflows for large x
+    let ln_s = freedom.ln() - ln_w;
+    let b = freedom / 2.0;
+    let ln_p_density =
+        |j: f64| j_ln(j, ln_y) + (b - 1.0) * ln_w - ln_beta(j + 0.5, b) + freedom.ln() - 1.5 * ln_s;
+    if delta == 0.0 {
+        return ln_p_density(0.0).exp();
+    }
+    let ln_q_density = |j: f64| {
+        j_ln(j, ln_y) + (b - 1.0) * ln_w - ln_beta(j + 1.0, b) + x.ln() + freedom.ln() - 2.0 * ln_s
+    };
+    let mu = delta * delta / 2.0;
+    let ln_q = delta.abs().ln() - 0.5 * LN_2;
+    mixture_sum(mu.floor(), |j| {
+        let ln_weight = ln_poisson_weight(mu, j);
+        let p = (ln_weight + ln_p_density(j)).exp();
+        let q = (ln_weight + ln_gamma(j + 1.0) - ln_gamma(j + 1.5) + ln_q + ln_q_density(j)).exp()
+            * delta.signum();
+        (p + q.abs(), p + q)
+    })
+}
+
+/// Returns `(P(T <= x), P(T > x))` for `x >= 0`. Only the smaller tail is
+/// summed directly and the other is its complement, so that the two add up
+/// to one even though `t_lower` inherits the error of `erfc`
+fn t_tails(freedom: f64, delta: f64, x: f64) -> (f64, f64) {
+    let upper = t_upper(freedom, delta, x);
+    if upper <= 0.5 {
+        (1.0 - upper, upper)
+    } else {
+        let lower = t_lower(freedom, delta, x);
+        (lower, 1.0 - lower)
+    }
+}
+
+/// Computes `P(T <= x)` for `x >= 0`
+fn t_lower(freedom: f64, delta: f64, x: f64) -> f64 {
+    if freedom == f64::INFINITY || x == f64::INFINITY {
+        return 0.5 * erfc((delta - x) * FRAC_1_SQRT_2);
+    }
+    let (y, _) = t_split(freedom, x);
+    let series = t_series(freedom, delta, |a, b| {
+        if y == 0.0 {
+            0.0
+        } else {
+            beta_reg(a, b, y)
+        }
+    });
+    (0.5 * erfc(delta * FRAC_1_SQRT_2) + 0.5 * series).clamp(0.0, 1.0)
+}
+
+/// Computes `P(T > x)` for `x >= 0`
+fn t_upper(freedom: f64, delta: f64, x: f64) -> f64 {
+    if freedom == f64::INFINITY || x == f64::INFINITY {
+        return 0.5 * erfc((x - delta) * FRAC_1_SQRT_2);
+    }
+    let (_, w) = t_split(freedom, x);
+    let series = if w < f64::MIN_POSITIVE {
+        // the leading term of `I_w(b, a)` is exact to double precision here,
+        // and remains representable when `w` itself has underflowed
+        let (_, ln_w) = t_ln_split(freedom, x);
+        t_series(freedom, delta, |a, b| {
+            (b * ln_w - b.ln() - ln_beta(b, a)).exp()
+        })
+    } else {
+        t_series(freedom, delta, |a, b| beta_reg(b, a, w))
+    };
+    (0.5 * series).clamp(0.0, 1.0)
+}
+
+/// Returns `(x² / (x² + ν), ν / (x² + ν))` for finite `x >= 0`, going
+/// through `r = ν / x²` once `x² > ν` so that `x²` cannot overflow
+fn t_split(freedom: f64, x: f64) -> (f64, f64) {
+    if x <= freedom.sqrt() {
+        let s = x * x + freedom;
+        (x * x / s, freedom / s)
+    } else {
+        let r = (freedom.sqrt() / x).powi(2);
+        (1.0 / (1.0 + r), r / (1.0 + r))
+    }
+}
+
+/// Returns the logarithms of the pair of `t_split`, which stay finite when
+/// `ν / (x² + ν)` underflows
+fn t_ln_split(freedom: f64, x: f64) -> (f64, f64) {
+    if x <= freedom.sqrt() {
+        let s = x * x + freedom;
+        ((x * x / s).ln(), (freedom / s).ln())
+    } else {
+        let r = (freedom.sqrt() / x).powi(2);
+        (-r.ln_1p(), freedom.ln() - 2.0 * x.ln() - r.ln_1p())
+    }
+}
+
+/// Sums `Σ (p_j reg(j + 1/2, ν/2) + q_j reg(j + 1, ν/2))` over the weights
+/// of `noncentral_t_cdf`
+fn t_series<F: Fn(f64, f64) -> f64>(freedom: f64, delta: f64, reg: F) -> f64 {
+    let b = freedom / 2.0;
+    if delta == 0.0 {
+        return reg(0.5, b);
+    }
+    let mu = delta * delta / 2.0;
+    let ln_mu = mu.ln();
+    let ln_q = delta.abs().ln() - 0.5 * LN_2;
+    mixture_sum(mu.floor(), |j| {
+        let p = (-mu + j * ln_mu - ln_gamma(j + 1.0)).exp();
+        let q = (-mu + j * ln_mu - ln_gamma(j + 1.5) + ln_q).exp() * delta.signum();
+        (p + q.abs(), p * reg(j + 0.5, b) + q * reg(j + 1.0, b))
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use std::f64;
+
+    #[test]
+    fn test_noncentral_chi_squared() {
+        assert_almost_eq!(super::noncentral_chi_squared_cdf(3.0, 2.5, 4.0), 0.43146149918696317272, 1e-14);
+        assert_almost_eq!(super::noncentral_chi_squared_sf(3.0, 2.5, 4.0), 0.56853850081303682728, 1e-14);
+        assert_almost_eq!(super::noncentral_chi_squared_cdf(7.5, 10.0, 2.0), 0.00047941851797090963096, 1e-17);
+        assert_almost_eq!(super::noncentral_chi_squared_cdf(2.0, 40.0, 80.0), 0.99466587824960834820, 1e-13);
+        assert_almost_eq!(super::noncentral_chi_squared_sf(2.0, 40.0, 80.0), 0.0053341217503916518025, 1e-15);
+        assert_almost_eq!(super::noncentral_chi_squared_cdf(4.0, 1.0, 0.1), 0.00073946359009671686677, 1e-17);
+        assert_almost_eq!(super::noncentral_chi_squared_cdf(4.0, 0.0, 3.0), super::gamma_lr(2.0, 1.5), 1e-16);
+        assert_eq!(super::noncentral_chi_squared_cdf(4.0, 1.0, 0.0), 0.0);
+        assert_eq!(super::noncentral_chi_squared_sf(4.0, 1.0, -1.0), 1.0);
+        assert_eq!(super::noncentral_chi_squared_cdf(4.0, 1.0, f64::INFINITY), 1.0);
+        assert!(super::noncentral_chi_squared_sf(4.0, 1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_noncentral_beta() {
+        assert_almost_eq!(super::noncentral_beta_cdf(2.0, 3.0, 4.0, 0.3), 0.11674344147530744501, 1e-14);
+        assert_almost_eq!(super::noncentral_beta_sf(2.0, 3.0, 4.0, 0.3), 0.88325655852469255499, 1e-14);
+        assert_almost_eq!(super::noncentral_beta_cdf(5.0, 1.5, 20.0, 0.95), 0.66971078684279972416, 1e-13);
+        assert_almost_eq!(super::noncentral_beta_sf(5.0, 1.5, 20.0, 0.95), 0.33028921315720027584, 1e-13);
+        assert_eq!(super::noncentral_beta_cdf(2.0, 3.0, 4.0, 0.0), 0.0);
+        assert_eq!(super::noncentral_beta_sf(2.0, 3.0, 4.0, 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_noncentral_f() {
+        assert_almost_eq!(super::noncentral_f_cdf(3.0, 20.0, 5.0, 2.5), 0.51635499925526642438, 1e-14);
+        assert_almost_eq!(super::noncentral_f_sf(3.0, 20.0, 5.0, 2.5), 0.48364500074473357562, 1e-14);
+        assert_almost_eq!(super::noncentral_f_cdf(4.0, 10.0, 12.0, 1.0), 0.030321686025568555040, 1e-15);
+        assert_almost_eq!(super::noncentral_f_sf(4.0, 10.0, 12.0, 1.0), 0.96967831397443144496, 1e-14);
+        assert_eq!(super::noncentral_f_cdf(4.0, 10.0, 12.0, 0.0), 0.0);
+        assert_eq!(super::noncentral_f_sf(4.0, 10.0, 12.0, f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_noncentral_t() {
+        assert_almost_eq!(super::noncentral_t_cdf(10.0, 1.5, 2.0), 0.65915407244219081540, 1e-11);
+        assert_almost_eq!(super::noncentral_t_sf(10.0, 1.5, 2.0), 0.34084592755780918460, 1e-13);
+        assert_almost_eq!(super::noncentral_t_cdf(10.0, 1.5, -1.0), 0.0077790953543366217351, 1e-14);
+        assert_almost_eq!(super::noncentral_t_sf(10.0, 1.5, -1.0), 0.99222090464566337826, 1e-11);
+        assert_almost_eq!(super::noncentral_t_cdf(3.5, -2.0, 0.5), 0.99241694953486981179, 1e-11);
+        assert_almost_eq!(super::noncentral_t_sf(3.5, -2.0, 0.5), 0.0075830504651301882081, 1e-15);
+        assert_almost_eq!(super::noncentral_t_cdf(30.0, 4.0, 6.0), 0.93958468776677766250, 1e-11);
+        assert_almost_eq!(super::noncentral_t_sf(30.0, 4.0, 6.0), 0.060415312233222337497, 1e-14);
+        assert_almost_eq!(super::noncentral_t_cdf(5.0, 0.5, 0.0), 0.30853753872598689636, 1e-11);
+        assert_almost_eq!(super::noncentral_t_cdf(f64::INFINITY, 0.5, 1.5), 0.84134474606854293, 1e-10);
+        assert_eq!(super::noncentral_t_sf(5.0, 0.5, f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_noncentral_pdf() {
+        assert_almost_eq!(super::noncentral_chi_squared_pdf(3.0, 2.5, 4.0), 0.11535432626766382273, 1e-12);
+        assert_almost_eq!(super::noncentral_chi_squared_pdf(7.5, 10.0, 2.0), 0.00091952308010076283035, 1e-15);
+        assert_almost_eq!(super::noncentral_chi_squared_pdf(2.0, 40.0, 80.0), 0.00085960280406256408062, 1e-15);
+        assert_almost_eq!(super::noncentral_chi_squared_pdf(1.0, 1.0, 0.1), 0.76455772372320106807, 1e-12);
+        assert_eq!(super::noncentral_chi_squared_pdf(1.0, 1.0, 0.0), f64::INFINITY);
+        assert_almost_eq!(super::noncentral_chi_squared_pdf(2.0, 1.0, 0.0), 0.5 * (-0.5f64).exp(), 1e-16);
+        assert_eq!(super::noncentral_chi_squared_pdf(3.0, 1.0, -1.0), 0.0);
+        assert_almost_eq!(super::noncentral_beta_pdf(2.0, 3.0, 4.0, 0.3), 0.90870882573696405540, 1e-12);
+        assert_almost_eq!(super::noncentral_beta_pdf(5.0, 1.5, 20.0, 0.95), 7.1738185457910349041, 1e-12);
+        assert_almost_eq!(super::noncentral_beta_pdf(0.5, 0.7, 2.0, 0.01), 1.5081489479385276949, 1e-12);
+        assert_almost_eq!(super::noncentral_beta_pdf(2.0, 1.0, 3.0, 1.0), 3.5, 1e-12);
+        assert_eq!(super::noncentral_beta_pdf(2.0, 3.0, 4.0, 1.5), 0.0);
+        assert_almost_eq!(super::noncentral_f_pdf(3.0, 20.0, 5.0, 2.5), 0.21127308173984323985, 1e-12);
+        assert_almost_eq!(super::noncentral_f_pdf(4.0, 10.0, 12.0, 1.0), 0.079364592671035617235, 1e-13);
+        assert_eq!(super::noncentral_f_pdf(4.0, 10.0, 12.0, -1.0), 0.0);
+        assert_almost_eq!(super::noncentral_t_pdf(10.0, 1.5, 2.0), 0.31460591845019614340, 1e-12);
+        assert_almost_eq!(super::noncentral_t_pdf(10.0, 1.5, -1.0), 0.018120743153011294142, 1e-13);
+        assert_almost_eq!(super::noncentral_t_pdf(3.5, -2.0, 0.5), 0.016219741004656664930, 1e-13);
+        assert_almost_eq!(super::noncentral_t_pdf(30.0, 4.0, 6.0), 0.084142359369668518192, 1e-13);
+        assert_almost_eq!(super::noncentral_t_pdf(5.0, 0.5, 0.0), 0.33500172796874259120, 1e-12);
+        assert_almost_eq!(super::noncentral_t_pdf(4.0, -1.0, 1e-3), 0.22720702678866225443, 1e-12);
+        assert_almost_eq!(super::noncentral_t_pdf(2.5, 3.0, -0.5), 0.00083470115575142798346, 1e-15);
+        assert_eq!(super::noncentral_t_pdf(2.5, 3.0, f64::INFINITY), 0.0);
+    }
+
+    #[test]
+    fn test_noncentral_t_huge_x() {
+        assert_almost_eq!(super::noncentral_t_sf(1.0, 1.0, 1e300), 8.6436068846080548443e-301, 1e-314);
+        assert_almost_eq!(super::noncentral_t_cdf(1.0, 1.0, -1e160), 6.6476127657940128547e-162, 1e-174);
+        assert_almost_eq!(super::noncentral_t_cdf(5.0, 2.0, -1e160), 0.0, 1e-300);
+        assert_almost_eq!(super::noncentral_t_pdf(0.01, 0.0, 1e200), 4.8526328575587004026e-205, 1e-217);
+        assert_eq!(super::noncentral_t_pdf(5.0, 2.0, 1e300), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_noncentral_t_freedom_zero() {
+        super::noncentral_t_cdf(0.0, 1.0, 1.0);
+    }
+}
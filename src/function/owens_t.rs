@@ -0,0 +1,196 @@
+//! Provides Owen's T function, which gives the probability of the bivariate
+//! normal distribution over wedge shaped regions and appears in the
+//! distribution functions of skewed normal distributions
+
+use crate::function::erf;
+use crate::function::evaluate::{adaptive_gauss_legendre, gauss_legendre};
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+/// Values of `h x` beyond which the integrand of Owen's T function is
+/// negligible relative to the integral
+const HX_MAX: f64 = 9.0;
+
+/// Values of the exponent beyond which the integrand of
+/// `owens_t_complement` is negligible relative to the integral
+const T_MAX: f64 = 40.0;
+
+/// Computes Owen's T function at `h` and `a`, the probability that a pair
+/// of independent standard normal variables `(X, Y)` falls into the region
+/// `X > h, 0 < Y < a X` for `h, a >= 0`
+///
+/// # Formula
+///
+/// ```ignore
+/// T(h, a) = 1 / (2π) * ∫ exp(-h^2 (1 + x^2) / 2) / (1 + x^2) dx
+/// ```
+///
+/// integrated over `x` from `0` to `a`. For `|a| > 1` the function is
+/// evaluated through the identity
+///
+/// ```ignore
+/// T(h, a) = (Q(h) + Q(ah)) / 2 - Q(h) Q(ah) - T(ah, 1 / a)
+/// ```
+///
+/// for `h, a >= 0`, where `Q` is the standard normal survival function.
+///
+/// # Remarks
+///
+/// Returns `NaN` if either argument is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::owens_t::owens_t;
+///
+/// assert!((owens_t(0.0, 1.0) - 0.125).abs() < 1e-15);
+/// assert!((owens_t(1.0, 0.5) - 0.043064691120785365632).abs() < 1e-15);
+/// ```
+pub fn owens_t(h: f64, a: f64) -> f64 {
+    if h.is_nan() || a.is_nan() {
+        return f64::NAN;
+    }
+    if a < 0.0 {
+        return -owens_t(h, -a);
+    }
+    let h = h.abs();
+    if a == 0.0 {
+        0.0
+    } else if h == 0.0 {
+        a.atan() / (2.0 * PI)
+    } else if a <= 1.0 {
+        integrate(h, a)
+    } else {
+        let ah = a * h;
+        let q_h = 0.5 * erf::erfc(h * FRAC_1_SQRT_2);
+        let q_ah = 0.5 * erf::erfc(ah * FRAC_1_SQRT_2);
+        let t = 0.5 * (q_h + q_ah) - q_h * q_ah;
+        if ah.is_infinite() {
+            t
+        } else {
+            t - integrate(ah, a.recip())
+        }
+    }
+}
+
+/// Computes `T(h, ∞) - T(h, a)`, the probability that a pair of independent
+/// standard normal variables `(X, Y)` falls into the region
+/// `X > |h|, Y > a X` for `a >= 0`
+///
+/// # Formula
+///
+/// ```ignore
+/// T(h, ∞) - T(h, a) = 1 / (2π) * ∫ exp(-h^2 (1 + x^2) / 2) / (1 + x^2) dx
+/// ```
+///
+/// integrated over `x` from `a` to `∞`. Unlike `Q(|h|) / 2 - T(h, a)` this
+/// keeps full relative precision when `a |h|` is large and the difference
+/// is far smaller than either term, e.g. in the short tail of the skew
+/// normal distribution.
+///
+/// # Panics
+///
+/// If `a < 0.0`
+///
+/// # Remarks
+///
+/// Returns `NaN` if either argument is `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::function::owens_t::{owens_t, owens_t_complement};
+///
+/// let t = 0.25 - owens_t(0.0, 2.0);
+/// assert!((owens_t_complement(0.0, 2.0) - t).abs() < 1e-15);
+/// ```
+pub fn owens_t_complement(h: f64, a: f64) -> f64 {
+    if h.is_nan() || a.is_nan() {
+        return f64::NAN;
+    }
+    assert!(a >= 0.0, "a must be non-negative");
+    let h = h.abs();
+    if a.is_infinite() {
+        0.0
+    } else if h == 0.0 {
+        a.recip().atan() / (2.0 * PI)
+    } else if a * h <= 1.0 {
+        // the difference is at least a fixed fraction of `Q(h) / 2`
+        0.25 * erf::erfc(h * FRAC_1_SQRT_2) - owens_t(h, a)
+    } else {
+        // substituting `h^2 x^2 / 2 = h^2 a^2 / 2 + t` leaves a smooth
+        // factor against `exp(-t)`
+        let h2 = h * h;
+        let integral = adaptive_gauss_legendre(0.0, T_MAX, 4.0, 1e-14, |t| {
+            let x2 = a * a + 2.0 * t / h2;
+            (-t).exp() / (h2 * x2.sqrt() * (1.0 + x2))
+        });
+        (-0.5 * h2 * (1.0 + a * a)).exp() / (2.0 * PI) * integral
+    }
+}
+
+/// Integrates the defining integral of `T(h, a)` for `h > 0, 0 < a <= 1`,
+/// cut off where the gaussian factor of the integrand becomes negligible
+fn integrate(h: f64, a: f64) -> f64 {
+    let hi = a.min(HX_MAX / h);
+    let integral = gauss_legendre(0.0, hi, 2.0 / h, |x| {
+        (-0.5 * h * h * x * x).exp() / (1.0 + x * x)
+    });
+    (-0.5 * h * h).exp() / (2.0 * PI) * integral
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::{owens_t, owens_t_complement};
+    use crate::distribution::{ContinuousCDF, Normal};
+    use std::f64;
+
+    #[test]
+    fn test_owens_t() {
+        assert_almost_eq!(owens_t(0.0, 1.0), 0.125, 1e-16);
+        assert_almost_eq!(owens_t(0.0, f64::INFINITY), 0.25, 1e-16);
+        assert_eq!(owens_t(2.0, 0.0), 0.0);
+        assert!(owens_t(f64::NAN, 1.0).is_nan());
+        assert!(owens_t(1.0, f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_owens_t_small_a() {
+        assert_almost_eq!(owens_t(0.1, 0.5), 0.073394997126852609976, 1e-16);
+        assert_almost_eq!(owens_t(1.0, 0.5), 0.043064691120785365632, 1e-16);
+        assert_almost_eq!(owens_t(2.0, 0.99), 0.011101337081657992172, 1e-16);
+        assert_almost_eq!(owens_t(3.7, 0.1), 1.6513532780974719948e-5, 1e-19);
+        assert_almost_eq!(owens_t(10.0, 0.5), 3.8099247740170698109e-24, 1e-37);
+        assert_almost_eq!(owens_t(25.0, 1.0), 1.5283483531913363716e-138, 1e-151);
+    }
+
+    #[test]
+    fn test_owens_t_large_a() {
+        assert_almost_eq!(owens_t(0.001, 1000.0), 0.24976729086693259703, 1e-12);
+        assert_almost_eq!(owens_t(0.5, 2.0), 0.14158060365397839347, 1e-10);
+        assert_almost_eq!(owens_t(1.0, 7.0), 0.079327626965722558153, 1e-10);
+        assert_almost_eq!(owens_t(3.7, 1000.0), 5.3899866738694168469e-5, 1e-14);
+        assert_almost_eq!(owens_t(6.0, 50.0), 4.9329382251884907035e-10, 1e-18);
+    }
+
+    #[test]
+    fn test_owens_t_complement() {
+        assert_almost_eq!(owens_t_complement(0.0, 1.0), 0.125, 1e-16);
+        assert_almost_eq!(owens_t_complement(1.0, 0.5), 0.5 * Normal::new(0.0, 1.0).unwrap().sf(1.0) - owens_t(1.0, 0.5), 1e-16);
+        assert_almost_eq!(owens_t_complement(0.2, 3.0), 0.020759928294388351067, 1e-16);
+        assert_almost_eq!(owens_t_complement(-5.0, 3.0), 1.08298037809056329475e-58, 1e-71);
+        assert_almost_eq!(owens_t_complement(2.0, 10.0), 7.323950328777247607e-93, 1e-106);
+        assert_almost_eq!(owens_t_complement(10.0, 3.0), 3.76800668782760661835e-222, 1e-235);
+        assert_eq!(owens_t_complement(1.0, f64::INFINITY), 0.0);
+        assert!(owens_t_complement(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_owens_t_symmetry() {
+        assert_eq!(owens_t(-1.0, 0.5), owens_t(1.0, 0.5));
+        assert_eq!(owens_t(1.0, -0.5), -owens_t(1.0, 0.5));
+        assert_eq!(owens_t(-2.0, -3.0), -owens_t(2.0, 3.0));
+        // T(h, 1) = Φ(h) (1 - Φ(h)) / 2
+        assert_almost_eq!(owens_t(1.0, 1.0), 0.066741882165700966623, 1e-15);
+    }
+}
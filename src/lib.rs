@@ -57,6 +57,7 @@ pub mod function;
 pub mod generate;
 pub mod prec;
 pub mod statistics;
+pub mod stats_tests;
 
 mod error;
 
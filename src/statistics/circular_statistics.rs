@@ -0,0 +1,179 @@
+use std::borrow::Borrow;
+use std::f64;
+
+/// The `CircularStatistics` trait provides descriptive statistics for
+/// angles in radians, such as wind directions or times of day mapped onto
+/// the circle, for which the linear statistics of the `Statistics` trait are
+/// misleading. All statistics are based on the resultant of the unit vectors
+/// `(cos x, sin x)` of the angles.
+pub trait CircularStatistics<T> {
+    /// Returns the circular mean of the angles in the data, the direction
+    /// of their resultant vector, in `[-π, π]`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`. The
+    /// mean direction is not meaningful if the mean resultant length is
+    /// close to zero.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// atan2(Σ sin(x_i), Σ cos(x_i))
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::CircularStatistics;
+    ///
+    /// // times of day around midnight, in hours
+    /// let hours = [23.0, 23.5, 0.5, 1.0];
+    /// let angles = hours.iter().map(|h| h / 24.0 * 2.0 * PI);
+    /// let mean = angles.circular_mean() / (2.0 * PI) * 24.0;
+    /// assert!(mean.abs() < 1e-14);
+    /// ```
+    fn circular_mean(self) -> T;
+
+    /// Returns the mean resultant length of the angles in the data, the
+    /// length of the mean of their unit vectors, in `[0, 1]`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`. The
+    /// mean resultant length is one if all angles coincide and close to
+    /// zero if they are spread evenly over the circle.
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// sqrt((Σ cos(x_i))^2 + (Σ sin(x_i))^2) / n
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::CircularStatistics;
+    ///
+    /// let x = [0.0, 0.5 * PI];
+    /// assert!((x.mean_resultant_length() - 0.5f64.sqrt()).abs() < 1e-15);
+    /// ```
+    fn mean_resultant_length(self) -> T;
+
+    /// Returns the circular variance of the angles in the data, which lies
+    /// in `[0, 1]`
+    ///
+    /// # Remarks
+    ///
+    /// Returns `f64::NAN` if data is empty or an entry is `f64::NAN`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// 1 - R
+    /// ```
+    ///
+    /// where `R` is the mean resultant length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use statrs::statistics::CircularStatistics;
+    ///
+    /// // wind directions in degrees
+    /// let degrees = [350.0, 10.0, 0.0, 5.0, 355.0];
+    /// let variance = degrees.iter().map(|d: &f64| d.to_radians()).circular_variance();
+    /// assert!(variance < 0.01);
+    /// ```
+    fn circular_variance(self) -> T;
+}
+
+impl<T> CircularStatistics<f64> for T
+where
+    T: IntoIterator,
+    T::Item: Borrow<f64>,
+{
+    fn circular_mean(self) -> f64 {
+        let (n, c, s) = resultant(self);
+        if n > 0.0 {
+            s.atan2(c)
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn mean_resultant_length(self) -> f64 {
+        let (n, c, s) = resultant(self);
+        if n > 0.0 {
+            c.hypot(s) / n
+        } else {
+            f64::NAN
+        }
+    }
+
+    fn circular_variance(self) -> f64 {
+        1.0 - self.mean_resultant_length()
+    }
+}
+
+/// Returns the number of angles in `data` and the sums of their cosines
+/// and sines
+fn resultant<T>(data: T) -> (f64, f64, f64)
+where
+    T: IntoIterator,
+    T::Item: Borrow<f64>,
+{
+    data.into_iter().fold((0.0, 0.0, 0.0), |(n, c, s), x| {
+        let (sin, cos) = x.borrow().sin_cos();
+        (n + 1.0, c + cos, s + sin)
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::CircularStatistics;
+    use crate::statistics::Statistics;
+    use std::f64;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_circular_mean() {
+        let x = [0.1, 0.2, 0.3];
+        assert_almost_eq!(x.circular_mean(), 0.2, 1e-15);
+        // angles on both sides of the branch cut at ±π
+        let x = [PI - 0.1, -PI + 0.3];
+        assert_almost_eq!(x.circular_mean(), -PI + 0.1, 1e-15);
+        assert_almost_eq!(Statistics::mean(x), 0.1, 1e-15);
+        let x = [1.0, 1.0 + 2.0 * PI, 1.0 - 4.0 * PI];
+        assert_almost_eq!(x.circular_mean(), 1.0, 1e-14);
+        let x = [0.0, 0.5 * PI];
+        assert_almost_eq!(x.iter().circular_mean(), 0.25 * PI, 1e-15);
+    }
+
+    #[test]
+    fn test_mean_resultant_length() {
+        assert_eq!([2.0; 5].mean_resultant_length(), 1.0);
+        let x = [0.0, 0.5 * PI, PI, 1.5 * PI];
+        assert_almost_eq!(x.mean_resultant_length(), 0.0, 1e-15);
+        assert_almost_eq!(x.circular_variance(), 1.0, 1e-15);
+        let x = [0.0, 2.0 / 3.0 * PI];
+        assert_almost_eq!(x.mean_resultant_length(), 0.5, 1e-15);
+        assert_almost_eq!(x.circular_variance(), 0.5, 1e-15);
+    }
+
+    #[test]
+    fn test_empty_or_nan() {
+        let x: [f64; 0] = [];
+        assert!(x.circular_mean().is_nan());
+        assert!(x.mean_resultant_length().is_nan());
+        assert!(x.circular_variance().is_nan());
+        let x = [0.0, f64::NAN];
+        assert!(x.circular_mean().is_nan());
+        assert!(x.mean_resultant_length().is_nan());
+        assert!(x.circular_variance().is_nan());
+    }
+}
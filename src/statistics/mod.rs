@@ -1,11 +1,13 @@
 //! Provides traits for statistical computation
 
+pub use self::circular_statistics::*;
 pub use self::iter_statistics::*;
 pub use self::order_statistics::*;
 pub use self::slice_statistics::*;
 pub use self::statistics::*;
 pub use self::traits::*;
 
+mod circular_statistics;
 mod iter_statistics;
 mod order_statistics;
 // TODO: fix later
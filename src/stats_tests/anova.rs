@@ -0,0 +1,614 @@
+use crate::distribution::{ContinuousCDF, FisherSnedecor, StudentizedRange};
+use crate::stats_tests::{check_level, ConfidenceInterval};
+use crate::{Result, StatsError};
+
+/// A row of an analysis of variance table
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AnovaRow {
+    /// The sum of squares attributed to the source of variation
+    pub ss: f64,
+    /// The degrees of freedom of the sum of squares
+    pub df: f64,
+    /// The mean square `ss / df`
+    pub ms: f64,
+    /// The F statistic of the source against the residual mean square,
+    /// or `None` for the residual row itself
+    pub f: Option<f64>,
+    /// The upper-tail p-value of the F statistic, or `None` for the
+    /// residual row
+    pub p_value: Option<f64>,
+}
+
+impl AnovaRow {
+    fn residual(ss: f64, df: f64) -> AnovaRow {
+        AnovaRow {
+            ss,
+            df,
+            ms: ss / df,
+            f: None,
+            p_value: None,
+        }
+    }
+
+    fn effect(ss: f64, df: f64, residual: &AnovaRow) -> Result<AnovaRow> {
+        let ms = ss / df;
+        let f = ms / residual.ms;
+        let dist = FisherSnedecor::new(df, residual.df)?;
+        Ok(AnovaRow {
+            ss,
+            df,
+            ms,
+            f: Some(f),
+            p_value: Some(dist.sf(f)),
+        })
+    }
+}
+
+/// The analysis of variance table of a one-way layout
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct OneWayAnova {
+    /// The variation between the group means
+    pub between: AnovaRow,
+    /// The residual variation within the groups
+    pub within: AnovaRow,
+}
+
+impl OneWayAnova {
+    /// Returns the proportion of the total sum of squares explained by the
+    /// groups
+    pub fn r_squared(&self) -> f64 {
+        self.between.ss / (self.between.ss + self.within.ss)
+    }
+}
+
+/// The analysis of variance table of a balanced two-way layout
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TwoWayAnova {
+    /// The main effect of the first factor, i.e. the rows of the layout
+    pub factor_a: AnovaRow,
+    /// The main effect of the second factor, i.e. the columns of the layout
+    pub factor_b: AnovaRow,
+    /// The interaction of both factors, or `None` if there is a single
+    /// observation per cell and the interaction serves as the residual
+    pub interaction: Option<AnovaRow>,
+    /// The residual variation within the cells
+    pub residual: AnovaRow,
+}
+
+/// The result of a test whose statistic follows an F distribution under
+/// the null hypothesis
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FTestResult {
+    /// The F statistic
+    pub statistic: f64,
+    /// The numerator degrees of freedom of the null F distribution
+    pub df1: f64,
+    /// The denominator degrees of freedom of the null F distribution
+    pub df2: f64,
+    /// The upper-tail p-value of the statistic
+    pub p_value: f64,
+}
+
+/// A pairwise comparison of group means from Tukey's HSD test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TukeyComparison {
+    /// The index of the first group
+    pub i: usize,
+    /// The index of the second group
+    pub j: usize,
+    /// The difference `mean_i - mean_j` of the group means
+    pub estimate: f64,
+    /// The standard error of the difference
+    pub std_err: f64,
+    /// The studentized range statistic `√2 |estimate| / std_err`
+    pub statistic: f64,
+    /// The p-value of the comparison, adjusted for all pairwise
+    /// comparisons
+    pub p_value: f64,
+    /// The simultaneous confidence interval for the difference
+    pub confidence_interval: ConfidenceInterval,
+}
+
+/// Returns the mean and the sum of squared deviations from the mean of
+/// `x` after subtracting `shift`, refining the mean with a second pass
+fn mean_ss(x: &[f64], shift: f64) -> (f64, f64) {
+    let n = x.len() as f64;
+    let mean = x.iter().map(|v| v - shift).sum::<f64>() / n;
+    let mean = mean + x.iter().map(|v| v - shift - mean).sum::<f64>() / n;
+    let ss = x.iter().map(|v| (v - shift - mean).powi(2)).sum();
+    (mean, ss)
+}
+
+/// Returns the means, sizes and within sum of squares of `groups`, shifted
+/// by the first observation to reduce cancellation
+fn group_summaries<T: AsRef<[f64]>>(groups: &[T]) -> Result<(Vec<f64>, Vec<f64>, f64)> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups.len()", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().is_empty()) {
+        return Err(StatsError::ArgMustBePositive("group length"));
+    }
+    let shift = groups[0].as_ref()[0];
+    let mut means = Vec::with_capacity(groups.len());
+    let mut sizes = Vec::with_capacity(groups.len());
+    let mut within = 0.0;
+    for g in groups {
+        let (mean, ss) = mean_ss(g.as_ref(), shift);
+        means.push(mean);
+        sizes.push(g.as_ref().len() as f64);
+        within += ss;
+    }
+    Ok((means, sizes, within))
+}
+
+/// Performs a one-way analysis of variance of the null hypothesis that
+/// all `groups` were drawn from populations with equal means, assuming
+/// normal populations with a common variance
+///
+/// # Formula
+///
+/// ```ignore
+/// SS_between = Σ n_i (x̄_i - x̄)^2
+/// SS_within = Σ Σ (x_ij - x̄_i)^2
+/// F = (SS_between / (k - 1)) / (SS_within / (N - k))
+/// ```
+///
+/// where `F` follows an F distribution with `k - 1` and `N - k` degrees of
+/// freedom for `k` groups with `N` observations in total
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if there are no more observations than groups, or if the data
+/// within every group are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::one_way_anova;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3],
+/// ];
+/// let result = one_way_anova(&groups).unwrap();
+/// assert_eq!(result.between.df, 2.0);
+/// assert_eq!(result.within.df, 12.0);
+/// assert!(result.between.p_value.unwrap() < 0.01);
+/// ```
+pub fn one_way_anova<T: AsRef<[f64]>>(groups: &[T]) -> Result<OneWayAnova> {
+    let (means, sizes, within) = group_summaries(groups)?;
+    let k = means.len() as f64;
+    let n: f64 = sizes.iter().sum();
+    if n <= k {
+        return Err(StatsError::ArgGt("total length", k));
+    }
+    if within <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let grand = means
+        .iter()
+        .zip(&sizes)
+        .map(|(m, n_i)| m * n_i)
+        .sum::<f64>()
+        / n;
+    let between = means
+        .iter()
+        .zip(&sizes)
+        .map(|(m, n_i)| n_i * (m - grand).powi(2))
+        .sum();
+    let within = AnovaRow::residual(within, n - k);
+    Ok(OneWayAnova {
+        between: AnovaRow::effect(between, k - 1.0, &within)?,
+        within,
+    })
+}
+
+/// Performs Welch's one-way analysis of variance of the null hypothesis
+/// that all `groups` were drawn from populations with equal means, without
+/// assuming equal variances
+///
+/// # Formula
+///
+/// ```ignore
+/// w_i = n_i / s_i^2, W = Σ w_i, x̃ = Σ w_i x̄_i / W
+/// λ = Σ (1 - w_i / W)^2 / (n_i - 1)
+/// F = (Σ w_i (x̄_i - x̃)^2 / (k - 1)) / (1 + 2 (k - 2) λ / (k^2 - 1))
+/// ```
+///
+/// where `F` approximately follows an F distribution with `k - 1` and
+/// `(k^2 - 1) / (3 λ)` degrees of freedom
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group has
+/// fewer than two elements, or if the data within any group are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::welch_anova;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3, 12.4, 4.2],
+/// ];
+/// let result = welch_anova(&groups).unwrap();
+/// assert_eq!(result.df1, 2.0);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn welch_anova<T: AsRef<[f64]>>(groups: &[T]) -> Result<FTestResult> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups.len()", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().len() < 2) {
+        return Err(StatsError::ArgGte("group length", 2.0));
+    }
+    let shift = groups[0].as_ref()[0];
+    let mut means = Vec::with_capacity(groups.len());
+    let mut weights = Vec::with_capacity(groups.len());
+    let mut sizes = Vec::with_capacity(groups.len());
+    for g in groups {
+        let n_i = g.as_ref().len() as f64;
+        let (mean, ss) = mean_ss(g.as_ref(), shift);
+        if ss <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        means.push(mean);
+        weights.push(n_i * (n_i - 1.0) / ss);
+        sizes.push(n_i);
+    }
+    let k = means.len() as f64;
+    let total: f64 = weights.iter().sum();
+    let grand = means.iter().zip(&weights).map(|(m, w)| m * w).sum::<f64>() / total;
+    let a = means
+        .iter()
+        .zip(&weights)
+        .map(|(m, w)| w * (m - grand).powi(2))
+        .sum::<f64>()
+        / (k - 1.0);
+    let lambda: f64 = weights
+        .iter()
+        .zip(&sizes)
+        .map(|(w, n_i)| (1.0 - w / total).powi(2) / (n_i - 1.0))
+        .sum();
+    let statistic = a / (1.0 + 2.0 * (k - 2.0) * lambda / (k * k - 1.0));
+    let df1 = k - 1.0;
+    let df2 = (k * k - 1.0) / (3.0 * lambda);
+    let dist = FisherSnedecor::new(df1, df2)?;
+    Ok(FTestResult {
+        statistic,
+        df1,
+        df2,
+        p_value: dist.sf(statistic),
+    })
+}
+
+/// Performs a two-way analysis of variance of a balanced layout, where
+/// `cells[i][j]` holds the observations at level `i` of the first factor
+/// and level `j` of the second factor
+///
+/// With more than one observation per cell the table includes the
+/// interaction of both factors. With a single observation per cell the
+/// interaction cannot be separated from the error and is used as the
+/// residual with `(a - 1)(b - 1)` degrees of freedom.
+///
+/// # Formula
+///
+/// ```ignore
+/// SS_A = b n Σ (x̄_i. - x̄)^2
+/// SS_B = a n Σ (x̄_.j - x̄)^2
+/// SS_AB = n Σ Σ (x̄_ij - x̄_i. - x̄_.j + x̄)^2
+/// SS_E = Σ Σ Σ (x_ijk - x̄_ij)^2
+/// ```
+///
+/// for `a` levels of the first factor, `b` levels of the second factor and
+/// `n` observations per cell
+///
+/// # Errors
+///
+/// Returns an error if either factor has fewer than two levels, if the
+/// rows of `cells` have different lengths, if the cells are empty or have
+/// different numbers of observations, or if the residual sum of squares
+/// is zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::two_way_anova;
+///
+/// let cells = [
+///     [vec![4.1, 3.9, 4.3], vec![5.2, 5.0, 5.5]],
+///     [vec![4.6, 4.4, 4.9], vec![6.8, 7.1, 6.6]],
+/// ];
+/// let result = two_way_anova(&cells).unwrap();
+/// assert!(result.factor_a.p_value.unwrap() < 0.01);
+/// assert!(result.interaction.unwrap().p_value.unwrap() < 0.01);
+/// ```
+pub fn two_way_anova<R: AsRef<[C]>, C: AsRef<[f64]>>(cells: &[R]) -> Result<TwoWayAnova> {
+    let a = cells.len();
+    if a < 2 {
+        return Err(StatsError::ArgGte("cells.len()", 2.0));
+    }
+    let b = cells[0].as_ref().len();
+    if b < 2 {
+        return Err(StatsError::ArgGte("cells[0].len()", 2.0));
+    }
+    if cells.iter().any(|row| row.as_ref().len() != b) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let n = cells[0].as_ref()[0].as_ref().len();
+    if n == 0 {
+        return Err(StatsError::ArgMustBePositive("cell length"));
+    }
+    if cells
+        .iter()
+        .any(|row| row.as_ref().iter().any(|c| c.as_ref().len() != n))
+    {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+
+    let shift = cells[0].as_ref()[0].as_ref()[0];
+    let mut means = vec![vec![0.0; b]; a];
+    let mut within = 0.0;
+    for (i, row) in cells.iter().enumerate() {
+        for (j, cell) in row.as_ref().iter().enumerate() {
+            let (mean, ss) = mean_ss(cell.as_ref(), shift);
+            means[i][j] = mean;
+            within += ss;
+        }
+    }
+    let (af, bf, nf) = (a as f64, b as f64, n as f64);
+    let row_means: Vec<f64> = means.iter().map(|r| r.iter().sum::<f64>() / bf).collect();
+    let col_means: Vec<f64> = (0..b)
+        .map(|j| means.iter().map(|r| r[j]).sum::<f64>() / af)
+        .collect();
+    let grand = row_means.iter().sum::<f64>() / af;
+    let ss_a = bf * nf * row_means.iter().map(|m| (m - grand).powi(2)).sum::<f64>();
+    let ss_b = af * nf * col_means.iter().map(|m| (m - grand).powi(2)).sum::<f64>();
+    let ss_ab = nf
+        * means
+            .iter()
+            .zip(&row_means)
+            .map(|(r, rm)| {
+                r.iter()
+                    .zip(&col_means)
+                    .map(|(m, cm)| (m - rm - cm + grand).powi(2))
+                    .sum::<f64>()
+            })
+            .sum::<f64>();
+    let df_ab = (af - 1.0) * (bf - 1.0);
+
+    let (residual, interaction) = if n == 1 {
+        (AnovaRow::residual(ss_ab, df_ab), None)
+    } else {
+        (
+            AnovaRow::residual(within, af * bf * (nf - 1.0)),
+            Some(ss_ab),
+        )
+    };
+    if residual.ss <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    Ok(TwoWayAnova {
+        factor_a: AnovaRow::effect(ss_a, af - 1.0, &residual)?,
+        factor_b: AnovaRow::effect(ss_b, bf - 1.0, &residual)?,
+        interaction: match interaction {
+            Some(ss) => Some(AnovaRow::effect(ss, df_ab, &residual)?),
+            None => None,
+        },
+        residual,
+    })
+}
+
+/// Performs Tukey's honestly significant difference test of all pairwise
+/// differences between the means of `groups`, computing simultaneous
+/// confidence intervals of level `level`
+///
+/// Unequal group sizes are handled with the Tukey-Kramer method. The
+/// comparisons are returned for all pairs `i < j` in lexicographic order.
+///
+/// # Formula
+///
+/// ```ignore
+/// se_ij = √(MS_within (1 / n_i + 1 / n_j))
+/// q_ij = √2 |x̄_i - x̄_j| / se_ij
+/// ```
+///
+/// where `q_ij` follows a studentized range distribution for `k` groups
+/// with `N - k` degrees of freedom
+///
+/// # Errors
+///
+/// Returns an error under the same conditions as `one_way_anova`, or if
+/// `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::tukey_hsd;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3],
+/// ];
+/// let result = tukey_hsd(&groups, 0.95).unwrap();
+/// assert_eq!((result[0].i, result[0].j), (0, 1));
+/// assert!(result[0].p_value < 0.05);
+/// assert!(!result[0].confidence_interval.contains(0.0));
+/// // the second and third group do not differ significantly
+/// assert!(result[2].p_value > 0.05);
+/// ```
+pub fn tukey_hsd<T: AsRef<[f64]>>(groups: &[T], level: f64) -> Result<Vec<TukeyComparison>> {
+    check_level(level)?;
+    let anova = one_way_anova(groups)?;
+    let (means, sizes, _) = group_summaries(groups)?;
+    let k = means.len();
+    let dist = StudentizedRange::new(k as u64, anova.within.df)?;
+    let q_crit = dist.inverse_sf(1.0 - level) / std::f64::consts::SQRT_2;
+    let mut comparisons = Vec::with_capacity(k * (k - 1) / 2);
+    for i in 0..k {
+        for j in i + 1..k {
+            let estimate = means[i] - means[j];
+            let std_err = (anova.within.ms * (1.0 / sizes[i] + 1.0 / sizes[j])).sqrt();
+            let statistic = std::f64::consts::SQRT_2 * estimate.abs() / std_err;
+            comparisons.push(TukeyComparison {
+                i,
+                j,
+                estimate,
+                std_err,
+                statistic,
+                p_value: dist.sf(statistic),
+                confidence_interval: ConfidenceInterval {
+                    lower: estimate - q_crit * std_err,
+                    upper: estimate + q_crit * std_err,
+                    level,
+                },
+            });
+        }
+    }
+    Ok(comparisons)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing;
+
+    fn groups() -> Vec<Vec<f64>> {
+        vec![
+            vec![6.9, 5.4, 5.8, 4.6, 4.0],
+            vec![8.3, 6.8, 7.8, 9.2, 6.5],
+            vec![8.0, 10.5, 8.1, 6.9, 9.3],
+        ]
+    }
+
+    #[test]
+    fn test_one_way_anova() {
+        let r = one_way_anova(&groups()).unwrap();
+        assert_almost_eq!(r.between.ss, 27.897333333333333, 1e-12);
+        assert_eq!(r.between.df, 2.0);
+        assert_almost_eq!(r.within.ss, 17.452, 1e-12);
+        assert_eq!(r.within.df, 12.0);
+        assert_almost_eq!(r.between.f.unwrap(), 9.5911070364428145771, 1e-12);
+        assert_almost_eq!(r.between.p_value.unwrap(), 0.0032482226008592988141, 1e-14);
+        assert_eq!(r.within.f, None);
+        assert_eq!(r.within.p_value, None);
+    }
+
+    #[test]
+    fn test_one_way_anova_nist_sirstv() {
+        let r = one_way_anova(&testing::load_groups("nist/sirstv.txt")).unwrap();
+        assert_almost_eq!(r.between.ss, 5.11462616000000E-02, 1e-13);
+        assert_almost_eq!(r.between.ms, 1.27865654000000E-02, 1e-13);
+        assert_almost_eq!(r.within.ss, 2.16636560000000E-01, 1e-13);
+        assert_almost_eq!(r.within.ms, 1.08318280000000E-02, 1e-13);
+        assert_almost_eq!(r.between.f.unwrap(), 1.18046237440255E+00, 1e-12);
+        assert_almost_eq!(r.r_squared(), 1.90999039051129E-01, 1e-13);
+    }
+
+    #[test]
+    fn test_one_way_anova_nist_smls() {
+        let r = one_way_anova(&testing::load_groups("nist/smls01.txt")).unwrap();
+        assert_almost_eq!(r.between.ss, 1.68, 1e-13);
+        assert_almost_eq!(r.within.ss, 1.8, 1e-13);
+        assert_almost_eq!(r.between.f.unwrap(), 21.0, 1e-12);
+        assert_almost_eq!(r.r_squared(), 0.482758620689655, 1e-14);
+
+        let r = one_way_anova(&testing::load_groups("nist/smls04.txt")).unwrap();
+        assert_almost_eq!(r.between.ss, 1.68, 1e-8);
+        assert_almost_eq!(r.within.ss, 1.8, 1e-8);
+        assert_almost_eq!(r.between.f.unwrap(), 21.0, 1e-8);
+
+        // the data are only representable to about 1e-4 at this magnitude
+        let r = one_way_anova(&testing::load_groups("nist/smls07.txt")).unwrap();
+        assert_almost_eq!(r.between.ss, 1.68, 1e-3);
+        assert_almost_eq!(r.within.ss, 1.8, 1e-3);
+        assert_almost_eq!(r.between.f.unwrap(), 21.0, 1e-2);
+    }
+
+    #[test]
+    fn test_one_way_anova_bad() {
+        assert!(one_way_anova(&[vec![1.0, 2.0]]).is_err());
+        assert!(one_way_anova(&[vec![1.0, 2.0], vec![]]).is_err());
+        assert!(one_way_anova(&[vec![1.0], vec![2.0]]).is_err());
+        assert!(one_way_anova(&[vec![1.0, 1.0], vec![2.0, 2.0]]).is_err());
+    }
+
+    #[test]
+    fn test_welch_anova() {
+        let mut g = groups();
+        g[2].extend_from_slice(&[12.4, 4.2]);
+        let r = welch_anova(&g).unwrap();
+        assert_almost_eq!(r.statistic, 6.9494843102820794989, 1e-12);
+        assert_eq!(r.df1, 2.0);
+        assert_almost_eq!(r.df2, 9.3233713537825958546, 1e-12);
+        assert_almost_eq!(r.p_value, 0.014204310219767786133, 1e-13);
+        assert!(welch_anova(&[vec![1.0, 2.0], vec![3.0]]).is_err());
+        assert!(welch_anova(&[vec![1.0, 2.0], vec![3.0, 3.0]]).is_err());
+    }
+
+    #[test]
+    fn test_two_way_anova() {
+        let cells = [
+            [vec![4.1, 3.9, 4.3], vec![5.2, 5.0, 5.5]],
+            [vec![4.6, 4.4, 4.9], vec![6.8, 7.1, 6.6]],
+        ];
+        let r = two_way_anova(&cells).unwrap();
+        assert_almost_eq!(r.factor_a.ss, 3.4133333333333336, 1e-12);
+        assert_almost_eq!(r.factor_a.f.unwrap(), 59.362318840579710145, 1e-10);
+        assert_almost_eq!(r.factor_a.p_value.unwrap(), 0.000057187505512935430155, 1e-16);
+        assert_almost_eq!(r.factor_b.ss, 8.333333333333334, 1e-12);
+        assert_almost_eq!(r.factor_b.p_value.unwrap(), 2.0920464595837722738e-6, 1e-17);
+        let interaction = r.interaction.unwrap();
+        assert_almost_eq!(interaction.ss, 0.8533333333333334, 1e-12);
+        assert_almost_eq!(interaction.f.unwrap(), 14.840579710144927536, 1e-10);
+        assert_almost_eq!(interaction.p_value.unwrap(), 0.0048613327172924546755, 1e-14);
+        assert_almost_eq!(r.residual.ss, 0.46, 1e-12);
+        assert_eq!(r.residual.df, 8.0);
+    }
+
+    #[test]
+    fn test_two_way_anova_single_replicate() {
+        let cells = [
+            [[4.1], [5.2], [3.3]],
+            [[4.6], [6.8], [4.0]],
+            [[5.0], [7.7], [4.1]],
+        ];
+        let r = two_way_anova(&cells).unwrap();
+        assert_eq!(r.interaction, None);
+        assert_almost_eq!(r.residual.ss, 0.9444444444444444, 1e-12);
+        assert_eq!(r.residual.df, 4.0);
+        assert_almost_eq!(r.factor_a.f.unwrap(), 6.4564705882352941176, 1e-11);
+        assert_almost_eq!(r.factor_a.p_value.unwrap(), 0.055934750584431047975, 1e-13);
+        assert_almost_eq!(r.factor_b.ss, 12.242222222222223, 1e-12);
+        assert_almost_eq!(r.factor_b.p_value.unwrap(), 0.0051295914422766826508, 1e-14);
+    }
+
+    #[test]
+    fn test_two_way_anova_bad() {
+        assert!(two_way_anova(&[[vec![1.0, 2.0], vec![3.0, 4.0]]]).is_err());
+        assert!(two_way_anova(&[vec![vec![1.0, 2.0], vec![3.0, 4.0]], vec![vec![1.0, 2.0]]]).is_err());
+        assert!(two_way_anova(&[[vec![1.0, 2.0], vec![3.0]], [vec![1.0, 2.0], vec![3.0, 4.0]]]).is_err());
+        assert!(two_way_anova(&[[[1.0], [2.0]], [[2.0], [3.0]]]).is_err());
+    }
+
+    #[test]
+    fn test_tukey_hsd() {
+        let r = tukey_hsd(&groups(), 0.95).unwrap();
+        assert_eq!(r.len(), 3);
+        assert_eq!((r[1].i, r[1].j), (0, 2));
+        assert_almost_eq!(r[0].estimate, -2.38, 1e-12);
+        assert_almost_eq!(r[0].std_err, (1.4543333333333333f64 * 0.4).sqrt(), 1e-14);
+        assert_almost_eq!(r[0].p_value, 0.0223404071385320084, 1e-10);
+        assert_almost_eq!(r[2].p_value, 0.531303389026691386, 1e-10);
+        // q(0.95; 3, 12) = 3.773
+        let half_width = 3.773 / 2f64.sqrt() * r[0].std_err;
+        assert_almost_eq!(r[0].confidence_interval.upper - r[0].estimate, half_width, 1e-3);
+        assert!(tukey_hsd(&groups(), 1.0).is_err());
+    }
+}
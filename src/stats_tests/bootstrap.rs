@@ -0,0 +1,543 @@
+use crate::distribution::{ContinuousCDF, Normal};
+use crate::statistics::{Data, OrderStatistics, Statistics};
+use crate::stats_tests::{check_level, ConfidenceInterval};
+use crate::{Result, StatsError};
+use rand::distributions::Distribution;
+use rand::Rng;
+
+/// The result of a nonparametric bootstrap of a statistic, from which
+/// confidence intervals for the statistic can be constructed
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bootstrap {
+    /// The statistic evaluated on the original data
+    pub estimate: f64,
+    /// The statistic evaluated on each resample, in the order drawn
+    pub replicates: Vec<f64>,
+    /// The statistic evaluated on the original data with each observation
+    /// left out in turn
+    pub jackknife: Vec<f64>,
+    /// The standard error of the statistic on the original data, only
+    /// computed by `bootstrap_studentized`
+    pub estimate_std_err: Option<f64>,
+    /// The standard error of the statistic on each resample, only computed
+    /// by `bootstrap_studentized` and empty otherwise
+    pub replicate_std_errs: Vec<f64>,
+}
+
+/// Resamples `data` with replacement `resamples` times using `rng` and
+/// evaluates `statistic` on every resample. The statistic is also
+/// evaluated on every leave-one-out subsample of the original data, which
+/// provides the acceleration of the BCa interval.
+///
+/// The studentized interval additionally needs a standard error for every
+/// resample and is only available from `bootstrap_studentized`.
+///
+/// # Errors
+///
+/// Returns an error if `data` has fewer than two elements or if
+/// `resamples` is zero
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::statistics::{Data, OrderStatistics};
+/// use statrs::stats_tests::bootstrap;
+///
+/// let x = [2.1, 3.4, 1.9, 5.6, 2.8, 3.3, 4.1, 2.5, 3.9, 2.2];
+/// let median = |s: &[f64]| Data::new(s.to_vec()).median();
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let boot = bootstrap(&x, median, 2000, &mut rng).unwrap();
+/// assert!((boot.estimate - 3.05).abs() < 1e-12);
+///
+/// let ci = boot.bca_interval(0.9).unwrap();
+/// assert!(ci.contains(boot.estimate));
+/// ```
+pub fn bootstrap<F, R>(
+    data: &[f64],
+    statistic: F,
+    resamples: usize,
+    rng: &mut R,
+) -> Result<Bootstrap>
+where
+    F: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    resample(data, statistic, None::<fn(&[f64]) -> f64>, resamples, rng)
+}
+
+/// Performs the same resampling as `bootstrap` and additionally evaluates
+/// `std_err` on the original data and on every resample, as required by
+/// `Bootstrap::studentized_interval`.
+///
+/// `std_err` should estimate the standard error of `statistic` on the
+/// sample it is given, e.g. `s / √n` for the mean. `jackknife_std_err`
+/// provides a generic choice for smooth statistics, at the cost of
+/// `n + 1` further evaluations of the statistic per resample.
+///
+/// # Errors
+///
+/// Returns an error if `data` has fewer than two elements or if
+/// `resamples` is zero
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::statistics::Statistics;
+/// use statrs::stats_tests::bootstrap_studentized;
+///
+/// let x = [2.1, 3.4, 1.9, 5.6, 2.8, 3.3, 4.1, 2.5, 3.9, 2.2];
+/// let mean = |s: &[f64]| s.mean();
+/// let std_err = |s: &[f64]| s.std_dev() / (s.len() as f64).sqrt();
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let boot = bootstrap_studentized(&x, mean, std_err, 2000, &mut rng).unwrap();
+///
+/// let ci = boot.studentized_interval(0.9).unwrap();
+/// assert!(ci.contains(boot.estimate));
+/// ```
+pub fn bootstrap_studentized<F, G, R>(
+    data: &[f64],
+    statistic: F,
+    std_err: G,
+    resamples: usize,
+    rng: &mut R,
+) -> Result<Bootstrap>
+where
+    F: FnMut(&[f64]) -> f64,
+    G: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    resample(data, statistic, Some(std_err), resamples, rng)
+}
+
+fn resample<F, G, R>(
+    data: &[f64],
+    mut statistic: F,
+    mut std_err: Option<G>,
+    resamples: usize,
+    rng: &mut R,
+) -> Result<Bootstrap>
+where
+    F: FnMut(&[f64]) -> f64,
+    G: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    if data.len() < 2 {
+        return Err(StatsError::ArgGte("data.len()", 2.0));
+    }
+    if resamples == 0 {
+        return Err(StatsError::ArgMustBePositive("resamples"));
+    }
+    let source = Data::new(data.to_vec());
+    let mut sample = vec![0.0; data.len()];
+    let mut replicates = Vec::with_capacity(resamples);
+    let mut replicate_std_errs = Vec::new();
+    for _ in 0..resamples {
+        for x in sample.iter_mut() {
+            *x = source.sample(rng);
+        }
+        replicates.push(statistic(&sample));
+        if let Some(std_err) = std_err.as_mut() {
+            replicate_std_errs.push(std_err(&sample));
+        }
+    }
+    let mut buffer = vec![0.0; data.len() - 1];
+    Ok(Bootstrap {
+        estimate: statistic(data),
+        replicates,
+        jackknife: jackknife(data, &mut statistic, &mut buffer),
+        estimate_std_err: std_err.as_mut().map(|std_err| std_err(data)),
+        replicate_std_errs,
+    })
+}
+
+/// Computes the jackknife standard error of `statistic` on `data` from its
+/// values on the leave-one-out subsamples, for use as the standard error
+/// function of `bootstrap_studentized`
+///
+/// Note that the jackknife standard error is only consistent for smooth
+/// statistics. It is not consistent for the median or other quantiles,
+/// where it is frequently zero on resamples with repeated values, and a
+/// dedicated standard error should be used for those instead.
+///
+/// # Panics
+///
+/// If `data` has fewer than two elements
+pub fn jackknife_std_err<F: FnMut(&[f64]) -> f64>(data: &[f64], mut statistic: F) -> f64 {
+    assert!(data.len() >= 2, "data must have at least two elements");
+    let mut buffer = vec![0.0; data.len() - 1];
+    leave_one_out_std_err(&jackknife(data, &mut statistic, &mut buffer))
+}
+
+/// Evaluates `statistic` on `data` with each element left out in turn,
+/// using `buffer` of length `data.len() - 1` as scratch space
+fn jackknife<F: FnMut(&[f64]) -> f64>(
+    data: &[f64],
+    statistic: &mut F,
+    buffer: &mut [f64],
+) -> Vec<f64> {
+    buffer.copy_from_slice(&data[1..]);
+    let mut values = Vec::with_capacity(data.len());
+    values.push(statistic(buffer));
+    for i in 1..data.len() {
+        // buffer now holds data without its i-th element
+        buffer[i - 1] = data[i - 1];
+        values.push(statistic(buffer));
+    }
+    values
+}
+
+/// Computes the jackknife standard error from the leave-one-out values
+/// of a statistic
+fn leave_one_out_std_err(values: &[f64]) -> f64 {
+    let n = values.len() as f64;
+    let mean = values.mean();
+    let ss: f64 = values.iter().map(|v| (v - mean) * (v - mean)).sum();
+    (ss * (n - 1.0) / n).sqrt()
+}
+
+/// Computes the quantile `tau` of `values` using the same definition as
+/// `OrderStatistics::quantile`
+fn quantile(values: &[f64], tau: f64) -> f64 {
+    Data::new(values.to_vec()).quantile(tau)
+}
+
+impl Bootstrap {
+    /// Returns the bootstrap standard error, i.e. the sample standard
+    /// deviation of the replicates
+    pub fn std_err(&self) -> f64 {
+        self.replicates.iter().std_dev()
+    }
+
+    /// Returns the bootstrap estimate of the bias of the statistic, i.e.
+    /// the mean of the replicates minus the estimate
+    pub fn bias(&self) -> f64 {
+        self.replicates.iter().mean() - self.estimate
+    }
+
+    /// Returns the jackknife standard error of the statistic on the
+    /// original data
+    pub fn jackknife_std_err(&self) -> f64 {
+        leave_one_out_std_err(&self.jackknife)
+    }
+
+    /// Returns the percentile interval of level `level`, bounded by the
+    /// `α / 2` and `1 - α / 2` quantiles of the replicates where
+    /// `α = 1 - level`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `level` is not in `(0, 1)`
+    pub fn percentile_interval(&self, level: f64) -> Result<ConfidenceInterval> {
+        check_level(level)?;
+        let alpha = 1.0 - level;
+        Ok(ConfidenceInterval {
+            lower: quantile(&self.replicates, alpha / 2.0),
+            upper: quantile(&self.replicates, 1.0 - alpha / 2.0),
+            level,
+        })
+    }
+
+    /// Returns the basic (reverse percentile) interval of level `level`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// [2θ̂ - q(1 - α / 2), 2θ̂ - q(α / 2)]
+    /// ```
+    ///
+    /// where `θ̂` is the estimate and `q` the quantile function of the
+    /// replicates
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `level` is not in `(0, 1)`
+    pub fn basic_interval(&self, level: f64) -> Result<ConfidenceInterval> {
+        let percentile = self.percentile_interval(level)?;
+        Ok(ConfidenceInterval {
+            lower: 2.0 * self.estimate - percentile.upper,
+            upper: 2.0 * self.estimate - percentile.lower,
+            level,
+        })
+    }
+
+    /// Returns the studentized (bootstrap-t) interval of level `level`,
+    /// using the standard errors computed by `bootstrap_studentized`
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// t*_b = (θ*_b - θ̂) / se*_b
+    /// [θ̂ - q(1 - α / 2) se, θ̂ - q(α / 2) se]
+    /// ```
+    ///
+    /// where `θ*_b` and `se*_b` are the statistic and its standard error
+    /// on resample `b`, `se` is the standard error of the estimate `θ̂`
+    /// and `q` the quantile function of the `t*_b`. Resamples whose pivot
+    /// is not finite, i.e. those with `se*_b = 0`, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `level` is not in `(0, 1)`, if the standard
+    /// errors were not computed, or if no resample has a finite pivot
+    pub fn studentized_interval(&self, level: f64) -> Result<ConfidenceInterval> {
+        check_level(level)?;
+        let alpha = 1.0 - level;
+        let std_err = self.estimate_std_err.ok_or(StatsError::SpecialCase(
+            "studentized interval requires the standard errors of bootstrap_studentized",
+        ))?;
+        if std_err == 0.0 {
+            return Ok(ConfidenceInterval {
+                lower: self.estimate,
+                upper: self.estimate,
+                level,
+            });
+        }
+        let pivots: Vec<f64> = self
+            .replicates
+            .iter()
+            .zip(self.replicate_std_errs.iter())
+            .map(|(x, se)| (x - self.estimate) / se)
+            .filter(|t| t.is_finite())
+            .collect();
+        if pivots.is_empty() {
+            return Err(StatsError::SpecialCase(
+                "no resample has a finite studentized pivot",
+            ));
+        }
+        Ok(ConfidenceInterval {
+            lower: self.estimate - quantile(&pivots, 1.0 - alpha / 2.0) * std_err,
+            upper: self.estimate - quantile(&pivots, alpha / 2.0) * std_err,
+            level,
+        })
+    }
+
+    /// Returns the bias-corrected and accelerated (BCa) interval of level
+    /// `level`, with the acceleration estimated by the jackknife
+    ///
+    /// # Formula
+    ///
+    /// ```ignore
+    /// z0 = Φ^-1(#{θ*_b < θ̂} / B + #{θ*_b = θ̂} / 2B)
+    /// a = Σ (θ̄ - θ_(i))^3 / (6 (Σ (θ̄ - θ_(i))^2)^(3/2))
+    /// α_1 = Φ(z0 + (z0 + z_(α/2)) / (1 - a (z0 + z_(α/2))))
+    /// α_2 = Φ(z0 + (z0 + z_(1-α/2)) / (1 - a (z0 + z_(1-α/2))))
+    /// [q(α_1), q(α_2)]
+    /// ```
+    ///
+    /// where `θ_(i)` are the leave-one-out values with mean `θ̄`, `Φ` is
+    /// the standard normal cdf with quantiles `z` and `q` the quantile
+    /// function of the `B` replicates `θ*_b`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `level` is not in `(0, 1)`
+    pub fn bca_interval(&self, level: f64) -> Result<ConfidenceInterval> {
+        check_level(level)?;
+        let alpha = 1.0 - level;
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let below = self
+            .replicates
+            .iter()
+            .filter(|&&x| x < self.estimate)
+            .count();
+        let ties = self
+            .replicates
+            .iter()
+            .filter(|&&x| x == self.estimate)
+            .count();
+        let fraction = (below as f64 + ties as f64 / 2.0) / self.replicates.len() as f64;
+        let z0 = normal.inverse_cdf(fraction);
+        let a = self.acceleration();
+        let adjust = |z: f64| {
+            if z0.is_infinite() {
+                // all replicates lie on one side of the estimate
+                if z0 > 0.0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            } else {
+                normal.cdf(z0 + (z0 + z) / (1.0 - a * (z0 + z)))
+            }
+        };
+        let z = normal.inverse_cdf(1.0 - alpha / 2.0);
+        Ok(ConfidenceInterval {
+            lower: quantile(&self.replicates, adjust(-z)),
+            upper: quantile(&self.replicates, adjust(z)),
+            level,
+        })
+    }
+
+    /// Returns the jackknife estimate of the acceleration of the BCa
+    /// interval, which is zero if the leave-one-out values are constant
+    fn acceleration(&self) -> f64 {
+        let mean = self.jackknife.iter().mean();
+        let (ss, cubes) = self.jackknife.iter().fold((0.0, 0.0), |(ss, cubes), v| {
+            let d = mean - v;
+            (ss + d * d, cubes + d * d * d)
+        });
+        if ss == 0.0 {
+            0.0
+        } else {
+            cubes / (6.0 * ss.powf(1.5))
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Normal;
+    use crate::stats_tests::{one_sample_t_test, Alternative};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn mean(x: &[f64]) -> f64 {
+        x.mean()
+    }
+
+    fn mean_std_err(x: &[f64]) -> f64 {
+        jackknife_std_err(x, mean)
+    }
+
+    fn normal_sample(n: usize) -> Vec<f64> {
+        let mut rng = StdRng::seed_from_u64(11);
+        let normal = Normal::new(10.0, 2.0).unwrap();
+        (0..n).map(|_| normal.sample(&mut rng)).collect()
+    }
+
+    #[test]
+    fn test_jackknife_mean() {
+        let x = [1.0, 4.0, 2.0, 8.0, 5.0, 7.0];
+        let boot = bootstrap(&x, mean, 10, &mut StdRng::seed_from_u64(1)).unwrap();
+        assert_eq!(boot.estimate, 4.5);
+        assert_eq!(boot.jackknife.len(), 6);
+        assert_almost_eq!(boot.jackknife[0], 5.2, 1e-14);
+        assert_almost_eq!(boot.jackknife[3], 3.8, 1e-14);
+        // the jackknife standard error of the mean is s / √n
+        assert_almost_eq!(boot.jackknife_std_err(), (x.variance() / 6.0).sqrt(), 1e-14);
+        // the acceleration of the mean is a multiple of the sample skewness
+        let d: Vec<f64> = x.iter().map(|v| v - 4.5).collect();
+        let ss: f64 = d.iter().map(|v| v * v).sum();
+        let cubes: f64 = d.iter().map(|v| v * v * v).sum();
+        assert_almost_eq!(boot.acceleration(), cubes / (6.0 * ss.powf(1.5)), 1e-14);
+    }
+
+    #[test]
+    fn test_replicates() {
+        let x = [3.0, 1.0, 2.0];
+        let boot = bootstrap(&x, mean, 500, &mut StdRng::seed_from_u64(2)).unwrap();
+        assert_eq!(boot.replicates.len(), 500);
+        assert!(boot.replicate_std_errs.is_empty());
+        assert_eq!(boot.estimate_std_err, None);
+        let again = bootstrap(&x, mean, 500, &mut StdRng::seed_from_u64(2)).unwrap();
+        assert_eq!(boot, again);
+        // the standard errors do not change the resamples drawn
+        let studentized = bootstrap_studentized(&x, mean, mean_std_err, 500, &mut StdRng::seed_from_u64(2)).unwrap();
+        assert_eq!(studentized.replicates, boot.replicates);
+        assert_eq!(studentized.replicate_std_errs.len(), 500);
+        assert_eq!(studentized.estimate_std_err, Some(boot.jackknife_std_err()));
+        for (&r, &se) in studentized.replicates.iter().zip(studentized.replicate_std_errs.iter()) {
+            assert!((1.0..=3.0).contains(&r));
+            assert!(se >= 0.0);
+        }
+    }
+
+    #[test]
+    fn test_intervals_normal_mean() {
+        let x = normal_sample(60);
+        let boot = bootstrap_studentized(&x, mean, mean_std_err, 4000, &mut StdRng::seed_from_u64(3)).unwrap();
+        let t = one_sample_t_test(&x, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        let expected = t.confidence_interval;
+        assert!(boot.bias().abs() < 0.05);
+        assert!((boot.std_err() / t.std_err - 1.0).abs() < 0.1);
+        for ci in [
+            boot.percentile_interval(0.95).unwrap(),
+            boot.basic_interval(0.95).unwrap(),
+            boot.studentized_interval(0.95).unwrap(),
+            boot.bca_interval(0.95).unwrap(),
+        ] {
+            assert_eq!(ci.level, 0.95);
+            assert!(ci.lower < boot.estimate && boot.estimate < ci.upper);
+            assert!((ci.lower - expected.lower).abs() < 0.1 * expected.width());
+            assert!((ci.upper - expected.upper).abs() < 0.1 * expected.width());
+        }
+    }
+
+    #[test]
+    fn test_basic_reflects_percentile() {
+        let x = [0.2, 0.9, 1.4, 3.8, 0.5, 2.2, 7.1, 0.8, 1.1, 0.3];
+        let boot = bootstrap(&x, mean, 1000, &mut StdRng::seed_from_u64(4)).unwrap();
+        let percentile = boot.percentile_interval(0.9).unwrap();
+        let basic = boot.basic_interval(0.9).unwrap();
+        assert_almost_eq!(basic.lower, 2.0 * boot.estimate - percentile.upper, 1e-14);
+        assert_almost_eq!(basic.upper, 2.0 * boot.estimate - percentile.lower, 1e-14);
+        // for right-skewed data the BCa interval is shifted to the right
+        // of the basic interval
+        let bca = boot.bca_interval(0.9).unwrap();
+        assert!(bca.lower > basic.lower);
+        assert!(bca.upper > basic.upper);
+    }
+
+    #[test]
+    fn test_constant_data() {
+        let x = [2.5; 8];
+        let boot = bootstrap_studentized(&x, mean, mean_std_err, 100, &mut StdRng::seed_from_u64(5)).unwrap();
+        assert_eq!(boot.std_err(), 0.0);
+        assert_eq!(boot.bias(), 0.0);
+        for ci in [
+            boot.percentile_interval(0.95).unwrap(),
+            boot.basic_interval(0.95).unwrap(),
+            boot.studentized_interval(0.95).unwrap(),
+            boot.bca_interval(0.95).unwrap(),
+        ] {
+            assert_eq!(ci.lower, 2.5);
+            assert_eq!(ci.upper, 2.5);
+        }
+    }
+
+    #[test]
+    fn test_studentized_median() {
+        // many resamples of the median have a jackknife standard error of
+        // zero, whose infinite pivots must not leak into the interval
+        let x = [2.1, 3.4, 1.9, 5.6, 2.8, 3.3, 4.1, 2.5, 3.9, 2.2, 3.0, 4.4];
+        let median = |s: &[f64]| Data::new(s.to_vec()).median();
+        let std_err = |s: &[f64]| jackknife_std_err(s, median);
+        let boot = bootstrap_studentized(&x, median, std_err, 1000, &mut StdRng::seed_from_u64(8)).unwrap();
+        assert!(boot.replicate_std_errs.contains(&0.0));
+        let ci = boot.studentized_interval(0.9).unwrap();
+        assert!(ci.lower.is_finite() && ci.upper.is_finite());
+        assert!(ci.lower < boot.estimate && boot.estimate < ci.upper);
+    }
+
+    #[test]
+    fn test_studentized_no_finite_pivot() {
+        let boot = Bootstrap {
+            estimate: 1.0,
+            replicates: vec![2.0, 0.5],
+            jackknife: vec![1.0, 1.0],
+            estimate_std_err: Some(0.5),
+            replicate_std_errs: vec![0.0, 0.0],
+        };
+        assert!(boot.studentized_interval(0.9).is_err());
+    }
+
+    #[test]
+    fn test_bootstrap_bad() {
+        let mut rng = StdRng::seed_from_u64(6);
+        assert!(bootstrap(&[1.0], mean, 10, &mut rng).is_err());
+        assert!(bootstrap(&[1.0, 2.0], mean, 0, &mut rng).is_err());
+        let boot = bootstrap(&[1.0, 2.0], mean, 10, &mut rng).unwrap();
+        assert!(boot.percentile_interval(0.0).is_err());
+        assert!(boot.basic_interval(1.0).is_err());
+        assert!(boot.studentized_interval(f64::NAN).is_err());
+        // the standard errors are only computed on request
+        assert!(boot.studentized_interval(0.9).is_err());
+        assert!(boot.bca_interval(1.5).is_err());
+    }
+}
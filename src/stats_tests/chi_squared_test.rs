@@ -0,0 +1,285 @@
+use crate::distribution::{ChiSquared, ContinuousCDF};
+use crate::{Result, StatsError};
+
+/// The statistic used to compare observed and expected counts
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ChiSquaredStatistic {
+    /// Pearson's chi-squared statistic `Σ (o - e)^2 / e`
+    Pearson,
+    /// The log-likelihood ratio, or G, statistic `2 Σ o ln(o / e)`
+    LikelihoodRatio,
+}
+
+/// The result of a test whose statistic asymptotically follows a
+/// chi-squared distribution under the null hypothesis
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ChiSquaredTestResult {
+    /// The test statistic
+    pub statistic: f64,
+    /// The degrees of freedom of the null chi-squared distribution
+    pub df: f64,
+    /// The upper-tail p-value of the statistic
+    pub p_value: f64,
+}
+
+impl ChiSquaredTestResult {
+    pub(super) fn new(statistic: f64, df: f64) -> Result<ChiSquaredTestResult> {
+        let dist = ChiSquared::new(df)?;
+        Ok(ChiSquaredTestResult {
+            statistic,
+            df,
+            p_value: dist.sf(statistic),
+        })
+    }
+}
+
+/// Computes the chi-squared or G statistic over pairs of observed and
+/// expected counts
+fn divergence<I: Iterator<Item = (f64, f64)>>(pairs: I, statistic: ChiSquaredStatistic) -> f64 {
+    match statistic {
+        ChiSquaredStatistic::Pearson => pairs.map(|(o, e)| (o - e) * (o - e) / e).sum(),
+        ChiSquaredStatistic::LikelihoodRatio => {
+            2.0 * pairs
+                .filter(|&(o, _)| o > 0.0)
+                .map(|(o, e)| o * (o / e).ln())
+                .sum::<f64>()
+        }
+    }
+}
+
+/// Performs a goodness-of-fit test of the null hypothesis that the
+/// category counts `observed` were drawn from a population with expected
+/// counts `expected`
+///
+/// `ddof` is the number of parameters estimated from the data to obtain
+/// `expected`, which reduces the degrees of freedom to
+/// `observed.len() - 1 - ddof`.
+///
+/// # Formula
+///
+/// ```ignore
+/// X^2 = Σ (o_i - e_i)^2 / e_i      // Pearson
+/// G = 2 Σ o_i ln(o_i / e_i)        // likelihood ratio
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `observed` and `expected` have different lengths,
+/// if any expected count is not positive, if the total expected count
+/// differs from the total observed count, or if there are no degrees of
+/// freedom left
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{chi_squared_gof, ChiSquaredStatistic};
+///
+/// let observed = [43, 52, 54, 40, 59, 52];
+/// let expected = [50.0; 6];
+/// let result = chi_squared_gof(&observed, &expected, 0, ChiSquaredStatistic::Pearson).unwrap();
+/// assert_eq!(result.df, 5.0);
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn chi_squared_gof(
+    observed: &[u64],
+    expected: &[f64],
+    ddof: usize,
+    statistic: ChiSquaredStatistic,
+) -> Result<ChiSquaredTestResult> {
+    if observed.len() != expected.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if expected.iter().any(|&e| e.is_nan() || e <= 0.0) {
+        return Err(StatsError::ArgMustBePositive("expected"));
+    }
+    let total_obs = observed.iter().sum::<u64>() as f64;
+    let total_exp = expected.iter().sum::<f64>();
+    if (total_obs - total_exp).abs() > 1e-8 * total_obs.max(total_exp) {
+        return Err(StatsError::ContainerExpectedSumVar("expected", "observed"));
+    }
+    if observed.len() < ddof + 2 {
+        return Err(StatsError::ArgGte("observed.len()", (ddof + 2) as f64));
+    }
+    let stat = divergence(
+        observed.iter().zip(expected).map(|(&o, &e)| (o as f64, e)),
+        statistic,
+    );
+    ChiSquaredTestResult::new(stat, (observed.len() - 1 - ddof) as f64)
+}
+
+/// Performs a goodness-of-fit test of the null hypothesis that the
+/// category counts `observed` were drawn from a multinomial distribution
+/// with category probabilities `p`, e.g. the parameters of a `Multinomial`
+/// or `Categorical` distribution
+///
+/// The elements of `p` do not need to be normalized. `ddof` is the number
+/// of parameters estimated from the data to obtain `p`.
+///
+/// # Errors
+///
+/// Returns an error if `observed` and `p` have different lengths, if any
+/// probability is not positive, if `observed` sums to zero, or if there
+/// are no degrees of freedom left
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Multinomial;
+/// use statrs::stats_tests::{chi_squared_gof_probs, ChiSquaredStatistic};
+///
+/// let model = Multinomial::new(&[9.0, 3.0, 3.0, 1.0], 556).unwrap();
+/// let observed = [315, 108, 101, 32];
+/// let result = chi_squared_gof_probs(&observed, model.p(), 0, ChiSquaredStatistic::Pearson).unwrap();
+/// assert!(result.p_value > 0.9);
+/// ```
+pub fn chi_squared_gof_probs(
+    observed: &[u64],
+    p: &[f64],
+    ddof: usize,
+    statistic: ChiSquaredStatistic,
+) -> Result<ChiSquaredTestResult> {
+    if p.iter().any(|&x| x.is_nan() || x <= 0.0) {
+        return Err(StatsError::ArgMustBePositive("p"));
+    }
+    let total = observed.iter().sum::<u64>() as f64;
+    if total == 0.0 {
+        return Err(StatsError::ArgMustBePositive("observed total"));
+    }
+    let scale = total / p.iter().sum::<f64>();
+    let expected: Vec<f64> = p.iter().map(|x| x * scale).collect();
+    chi_squared_gof(observed, &expected, ddof, statistic)
+}
+
+/// Performs a test of independence of the rows and columns of the `r x c`
+/// contingency table `table`
+///
+/// The expected count of each cell is `row_total * col_total / total`
+/// and the statistic has `(r - 1)(c - 1)` degrees of freedom. If
+/// `correction` is true and there is a single degree of freedom, Yates'
+/// continuity correction moves each observed count by up to `0.5`
+/// towards its expected count before the statistic is computed.
+///
+/// # Errors
+///
+/// Returns an error if the table has fewer than two rows or columns, if
+/// the rows have different lengths, or if any row or column sums to zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{chi_squared_independence, ChiSquaredStatistic};
+///
+/// let table = [[12, 5], [7, 19]];
+/// let result = chi_squared_independence(&table, true, ChiSquaredStatistic::Pearson).unwrap();
+/// assert_eq!(result.df, 1.0);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn chi_squared_independence<R: AsRef<[u64]>>(
+    table: &[R],
+    correction: bool,
+    statistic: ChiSquaredStatistic,
+) -> Result<ChiSquaredTestResult> {
+    if table.len() < 2 {
+        return Err(StatsError::ArgGte("table rows", 2.0));
+    }
+    let cols = table[0].as_ref().len();
+    if cols < 2 {
+        return Err(StatsError::ArgGte("table columns", 2.0));
+    }
+    if table.iter().any(|row| row.as_ref().len() != cols) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let row_totals: Vec<f64> = table
+        .iter()
+        .map(|row| row.as_ref().iter().sum::<u64>() as f64)
+        .collect();
+    let col_totals: Vec<f64> = (0..cols)
+        .map(|j| table.iter().map(|row| row.as_ref()[j]).sum::<u64>() as f64)
+        .collect();
+    if row_totals.iter().chain(&col_totals).any(|&t| t == 0.0) {
+        return Err(StatsError::ArgMustBePositive("table margins"));
+    }
+    let total: f64 = row_totals.iter().sum();
+    let df = ((table.len() - 1) * (cols - 1)) as f64;
+    let yates = correction && df == 1.0;
+    let pairs = table.iter().zip(&row_totals).flat_map(|(row, rt)| {
+        row.as_ref().iter().zip(&col_totals).map(move |(&o, ct)| {
+            let o = o as f64;
+            let e = rt * ct / total;
+            if yates {
+                let shift = (e - o).abs().min(0.5);
+                (o + shift.copysign(e - o), e)
+            } else {
+                (o, e)
+            }
+        })
+    });
+    ChiSquaredTestResult::new(divergence(pairs, statistic), df)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stats_tests::ChiSquaredStatistic::{LikelihoodRatio, Pearson};
+
+    const DICE: [u64; 6] = [43, 52, 54, 40, 59, 52];
+
+    fn check(r: ChiSquaredTestResult, stat: f64, df: f64, p: f64) {
+        assert_almost_eq!(r.statistic, stat, 1e-12);
+        assert_eq!(r.df, df);
+        assert_almost_eq!(r.p_value, p, 1e-14);
+    }
+
+    #[test]
+    fn test_chi_squared_gof() {
+        let uniform = [50.0; 6];
+        check(chi_squared_gof(&DICE, &uniform, 0, Pearson).unwrap(), 5.08, 5.0, 0.40619516943261983849);
+        check(chi_squared_gof(&DICE, &uniform, 0, LikelihoodRatio).unwrap(), 5.1781518966270558941, 5.0, 0.39452763529950650936);
+        let expected = [40.0, 45.0, 50.0, 55.0, 60.0, 50.0];
+        check(chi_squared_gof(&DICE, &expected, 2, Pearson).unwrap(), 5.8214646464646464646, 3.0, 0.12062691210265763057);
+    }
+
+    #[test]
+    fn test_chi_squared_gof_errors() {
+        assert!(chi_squared_gof(&DICE, &[50.0; 5], 0, Pearson).is_err());
+        assert!(chi_squared_gof(&DICE, &[60.0; 6], 0, Pearson).is_err());
+        assert!(chi_squared_gof(&DICE, &[0.0, 60.0, 60.0, 60.0, 60.0, 60.0], 0, Pearson).is_err());
+        assert!(chi_squared_gof(&DICE, &[50.0; 6], 4, Pearson).is_ok());
+        assert!(chi_squared_gof(&DICE, &[50.0; 6], 5, Pearson).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared_gof_probs() {
+        let observed = [315, 108, 101, 32];
+        let p = [9.0, 3.0, 3.0, 1.0];
+        check(chi_squared_gof_probs(&observed, &p, 0, Pearson).unwrap(), 0.47002398081534772182, 3.0, 0.92542589510361596739);
+        check(chi_squared_gof_probs(&observed, &p, 0, LikelihoodRatio).unwrap(), 0.47544523899826314612, 3.0, 0.92425190397452604708);
+        assert!(chi_squared_gof_probs(&observed, &[9.0, 3.0, 3.0, 0.0], 0, Pearson).is_err());
+        assert!(chi_squared_gof_probs(&[0, 0, 0, 0], &p, 0, Pearson).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared_independence() {
+        let table = [[12, 5], [7, 19]];
+        check(chi_squared_independence(&table, true, Pearson).unwrap(), 6.2749402139398269429, 1.0, 0.012245725251445836722);
+        check(chi_squared_independence(&table, false, Pearson).unwrap(), 7.9468673096769072001, 1.0, 0.0048170688312911455225);
+        check(chi_squared_independence(&table, true, LikelihoodRatio).unwrap(), 6.3849444329585057972, 1.0, 0.011509236433067999259);
+        check(chi_squared_independence(&table, false, LikelihoodRatio).unwrap(), 8.1412332239439630456, 1.0, 0.0043269845601709974042);
+
+        // the correction only applies to tables with one degree of freedom
+        let table = vec![vec![20, 15, 30], vec![25, 10, 5], vec![8, 12, 40]];
+        check(chi_squared_independence(&table, true, Pearson).unwrap(), 33.913969847932112083, 4.0, 7.7608347438109180169e-7);
+        check(chi_squared_independence(&table, false, LikelihoodRatio).unwrap(), 36.429564795839206788, 4.0, 2.3607825484417876692e-7);
+        check(chi_squared_independence(&[[0, 5, 3], [4, 2, 6]], false, LikelihoodRatio).unwrap(), 7.08743741087002542, 2.0, 0.028905635425965921062);
+    }
+
+    #[test]
+    fn test_chi_squared_independence_errors() {
+        assert!(chi_squared_independence(&[[1, 2]], false, Pearson).is_err());
+        assert!(chi_squared_independence(&[[1], [2]], false, Pearson).is_err());
+        assert!(chi_squared_independence(&[vec![1, 2], vec![3]], false, Pearson).is_err());
+        assert!(chi_squared_independence(&[[1, 0], [2, 0]], false, Pearson).is_err());
+        assert!(chi_squared_independence(&[[0, 0], [2, 1]], false, Pearson).is_err());
+    }
+}
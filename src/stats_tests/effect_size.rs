@@ -0,0 +1,656 @@
+use crate::distribution::{ContinuousCDF, Normal};
+use crate::function::gamma::ln_gamma;
+use crate::function::noncentral::{noncentral_chi_squared_cdf, noncentral_f_cdf, noncentral_t_cdf};
+use crate::statistics::Statistics;
+use crate::stats_tests::{
+    bisect, check_level, chi_squared_independence, one_way_anova, ChiSquaredStatistic,
+    ConfidenceInterval, OneWayAnova,
+};
+use crate::{Result, StatsError};
+
+/// An estimated effect size together with a confidence interval
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EffectSize {
+    /// The point estimate of the effect size
+    pub estimate: f64,
+    /// The two-sided confidence interval for the effect size
+    pub confidence_interval: ConfidenceInterval,
+}
+
+/// Finds the noncentrality `ncp >= min` at which the continuous function
+/// `cdf`, which decreases in the noncentrality, equals `p`, searching
+/// outwards from `start`
+fn solve_noncentrality<F: Fn(f64) -> f64>(cdf: F, p: f64, start: f64, min: f64) -> f64 {
+    if min.is_finite() && cdf(min) <= p {
+        return min;
+    }
+    let (mut lo, mut hi) = (start, start);
+    let mut step = 1.0;
+    while cdf(hi) > p {
+        lo = hi;
+        hi += step;
+        step *= 2.0;
+    }
+    while cdf(lo) < p {
+        hi = lo;
+        lo = (lo - step).max(min);
+        step *= 2.0;
+    }
+    bisect(|ncp| cdf(ncp) - p, lo, hi)
+}
+
+/// Computes the equal-tailed confidence interval of level `level` for the
+/// noncentrality of a distribution from an observed statistic, where
+/// `cdf(ncp)` is the cdf of the statistic at its observed value
+fn noncentrality_interval<F: Fn(f64) -> f64>(
+    cdf: F,
+    estimate: f64,
+    min: f64,
+    level: f64,
+) -> (f64, f64) {
+    let alpha = 1.0 - level;
+    (
+        solve_noncentrality(&cdf, 1.0 - alpha / 2.0, estimate, min),
+        solve_noncentrality(&cdf, alpha / 2.0, estimate, min),
+    )
+}
+
+/// Returns the interval `estimate ± z * std_err` for the standard normal
+/// quantile `z` of the two-sided level `level`
+fn normal_interval(estimate: f64, std_err: f64, level: f64) -> (f64, f64) {
+    let z = Normal::new(0.0, 1.0)
+        .unwrap()
+        .inverse_sf((1.0 - level) / 2.0);
+    (estimate - z * std_err, estimate + z * std_err)
+}
+
+fn effect_size(estimate: f64, (lower, upper): (f64, f64), level: f64) -> EffectSize {
+    EffectSize {
+        estimate,
+        confidence_interval: ConfidenceInterval {
+            lower,
+            upper,
+            level,
+        },
+    }
+}
+
+/// Returns the pooled standard deviation of `x` and `y` and the scale
+/// `√(1 / n_x + 1 / n_y)` relating Cohen's `d` to the t statistic
+fn pooled_std_dev(x: &[f64], y: &[f64]) -> Result<(f64, f64)> {
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if y.len() < 2 {
+        return Err(StatsError::ArgGte("y.len()", 2.0));
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let pooled = ((nx - 1.0) * x.variance() + (ny - 1.0) * y.variance()) / (nx + ny - 2.0);
+    if pooled.is_nan() || pooled <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    Ok((pooled.sqrt(), (1.0 / nx + 1.0 / ny).sqrt()))
+}
+
+/// Computes Cohen's `d`, the standardized difference between the means of
+/// `x` and `y`, with a confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// s_p^2 = ((n_x - 1) * s_x^2 + (n_y - 1) * s_y^2) / (n_x + n_y - 2)
+/// d = (x̄ - ȳ) / s_p
+/// ```
+///
+/// The confidence interval is exact for normal populations with equal
+/// variances. It is obtained by inverting the noncentral t distribution
+/// with `n_x + n_y - 2` degrees of freedom of the two-sample t statistic
+/// `d / √(1 / n_x + 1 / n_y)`.
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `level` is not in `(0, 1)`, or if the data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::cohens_d;
+///
+/// let x = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1];
+/// let y = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+/// let d = cohens_d(&x, &y, 0.95).unwrap();
+/// assert!(d.estimate > 1.5);
+/// assert!(!d.confidence_interval.contains(0.0));
+/// ```
+pub fn cohens_d(x: &[f64], y: &[f64], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    let (std_dev, scale) = pooled_std_dev(x, y)?;
+    let d = (x.mean() - y.mean()) / std_dev;
+    let freedom = (x.len() + y.len() - 2) as f64;
+    let t = d / scale;
+    let (lower, upper) = noncentrality_interval(
+        |ncp| noncentral_t_cdf(freedom, ncp, t),
+        t,
+        f64::NEG_INFINITY,
+        level,
+    );
+    Ok(effect_size(d, (lower * scale, upper * scale), level))
+}
+
+/// Computes Hedges' `g`, Cohen's `d` multiplied by the exact correction
+/// factor `J` for its small sample bias, with a confidence interval of
+/// level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// J = Γ(ν / 2) / (√(ν / 2) * Γ((ν - 1) / 2))
+/// g = J * d
+/// ```
+///
+/// where `ν = n_x + n_y - 2`. The confidence interval is that of
+/// `cohens_d` multiplied by `J`.
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `level` is not in `(0, 1)`, or if the data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{cohens_d, hedges_g};
+///
+/// let x = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1];
+/// let y = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+/// let d = cohens_d(&x, &y, 0.95).unwrap();
+/// let g = hedges_g(&x, &y, 0.95).unwrap();
+/// assert!(g.estimate < d.estimate);
+/// ```
+pub fn hedges_g(x: &[f64], y: &[f64], level: f64) -> Result<EffectSize> {
+    let d = cohens_d(x, y, level)?;
+    let freedom = (x.len() + y.len() - 2) as f64;
+    let j =
+        (ln_gamma(freedom / 2.0) - ln_gamma((freedom - 1.0) / 2.0)).exp() / (freedom / 2.0).sqrt();
+    let ci = d.confidence_interval;
+    Ok(effect_size(
+        j * d.estimate,
+        (j * ci.lower, j * ci.upper),
+        level,
+    ))
+}
+
+/// Computes Glass's `Δ`, the difference between the means of `x` and `y`
+/// standardized by the standard deviation of the control sample `y`, with
+/// an approximate confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// Δ = (x̄ - ȳ) / s_y
+/// SE = √(1 / n_x + 1 / n_y + Δ^2 / (2 (n_y - 1)))
+/// ```
+///
+/// where the confidence interval `Δ ± z SE` is based on the asymptotic
+/// normality of `Δ`
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `level` is not in `(0, 1)`, or if `y` is constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::glass_delta;
+///
+/// let treated = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1];
+/// let control = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+/// let delta = glass_delta(&treated, &control, 0.95).unwrap();
+/// assert!(delta.estimate > 1.5);
+/// ```
+pub fn glass_delta(x: &[f64], y: &[f64], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if y.len() < 2 {
+        return Err(StatsError::ArgGte("y.len()", 2.0));
+    }
+    let std_dev = y.std_dev();
+    if std_dev.is_nan() || std_dev <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let delta = (x.mean() - y.mean()) / std_dev;
+    let std_err = (1.0 / nx + 1.0 / ny + delta * delta / (2.0 * (ny - 1.0))).sqrt();
+    Ok(effect_size(
+        delta,
+        normal_interval(delta, std_err, level),
+        level,
+    ))
+}
+
+/// Returns the one-way analysis of variance of `groups` and the confidence
+/// interval of level `level` for the proportion of variance explained by
+/// the groups
+fn explained_variance<T: AsRef<[f64]>>(
+    groups: &[T],
+    level: f64,
+) -> Result<(OneWayAnova, (f64, f64))> {
+    check_level(level)?;
+    let anova = one_way_anova(groups)?;
+    let (df1, df2) = (anova.between.df, anova.within.df);
+    let f = anova.between.f.unwrap();
+    let n = df1 + df2 + 1.0;
+    let (lower, upper) = noncentrality_interval(
+        |ncp| noncentral_f_cdf(df1, df2, ncp, f),
+        f * df1,
+        0.0,
+        level,
+    );
+    Ok((anova, (lower / (lower + n), upper / (upper + n))))
+}
+
+/// Computes `η²`, the proportion of the total variation of `groups`
+/// explained by the group means in a one-way analysis of variance, with a
+/// confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// η² = SS_between / (SS_between + SS_within)
+/// ```
+///
+/// The confidence interval for the population proportion of variance
+/// explained is obtained by inverting the noncentral F distribution of the
+/// F statistic for the noncentrality `λ` and mapping its limits through
+/// `λ / (λ + N)`, where `N` is the total number of observations.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if there are no more observations than groups, if `level` is not
+/// in `(0, 1)`, or if the data within all groups are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::eta_squared;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3],
+/// ];
+/// let eta = eta_squared(&groups, 0.95).unwrap();
+/// assert!(eta.estimate > 0.5);
+/// assert!(eta.confidence_interval.contains(eta.estimate));
+/// ```
+pub fn eta_squared<T: AsRef<[f64]>>(groups: &[T], level: f64) -> Result<EffectSize> {
+    let (anova, interval) = explained_variance(groups, level)?;
+    Ok(effect_size(anova.r_squared(), interval, level))
+}
+
+/// Computes `ω²`, a less biased estimate than `η²` of the proportion of
+/// the total variation of `groups` explained by the group means in a
+/// one-way analysis of variance, with a confidence interval of level
+/// `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// ω² = (SS_between - df_between * MS_within) / (SS_total + MS_within)
+/// ```
+///
+/// The estimate is negative when the F statistic is less than one. The
+/// confidence interval is the same as that of `eta_squared` since both
+/// estimate the same population quantity.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if there are no more observations than groups, if `level` is not
+/// in `(0, 1)`, or if the data within all groups are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{eta_squared, omega_squared};
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3],
+/// ];
+/// let eta = eta_squared(&groups, 0.95).unwrap();
+/// let omega = omega_squared(&groups, 0.95).unwrap();
+/// assert!(omega.estimate < eta.estimate);
+/// ```
+pub fn omega_squared<T: AsRef<[f64]>>(groups: &[T], level: f64) -> Result<EffectSize> {
+    let (anova, interval) = explained_variance(groups, level)?;
+    let (between, within) = (anova.between, anova.within);
+    let omega = (between.ss - between.df * within.ms) / (between.ss + within.ss + within.ms);
+    Ok(effect_size(omega, interval, level))
+}
+
+/// Computes Cramér's `V`, the strength of association between the rows and
+/// columns of the `r x c` contingency table `table`, with a confidence
+/// interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// V = √(χ² / (N (min(r, c) - 1)))
+/// ```
+///
+/// where `χ²` is Pearson's statistic without continuity correction and `N`
+/// is the total count. The confidence interval is obtained by inverting
+/// the noncentral chi-squared distribution of `χ²` for the noncentrality
+/// `λ` and mapping its limits through `√(λ / (N (min(r, c) - 1)))`,
+/// capped at one.
+///
+/// # Errors
+///
+/// Returns an error if the table has fewer than two rows or columns, if
+/// the rows have different lengths, if any row or column sums to zero, or
+/// if `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::cramers_v;
+///
+/// let table = [[12, 5, 8], [7, 19, 10]];
+/// let v = cramers_v(&table, 0.95).unwrap();
+/// assert!(v.estimate > 0.0 && v.estimate < 1.0);
+/// ```
+pub fn cramers_v<R: AsRef<[u64]>>(table: &[R], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    let chi = chi_squared_independence(table, false, ChiSquaredStatistic::Pearson)?;
+    let total: u64 = table
+        .iter()
+        .map(|row| row.as_ref().iter().sum::<u64>())
+        .sum();
+    let k = table.len().min(table[0].as_ref().len()) as f64;
+    let scale = total as f64 * (k - 1.0);
+    let to_v = |lambda: f64| (lambda / scale).sqrt().min(1.0);
+    let (lower, upper) = noncentrality_interval(
+        |ncp| noncentral_chi_squared_cdf(chi.df, ncp, chi.statistic),
+        chi.statistic,
+        0.0,
+        level,
+    );
+    Ok(effect_size(
+        to_v(chi.statistic),
+        (to_v(lower), to_v(upper)),
+        level,
+    ))
+}
+
+/// Computes the `φ` coefficient, the correlation between the row and
+/// column indicators of the 2x2 contingency `table`
+///
+/// # Formula
+///
+/// ```ignore
+/// φ = (a d - b c) / √(r_1 r_2 c_1 c_2)
+/// ```
+///
+/// where `table` is `[[a, b], [c, d]]` with row totals `r_1`, `r_2` and
+/// column totals `c_1`, `c_2`. The absolute value of `φ` equals Cramér's
+/// `V` of the table.
+///
+/// # Errors
+///
+/// Returns an error if any row or column sums to zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::phi;
+///
+/// assert!((phi([[10, 0], [0, 10]]).unwrap() - 1.0).abs() < 1e-15);
+/// assert!((phi([[5, 5], [5, 5]]).unwrap()).abs() < 1e-15);
+/// ```
+pub fn phi(table: [[u64; 2]; 2]) -> Result<f64> {
+    let [[a, b], [c, d]] = table.map(|row| row.map(|x| x as f64));
+    let margins = (a + b) * (c + d) * (a + c) * (b + d);
+    if margins == 0.0 {
+        return Err(StatsError::ArgMustBePositive("table margins"));
+    }
+    Ok((a * d - b * c) / margins.sqrt())
+}
+
+/// Returns the cells of `table` as floats, adding `0.5` to every cell if
+/// `zero` holds for them
+fn corrected_cells<F: Fn(&[f64; 4]) -> bool>(table: [[u64; 2]; 2], zero: F) -> [f64; 4] {
+    let [[a, b], [c, d]] = table;
+    let cells = [a as f64, b as f64, c as f64, d as f64];
+    if zero(&cells) {
+        cells.map(|x| x + 0.5)
+    } else {
+        cells
+    }
+}
+
+/// Computes the odds ratio of the 2x2 contingency `table` with Woolf's
+/// log-scale confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// OR = (a d) / (b c)
+/// SE(ln OR) = √(1 / a + 1 / b + 1 / c + 1 / d)
+/// ```
+///
+/// where `table` is `[[a, b], [c, d]]` and the confidence interval is
+/// `exp(ln OR ± z SE)`. If any cell is zero, `0.5` is added to every cell
+/// (Haldane-Anscombe correction).
+///
+/// # Errors
+///
+/// Returns an error if `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::odds_ratio;
+///
+/// let or = odds_ratio([[20, 80], [10, 90]], 0.95).unwrap();
+/// assert!((or.estimate - 2.25).abs() < 1e-12);
+/// assert!(or.confidence_interval.contains(1.0));
+/// ```
+pub fn odds_ratio(table: [[u64; 2]; 2], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    let [a, b, c, d] = corrected_cells(table, |cells| cells.contains(&0.0));
+    let ln_or = (a * d / (b * c)).ln();
+    let std_err = (1.0 / a + 1.0 / b + 1.0 / c + 1.0 / d).sqrt();
+    let (lower, upper) = normal_interval(ln_or, std_err, level);
+    Ok(effect_size(ln_or.exp(), (lower.exp(), upper.exp()), level))
+}
+
+/// Computes the relative risk of the event in the first column for the
+/// first row of the 2x2 contingency `table` compared to the second row,
+/// with Katz's log-scale confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// RR = (a / (a + b)) / (c / (c + d))
+/// SE(ln RR) = √(1 / a - 1 / (a + b) + 1 / c - 1 / (c + d))
+/// ```
+///
+/// where `table` is `[[a, b], [c, d]]` with rows for the exposed and
+/// unexposed groups and columns for events and non-events. The confidence
+/// interval is `exp(ln RR ± z SE)`. If `a` or `c` is zero, `0.5` is added
+/// to every cell.
+///
+/// # Errors
+///
+/// Returns an error if either row sums to zero or if `level` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::relative_risk;
+///
+/// let rr = relative_risk([[20, 80], [10, 90]], 0.95).unwrap();
+/// assert!((rr.estimate - 2.0).abs() < 1e-12);
+/// ```
+pub fn relative_risk(table: [[u64; 2]; 2], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    let [[a, b], [c, d]] = table;
+    if a + b == 0 || c + d == 0 {
+        return Err(StatsError::ArgMustBePositive("table row totals"));
+    }
+    let [a, b, c, d] = corrected_cells(table, |cells| cells[0] == 0.0 || cells[2] == 0.0);
+    let ln_rr = (a / (a + b) / (c / (c + d))).ln();
+    let std_err = (1.0 / a - 1.0 / (a + b) + 1.0 / c - 1.0 / (c + d)).sqrt();
+    let (lower, upper) = normal_interval(ln_rr, std_err, level);
+    Ok(effect_size(ln_rr.exp(), (lower.exp(), upper.exp()), level))
+}
+
+/// Computes the common language effect size, the probability that a
+/// random observation from the population of `x` exceeds one from the
+/// population of `y`, counting ties as one half, with an approximate
+/// confidence interval of level `level`
+///
+/// # Formula
+///
+/// ```ignore
+/// A = (#{x_i > y_j} + #{x_i = y_j} / 2) / (n_x n_y)
+/// Q_1 = A / (2 - A), Q_2 = 2 A^2 / (1 + A)
+/// SE^2 = (A (1 - A) + (n_x - 1) (Q_1 - A^2) + (n_y - 1) (Q_2 - A^2)) / (n_x n_y)
+/// ```
+///
+/// where `A` equals the Mann-Whitney `U` statistic divided by `n_x n_y`
+/// and the confidence interval `A ± z SE` (Hanley and McNeil, 1982) is
+/// clamped to `[0, 1]`
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty or if `level` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::common_language_effect_size;
+///
+/// let x = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1];
+/// let y = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+/// let cles = common_language_effect_size(&x, &y, 0.95).unwrap();
+/// assert!((cles.estimate - 35.0 / 36.0).abs() < 1e-15);
+/// ```
+pub fn common_language_effect_size(x: &[f64], y: &[f64], level: f64) -> Result<EffectSize> {
+    check_level(level)?;
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if y.is_empty() {
+        return Err(StatsError::ArgGte("y.len()", 1.0));
+    }
+    let wins: f64 = x
+        .iter()
+        .flat_map(|xi| y.iter().map(move |yj| (xi, yj)))
+        .map(|(xi, yj)| {
+            if xi > yj {
+                1.0
+            } else if xi == yj {
+                0.5
+            } else {
+                0.0
+            }
+        })
+        .sum();
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let a = wins / (nx * ny);
+    let (q1, q2) = (a / (2.0 - a), 2.0 * a * a / (1.0 + a));
+    let variance =
+        (a * (1.0 - a) + (nx - 1.0) * (q1 - a * a) + (ny - 1.0) * (q2 - a * a)) / (nx * ny);
+    let (lower, upper) = normal_interval(a, variance.max(0.0).sqrt(), level);
+    Ok(effect_size(a, (lower.max(0.0), upper.min(1.0)), level))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X: [f64; 6] = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1];
+    const Y: [f64; 6] = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+
+    fn check(e: EffectSize, estimate: f64, lower: f64, upper: f64, prec: f64) {
+        assert_almost_eq!(e.estimate, estimate, 1e-12);
+        assert_almost_eq!(e.confidence_interval.lower, lower, prec);
+        assert_almost_eq!(e.confidence_interval.upper, upper, prec);
+    }
+
+    #[test]
+    fn test_cohens_d() {
+        check(cohens_d(&X, &Y, 0.95).unwrap(), 2.174237458351836959, 0.671876707296664719, 3.613745798876768600, 1e-9);
+        let d = cohens_d(&X, &Y, 0.9).unwrap();
+        assert_almost_eq!(d.confidence_interval.lower, 0.899342792085510978, 1e-9);
+        assert_almost_eq!(d.confidence_interval.upper, 3.368676178658817432, 1e-9);
+        // swapping the samples mirrors the interval
+        check(cohens_d(&Y, &X, 0.95).unwrap(), -2.174237458351836959, -3.613745798876768600, -0.671876707296664719, 1e-9);
+        check(hedges_g(&X, &Y, 0.95).unwrap(), 2.006268065558664442, 0.619971280811166911, 3.334568064533832856, 1e-9);
+        assert!(cohens_d(&X, &[1.0], 0.95).is_err());
+        assert!(cohens_d(&[1.0, 1.0], &[2.0, 2.0], 0.95).is_err());
+        assert!(hedges_g(&X, &Y, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_glass_delta() {
+        check(glass_delta(&X, &Y, 0.95).unwrap(), 1.838031382759096616, 0.232332434824347832, 3.443730330693845400, 1e-9);
+        assert!(glass_delta(&X, &[1.0, 1.0], 0.95).is_err());
+    }
+
+    #[test]
+    fn test_explained_variance() {
+        let groups = [
+            vec![6.9, 5.4, 5.8, 4.6, 4.0],
+            vec![8.3, 6.8, 7.8, 9.2, 6.5],
+            vec![8.0, 10.5, 8.1, 6.9, 9.3],
+        ];
+        check(eta_squared(&groups, 0.95).unwrap(), 0.615165235799129719, 0.127089289149437812, 0.758457483871493503, 1e-11);
+        check(omega_squared(&groups, 0.95).unwrap(), 0.533904038857354481, 0.127089289149437812, 0.758457483871493503, 1e-11);
+        // an F statistic below its lower quantile leaves an empty lower tail
+        let flat = [vec![1.0, 2.0, 3.0], vec![1.1, 2.0, 2.9]];
+        let eta = eta_squared(&flat, 0.95).unwrap();
+        assert_eq!(eta.confidence_interval.lower, 0.0);
+        assert_eq!(eta.confidence_interval.upper, 0.0);
+        assert!(omega_squared(&flat, 0.95).unwrap().estimate < 0.0);
+    }
+
+    #[test]
+    fn test_contingency() {
+        let table = [[12, 5, 8], [7, 19, 10]];
+        check(cramers_v(&table, 0.95).unwrap(), 0.361703293121186348, 0.052675037965621292, 0.594917534609144017, 1e-11);
+        assert_almost_eq!(phi([[12, 5], [7, 19]]).unwrap(), 0.429896345258836573, 1e-15);
+        assert_almost_eq!(phi([[5, 12], [19, 7]]).unwrap(), -0.429896345258836573, 1e-15);
+        assert_almost_eq!(cramers_v(&[[12, 5], [7, 19]], 0.95).unwrap().estimate, 0.429896345258836573, 1e-15);
+        assert!(phi([[0, 0], [3, 4]]).is_err());
+        assert!(cramers_v(&[[0, 0], [3, 4]], 0.95).is_err());
+    }
+
+    #[test]
+    fn test_odds_ratio_relative_risk() {
+        check(odds_ratio([[20, 80], [10, 90]], 0.95).unwrap(), 2.25, 0.994294892281506270, 5.091547828817265373, 1e-10);
+        check(odds_ratio([[7, 0], [3, 10]], 0.95).unwrap(), 45.0, 2.011429626811007681, 1006.746630858026722, 1e-7);
+        check(relative_risk([[20, 80], [10, 90]], 0.95).unwrap(), 2.0, 0.986563206731782158, 4.054479198804627286, 1e-10);
+        assert!(relative_risk([[0, 0], [10, 90]], 0.95).is_err());
+        assert!(odds_ratio([[20, 80], [10, 90]], 0.0).is_err());
+    }
+
+    #[test]
+    fn test_common_language_effect_size() {
+        check(common_language_effect_size(&X, &Y, 0.95).unwrap(), 35.0 / 36.0, 0.870368942001156497, 1.0, 1e-10);
+        let e = common_language_effect_size(&[1.0, 2.0], &[2.0, 1.0], 0.95).unwrap();
+        assert_eq!(e.estimate, 0.5);
+        assert!(common_language_effect_size(&[], &Y, 0.95).is_err());
+    }
+}
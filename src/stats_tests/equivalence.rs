@@ -0,0 +1,374 @@
+use crate::distribution::{ContinuousCDF, Normal, StudentsT};
+use crate::statistics::Statistics;
+use crate::stats_tests::proportion::check_counts;
+use crate::stats_tests::{pivot_interval, Alternative, ConfidenceInterval};
+use crate::{Result, StatsError};
+
+/// The result of a two one-sided tests (TOST) procedure for equivalence
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct EquivalenceTestResult {
+    /// The estimated mean, difference of means, or difference of
+    /// proportions
+    pub estimate: f64,
+    /// The standard error of the estimate
+    pub std_err: f64,
+    /// The degrees of freedom of the null t distribution, or infinity for
+    /// tests based on the normal approximation
+    pub df: f64,
+    /// The statistic `(estimate - lower) / std_err` of the test of the null
+    /// hypothesis that the parameter is at most the lower margin
+    pub lower_statistic: f64,
+    /// The p-value of the test against the lower margin
+    pub lower_p_value: f64,
+    /// The statistic `(estimate - upper) / std_err` of the test of the
+    /// null hypothesis that the parameter is at least the upper margin
+    pub upper_statistic: f64,
+    /// The p-value of the test against the upper margin
+    pub upper_p_value: f64,
+    /// The p-value of the equivalence test, the larger of the one-sided
+    /// p-values
+    pub p_value: f64,
+    /// The confidence interval of level `1 - 2α` for the parameter, which
+    /// lies within the margins exactly when equivalence is established at
+    /// level `α`
+    pub confidence_interval: ConfidenceInterval,
+}
+
+impl EquivalenceTestResult {
+    fn new<D: ContinuousCDF<f64, f64>>(
+        dist: &D,
+        df: f64,
+        estimate: f64,
+        std_err: f64,
+        (lower, upper): (f64, f64),
+        alpha: f64,
+    ) -> Result<EquivalenceTestResult> {
+        if std_err.is_nan() || std_err <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let lower_statistic = (estimate - lower) / std_err;
+        let upper_statistic = (estimate - upper) / std_err;
+        let lower_p_value = dist.sf(lower_statistic);
+        let upper_p_value = dist.cdf(upper_statistic);
+        Ok(EquivalenceTestResult {
+            estimate,
+            std_err,
+            df,
+            lower_statistic,
+            lower_p_value,
+            upper_statistic,
+            upper_p_value,
+            p_value: lower_p_value.max(upper_p_value),
+            confidence_interval: pivot_interval(
+                dist,
+                estimate,
+                std_err,
+                Alternative::TwoSided,
+                1.0 - 2.0 * alpha,
+            ),
+        })
+    }
+
+    /// Returns true if equivalence is established at significance level
+    /// `alpha`, i.e. if both one-sided null hypotheses are rejected
+    pub fn is_equivalent(&self, alpha: f64) -> bool {
+        self.p_value < alpha
+    }
+}
+
+/// Returns an error if the margins are not ordered or `alpha` does not lie
+/// in `(0, 1/2)`
+fn check_margins(lower: f64, upper: f64, alpha: f64) -> Result<()> {
+    if lower.is_nan() || upper.is_nan() || lower >= upper {
+        Err(StatsError::ArgLtArg("lower", "upper"))
+    } else if alpha > 0.0 && alpha < 0.5 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("alpha", 0.0, 0.5))
+    }
+}
+
+/// Performs the two one-sided tests (TOST) procedure of the null
+/// hypothesis that the mean of the population `x` was drawn from lies
+/// outside the equivalence margins `(lower, upper)`, and computes the
+/// confidence interval of level `1 - 2 alpha` for that mean
+///
+/// # Formula
+///
+/// ```ignore
+/// t_L = (x̄ - lower) / (s / √n)
+/// t_U = (x̄ - upper) / (s / √n)
+/// ```
+///
+/// where `t_L` tests `μ <= lower` against `μ > lower`, `t_U` tests
+/// `μ >= upper` against `μ < upper`, and both follow a Student's t
+/// distribution with `n - 1` degrees of freedom. Equivalence is established
+/// at level `alpha` if both tests reject. A non-inferiority test is
+/// obtained with `upper = f64::INFINITY` and a non-superiority test with
+/// `lower = f64::NEG_INFINITY`.
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than two elements, if `lower` is not
+/// less than `upper`, if `alpha` is not in `(0, 1/2)`, or if the data are
+/// constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::tost_one_sample_t_test;
+///
+/// let x = [5.1, 4.9, 5.0, 5.2, 4.8, 5.0, 5.1, 4.9];
+/// let result = tost_one_sample_t_test(&x, 4.8, 5.2, 0.05).unwrap();
+/// assert!(result.is_equivalent(0.05));
+/// assert!(result.confidence_interval.lower > 4.8);
+/// assert!(result.confidence_interval.upper < 5.2);
+/// ```
+pub fn tost_one_sample_t_test(
+    x: &[f64],
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<EquivalenceTestResult> {
+    check_margins(lower, upper, alpha)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    let n = x.len() as f64;
+    let std_err = (x.variance() / n).sqrt();
+    let dist = StudentsT::new(0.0, 1.0, n - 1.0)?;
+    EquivalenceTestResult::new(&dist, n - 1.0, x.mean(), std_err, (lower, upper), alpha)
+}
+
+/// Performs the two one-sided tests (TOST) procedure of the null
+/// hypothesis that the mean difference `x - y` of paired observations lies
+/// outside the equivalence margins `(lower, upper)`, which is
+/// `tost_one_sample_t_test` applied to the differences
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` have different lengths, if there are
+/// fewer than two pairs, if `lower` is not less than `upper`, if `alpha` is
+/// not in `(0, 1/2)`, or if the differences are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::tost_paired_t_test;
+///
+/// let reference = [72.0, 80.5, 65.2, 90.1, 77.7, 69.3];
+/// let test = [72.4, 80.1, 65.9, 89.6, 78.0, 69.5];
+/// let result = tost_paired_t_test(&test, &reference, -1.0, 1.0, 0.05).unwrap();
+/// assert!(result.is_equivalent(0.05));
+/// ```
+pub fn tost_paired_t_test(
+    x: &[f64],
+    y: &[f64],
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<EquivalenceTestResult> {
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let diff: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    tost_one_sample_t_test(&diff, lower, upper, alpha)
+}
+
+/// Performs the two one-sided tests (TOST) procedure with Welch's t-test of
+/// the null hypothesis that the difference between the means of the
+/// populations `x` and `y` were drawn from lies outside the equivalence
+/// margins `(lower, upper)`, without assuming equal variances
+///
+/// # Formula
+///
+/// ```ignore
+/// SE = √(s_x^2 / n_x + s_y^2 / n_y)
+/// t_L = (x̄ - ȳ - lower) / SE
+/// t_U = (x̄ - ȳ - upper) / SE
+/// ```
+///
+/// where both statistics follow a Student's t distribution with the
+/// Welch-Satterthwaite degrees of freedom of `welch_t_test`
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `lower` is not less than `upper`, if `alpha` is not in `(0, 1/2)`, or
+/// if the data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::tost_welch_t_test;
+///
+/// let x = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6, 20.1, 19.5];
+/// let y = [19.9, 20.4, 19.6, 20.8, 19.3, 20.0, 20.2];
+/// let result = tost_welch_t_test(&x, &y, -1.5, 1.5, 0.05).unwrap();
+/// assert!(result.is_equivalent(0.05));
+/// ```
+pub fn tost_welch_t_test(
+    x: &[f64],
+    y: &[f64],
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<EquivalenceTestResult> {
+    check_margins(lower, upper, alpha)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if y.len() < 2 {
+        return Err(StatsError::ArgGte("y.len()", 2.0));
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let vx = x.variance() / nx;
+    let vy = y.variance() / ny;
+    let std_err = (vx + vy).sqrt();
+    let df = (vx + vy) * (vx + vy) / (vx * vx / (nx - 1.0) + vy * vy / (ny - 1.0));
+    if df.is_nan() {
+        return Err(StatsError::BadParams);
+    }
+    let dist = StudentsT::new(0.0, 1.0, df)?;
+    EquivalenceTestResult::new(
+        &dist,
+        df,
+        x.mean() - y.mean(),
+        std_err,
+        (lower, upper),
+        alpha,
+    )
+}
+
+/// Performs the two one-sided tests (TOST) procedure of the null
+/// hypothesis that the difference `p_1 - p_2` between two binomial
+/// proportions lies outside the equivalence margins `(lower, upper)`,
+/// using the normal approximation with unpooled variance
+///
+/// # Formula
+///
+/// ```ignore
+/// SE = √(p̂_1 (1 - p̂_1) / n_1 + p̂_2 (1 - p̂_2) / n_2)
+/// z_L = (p̂_1 - p̂_2 - lower) / SE
+/// z_U = (p̂_1 - p̂_2 - upper) / SE
+/// ```
+///
+/// where `p̂_i = successes_i / trials_i` and both statistics approximately
+/// follow a standard normal distribution
+///
+/// # Errors
+///
+/// Returns an error if either number of trials is zero, if either number
+/// of successes exceeds its number of trials, if `lower` is not less than
+/// `upper`, if `alpha` is not in `(0, 1/2)`, or if both observed
+/// proportions are zero or one
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::tost_proportion_test;
+///
+/// // non-inferiority of a new treatment with a margin of 10 points
+/// let result = tost_proportion_test(164, 200, 168, 200, -0.1, f64::INFINITY, 0.025).unwrap();
+/// assert!(result.is_equivalent(0.025));
+/// assert_eq!(result.upper_p_value, 0.0);
+/// ```
+pub fn tost_proportion_test(
+    successes_1: u64,
+    trials_1: u64,
+    successes_2: u64,
+    trials_2: u64,
+    lower: f64,
+    upper: f64,
+    alpha: f64,
+) -> Result<EquivalenceTestResult> {
+    check_margins(lower, upper, alpha)?;
+    check_counts(successes_1, trials_1)?;
+    check_counts(successes_2, trials_2)?;
+    let (n1, n2) = (trials_1 as f64, trials_2 as f64);
+    let p1 = successes_1 as f64 / n1;
+    let p2 = successes_2 as f64 / n2;
+    let std_err = (p1 * (1.0 - p1) / n1 + p2 * (1.0 - p2) / n2).sqrt();
+    let dist = Normal::new(0.0, 1.0)?;
+    EquivalenceTestResult::new(
+        &dist,
+        f64::INFINITY,
+        p1 - p2,
+        std_err,
+        (lower, upper),
+        alpha,
+    )
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(r: EquivalenceTestResult, estimate: f64, std_err: f64, p_lower: f64, p_upper: f64, lower: f64, upper: f64) {
+        assert_almost_eq!(r.estimate, estimate, 1e-14);
+        assert_almost_eq!(r.std_err, std_err, 1e-14);
+        assert_almost_eq!(r.lower_p_value, p_lower, 1e-10);
+        assert_almost_eq!(r.upper_p_value, p_upper, 1e-10);
+        assert_eq!(r.p_value, r.lower_p_value.max(r.upper_p_value));
+        assert_almost_eq!(r.confidence_interval.lower, lower, 1e-10);
+        assert_almost_eq!(r.confidence_interval.upper, upper, 1e-10);
+    }
+
+    #[test]
+    fn test_tost_one_sample_t_test() {
+        let x = [5.1, 4.9, 5.0, 5.2, 4.8, 5.0, 5.1, 4.9];
+        let r = tost_one_sample_t_test(&x, 4.8, 5.2, 0.05).unwrap();
+        assert_eq!(r.df, 7.0);
+        assert_almost_eq!(r.lower_statistic, 4.3204937989385734873, 1e-12);
+        assert_almost_eq!(r.upper_statistic, -4.3204937989385734873, 1e-12);
+        assert_eq!(r.confidence_interval.level, 0.9);
+        check(r, 5.0, 0.046291004988627573078, 0.0017390421325013918507, 0.0017390421325013918507, 4.9122980523404313992, 5.0877019476595686008);
+        assert!(tost_one_sample_t_test(&x, 5.2, 4.8, 0.05).is_err());
+        assert!(tost_one_sample_t_test(&x, 4.8, 5.2, 0.5).is_err());
+        assert!(tost_one_sample_t_test(&[5.0], 4.8, 5.2, 0.05).is_err());
+        assert!(tost_one_sample_t_test(&[5.0, 5.0], 4.8, 5.2, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_tost_paired_t_test() {
+        let reference = [72.0, 80.5, 65.2, 90.1, 77.7, 69.3];
+        let test = [72.4, 80.1, 65.9, 89.6, 78.0, 69.5];
+        let r = tost_paired_t_test(&test, &reference, -1.0, 1.0, 0.05).unwrap();
+        check(r, 0.11666666666666666667, 0.19220937657784659816, 0.0010658210500245197859, 0.0029316301974901446612, -0.27064452494587780993, 0.50397785827921114326);
+        let r = tost_paired_t_test(&test, &reference, -0.5, 0.5, 0.1).unwrap();
+        check(r, 0.11666666666666666667, 0.19220937657784659816, 0.011886809408079683365, 0.051338696086874711167, -0.16701208625907495185, 0.40034541959240828518);
+        assert!(!r.is_equivalent(0.05));
+        assert!(r.is_equivalent(0.1));
+        assert!(tost_paired_t_test(&test, &reference[1..], -1.0, 1.0, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_tost_welch_t_test() {
+        let x = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6, 20.1, 19.5];
+        let y = [19.9, 20.4, 19.6, 20.8, 19.3, 20.0, 20.2];
+        let r = tost_welch_t_test(&x, &y, -1.5, 1.5, 0.05).unwrap();
+        assert_almost_eq!(r.df, 12.085827708036541430, 1e-12);
+        check(r, -0.14107142857142857143, 0.33115412230725504797, 0.00072091801628962410994, 0.00016311894551772737746, -0.73093426715515116452, 0.44879141001229402166);
+        let r = tost_welch_t_test(&x, &y, -0.5, 0.3, 0.05).unwrap();
+        check(r, -0.14107142857142857143, 0.33115412230725504797, 0.14978545368227456845, 0.10372948190609707300, -0.73093426715515116452, 0.44879141001229402166);
+        assert!(!r.is_equivalent(0.05));
+        assert!(tost_welch_t_test(&x, &[1.0], -1.5, 1.5, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_tost_proportion_test() {
+        let r = tost_proportion_test(164, 200, 168, 200, -0.1, 0.1, 0.025).unwrap();
+        assert!(r.df.is_infinite());
+        check(r, -0.02, 0.037549966711037175033, 0.016565402015919367938, 0.00069735919741573841485, -0.093596582374310816915, 0.053596582374310816915);
+        let r = tost_proportion_test(45, 60, 52, 75, -0.15, 0.15, 0.05).unwrap();
+        check(r, 0.056666666666666666667, 0.077200796388139435844, 0.0037142124011453085327, 0.11333746792743337123, -0.070317343275906639104, 0.18365067660923997244);
+        // non-inferiority with an infinite upper margin
+        let r = tost_proportion_test(45, 60, 52, 75, -0.15, f64::INFINITY, 0.05).unwrap();
+        assert_eq!(r.upper_p_value, 0.0);
+        assert_almost_eq!(r.p_value, 0.0037142124011453085327, 1e-10);
+        assert!(tost_proportion_test(0, 60, 0, 75, -0.15, 0.15, 0.05).is_err());
+        assert!(tost_proportion_test(61, 60, 0, 75, -0.15, 0.15, 0.05).is_err());
+        assert!(tost_proportion_test(45, 60, 52, 75, f64::NAN, 0.15, 0.05).is_err());
+    }
+}
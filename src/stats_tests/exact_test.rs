@@ -0,0 +1,439 @@
+use crate::distribution::{Binomial, Discrete, DiscreteCDF, Hypergeometric};
+use crate::statistics::{Max, Min};
+use crate::stats_tests::proportion::{check_counts, clopper_pearson_lower, clopper_pearson_upper};
+use crate::stats_tests::{bisect, check_level, Alternative, ConfidenceInterval};
+use crate::{Result, StatsError};
+
+/// The relative tolerance used to decide which outcomes are at most as
+/// likely as the observed one in two-sided exact tests
+const REL_ERR: f64 = 1.0 + 1e-7;
+
+/// The result of Fisher's exact test of a 2x2 contingency table
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct FisherExactResult {
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// The conditional maximum likelihood estimate of the odds ratio
+    pub odds_ratio: f64,
+    /// The confidence interval for the odds ratio
+    pub confidence_interval: ConfidenceInterval,
+    /// The alternative hypothesis the p-value and interval refer to
+    pub alternative: Alternative,
+}
+
+/// The result of an exact binomial test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BinomialTestResult {
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// The estimated success probability `successes / trials`
+    pub estimate: f64,
+    /// The Clopper-Pearson confidence interval for the success probability
+    pub confidence_interval: ConfidenceInterval,
+    /// The alternative hypothesis the p-value and interval refer to
+    pub alternative: Alternative,
+}
+
+/// The distribution of the top left cell of a 2x2 table with fixed
+/// margins, i.e. Fisher's noncentral hypergeometric distribution, for
+/// varying odds ratios
+struct FisherSupport {
+    lo: u64,
+    hi: u64,
+    ln_pmf: Vec<f64>,
+}
+
+impl FisherSupport {
+    fn new(null: &Hypergeometric) -> FisherSupport {
+        let (lo, hi) = (null.min(), null.max());
+        FisherSupport {
+            lo,
+            hi,
+            ln_pmf: (lo..=hi).map(|x| null.ln_pmf(x)).collect(),
+        }
+    }
+
+    /// Returns the probabilities of `lo..=hi` for the finite and positive
+    /// odds ratio `t`
+    fn pmf(&self, t: f64) -> Vec<f64> {
+        let ln_t = t.ln();
+        let ln_d: Vec<f64> = self
+            .ln_pmf
+            .iter()
+            .zip(self.lo..=self.hi)
+            .map(|(d, x)| d + ln_t * x as f64)
+            .collect();
+        let max = ln_d.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let d: Vec<f64> = ln_d.iter().map(|d| (d - max).exp()).collect();
+        let sum: f64 = d.iter().sum();
+        d.iter().map(|d| d / sum).collect()
+    }
+
+    /// Returns the mean for the odds ratio `t`
+    fn mean(&self, t: f64) -> f64 {
+        if t == 0.0 {
+            self.lo as f64
+        } else if t.is_infinite() {
+            self.hi as f64
+        } else {
+            self.pmf(t)
+                .iter()
+                .zip(self.lo..=self.hi)
+                .map(|(d, x)| d * x as f64)
+                .sum()
+        }
+    }
+
+    /// Returns `P(X <= x)` for the odds ratio `t`
+    fn cdf(&self, x: u64, t: f64) -> f64 {
+        if t == 0.0 {
+            1.0
+        } else if t.is_infinite() {
+            if x >= self.hi {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            self.pmf(t)[..=(x - self.lo) as usize].iter().sum()
+        }
+    }
+
+    /// Returns `P(X >= x)` for the odds ratio `t`
+    fn sf(&self, x: u64, t: f64) -> f64 {
+        if t == 0.0 {
+            if x <= self.lo {
+                1.0
+            } else {
+                0.0
+            }
+        } else if t.is_infinite() {
+            1.0
+        } else {
+            self.pmf(t)[(x - self.lo) as usize..].iter().sum()
+        }
+    }
+
+    /// Solves `f(t) = 0` for an odds ratio `t` in `(0, ∞)`, where `f` is
+    /// monotone, by bisection on `t` below one and on `1 / t` above one
+    fn solve<F: Fn(f64) -> f64>(&self, f: F) -> f64 {
+        let at_one = f(1.0);
+        if at_one == 0.0 {
+            1.0
+        } else if (at_one > 0.0) == (f(0.0) > 0.0) {
+            1.0 / bisect(|u| f(1.0 / u), 0.0, 1.0)
+        } else {
+            bisect(&f, 0.0, 1.0)
+        }
+    }
+
+    /// Returns the conditional maximum likelihood estimate of the odds
+    /// ratio given the observation `x`
+    fn mle(&self, x: u64) -> f64 {
+        if x == self.lo {
+            0.0
+        } else if x == self.hi {
+            f64::INFINITY
+        } else {
+            self.solve(|t| self.mean(t) - x as f64)
+        }
+    }
+
+    /// Returns the upper confidence bound of the odds ratio at level
+    /// `1 - alpha` given the observation `x`
+    fn upper(&self, x: u64, alpha: f64) -> f64 {
+        if x == self.hi {
+            f64::INFINITY
+        } else {
+            self.solve(|t| self.cdf(x, t) - alpha)
+        }
+    }
+
+    /// Returns the lower confidence bound of the odds ratio at level
+    /// `1 - alpha` given the observation `x`
+    fn lower(&self, x: u64, alpha: f64) -> f64 {
+        if x == self.lo {
+            0.0
+        } else {
+            self.solve(|t| self.sf(x, t) - alpha)
+        }
+    }
+}
+
+/// Performs Fisher's exact test of the null hypothesis that the odds ratio
+/// of the 2x2 contingency `table` equals one, i.e. that rows and columns
+/// are independent, conditioning on the row and column totals
+///
+/// The odds ratio is `(table[0][0] * table[1][1]) / (table[0][1] *
+/// table[1][0])`. `Alternative::Greater` tests for an odds ratio greater
+/// than one. The two-sided p-value sums the probabilities of all tables
+/// that are at most as likely as the observed one. The odds ratio is
+/// estimated by conditional maximum likelihood and its confidence
+/// interval of level `level` is obtained by inverting the one-sided tests.
+///
+/// # Formula
+///
+/// ```ignore
+/// P(X = x) = (r_1 choose x) * (r_2 choose c_1 - x) / (N choose c_1)
+/// ```
+///
+/// where `X` is the top left cell, `r_1` and `r_2` are the row totals,
+/// `c_1` is the first column total and `N` is the grand total
+///
+/// # Errors
+///
+/// Returns an error if the table is empty or if `level` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{fisher_exact_test, Alternative};
+///
+/// // Fisher's lady tasting tea
+/// let result = fisher_exact_test([[3, 1], [1, 3]], Alternative::Greater, 0.95).unwrap();
+/// assert!((result.p_value - 17.0 / 70.0).abs() < 1e-15);
+/// assert!(result.confidence_interval.contains(1.0));
+/// ```
+pub fn fisher_exact_test(
+    table: [[u64; 2]; 2],
+    alternative: Alternative,
+    level: f64,
+) -> Result<FisherExactResult> {
+    check_level(level)?;
+    let [[a, b], [c, d]] = table;
+    let total = a + b + c + d;
+    if total == 0 {
+        return Err(StatsError::BadParams);
+    }
+    let null = Hypergeometric::new(total, a + b, a + c)?;
+    let support = FisherSupport::new(&null);
+    let p_value = match alternative {
+        Alternative::Less => support.cdf(a, 1.0),
+        Alternative::Greater => support.sf(a, 1.0),
+        Alternative::TwoSided => {
+            let d = support.pmf(1.0);
+            let observed = d[(a - support.lo) as usize] * REL_ERR;
+            d.iter().filter(|&&p| p <= observed).sum()
+        }
+    };
+    let (lower, upper) = match alternative {
+        Alternative::Less => (0.0, support.upper(a, 1.0 - level)),
+        Alternative::Greater => (support.lower(a, 1.0 - level), f64::INFINITY),
+        Alternative::TwoSided => {
+            let alpha = (1.0 - level) / 2.0;
+            (support.lower(a, alpha), support.upper(a, alpha))
+        }
+    };
+    Ok(FisherExactResult {
+        p_value: p_value.min(1.0),
+        odds_ratio: support.mle(a),
+        confidence_interval: ConfidenceInterval {
+            lower,
+            upper,
+            level,
+        },
+        alternative,
+    })
+}
+
+/// Performs an exact binomial test of the null hypothesis that the
+/// probability of success in `trials` Bernoulli trials with `successes`
+/// observed successes equals `p`, and computes the Clopper-Pearson
+/// confidence interval of level `level` for that probability
+///
+/// The two-sided p-value sums the probabilities of all outcomes that are
+/// at most as likely as the observed one.
+///
+/// # Formula
+///
+/// ```ignore
+/// lower = B^-1(α / 2; x, n - x + 1)
+/// upper = B^-1(1 - α / 2; x + 1, n - x)
+/// ```
+///
+/// where `B^-1(q; a, b)` is the inverse cdf of the beta distribution, `x`
+/// is `successes`, `n` is `trials` and `α` is `1 - level`
+///
+/// # Errors
+///
+/// Returns an error if `trials` is zero, if `successes > trials`, if `p`
+/// is not in `[0, 1]`, or if `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{binomial_test, Alternative};
+///
+/// let result = binomial_test(682, 925, 0.75, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(result.p_value > 0.05);
+/// assert!(result.confidence_interval.contains(0.75));
+/// ```
+pub fn binomial_test(
+    successes: u64,
+    trials: u64,
+    p: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<BinomialTestResult> {
+    check_level(level)?;
+    check_counts(successes, trials)?;
+    if !(0.0..=1.0).contains(&p) {
+        return Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0));
+    }
+    let dist = Binomial::new(p, trials)?;
+    let x = successes;
+    // P(X >= x)
+    let upper_tail = |x: u64| if x == 0 { 1.0 } else { dist.sf(x - 1) };
+    let p_value = match alternative {
+        Alternative::Less => dist.cdf(x),
+        Alternative::Greater => upper_tail(x),
+        Alternative::TwoSided => {
+            let mean = trials as f64 * p;
+            let observed = dist.pmf(x) * REL_ERR;
+            if x as f64 == mean {
+                1.0
+            } else if (x as f64) < mean {
+                let y = (mean.ceil() as u64..=trials)
+                    .filter(|&i| dist.pmf(i) <= observed)
+                    .count() as u64;
+                dist.cdf(x) + upper_tail(trials - y + 1)
+            } else {
+                let y = (0..=mean.floor() as u64)
+                    .filter(|&i| dist.pmf(i) <= observed)
+                    .count() as u64;
+                let lower_tail = if y == 0 { 0.0 } else { dist.cdf(y - 1) };
+                lower_tail + upper_tail(x)
+            }
+        }
+    };
+
+    let (lower, upper) = match alternative {
+        Alternative::Less => (0.0, clopper_pearson_upper(x, trials, 1.0 - level)),
+        Alternative::Greater => (clopper_pearson_lower(x, trials, 1.0 - level), 1.0),
+        Alternative::TwoSided => {
+            let alpha = (1.0 - level) / 2.0;
+            (
+                clopper_pearson_lower(x, trials, alpha),
+                clopper_pearson_upper(x, trials, alpha),
+            )
+        }
+    };
+    Ok(BinomialTestResult {
+        p_value: p_value.min(1.0),
+        estimate: x as f64 / trials as f64,
+        confidence_interval: ConfidenceInterval {
+            lower,
+            upper,
+            level,
+        },
+        alternative,
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fisher(table: [[u64; 2]; 2], alternative: Alternative) -> FisherExactResult {
+        fisher_exact_test(table, alternative, 0.95).unwrap()
+    }
+
+    fn binomial(x: u64, n: u64, p: f64, alternative: Alternative) -> BinomialTestResult {
+        binomial_test(x, n, p, alternative, 0.95).unwrap()
+    }
+
+    #[test]
+    fn test_fisher_exact_p_value() {
+        assert_almost_eq!(fisher([[3, 1], [1, 3]], Alternative::TwoSided).p_value, 0.48571428571428571429, 1e-15);
+        assert_almost_eq!(fisher([[3, 1], [1, 3]], Alternative::Less).p_value, 0.98571428571428571429, 1e-15);
+        assert_almost_eq!(fisher([[3, 1], [1, 3]], Alternative::Greater).p_value, 0.24285714285714285714, 1e-15);
+        assert_almost_eq!(fisher([[10, 2], [3, 15]], Alternative::TwoSided).p_value, 0.0005367241191434358009, 1e-17);
+        assert_almost_eq!(fisher([[10, 2], [3, 15]], Alternative::Greater).p_value, 0.00046518094336290501366, 1e-17);
+        assert_almost_eq!(fisher([[1, 9], [11, 3]], Alternative::TwoSided).p_value, 0.0027594561852200834567, 1e-16);
+        assert_almost_eq!(fisher([[1, 9], [11, 3]], Alternative::Less).p_value, 0.0013797280926100417284, 1e-16);
+        assert_almost_eq!(fisher([[0, 5], [4, 2]], Alternative::TwoSided).p_value, 0.060606060606060606061, 1e-15);
+        assert_almost_eq!(fisher([[0, 5], [4, 2]], Alternative::Greater).p_value, 1.0, 1e-15);
+        assert_almost_eq!(fisher([[7, 0], [2, 5]], Alternative::TwoSided).p_value, 0.020979020979020979021, 1e-15);
+    }
+
+    #[test]
+    fn test_fisher_exact_odds_ratio() {
+        assert_almost_eq!(fisher([[3, 1], [1, 3]], Alternative::TwoSided).odds_ratio, 6.4083196581996743223, 1e-12);
+        assert_almost_eq!(fisher([[10, 2], [3, 15]], Alternative::TwoSided).odds_ratio, 21.30531755675033736, 1e-11);
+        assert_almost_eq!(fisher([[1, 9], [11, 3]], Alternative::TwoSided).odds_ratio, 0.037209084832381190327, 1e-14);
+        assert_eq!(fisher([[0, 5], [4, 2]], Alternative::TwoSided).odds_ratio, 0.0);
+        assert_eq!(fisher([[7, 0], [2, 5]], Alternative::TwoSided).odds_ratio, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_fisher_exact_confidence_interval() {
+        let ci = fisher([[3, 1], [1, 3]], Alternative::TwoSided).confidence_interval;
+        assert_almost_eq!(ci.lower, 0.21173559544657856704, 1e-12);
+        assert_almost_eq!(ci.upper, 626.24353058881404194, 1e-9);
+        let ci = fisher([[10, 2], [3, 15]], Alternative::TwoSided).confidence_interval;
+        assert_almost_eq!(ci.lower, 2.7533827882493229491, 1e-11);
+        assert_almost_eq!(ci.upper, 301.4623379715164148, 1e-9);
+        let ci = fisher([[10, 2], [3, 15]], Alternative::Greater).confidence_interval;
+        assert_almost_eq!(ci.lower, 3.5092700538433156762, 1e-11);
+        assert_eq!(ci.upper, f64::INFINITY);
+        let ci = fisher([[1, 9], [11, 3]], Alternative::Less).confidence_interval;
+        assert_eq!(ci.lower, 0.0);
+        assert_almost_eq!(ci.upper, 0.32600296913224913937, 1e-12);
+        let ci = fisher([[0, 5], [4, 2]], Alternative::TwoSided).confidence_interval;
+        assert_eq!(ci.lower, 0.0);
+        assert_almost_eq!(ci.upper, 1.3639501358765491453, 1e-12);
+        let ci = fisher([[7, 0], [2, 5]], Alternative::TwoSided).confidence_interval;
+        assert_almost_eq!(ci.lower, 1.4494783668421310957, 1e-12);
+        assert_eq!(ci.upper, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_fisher_exact_bad() {
+        assert!(fisher_exact_test([[0, 0], [0, 0]], Alternative::TwoSided, 0.95).is_err());
+        assert!(fisher_exact_test([[1, 2], [3, 4]], Alternative::TwoSided, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_binomial_p_value() {
+        assert_almost_eq!(binomial(682, 925, 0.75, Alternative::TwoSided).p_value, 0.38249155957485168132, 1e-12);
+        assert_almost_eq!(binomial(682, 925, 0.75, Alternative::Less).p_value, 0.19600926705388334616, 1e-12);
+        assert_almost_eq!(binomial(682, 925, 0.75, Alternative::Greater).p_value, 0.82408912235242257935, 1e-12);
+        assert_almost_eq!(binomial(3, 20, 0.5, Alternative::TwoSided).p_value, 0.0025768280029296875, 1e-15);
+        assert_almost_eq!(binomial(9, 10, 0.5, Alternative::TwoSided).p_value, 0.021484375, 1e-15);
+        assert_almost_eq!(binomial(9, 10, 0.5, Alternative::Greater).p_value, 0.0107421875, 1e-15);
+        assert_almost_eq!(binomial(0, 12, 0.25, Alternative::TwoSided).p_value, 0.045929133892059326172, 1e-14);
+        assert_almost_eq!(binomial(0, 12, 0.25, Alternative::Greater).p_value, 1.0, 1e-15);
+        assert_almost_eq!(binomial(7, 15, 0.3, Alternative::TwoSided).p_value, 0.166410173171269, 1e-13);
+        assert_eq!(binomial(5, 10, 0.5, Alternative::TwoSided).p_value, 1.0);
+    }
+
+    #[test]
+    fn test_binomial_confidence_interval() {
+        let r = binomial(682, 925, 0.75, Alternative::TwoSided);
+        assert_almost_eq!(r.estimate, 682.0 / 925.0, 1e-15);
+        assert_almost_eq!(r.confidence_interval.lower, 0.70766826407903902303, 1e-12);
+        assert_almost_eq!(r.confidence_interval.upper, 0.76540655824152524698, 1e-12);
+        let ci = binomial(3, 20, 0.5, Alternative::TwoSided).confidence_interval;
+        assert_almost_eq!(ci.lower, 0.032070937185463705588, 1e-12);
+        assert_almost_eq!(ci.upper, 0.37892682654531393973, 1e-12);
+        let ci = binomial(9, 10, 0.5, Alternative::Less).confidence_interval;
+        assert_eq!(ci.lower, 0.0);
+        assert_almost_eq!(ci.upper, 0.99488380310817629887, 1e-12);
+        let ci = binomial(7, 15, 0.3, Alternative::Greater).confidence_interval;
+        assert_almost_eq!(ci.lower, 0.24372741576654897808, 1e-12);
+        assert_eq!(ci.upper, 1.0);
+        let ci = binomial(0, 12, 0.25, Alternative::TwoSided).confidence_interval;
+        assert_eq!(ci.lower, 0.0);
+        assert_almost_eq!(ci.upper, 0.26464846939705124867, 1e-12);
+    }
+
+    #[test]
+    fn test_binomial_bad() {
+        assert!(binomial_test(1, 0, 0.5, Alternative::TwoSided, 0.95).is_err());
+        assert!(binomial_test(3, 2, 0.5, Alternative::TwoSided, 0.95).is_err());
+        assert!(binomial_test(1, 2, 1.5, Alternative::TwoSided, 0.95).is_err());
+        assert!(binomial_test(1, 2, 0.5, Alternative::TwoSided, 1.5).is_err());
+    }
+}
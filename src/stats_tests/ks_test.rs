@@ -0,0 +1,291 @@
+use crate::distribution::{ContinuousCDF, Kolmogorov};
+use crate::function::kolmogorov;
+use crate::stats_tests::Alternative;
+use crate::{Result, StatsError};
+
+/// The result of a Kolmogorov-Smirnov test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KsTestResult {
+    /// The Kolmogorov-Smirnov statistic for the requested alternative
+    pub statistic: f64,
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+}
+
+/// The largest product of the sample sizes for which the two-sample test
+/// uses the exact null distribution
+const EXACT_MAX_NM: usize = 10_000;
+
+/// Returns a sorted copy of `data`, or an error if it contains `NaN`
+fn sorted(data: &[f64]) -> Result<Vec<f64>> {
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(StatsError::BadParams);
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(sorted)
+}
+
+/// Performs the one-sample Kolmogorov-Smirnov test of the null hypothesis
+/// that `x` was drawn from the continuous distribution `dist`
+///
+/// For `Alternative::TwoSided` the statistic is `D = sup |F_n(t) - F(t)|`
+/// and the p-value follows the `Kolmogorov` distribution for `n = x.len()`,
+/// which is exact for `n <= 500`. `Alternative::Greater` states that the
+/// distribution of `x` lies above `dist` and uses `D^+ = sup (F_n(t) - F(t))`,
+/// while `Alternative::Less` uses `D^- = sup (F(t) - F_n(t))`, both with
+/// exact p-values.
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty or contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::stats_tests::{ks_test, Alternative};
+///
+/// let x = [-1.2, 0.3, -0.5, 1.9, 0.8, -0.1, 0.4, -2.1, 1.1, 0.6];
+/// let result = ks_test(&x, &Normal::new(0.0, 1.0).unwrap(), Alternative::TwoSided).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn ks_test<D: ContinuousCDF<f64, f64>>(
+    x: &[f64],
+    dist: &D,
+    alternative: Alternative,
+) -> Result<KsTestResult> {
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    let x = sorted(x)?;
+    let n = x.len() as f64;
+    let (d_plus, d_minus) =
+        x.iter()
+            .enumerate()
+            .fold((0.0f64, 0.0f64), |(plus, minus), (i, &v)| {
+                let f = dist.cdf(v);
+                (
+                    plus.max((i + 1) as f64 / n - f),
+                    minus.max(f - i as f64 / n),
+                )
+            });
+    let len = x.len() as u64;
+    let (statistic, p_value) = match alternative {
+        Alternative::TwoSided => {
+            let d = d_plus.max(d_minus);
+            (d, Kolmogorov::new(len)?.sf(d))
+        }
+        Alternative::Greater => (d_plus, kolmogorov::smirnov_sf(len, d_plus)),
+        Alternative::Less => (d_minus, kolmogorov::smirnov_sf(len, d_minus)),
+    };
+    Ok(KsTestResult { statistic, p_value })
+}
+
+/// Performs the two-sample Kolmogorov-Smirnov test of the null hypothesis
+/// that `x` and `y` were drawn from the same continuous distribution
+///
+/// For `Alternative::TwoSided` the statistic is `D = sup |F_x(t) - F_y(t)|`.
+/// `Alternative::Greater` states that the distribution of `x` lies above
+/// that of `y` and uses `D^+ = sup (F_x(t) - F_y(t))`, while
+/// `Alternative::Less` uses `D^- = sup (F_y(t) - F_x(t))`.
+///
+/// The p-value is computed exactly by counting lattice paths if
+/// `x.len() * y.len() < 10000`, ignoring ties, and from the limiting
+/// distributions with the effective sample size
+/// `x.len() * y.len() / (x.len() + y.len())` otherwise.
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty or contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{ks_test_two_sample, Alternative};
+///
+/// let x = [0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64];
+/// let y = [2.20, 1.66, 1.38, 0.20, 0.36, 0.00, 0.96, 1.56, 0.44, 1.50];
+/// let result = ks_test_two_sample(&x, &y, Alternative::TwoSided).unwrap();
+/// assert_eq!(result.statistic, 0.5);
+/// assert!(result.p_value > 0.1);
+/// ```
+pub fn ks_test_two_sample(x: &[f64], y: &[f64], alternative: Alternative) -> Result<KsTestResult> {
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if y.is_empty() {
+        return Err(StatsError::ArgGte("y.len()", 1.0));
+    }
+    let x = sorted(x)?;
+    let y = sorted(y)?;
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let (mut i, mut j) = (0, 0);
+    let (mut d_plus, mut d_minus) = (0.0f64, 0.0f64);
+    while i < x.len() && j < y.len() {
+        let v = x[i].min(y[j]);
+        while i < x.len() && x[i] == v {
+            i += 1;
+        }
+        while j < y.len() && y[j] == v {
+            j += 1;
+        }
+        let diff = i as f64 / nx - j as f64 / ny;
+        d_plus = d_plus.max(diff);
+        d_minus = d_minus.max(-diff);
+    }
+    let statistic = match alternative {
+        Alternative::TwoSided => d_plus.max(d_minus),
+        Alternative::Greater => d_plus,
+        Alternative::Less => d_minus,
+    };
+    let p_value = if x.len() * y.len() < EXACT_MAX_NM {
+        exact_two_sample_sf(x.len(), y.len(), statistic, alternative)
+    } else {
+        let n = nx * ny / (nx + ny);
+        match alternative {
+            Alternative::TwoSided => kolmogorov::kolmogorov_sf(n.sqrt() * statistic),
+            _ => (-2.0 * n * statistic * statistic).exp(),
+        }
+    };
+    Ok(KsTestResult {
+        statistic,
+        p_value: p_value.clamp(0.0, 1.0),
+    })
+}
+
+/// Computes the probability that the two-sample Kolmogorov-Smirnov
+/// statistic for samples of sizes `m` and `n` is at least `d` under the
+/// null hypothesis, by counting the monotone lattice paths from `(0, 0)` to
+/// `(m, n)` that leave the band defined by `d`. The counts are normalized
+/// by `C(i + n, n)` along the way to avoid overflow, and the upper tail is
+/// accumulated directly so that small p-values keep full precision.
+fn exact_two_sample_sf(m: usize, n: usize, d: f64, alternative: Alternative) -> f64 {
+    let (md, nd) = (m as f64, n as f64);
+    // the statistic is a multiple of 1 / mn, so move the boundary half a
+    // step inwards to make the comparison robust to rounding
+    let q = (0.5 + (d * md * nd - 1e-7).floor()) / (md * nd);
+    let outside = |i: usize, j: usize| {
+        let diff = i as f64 / md - j as f64 / nd;
+        match alternative {
+            Alternative::TwoSided => diff.abs() > q,
+            Alternative::Greater => diff > q,
+            Alternative::Less => -diff > q,
+        }
+    };
+    // `total` counts all paths to each point, `u` those that have already
+    // left the band; once outside, every path through the point counts
+    let mut total = vec![1.0; n + 1];
+    let mut u: Vec<f64> = (0..=n)
+        .map(|j| if outside(0, j) { 1.0 } else { 0.0 })
+        .collect();
+    for j in 1..=n {
+        if u[j] == 0.0 {
+            u[j] = u[j - 1];
+        }
+    }
+    for i in 1..=m {
+        let w = i as f64 / (i + n) as f64;
+        total[0] *= w;
+        u[0] = if outside(i, 0) { total[0] } else { w * u[0] };
+        for j in 1..=n {
+            total[j] = w * total[j] + total[j - 1];
+            u[j] = if outside(i, j) {
+                total[j]
+            } else {
+                w * u[j] + u[j - 1]
+            };
+        }
+    }
+    u[n]
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::{Normal, Uniform};
+
+    const X: [f64; 10] = [-1.2, 0.3, -0.5, 1.9, 0.8, -0.1, 0.4, -2.1, 1.1, 0.6];
+    const A: [f64; 10] = [0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64];
+    const B: [f64; 10] = [2.20, 1.66, 1.38, 0.20, 0.36, 0.00, 0.96, 1.56, 0.44, 1.50];
+
+    fn check(r: KsTestResult, stat: f64, p: f64) {
+        assert_almost_eq!(r.statistic, stat, 1e-10);
+        assert_almost_eq!(r.p_value, p, 1e-10);
+    }
+
+    #[test]
+    fn test_ks_test() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        check(ks_test(&X, &normal, Alternative::TwoSided).unwrap(), 0.21791142218895263307, 0.65382470364369268418);
+        check(ks_test(&X, &normal, Alternative::Greater).unwrap(), 0.084930329778291723354, 0.82311970127048568776);
+        check(ks_test(&X, &normal, Alternative::Less).unwrap(), 0.21791142218895263307, 0.33843075557129748622);
+        let shifted: Vec<f64> = X.iter().map(|x| x + 0.5).collect();
+        check(ks_test(&shifted, &normal, Alternative::TwoSided).unwrap(), 0.38814460141660332729, 0.072441812533792135175);
+        check(ks_test(&shifted, &normal, Alternative::Greater).unwrap(), 0.045200708300442015891, 0.93271124412113615725);
+        check(ks_test(&shifted, &normal, Alternative::Less).unwrap(), 0.38814460141660332729, 0.036221128891193111473);
+    }
+
+    #[test]
+    fn test_ks_test_large_sample() {
+        // evenly spaced points lie as close to the uniform cdf as possible
+        let x: Vec<f64> = (0..1000).map(|i| (i as f64 + 0.5) / 1000.0).collect();
+        let r = ks_test(&x, &Uniform::new(0.0, 1.0).unwrap(), Alternative::TwoSided).unwrap();
+        assert_almost_eq!(r.statistic, 0.0005, 1e-12);
+        assert_eq!(r.p_value, 1.0);
+        let r = ks_test(&x, &Uniform::new(0.0, 2.0).unwrap(), Alternative::TwoSided).unwrap();
+        assert_almost_eq!(r.statistic, 0.50025, 1e-12);
+        assert!(r.p_value < 1e-100);
+    }
+
+    #[test]
+    fn test_ks_test_errors() {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        assert!(ks_test(&[], &normal, Alternative::TwoSided).is_err());
+        assert!(ks_test(&[0.0, f64::NAN], &normal, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_ks_test_two_sample() {
+        check(ks_test_two_sample(&A, &B, Alternative::TwoSided).unwrap(), 0.5, 0.16782134274394336314);
+        check(ks_test_two_sample(&A, &B, Alternative::Greater).unwrap(), 0.5, 0.083916083916083916084);
+        check(ks_test_two_sample(&A, &B, Alternative::Less).unwrap(), 0.0, 1.0);
+        let b: Vec<f64> = B.iter().chain(&[0.05, -0.3, 1.1]).cloned().collect();
+        check(ks_test_two_sample(&A, &b, Alternative::TwoSided).unwrap(), 0.43846153846153846, 0.17183798836780395537);
+        check(ks_test_two_sample(&A, &b, Alternative::Greater).unwrap(), 0.43846153846153846, 0.085929483089262332768);
+        check(ks_test_two_sample(&b, &A, Alternative::Less).unwrap(), 0.43846153846153846, 0.085929483089262332768);
+    }
+
+    #[test]
+    fn test_ks_test_two_sample_small_p_value() {
+        // fully separated samples, only the extreme paths reach `D = 1`
+        let x: Vec<f64> = (0..50).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..50).map(|i| i as f64 + 100.0).collect();
+        let r = ks_test_two_sample(&x, &y, Alternative::TwoSided).unwrap();
+        assert_eq!(r.statistic, 1.0);
+        assert_almost_eq!(r.p_value, 1.9823306042836678135e-29, 1e-42);
+        let r = ks_test_two_sample(&x, &y, Alternative::Greater).unwrap();
+        assert_almost_eq!(r.p_value, 9.9116530214183390674e-30, 1e-43);
+        let r = ks_test_two_sample(&x[..30], &y[..40], Alternative::TwoSided).unwrap();
+        assert_almost_eq!(r.p_value, 3.6135170070159585223e-20, 1e-33);
+    }
+
+    #[test]
+    fn test_ks_test_two_sample_large() {
+        let x: Vec<f64> = (0..200).map(|i| i as f64).collect();
+        let y: Vec<f64> = (0..100).map(|i| 2.0 * i as f64 + 20.0).collect();
+        let r = ks_test_two_sample(&x, &y, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(r.statistic, 0.1, 1e-12);
+        assert_almost_eq!(r.p_value, crate::function::kolmogorov::kolmogorov_sf((200.0f64 / 3.0).sqrt() * 0.1), 1e-12);
+        let r = ks_test_two_sample(&x, &y, Alternative::Greater).unwrap();
+        assert_almost_eq!(r.p_value, (-2.0 * 200.0 / 3.0 * 0.01f64).exp(), 1e-12);
+    }
+
+    #[test]
+    fn test_ks_test_two_sample_errors() {
+        assert!(ks_test_two_sample(&[], &A, Alternative::TwoSided).is_err());
+        assert!(ks_test_two_sample(&A, &[], Alternative::TwoSided).is_err());
+        assert!(ks_test_two_sample(&A, &[f64::NAN], Alternative::TwoSided).is_err());
+    }
+}
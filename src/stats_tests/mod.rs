@@ -0,0 +1,154 @@
+//! Provides statistical hypothesis tests built on top of the
+//! distributions in `statrs::distribution` and the sample statistics in
+//! `statrs::statistics`.
+
+pub use self::anova::*;
+pub use self::bootstrap::*;
+pub use self::chi_squared_test::*;
+pub use self::effect_size::*;
+pub use self::equivalence::*;
+pub use self::exact_test::*;
+pub use self::ks_test::*;
+pub use self::multiple_testing::*;
+pub use self::normality::*;
+pub use self::permutation::*;
+pub use self::power::*;
+pub use self::proportion::*;
+pub use self::rank_test::*;
+pub use self::rayleigh_test::*;
+pub use self::t_test::*;
+pub use self::variance_test::*;
+
+mod anova;
+mod bootstrap;
+mod chi_squared_test;
+mod effect_size;
+mod equivalence;
+mod exact_test;
+mod ks_test;
+mod multiple_testing;
+mod normality;
+mod permutation;
+mod power;
+mod proportion;
+mod rank_test;
+mod rayleigh_test;
+mod t_test;
+mod variance_test;
+
+use crate::distribution::ContinuousCDF;
+use crate::{Result, StatsError};
+
+/// The alternative hypothesis of a test, i.e. the direction in which the
+/// true parameter is assumed to deviate from its hypothesized value
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Alternative {
+    /// The parameter differs from its hypothesized value
+    TwoSided,
+    /// The parameter is less than its hypothesized value
+    Less,
+    /// The parameter is greater than its hypothesized value
+    Greater,
+}
+
+/// A confidence interval `[lower, upper]` for a parameter with nominal
+/// coverage `level`. One-sided intervals have an infinite bound.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ConfidenceInterval {
+    /// The lower bound of the interval
+    pub lower: f64,
+    /// The upper bound of the interval
+    pub upper: f64,
+    /// The confidence level of the interval, e.g. `0.95`
+    pub level: f64,
+}
+
+impl ConfidenceInterval {
+    /// Returns true if `x` lies within the closed interval
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use statrs::stats_tests::ConfidenceInterval;
+    ///
+    /// let ci = ConfidenceInterval { lower: -1.0, upper: 2.0, level: 0.95 };
+    /// assert!(ci.contains(0.0));
+    /// assert!(!ci.contains(3.0));
+    /// ```
+    pub fn contains(&self, x: f64) -> bool {
+        self.lower <= x && x <= self.upper
+    }
+
+    /// Returns the width `upper - lower` of the interval
+    pub fn width(&self) -> f64 {
+        self.upper - self.lower
+    }
+}
+
+/// Returns an error if `level` does not lie in `(0, 1)`
+fn check_level(level: f64) -> Result<()> {
+    if level > 0.0 && level < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("level", 0.0, 1.0))
+    }
+}
+
+/// Computes the p-value of the test statistic `stat` under the null
+/// distribution `dist`, which is assumed to be symmetric for two-sided
+/// alternatives
+fn p_value<D: ContinuousCDF<f64, f64>>(dist: &D, stat: f64, alternative: Alternative) -> f64 {
+    match alternative {
+        Alternative::TwoSided => (2.0 * dist.cdf(stat).min(dist.sf(stat))).min(1.0),
+        Alternative::Less => dist.cdf(stat),
+        Alternative::Greater => dist.sf(stat),
+    }
+}
+
+/// Computes the confidence interval `estimate ± q * std_err` for a
+/// parameter whose pivot `(estimate - parameter) / std_err` follows the
+/// symmetric distribution `dist`
+fn pivot_interval<D: ContinuousCDF<f64, f64>>(
+    dist: &D,
+    estimate: f64,
+    std_err: f64,
+    alternative: Alternative,
+    level: f64,
+) -> ConfidenceInterval {
+    let (lower, upper) = match alternative {
+        Alternative::TwoSided => {
+            let q = dist.inverse_sf((1.0 - level) / 2.0);
+            (estimate - q * std_err, estimate + q * std_err)
+        }
+        Alternative::Less => (
+            f64::NEG_INFINITY,
+            estimate + dist.inverse_sf(1.0 - level) * std_err,
+        ),
+        Alternative::Greater => (
+            estimate - dist.inverse_sf(1.0 - level) * std_err,
+            f64::INFINITY,
+        ),
+    };
+    ConfidenceInterval {
+        lower,
+        upper,
+        level,
+    }
+}
+
+/// Finds the root of the monotone function `f` in `[lo, hi]` by bisection
+/// to full precision, assuming `f` changes sign in the interval
+fn bisect<F: Fn(f64) -> f64>(f: F, mut lo: f64, mut hi: f64) -> f64 {
+    let lo_negative = f(lo) < 0.0;
+    loop {
+        let mid = 0.5 * (lo + hi);
+        if mid <= lo || mid >= hi {
+            return mid;
+        }
+        if (f(mid) < 0.0) == lo_negative {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
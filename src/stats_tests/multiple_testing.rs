@@ -0,0 +1,343 @@
+use crate::{Result, StatsError};
+use std::cmp::Ordering;
+
+/// The procedure used to adjust p-values for multiple comparisons
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Adjustment {
+    /// Bonferroni's correction, controlling the family-wise error rate
+    Bonferroni,
+    /// Holm's step-down procedure, controlling the family-wise error rate
+    /// and uniformly more powerful than Bonferroni's correction
+    Holm,
+    /// Hochberg's step-up procedure, controlling the family-wise error rate
+    /// for independent or positively dependent tests
+    Hochberg,
+    /// Hommel's procedure, controlling the family-wise error rate for
+    /// independent or positively dependent tests and more powerful than
+    /// Hochberg's procedure
+    Hommel,
+    /// The Benjamini-Hochberg procedure, controlling the false discovery
+    /// rate for independent or positively dependent tests
+    BenjaminiHochberg,
+    /// The Benjamini-Yekutieli procedure, controlling the false discovery
+    /// rate under arbitrary dependence
+    BenjaminiYekutieli,
+}
+
+/// Storey's q-values together with the estimated proportion of true null
+/// hypotheses they are based on
+#[derive(Debug, Clone, PartialEq)]
+pub struct QValues {
+    /// The estimated proportion `π0` of true null hypotheses
+    pub pi0: f64,
+    /// The q-values in the order of the p-values they were computed from
+    pub q_values: Vec<f64>,
+}
+
+/// Returns an error if any of `p` is not a probability
+fn check_p_values(p: &[f64]) -> Result<()> {
+    if p.iter().all(|x| (0.0..=1.0).contains(x)) {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalIncl("p", 0.0, 1.0))
+    }
+}
+
+/// Returns the indices of `p` ordered by increasing p-value
+fn ascending_order(p: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..p.len()).collect();
+    order.sort_by(|&i, &j| p[i].partial_cmp(&p[j]).unwrap_or(Ordering::Equal));
+    order
+}
+
+/// Adjusts the sorted p-values `sorted` of a step-up procedure, where the
+/// `i`-th smallest p-value is scaled by `factor(i)` for `i = 1, ..., m`
+fn step_up<F: Fn(f64) -> f64>(sorted: &[f64], factor: F) -> Vec<f64> {
+    let mut adjusted = vec![0.0; sorted.len()];
+    let mut min = 1.0f64;
+    for (i, p) in sorted.iter().enumerate().rev() {
+        min = min.min(factor(i as f64 + 1.0) * p);
+        adjusted[i] = min;
+    }
+    adjusted
+}
+
+/// Computes Hommel's adjusted p-values from the sorted p-values `sorted`
+fn hommel(sorted: &[f64]) -> Vec<f64> {
+    let n = sorted.len();
+    let init = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, p)| n as f64 * p / (i + 1) as f64)
+        .fold(f64::INFINITY, f64::min);
+    let mut q = vec![init; n];
+    let mut adjusted = vec![init; n];
+    for m in (2..n).rev() {
+        let mf = m as f64;
+        // Simes' test of the intersection of the m - 1 largest p-values
+        let q1 = (n - m + 1..n)
+            .map(|i| mf * sorted[i] / (i + m + 1 - n) as f64)
+            .fold(f64::INFINITY, f64::min);
+        for i in 0..=n - m {
+            q[i] = (mf * sorted[i]).min(q1);
+        }
+        for i in n - m + 1..n {
+            q[i] = q[n - m];
+        }
+        for (a, q) in adjusted.iter_mut().zip(&q) {
+            *a = a.max(*q);
+        }
+    }
+    adjusted
+        .iter()
+        .zip(sorted)
+        .map(|(a, p)| a.max(*p))
+        .collect()
+}
+
+/// Adjusts the p-values `p` of a family of hypothesis tests for multiple
+/// comparisons with the procedure `method`, returning the adjusted
+/// p-values in the order of `p`
+///
+/// A hypothesis is rejected at level `α` by the procedure if and only if
+/// its adjusted p-value is at most `α`, see `reject`.
+///
+/// # Formula
+///
+/// For sorted p-values `p_(1) <= ... <= p_(m)`
+///
+/// ```ignore
+/// Bonferroni:         min(1, m p_(i))
+/// Holm:               max_{j <= i} min(1, (m - j + 1) p_(j))
+/// Hochberg:           min_{j >= i} min(1, (m - j + 1) p_(j))
+/// BenjaminiHochberg:  min_{j >= i} min(1, m / j p_(j))
+/// BenjaminiYekutieli: min_{j >= i} min(1, c(m) m / j p_(j))
+/// ```
+///
+/// where `c(m) = Σ 1 / k` for `k = 1, ..., m`. Hommel's procedure follows
+/// the algorithm of Wright (1992).
+///
+/// # Errors
+///
+/// Returns an error if any p-value is not in `[0, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{adjust_p_values, Adjustment};
+///
+/// let p = [0.01, 0.04, 0.03, 0.005];
+/// let adjusted = adjust_p_values(&p, Adjustment::Holm).unwrap();
+/// let expected = [0.03, 0.06, 0.06, 0.02];
+/// assert!(adjusted.iter().zip(&expected).all(|(a, e)| (a - e).abs() < 1e-15));
+/// ```
+pub fn adjust_p_values(p: &[f64], method: Adjustment) -> Result<Vec<f64>> {
+    check_p_values(p)?;
+    let n = p.len();
+    if n <= 1 {
+        return Ok(p.to_vec());
+    }
+    let nf = n as f64;
+    let order = ascending_order(p);
+    let sorted: Vec<f64> = order.iter().map(|&i| p[i]).collect();
+    let adjusted = match method {
+        Adjustment::Bonferroni => sorted.iter().map(|p| (nf * p).min(1.0)).collect(),
+        Adjustment::Holm => {
+            let mut max = 0.0f64;
+            sorted
+                .iter()
+                .enumerate()
+                .map(|(i, p)| {
+                    max = max.max((nf - i as f64) * p);
+                    max.min(1.0)
+                })
+                .collect()
+        }
+        Adjustment::Hochberg => step_up(&sorted, |i| nf - i + 1.0),
+        // Hommel's procedure reduces to Hochberg's for two hypotheses
+        Adjustment::Hommel if n == 2 => step_up(&sorted, |i| nf - i + 1.0),
+        Adjustment::Hommel => hommel(&sorted),
+        Adjustment::BenjaminiHochberg => step_up(&sorted, |i| nf / i),
+        Adjustment::BenjaminiYekutieli => {
+            let c: f64 = (1..=n).map(|k| 1.0 / k as f64).sum();
+            step_up(&sorted, |i| c * nf / i)
+        }
+    };
+    let mut result = vec![0.0; n];
+    for (i, a) in order.iter().zip(adjusted) {
+        result[*i] = a;
+    }
+    Ok(result)
+}
+
+/// Returns for each of the p-values `p` of a family of hypothesis tests
+/// whether the procedure `method` rejects it at level `alpha`
+///
+/// # Errors
+///
+/// Returns an error if any p-value is not in `[0, 1]` or if `alpha` is
+/// not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{reject, Adjustment};
+///
+/// let p = [0.01, 0.04, 0.03, 0.005];
+/// let rejected = reject(&p, Adjustment::BenjaminiHochberg, 0.05).unwrap();
+/// assert_eq!(rejected, vec![true, true, true, true]);
+/// let rejected = reject(&p, Adjustment::Bonferroni, 0.05).unwrap();
+/// assert_eq!(rejected, vec![true, false, false, true]);
+/// ```
+pub fn reject(p: &[f64], method: Adjustment, alpha: f64) -> Result<Vec<bool>> {
+    if alpha.is_nan() || alpha <= 0.0 || alpha >= 1.0 {
+        return Err(StatsError::ArgIntervalExcl("alpha", 0.0, 1.0));
+    }
+    Ok(adjust_p_values(p, method)?
+        .iter()
+        .map(|&a| a <= alpha)
+        .collect())
+}
+
+/// Computes Storey's q-values of the p-values `p`, i.e. the minimal false
+/// discovery rate at which each test is called significant, estimating
+/// the proportion of true null hypotheses from the p-values above the
+/// tuning parameter `lambda`
+///
+/// `lambda = 0.5` is a common choice. With `lambda = 0` every hypothesis
+/// is assumed to be a true null and the q-values equal the
+/// Benjamini-Hochberg adjusted p-values.
+///
+/// # Formula
+///
+/// ```ignore
+/// π0 = min(1, #{p_i > λ} / (m (1 - λ)))
+/// q_(i) = min_{j >= i} min(1, π0 m p_(j) / j)
+/// ```
+///
+/// for sorted p-values `p_(1) <= ... <= p_(m)`
+///
+/// # Errors
+///
+/// Returns an error if any p-value is not in `[0, 1]`, if `lambda` is not
+/// in `[0, 1)`, or if no p-value exceeds `lambda` so that the estimated
+/// proportion of true null hypotheses is zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::q_values;
+///
+/// let p = [0.001, 0.008, 0.039, 0.041, 0.27, 0.6, 0.74, 0.9];
+/// let result = q_values(&p, 0.5).unwrap();
+/// assert_eq!(result.pi0, 0.75);
+/// assert!(result.q_values[0] < 0.01);
+/// ```
+pub fn q_values(p: &[f64], lambda: f64) -> Result<QValues> {
+    check_p_values(p)?;
+    if !(0.0..1.0).contains(&lambda) {
+        return Err(StatsError::ArgIntervalExclMax("lambda", 0.0, 1.0));
+    }
+    let n = p.len() as f64;
+    let above = p.iter().filter(|&&x| x > lambda).count() as f64;
+    let pi0 = (above / (n * (1.0 - lambda))).min(1.0);
+    if pi0 <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let order = ascending_order(p);
+    let sorted: Vec<f64> = order.iter().map(|&i| p[i]).collect();
+    let mut q_values = vec![0.0; p.len()];
+    for (i, q) in order.iter().zip(step_up(&sorted, |i| pi0 * n / i)) {
+        q_values[*i] = q;
+    }
+    Ok(QValues { pi0, q_values })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P1: [f64; 4] = [0.01, 0.04, 0.03, 0.005];
+    const P2: [f64; 12] = [0.0021, 0.042, 0.31, 0.0009, 0.6, 0.018, 0.74, 0.049, 0.0047, 0.5, 0.2, 0.03];
+
+    fn check(p: &[f64], method: Adjustment, expected: &[f64]) {
+        let adjusted = adjust_p_values(p, method).unwrap();
+        assert_eq!(adjusted.len(), expected.len());
+        for (a, e) in adjusted.iter().zip(expected) {
+            assert_almost_eq!(*a, *e, 1e-15);
+        }
+    }
+
+    #[test]
+    fn test_bonferroni() {
+        check(&P1, Adjustment::Bonferroni, &[0.04, 0.16, 0.12, 0.02]);
+        check(&P2, Adjustment::Bonferroni, &[0.0252, 0.504, 1.0, 0.0108, 1.0, 0.216, 1.0, 0.588, 0.0564, 1.0, 1.0, 0.36]);
+    }
+
+    #[test]
+    fn test_holm() {
+        check(&P1, Adjustment::Holm, &[0.03, 0.06, 0.06, 0.02]);
+        check(&P2, Adjustment::Holm, &[0.0231, 0.294, 1.0, 0.0108, 1.0, 0.162, 1.0, 0.294, 0.047, 1.0, 1.0, 0.24]);
+    }
+
+    #[test]
+    fn test_hochberg() {
+        check(&P1, Adjustment::Hochberg, &[0.03, 0.04, 0.04, 0.02]);
+        check(&P2, Adjustment::Hochberg, &[0.0231, 0.294, 0.74, 0.0108, 0.74, 0.162, 0.74, 0.294, 0.047, 0.74, 0.74, 0.24]);
+    }
+
+    #[test]
+    fn test_hommel() {
+        check(&P1, Adjustment::Hommel, &[0.03, 0.04, 0.04, 0.02]);
+        check(&P2, Adjustment::Hommel, &[0.0231, 0.252, 0.74, 0.0108, 0.74, 0.13066666666666665, 0.74, 0.294, 0.047, 0.74, 0.74, 0.18]);
+        check(&[0.02, 0.04], Adjustment::Hommel, &[0.04, 0.04]);
+    }
+
+    #[test]
+    fn test_benjamini_hochberg() {
+        check(&P1, Adjustment::BenjaminiHochberg, &[0.02, 0.04, 0.04, 0.02]);
+        check(&P2, Adjustment::BenjaminiHochberg, &[0.0126, 0.084, 0.41333333333333333, 0.0108, 0.6545454545454545, 0.054, 0.74, 0.084, 0.0188, 0.6, 0.3, 0.072]);
+    }
+
+    #[test]
+    fn test_benjamini_yekutieli() {
+        check(&P1, Adjustment::BenjaminiYekutieli, &[0.041666666666666664, 0.08333333333333333, 0.08333333333333333, 0.041666666666666664]);
+        check(&P2, Adjustment::BenjaminiYekutieli, &[0.03910045454545454, 0.260669696969697, 1.0, 0.03351467532467532, 1.0, 0.16757337662337662, 1.0, 0.260669696969697, 0.058340360750360754, 1.0, 0.9309632034632035, 0.22343116883116884]);
+    }
+
+    #[test]
+    fn test_adjust_trivial() {
+        check(&[], Adjustment::Holm, &[]);
+        check(&[0.3], Adjustment::BenjaminiYekutieli, &[0.3]);
+        assert!(adjust_p_values(&[0.1, 1.1], Adjustment::Holm).is_err());
+        assert!(adjust_p_values(&[0.1, f64::NAN], Adjustment::Holm).is_err());
+    }
+
+    #[test]
+    fn test_reject() {
+        assert_eq!(reject(&P2, Adjustment::Holm, 0.05).unwrap(), vec![true, false, false, true, false, false, false, false, true, false, false, false]);
+        assert_eq!(reject(&P2, Adjustment::BenjaminiHochberg, 0.05).unwrap(), vec![true, false, false, true, false, false, false, false, true, false, false, false]);
+        assert_eq!(reject(&P2, Adjustment::BenjaminiHochberg, 0.1).unwrap(), vec![true, true, false, true, false, true, false, true, true, false, false, true]);
+        assert!(reject(&P2, Adjustment::Holm, 0.0).is_err());
+    }
+
+    #[test]
+    fn test_q_values() {
+        let r = q_values(&[0.001, 0.008, 0.039, 0.041, 0.27, 0.6, 0.74, 0.9], 0.5).unwrap();
+        assert_eq!(r.pi0, 0.75);
+        let expected = [0.006, 0.024, 0.0615, 0.0615, 0.324, 0.6, 0.6342857142857142, 0.675];
+        for (q, e) in r.q_values.iter().zip(&expected) {
+            assert_almost_eq!(*q, *e, 1e-15);
+        }
+        let r = q_values(&P2, 0.5).unwrap();
+        assert_almost_eq!(r.pi0, 1.0 / 3.0, 1e-15);
+        assert_almost_eq!(r.q_values[2], 0.13777777777777778, 1e-15);
+        // without estimating π0 the q-values are the BH adjusted p-values
+        let r = q_values(&P2, 0.0).unwrap();
+        assert_eq!(r.pi0, 1.0);
+        assert_eq!(r.q_values, adjust_p_values(&P2, Adjustment::BenjaminiHochberg).unwrap());
+        assert!(q_values(&P2, 1.0).is_err());
+        assert!(q_values(&[0.01, 0.02], 0.5).is_err());
+    }
+}
@@ -0,0 +1,522 @@
+use crate::distribution::{ChiSquared, ContinuousCDF, Normal};
+use crate::statistics::Statistics;
+use crate::{Result, StatsError};
+use std::f64::consts::PI;
+
+/// The result of a goodness-of-fit test for normality or for a given
+/// distribution
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NormalityTestResult {
+    /// The test statistic
+    pub statistic: f64,
+    /// The p-value of the test
+    pub p_value: f64,
+}
+
+/// Returns a sorted copy of `data`, or an error if it contains `NaN`
+fn sorted(data: &[f64]) -> Result<Vec<f64>> {
+    if data.iter().any(|x| x.is_nan()) {
+        return Err(StatsError::BadParams);
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    Ok(sorted)
+}
+
+/// Computes the second, third and fourth sample central moments of `x`,
+/// normalized by `n`, or returns an error if `x` is constant
+fn central_moments(x: &[f64]) -> Result<(f64, f64, f64)> {
+    let mean = x.mean();
+    let n = x.len() as f64;
+    let (m2, m3, m4) = x.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), v| {
+        let d = v - mean;
+        let d2 = d * d;
+        (m2 + d2, m3 + d2 * d, m4 + d2 * d2)
+    });
+    if m2 == 0.0 || m2.is_nan() {
+        return Err(StatsError::BadParams);
+    }
+    Ok((m2 / n, m3 / n, m4 / n))
+}
+
+/// Evaluates the polynomial with coefficients `c` in increasing order of
+/// degree at `x`
+fn poly(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Performs the Shapiro-Wilk test of the null hypothesis that `x` was
+/// drawn from a normal distribution
+///
+/// The coefficients of `W` and its p-value are computed with Royston's
+/// approximation (algorithm AS R94), which is exact for `n = 3`.
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than 3 or more than 5000 elements,
+/// contains `NaN` or is constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::shapiro_wilk_test;
+///
+/// // weights of 11 men from Shapiro and Wilk (1965)
+/// let x = [148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0];
+/// let result = shapiro_wilk_test(&x).unwrap();
+/// assert!((result.statistic - 0.79).abs() < 0.005);
+/// assert!(result.p_value < 0.01);
+/// ```
+pub fn shapiro_wilk_test(x: &[f64]) -> Result<NormalityTestResult> {
+    const C1: [f64; 6] = [0.0, 0.221157, -0.147981, -2.07119, 4.434685, -2.706056];
+    const C2: [f64; 6] = [0.0, 0.042981, -0.293762, -1.752461, 5.682633, -3.582633];
+    const C3: [f64; 4] = [0.544, -0.39978, 0.025054, -6.714e-4];
+    const C4: [f64; 4] = [1.3822, -0.77857, 0.062767, -0.0020322];
+    const C5: [f64; 4] = [-1.5861, -0.31082, -0.083751, 0.0038915];
+    const C6: [f64; 3] = [-0.4803, -0.082676, 0.0030302];
+    const G: [f64; 2] = [-2.273, 0.459];
+
+    if x.len() < 3 {
+        return Err(StatsError::ArgGte("x.len()", 3.0));
+    }
+    if x.len() > 5000 {
+        return Err(StatsError::ArgLte("x.len()", 5000.0));
+    }
+    let x = sorted(x)?;
+    let n = x.len();
+    let nf = n as f64;
+    let range = x[n - 1] - x[0];
+    if range == 0.0 {
+        return Err(StatsError::BadParams);
+    }
+
+    // the coefficients of the lower half of the order statistics, the
+    // upper half being their negation
+    let half = n / 2;
+    let mut a = vec![0.0; half];
+    if n == 3 {
+        a[0] = -0.5f64.sqrt();
+    } else {
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        let m: Vec<f64> = (1..=half)
+            .map(|i| normal.inverse_sf((i as f64 - 0.375) / (nf + 0.25)))
+            .collect();
+        let summ2 = 2.0 * m.iter().map(|v| v * v).sum::<f64>();
+        let ssumm2 = summ2.sqrt();
+        let rsn = 1.0 / nf.sqrt();
+        let a1 = -poly(&C1, rsn) - m[0] / ssumm2;
+        let (first, fac) = if n > 5 {
+            let a2 = -poly(&C2, rsn) - m[1] / ssumm2;
+            let fac = ((summ2 - 2.0 * m[0] * m[0] - 2.0 * m[1] * m[1])
+                / (1.0 - 2.0 * a1 * a1 - 2.0 * a2 * a2))
+                .sqrt();
+            a[1] = a2;
+            (2, fac)
+        } else {
+            let fac = ((summ2 - 2.0 * m[0] * m[0]) / (1.0 - 2.0 * a1 * a1)).sqrt();
+            (1, fac)
+        };
+        a[0] = a1;
+        for i in first..half {
+            a[i] = -m[i] / fac;
+        }
+    }
+
+    // W is the squared correlation between the data and the coefficients,
+    // computed as one minus its complement for accuracy near one
+    let coefficient = |i: usize| {
+        let j = n - 1 - i;
+        match i.cmp(&j) {
+            std::cmp::Ordering::Less => a[i],
+            std::cmp::Ordering::Greater => -a[j],
+            std::cmp::Ordering::Equal => 0.0,
+        }
+    };
+    let mean_x = x.iter().map(|v| v / range).sum::<f64>() / nf;
+    let (mut ssa, mut ssx, mut sax) = (0.0, 0.0, 0.0);
+    for (i, v) in x.iter().enumerate() {
+        let asa = coefficient(i);
+        let xsx = v / range - mean_x;
+        ssa += asa * asa;
+        ssx += xsx * xsx;
+        sax += asa * xsx;
+    }
+    let ssassx = (ssa * ssx).sqrt();
+    let w1 = (ssassx - sax) * (ssassx + sax) / (ssa * ssx);
+    let w = 1.0 - w1;
+
+    if n == 3 {
+        // exact p-value
+        let p_value = (6.0 / PI * (w.sqrt().asin() - PI / 3.0)).max(0.0);
+        return Ok(NormalityTestResult {
+            statistic: w,
+            p_value,
+        });
+    }
+
+    let mut y = w1.ln();
+    let (m, s) = if n <= 11 {
+        let gamma = poly(&G, nf);
+        if y >= gamma {
+            return Ok(NormalityTestResult {
+                statistic: w,
+                p_value: 0.0,
+            });
+        }
+        y = -(gamma - y).ln();
+        (poly(&C3, nf), poly(&C4, nf).exp())
+    } else {
+        let ln_n = nf.ln();
+        (poly(&C5, ln_n), poly(&C6, ln_n).exp())
+    };
+    Ok(NormalityTestResult {
+        statistic: w,
+        p_value: Normal::new(m, s)?.sf(y),
+    })
+}
+
+/// Computes the Anderson-Darling statistic of the sorted sample `x`,
+/// whose `i`-th smallest element has log cdf `ln_cdf[i]` and log survival
+/// function `ln_sf[i]`
+fn anderson_darling_statistic(ln_cdf: &[f64], ln_sf: &[f64]) -> f64 {
+    let n = ln_cdf.len();
+    let sum: f64 = (0..n)
+        .map(|i| (2 * i + 1) as f64 * (ln_cdf[i] + ln_sf[n - 1 - i]))
+        .sum();
+    -(n as f64) - sum / n as f64
+}
+
+/// Computes the cdf of the Anderson-Darling statistic `z` for a sample of
+/// size `n` from a fully specified distribution using the approximation of
+/// Marsaglia and Marsaglia (2004)
+fn anderson_darling_cdf(n: f64, z: f64) -> f64 {
+    let x = if z < 2.0 {
+        (-1.2337141 / z).exp() / z.sqrt()
+            * (2.00012
+                + (0.247105 - (0.0649821 - (0.0347962 - (0.011672 - 0.00168691 * z) * z) * z) * z)
+                    * z)
+    } else {
+        (-(1.0776
+            - (2.30695 - (0.43424 - (0.082433 - (0.008056 - 0.0003146 * z) * z) * z) * z) * z)
+            .exp())
+        .exp()
+    };
+    // correct the limiting distribution for the sample size
+    let correction = if x > 0.8 {
+        (-130.2137
+            + (745.2337 - (1705.091 - (1950.646 - (1116.360 - 255.7844 * x) * x) * x) * x) * x)
+            / n
+    } else {
+        let c = 0.01265 + 0.1757 / n;
+        if x < c {
+            let t = x / c;
+            let t = t.sqrt() * (1.0 - t) * (49.0 * t - 102.0);
+            t * (0.0037 / (n * n) + 0.00078 / n + 0.00006) / n
+        } else {
+            let t = (x - c) / (0.8 - c);
+            let t = -0.00022633
+                + (6.54034 - (14.6538 - (14.458 - (8.259 - 1.91864 * t) * t) * t) * t) * t;
+            t * (0.04213 / n + 0.01365 / (n * n))
+        }
+    };
+    (x + correction).clamp(0.0, 1.0)
+}
+
+/// Performs the Anderson-Darling test of the null hypothesis that `x` was
+/// drawn from the fully specified continuous distribution `dist`
+///
+/// # Formula
+///
+/// ```ignore
+/// A^2 = -n - (1 / n) Σ (2i - 1) (ln F(x_(i)) + ln(1 - F(x_(n+1-i))))
+/// ```
+///
+/// where `x_(i)` is the `i`-th smallest element of `x` and `F` the cdf of
+/// `dist`. The p-value is computed with the approximation of Marsaglia and
+/// Marsaglia (2004), which is accurate to about `1e-5`. If the parameters
+/// of `dist` were estimated from `x`, the p-value is too large; use
+/// `anderson_darling_normality_test` to test for normality with unknown
+/// parameters.
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty or contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::distribution::Normal;
+/// use statrs::stats_tests::anderson_darling_test;
+///
+/// let x = [-1.2, 0.3, -0.5, 1.9, 0.8, -0.1, 0.4, -2.1, 1.1, 0.6];
+/// let result = anderson_darling_test(&x, &Normal::new(0.0, 1.0).unwrap()).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn anderson_darling_test<D: ContinuousCDF<f64, f64>>(
+    x: &[f64],
+    dist: &D,
+) -> Result<NormalityTestResult> {
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    let x = sorted(x)?;
+    let ln_cdf: Vec<f64> = x.iter().map(|&v| dist.ln_cdf(v)).collect();
+    let ln_sf: Vec<f64> = x.iter().map(|&v| dist.ln_sf(v)).collect();
+    let statistic = anderson_darling_statistic(&ln_cdf, &ln_sf);
+    Ok(NormalityTestResult {
+        statistic,
+        p_value: 1.0 - anderson_darling_cdf(x.len() as f64, statistic),
+    })
+}
+
+/// Performs the Anderson-Darling test of the null hypothesis that `x` was
+/// drawn from a normal distribution with unknown mean and variance
+///
+/// The statistic `A^2` is computed against the normal distribution with
+/// the sample mean and standard deviation of `x` and adjusted to
+/// `A^2 (1 + 0.75 / n + 2.25 / n^2)` as proposed by Stephens. The p-value
+/// is computed from the adjusted statistic using the approximation of
+/// D'Agostino and Stephens (1986).
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than 8 elements, contains `NaN` or is
+/// constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::anderson_darling_normality_test;
+///
+/// let x = [2.4, 3.1, 2.8, 3.6, 2.9, 3.3, 2.2, 3.0, 2.7, 3.4];
+/// let result = anderson_darling_normality_test(&x).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn anderson_darling_normality_test(x: &[f64]) -> Result<NormalityTestResult> {
+    if x.len() < 8 {
+        return Err(StatsError::ArgGte("x.len()", 8.0));
+    }
+    let x = sorted(x)?;
+    let std_dev = x.iter().std_dev();
+    if std_dev == 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let normal = Normal::new(x.iter().mean(), std_dev)?;
+    let ln_cdf: Vec<f64> = x.iter().map(|&v| normal.ln_cdf(v)).collect();
+    let ln_sf: Vec<f64> = x.iter().map(|&v| normal.ln_sf(v)).collect();
+    let n = x.len() as f64;
+    let statistic = anderson_darling_statistic(&ln_cdf, &ln_sf);
+    let adjusted = statistic * (1.0 + 0.75 / n + 2.25 / (n * n));
+    let p_value = if adjusted < 0.2 {
+        1.0 - (-13.436 + 101.14 * adjusted - 223.73 * adjusted * adjusted).exp()
+    } else if adjusted < 0.34 {
+        1.0 - (-8.318 + 42.796 * adjusted - 59.938 * adjusted * adjusted).exp()
+    } else if adjusted < 0.6 {
+        (0.9177 - 4.279 * adjusted - 1.38 * adjusted * adjusted).exp()
+    } else if adjusted < 10.0 {
+        (1.2937 - 5.709 * adjusted + 0.0186 * adjusted * adjusted).exp()
+    } else {
+        3.7e-24
+    };
+    Ok(NormalityTestResult {
+        statistic: adjusted,
+        p_value,
+    })
+}
+
+/// Performs the Jarque-Bera test of the null hypothesis that `x` was drawn
+/// from a normal distribution
+///
+/// # Formula
+///
+/// ```ignore
+/// JB = n / 6 * (S^2 + (K - 3)^2 / 4)
+/// ```
+///
+/// where `S` and `K` are the sample skewness and kurtosis computed from
+/// the central moments normalized by `n`, and `JB` asymptotically follows
+/// a chi-squared distribution with 2 degrees of freedom
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than two elements or is constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::jarque_bera_test;
+///
+/// let x = [2.4, 3.1, 2.8, 3.6, 2.9, 3.3, 2.2, 3.0, 2.7, 3.4];
+/// let result = jarque_bera_test(&x).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn jarque_bera_test(x: &[f64]) -> Result<NormalityTestResult> {
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    let (m2, m3, m4) = central_moments(x)?;
+    let skewness = m3 / m2.powf(1.5);
+    let kurtosis = m4 / (m2 * m2);
+    let statistic = x.len() as f64 / 6.0 * (skewness * skewness + (kurtosis - 3.0).powi(2) / 4.0);
+    Ok(NormalityTestResult {
+        statistic,
+        p_value: ChiSquared::new(2.0)?.sf(statistic),
+    })
+}
+
+/// Performs D'Agostino and Pearson's omnibus K² test of the null
+/// hypothesis that `x` was drawn from a normal distribution
+///
+/// # Formula
+///
+/// ```ignore
+/// K^2 = Z_1(√b_1)^2 + Z_2(b_2)^2
+/// ```
+///
+/// where `Z_1` is D'Agostino's normalizing transformation of the sample
+/// skewness `√b_1` and `Z_2` that of Anscombe and Glynn for the sample
+/// kurtosis `b_2`. `K^2` asymptotically follows a chi-squared
+/// distribution with 2 degrees of freedom.
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than 8 elements or is constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::dagostino_pearson_test;
+///
+/// let x = [2.4, 3.1, 2.8, 3.6, 2.9, 3.3, 2.2, 3.0, 2.7, 3.4];
+/// let result = dagostino_pearson_test(&x).unwrap();
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn dagostino_pearson_test(x: &[f64]) -> Result<NormalityTestResult> {
+    if x.len() < 8 {
+        return Err(StatsError::ArgGte("x.len()", 8.0));
+    }
+    let (m2, m3, m4) = central_moments(x)?;
+    let n = x.len() as f64;
+
+    let b1 = m3 / m2.powf(1.5);
+    let y = b1 * ((n + 1.0) * (n + 3.0) / (6.0 * (n - 2.0))).sqrt();
+    let beta2 = 3.0 * (n * n + 27.0 * n - 70.0) * (n + 1.0) * (n + 3.0)
+        / ((n - 2.0) * (n + 5.0) * (n + 7.0) * (n + 9.0));
+    let w2 = -1.0 + (2.0 * (beta2 - 1.0)).sqrt();
+    let delta = 1.0 / (0.5 * w2.ln()).sqrt();
+    let alpha = (2.0 / (w2 - 1.0)).sqrt();
+    let z_skewness = delta * (y / alpha).asinh();
+
+    let b2 = m4 / (m2 * m2);
+    let mean = 3.0 * (n - 1.0) / (n + 1.0);
+    let variance =
+        24.0 * n * (n - 2.0) * (n - 3.0) / ((n + 1.0) * (n + 1.0) * (n + 3.0) * (n + 5.0));
+    let x = (b2 - mean) / variance.sqrt();
+    let sqrt_beta1 = 6.0 * (n * n - 5.0 * n + 2.0) / ((n + 7.0) * (n + 9.0))
+        * (6.0 * (n + 3.0) * (n + 5.0) / (n * (n - 2.0) * (n - 3.0))).sqrt();
+    let a = 6.0
+        + 8.0 / sqrt_beta1 * (2.0 / sqrt_beta1 + (1.0 + 4.0 / (sqrt_beta1 * sqrt_beta1)).sqrt());
+    let denom = 1.0 + x * (2.0 / (a - 4.0)).sqrt();
+    let term = ((1.0 - 2.0 / a) / denom).cbrt();
+    let z_kurtosis = (1.0 - 2.0 / (9.0 * a) - term) / (2.0 / (9.0 * a)).sqrt();
+
+    let statistic = z_skewness * z_skewness + z_kurtosis * z_kurtosis;
+    Ok(NormalityTestResult {
+        statistic,
+        p_value: ChiSquared::new(2.0)?.sf(statistic),
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::Exp;
+
+    const WEIGHTS: [f64; 11] = [148.0, 154.0, 158.0, 160.0, 161.0, 162.0, 166.0, 170.0, 182.0, 195.0, 236.0];
+    const D1: [f64; 10] = [2.4, 3.1, 2.8, 3.6, 2.9, 3.3, 2.2, 3.0, 2.7, 3.4];
+    const D2: [f64; 25] = [
+        0.61, 0.29, 0.06, 0.59, -1.73, -0.74, 0.51, -0.56, 0.39, 1.64, 0.05, -0.06, 0.64,
+        -0.82, 0.37, 1.77, 1.09, -1.28, 2.36, 1.31, 1.05, -0.32, -0.4, 1.06, -2.47,
+    ];
+    const D3: [f64; 12] = [1.2, 0.3, 4.7, 0.9, 2.2, 0.1, 8.6, 1.5, 0.6, 3.3, 0.4, 0.2];
+
+    fn check(result: Result<NormalityTestResult>, statistic: f64, p_value: f64, prec: f64) {
+        let result = result.unwrap();
+        assert_almost_eq!(result.statistic, statistic, prec);
+        assert_almost_eq!(result.p_value, p_value, prec);
+    }
+
+    #[test]
+    fn test_shapiro_wilk() {
+        check(shapiro_wilk_test(&WEIGHTS), 0.78881469483538743, 0.0067038140565030117, 1e-10);
+        check(shapiro_wilk_test(&D1), 0.98273231893376033, 0.97810007106880273, 1e-10);
+        check(shapiro_wilk_test(&D2), 0.98422404641237371, 0.95409246020661407, 1e-10);
+        check(shapiro_wilk_test(&D3), 0.75858169780174469, 0.0032895422804389556, 1e-10);
+        check(shapiro_wilk_test(&[1.0, 2.0, 4.0]), 0.96428571428571429, 0.63688684502896981, 1e-10);
+        check(shapiro_wilk_test(&[3.1, 2.2, 5.9, 4.4]), 0.97849828238283642, 0.89315651144444624, 1e-10);
+        check(shapiro_wilk_test(&[0.5, 1.1, 1.9, 2.0, 2.8, 3.5, 9.4]), 0.76825248809728605, 0.019605442059436312, 1e-10);
+        // invariant under location, scale and order
+        let mut shifted: Vec<f64> = D3.iter().map(|x| 10.0 - 3.0 * x).collect();
+        shifted.reverse();
+        check(shapiro_wilk_test(&shifted), 0.75858169780174469, 0.0032895422804389556, 1e-10);
+    }
+
+    #[test]
+    fn test_shapiro_wilk_bad() {
+        assert!(shapiro_wilk_test(&[1.0, 2.0]).is_err());
+        assert!(shapiro_wilk_test(&[1.0, 1.0, 1.0, 1.0]).is_err());
+        assert!(shapiro_wilk_test(&[1.0, f64::NAN, 2.0]).is_err());
+        assert!(shapiro_wilk_test(&vec![1.0; 5001]).is_err());
+    }
+
+    #[test]
+    fn test_anderson_darling() {
+        let x = [-1.2, 0.3, -0.5, 1.9, 0.8, -0.1, 0.4, -2.1, 1.1, 0.6];
+        let normal = Normal::new(0.0, 1.0).unwrap();
+        check(anderson_darling_test(&x, &normal), 0.4074232246901418, 0.83810312399566345, 1e-10);
+        check(anderson_darling_test(&D2, &normal), 0.98271498592311045, 0.36558266095784949, 1e-10);
+        let exp = Exp::new(0.5).unwrap();
+        check(anderson_darling_test(&D3, &exp), 0.41616681177196514, 0.82973924254681327, 1e-12);
+        assert!(anderson_darling_test(&[], &normal).is_err());
+        assert!(anderson_darling_test(&[f64::NAN], &normal).is_err());
+    }
+
+    #[test]
+    fn test_anderson_darling_cdf() {
+        // asymptotic critical values tabulated by Stephens (1974)
+        assert_almost_eq!(anderson_darling_cdf(f64::INFINITY, 1.933), 0.90, 1e-4);
+        assert_almost_eq!(anderson_darling_cdf(f64::INFINITY, 2.492), 0.95, 1e-4);
+        assert_almost_eq!(anderson_darling_cdf(f64::INFINITY, 3.857), 0.99, 1e-3);
+    }
+
+    #[test]
+    fn test_anderson_darling_normality() {
+        check(anderson_darling_normality_test(&WEIGHTS), 1.0289297699773031, 0.010454024005147697, 1e-10);
+        check(anderson_darling_normality_test(&D1), 0.13333572351104434, 0.98031679901253857, 1e-10);
+        check(anderson_darling_normality_test(&D2), 0.19476597239327168, 0.89185637205302419, 1e-10);
+        check(anderson_darling_normality_test(&D3), 1.1920956118518621, 0.0041462890987817829, 1e-10);
+        assert!(anderson_darling_normality_test(&D1[..7]).is_err());
+        assert!(anderson_darling_normality_test(&[2.0; 10]).is_err());
+    }
+
+    #[test]
+    fn test_jarque_bera() {
+        check(jarque_bera_test(&WEIGHTS), 6.9828482373446447, 0.030457466224581905, 1e-12);
+        check(jarque_bera_test(&D1), 0.3643111583221499, 0.83347165950660758, 1e-12);
+        check(jarque_bera_test(&D2), 0.69397905875631212, 0.70681272898780399, 1e-12);
+        check(jarque_bera_test(&D3), 7.9539842334262842, 0.018741928172685155, 1e-12);
+        assert!(jarque_bera_test(&[1.0]).is_err());
+        assert!(jarque_bera_test(&[1.0, 1.0]).is_err());
+    }
+
+    #[test]
+    fn test_dagostino_pearson() {
+        check(dagostino_pearson_test(&WEIGHTS), 13.034263121192582, 0.0014779023013100174, 1e-12);
+        check(dagostino_pearson_test(&D1), 0.19632707499066107, 0.90650064478633737, 1e-12);
+        check(dagostino_pearson_test(&D2), 1.3672170110279807, 0.50479215660026373, 1e-12);
+        check(dagostino_pearson_test(&D3), 13.408787309092984, 0.001225515564499841, 1e-12);
+        assert!(dagostino_pearson_test(&D1[..7]).is_err());
+        assert!(dagostino_pearson_test(&[2.0; 10]).is_err());
+    }
+}
@@ -0,0 +1,358 @@
+use crate::function::factorial::binomial;
+use crate::statistics::Statistics;
+use crate::stats_tests::Alternative;
+use crate::{Result, StatsError};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// The result of a permutation test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PermutationTestResult {
+    /// The statistic evaluated on the observed data
+    pub statistic: f64,
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// The Monte Carlo standard error of the p-value, which is zero if the
+    /// permutation distribution was enumerated exactly
+    pub std_err: f64,
+    /// The number of permutations the statistic was evaluated on
+    pub permutations: usize,
+    /// Whether the permutation distribution was enumerated exactly
+    pub exact: bool,
+    /// The alternative hypothesis the p-value refers to
+    pub alternative: Alternative,
+}
+
+/// Tallies how many permuted statistics are at least as extreme as the
+/// observed one in either direction
+struct Tally {
+    statistic: f64,
+    tolerance: f64,
+    greater: usize,
+    less: usize,
+    total: usize,
+}
+
+impl Tally {
+    fn new(statistic: f64) -> Tally {
+        Tally {
+            statistic,
+            // permutations which only differ in the order of summation
+            // must compare as ties
+            tolerance: 1e-12 * statistic.abs(),
+            greater: 0,
+            less: 0,
+            total: 0,
+        }
+    }
+
+    fn add(&mut self, t: f64) {
+        if t >= self.statistic - self.tolerance {
+            self.greater += 1;
+        }
+        if t <= self.statistic + self.tolerance {
+            self.less += 1;
+        }
+        self.total += 1;
+    }
+
+    /// Computes the p-value, counting the observed data as one of the
+    /// permutations for Monte Carlo tests so that the p-value is never zero
+    fn finish(self, exact: bool, alternative: Alternative) -> PermutationTestResult {
+        let offset = if exact { 0.0 } else { 1.0 };
+        let tail = |count: usize| (count as f64 + offset) / (self.total as f64 + offset);
+        let p_value = match alternative {
+            Alternative::TwoSided => (2.0 * tail(self.greater).min(tail(self.less))).min(1.0),
+            Alternative::Less => tail(self.less),
+            Alternative::Greater => tail(self.greater),
+        };
+        let std_err = if exact {
+            0.0
+        } else {
+            (p_value * (1.0 - p_value) / self.total as f64).sqrt()
+        };
+        PermutationTestResult {
+            statistic: self.statistic,
+            p_value,
+            std_err,
+            permutations: self.total,
+            exact,
+            alternative,
+        }
+    }
+}
+
+/// Returns the difference of the means of `x` and `y`, the usual
+/// statistic of a two-sample permutation test
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::mean_difference;
+///
+/// assert_eq!(mean_difference(&[1.0, 2.0, 3.0], &[1.0, 1.0]), 1.0);
+/// ```
+pub fn mean_difference(x: &[f64], y: &[f64]) -> f64 {
+    x.mean() - y.mean()
+}
+
+/// Performs a two-sample permutation test of the null hypothesis that
+/// `x` and `y` were drawn from the same population, using the test
+/// statistic `statistic(x, y)`. Large values of the statistic are taken
+/// as evidence for `Alternative::Greater`.
+///
+/// If the number of ways to split the pooled data into samples of the
+/// sizes of `x` and `y` does not exceed `permutations`, the permutation
+/// distribution is enumerated exactly. Otherwise `permutations` random
+/// permutations are drawn using `rng`, and the p-value is estimated as
+/// `(b + 1) / (permutations + 1)` where `b` is the number of permuted
+/// statistics at least as extreme as the observed one. Two-sided p-values
+/// are twice the smaller one-sided p-value.
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty or if `permutations` is zero
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::stats_tests::{mean_difference, permutation_test, Alternative};
+///
+/// let x = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+/// let y = [21.2, 22.0, 20.9, 22.4, 21.7, 20.1, 19.9];
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let result = permutation_test(&x, &y, mean_difference, Alternative::Less, 10000, &mut rng).unwrap();
+/// assert!(result.exact);
+/// assert_eq!(result.permutations, 1716);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn permutation_test<F, R>(
+    x: &[f64],
+    y: &[f64],
+    mut statistic: F,
+    alternative: Alternative,
+    permutations: usize,
+    rng: &mut R,
+) -> Result<PermutationTestResult>
+where
+    F: FnMut(&[f64], &[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if y.is_empty() {
+        return Err(StatsError::ArgGte("y.len()", 1.0));
+    }
+    if permutations == 0 {
+        return Err(StatsError::ArgMustBePositive("permutations"));
+    }
+    let (nx, n) = (x.len(), x.len() + y.len());
+    let mut pooled: Vec<f64> = x.iter().chain(y).cloned().collect();
+    let mut tally = Tally::new(statistic(x, y));
+    let exact = binomial(n as u64, nx as u64) <= permutations as f64;
+    if exact {
+        // enumerate the subsets of the pooled indices assigned to x in
+        // lexicographic order
+        let mut subset: Vec<usize> = (0..nx).collect();
+        let (mut xs, mut ys) = (Vec::with_capacity(nx), Vec::with_capacity(n - nx));
+        loop {
+            xs.clear();
+            ys.clear();
+            let mut next = 0;
+            for (i, &v) in pooled.iter().enumerate() {
+                if next < nx && subset[next] == i {
+                    xs.push(v);
+                    next += 1;
+                } else {
+                    ys.push(v);
+                }
+            }
+            tally.add(statistic(&xs, &ys));
+            match (0..nx).rev().find(|&i| subset[i] < n - nx + i) {
+                Some(i) => {
+                    subset[i] += 1;
+                    for j in i + 1..nx {
+                        subset[j] = subset[j - 1] + 1;
+                    }
+                }
+                None => break,
+            }
+        }
+    } else {
+        for _ in 0..permutations {
+            pooled.shuffle(rng);
+            let (xs, ys) = pooled.split_at(nx);
+            tally.add(statistic(xs, ys));
+        }
+    }
+    Ok(tally.finish(exact, alternative))
+}
+
+/// Performs a paired permutation test of the null hypothesis that the
+/// differences `x[i] - y[i]` are symmetrically distributed around zero,
+/// using the test statistic `statistic(d)` of the differences `d`, e.g.
+/// their mean. Large values of the statistic are taken as evidence for
+/// `Alternative::Greater`.
+///
+/// The permutation distribution is obtained by flipping the signs of the
+/// differences. If the `2^n` sign patterns do not exceed `permutations`,
+/// they are enumerated exactly. Otherwise `permutations` random sign
+/// patterns are drawn using `rng`, as for `permutation_test`.
+///
+/// # Errors
+///
+/// Returns an error if `x` is empty, if `x` and `y` differ in length or if
+/// `permutations` is zero
+///
+/// # Examples
+///
+/// ```
+/// use rand::rngs::StdRng;
+/// use rand::SeedableRng;
+/// use statrs::statistics::Statistics;
+/// use statrs::stats_tests::{paired_permutation_test, Alternative};
+///
+/// let x = [5.1, 4.9, 5.6, 5.8, 6.0, 5.3, 5.5, 4.7, 5.2, 5.9];
+/// let y = [4.8, 5.0, 5.1, 5.2, 5.4, 5.2, 5.0, 4.9, 5.3, 5.1];
+/// let mut rng = StdRng::seed_from_u64(1);
+/// let mean = |d: &[f64]| d.mean();
+/// let result = paired_permutation_test(&x, &y, mean, Alternative::TwoSided, 10000, &mut rng).unwrap();
+/// assert!(result.exact);
+/// assert!((result.p_value - 0.037109375).abs() < 1e-15);
+/// ```
+pub fn paired_permutation_test<F, R>(
+    x: &[f64],
+    y: &[f64],
+    mut statistic: F,
+    alternative: Alternative,
+    permutations: usize,
+    rng: &mut R,
+) -> Result<PermutationTestResult>
+where
+    F: FnMut(&[f64]) -> f64,
+    R: Rng + ?Sized,
+{
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if permutations == 0 {
+        return Err(StatsError::ArgMustBePositive("permutations"));
+    }
+    let d: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    let mut flipped = d.clone();
+    let mut tally = Tally::new(statistic(&d));
+    let exact = d.len() < 64 && (1u64 << d.len()) <= permutations as u64;
+    if exact {
+        for signs in 0..1u64 << d.len() {
+            for (i, (f, v)) in flipped.iter_mut().zip(&d).enumerate() {
+                *f = if signs >> i & 1 == 1 { -v } else { *v };
+            }
+            tally.add(statistic(&flipped));
+        }
+    } else {
+        for _ in 0..permutations {
+            for (f, v) in flipped.iter_mut().zip(&d) {
+                *f = if rng.gen::<bool>() { -v } else { *v };
+            }
+            tally.add(statistic(&flipped));
+        }
+    }
+    Ok(tally.finish(exact, alternative))
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    const X: [f64; 6] = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6];
+    const Y: [f64; 7] = [21.2, 22.0, 20.9, 22.4, 21.7, 20.1, 19.9];
+
+    fn mean(d: &[f64]) -> f64 {
+        d.mean()
+    }
+
+    fn rng() -> StdRng {
+        StdRng::seed_from_u64(42)
+    }
+
+    #[test]
+    fn test_permutation_test_exact() {
+        let run = |alt| permutation_test(&X, &Y, mean_difference, alt, 2000, &mut rng()).unwrap();
+        let result = run(Alternative::Less);
+        assert!(result.exact);
+        assert_eq!(result.permutations, 1716);
+        assert_eq!(result.std_err, 0.0);
+        assert_almost_eq!(result.statistic, -1.2547619047619047, 1e-13);
+        assert_almost_eq!(result.p_value, 32.0 / 1716.0, 1e-15);
+        assert_almost_eq!(run(Alternative::Greater).p_value, 0.9836829836829837, 1e-15);
+        assert_almost_eq!(run(Alternative::TwoSided).p_value, 64.0 / 1716.0, 1e-15);
+    }
+
+    #[test]
+    fn test_permutation_test_exact_ties() {
+        let x = [1.0, 2.0, 2.0, 3.0, 5.0];
+        let y = [2.0, 3.0, 3.0, 4.0, 6.0, 7.0];
+        let run = |alt| permutation_test(&x, &y, mean_difference, alt, 462, &mut rng()).unwrap();
+        assert!(run(Alternative::Less).exact);
+        assert_almost_eq!(run(Alternative::Less).p_value, 54.0 / 462.0, 1e-15);
+        assert_almost_eq!(run(Alternative::Greater).p_value, 0.9372294372294372, 1e-15);
+        assert_almost_eq!(run(Alternative::TwoSided).p_value, 108.0 / 462.0, 1e-15);
+    }
+
+    #[test]
+    fn test_permutation_test_monte_carlo() {
+        let result = permutation_test(&X, &Y, mean_difference, Alternative::Less, 1715, &mut rng()).unwrap();
+        assert!(!result.exact);
+        assert_eq!(result.permutations, 1715);
+        assert!(result.std_err > 0.0);
+        assert!((result.p_value - 32.0 / 1716.0).abs() < 4.0 * result.std_err);
+        let big = permutation_test(&X, &Y, mean_difference, Alternative::TwoSided, 100000, &mut rng()).unwrap();
+        assert!(big.exact);
+        // a statistic that never reaches the observed value still gives a
+        // positive Monte Carlo p-value
+        let mut calls = 0;
+        let statistic = |_: &[f64], _: &[f64]| {
+            calls += 1;
+            if calls == 1 { 1.0 } else { 0.0 }
+        };
+        let result = permutation_test(&X, &Y, statistic, Alternative::Greater, 99, &mut rng()).unwrap();
+        assert_eq!(result.p_value, 0.01);
+        assert_almost_eq!(result.std_err, (0.01f64 * 0.99 / 99.0).sqrt(), 1e-15);
+    }
+
+    #[test]
+    fn test_paired_permutation_test() {
+        let x = [5.1, 4.9, 5.6, 5.8, 6.0, 5.3, 5.5, 4.7, 5.2, 5.9];
+        let y = [4.8, 5.0, 5.1, 5.2, 5.4, 5.2, 5.0, 4.9, 5.3, 5.1];
+        let run = |alt, n| paired_permutation_test(&x, &y, mean, alt, n, &mut rng()).unwrap();
+        let result = run(Alternative::Greater, 1024);
+        assert!(result.exact);
+        assert_eq!(result.permutations, 1024);
+        assert_almost_eq!(result.statistic, 0.3, 1e-14);
+        assert_almost_eq!(result.p_value, 19.0 / 1024.0, 1e-15);
+        assert_almost_eq!(run(Alternative::Less, 1024).p_value, 0.9873046875, 1e-15);
+        assert_almost_eq!(run(Alternative::TwoSided, 1024).p_value, 0.037109375, 1e-15);
+        let result = run(Alternative::Greater, 1000);
+        assert!(!result.exact);
+        assert!((result.p_value - 19.0 / 1024.0).abs() < 4.0 * result.std_err);
+    }
+
+    #[test]
+    fn test_permutation_test_bad() {
+        assert!(permutation_test(&[], &Y, mean_difference, Alternative::Less, 10, &mut rng()).is_err());
+        assert!(permutation_test(&X, &[], mean_difference, Alternative::Less, 10, &mut rng()).is_err());
+        assert!(permutation_test(&X, &Y, mean_difference, Alternative::Less, 0, &mut rng()).is_err());
+        assert!(paired_permutation_test(&[], &[], mean, Alternative::Less, 10, &mut rng()).is_err());
+        assert!(paired_permutation_test(&X, &Y, mean, Alternative::Less, 10, &mut rng()).is_err());
+        assert!(paired_permutation_test(&X, &X, mean, Alternative::Less, 0, &mut rng()).is_err());
+    }
+}
@@ -0,0 +1,592 @@
+use crate::distribution::{ChiSquared, ContinuousCDF, FisherSnedecor, Normal, StudentsT};
+use crate::function::noncentral::{
+    noncentral_chi_squared_sf, noncentral_f_sf, noncentral_t_cdf, noncentral_t_sf,
+};
+use crate::stats_tests::{bisect, Alternative};
+use crate::{Result, StatsError};
+
+/// The largest sample size the sample size solvers search up to
+const MAX_SAMPLE_SIZE: f64 = 1e9;
+
+/// The sampling design of a test comparing means or proportions
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SampleDesign {
+    /// A single sample compared to a hypothesized value. Paired designs are
+    /// one-sample designs on the within-pair differences.
+    OneSample,
+    /// Two independent samples of equal size
+    TwoSample,
+}
+
+impl SampleDesign {
+    /// Returns the factor `√m` by which the effect size is scaled in the
+    /// noncentrality parameter for samples of size `n`
+    fn ncp_scale(self, n: f64) -> f64 {
+        match self {
+            SampleDesign::OneSample => n.sqrt(),
+            SampleDesign::TwoSample => (n / 2.0).sqrt(),
+        }
+    }
+}
+
+/// Computes Cohen's effect size `h` for the difference between the
+/// proportions `p1` and `p2`
+///
+/// # Formula
+///
+/// ```ignore
+/// h = 2 asin(√p1) - 2 asin(√p2)
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if `p1` or `p2` are not in `[0, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::cohens_h;
+///
+/// let h = cohens_h(0.65, 0.45).unwrap();
+/// assert!((h - 0.4048601).abs() < 1e-7);
+/// ```
+pub fn cohens_h(p1: f64, p2: f64) -> Result<f64> {
+    if !(0.0..=1.0).contains(&p1) {
+        return Err(StatsError::ArgIntervalIncl("p1", 0.0, 1.0));
+    }
+    if !(0.0..=1.0).contains(&p2) {
+        return Err(StatsError::ArgIntervalIncl("p2", 0.0, 1.0));
+    }
+    Ok(2.0 * p1.sqrt().asin() - 2.0 * p2.sqrt().asin())
+}
+
+fn check_alpha(alpha: f64) -> Result<()> {
+    if alpha > 0.0 && alpha < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("alpha", 0.0, 1.0))
+    }
+}
+
+fn check_power(power: f64) -> Result<()> {
+    if power > 0.0 && power < 1.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgIntervalExcl("power", 0.0, 1.0))
+    }
+}
+
+fn check_effect_size(effect_size: f64) -> Result<()> {
+    if effect_size.is_finite() {
+        Ok(())
+    } else {
+        Err(StatsError::BadParams)
+    }
+}
+
+fn check_non_negative_effect_size(effect_size: f64) -> Result<()> {
+    if effect_size.is_finite() && effect_size >= 0.0 {
+        Ok(())
+    } else {
+        Err(StatsError::ArgNotNegative("effect_size"))
+    }
+}
+
+/// Returns an error if a test against `alternative` cannot detect the
+/// effect `effect_size` with more than its significance level as power
+fn check_detectable(effect_size: f64, alternative: Alternative) -> Result<()> {
+    let detectable = match alternative {
+        Alternative::TwoSided => effect_size != 0.0,
+        Alternative::Less => effect_size < 0.0,
+        Alternative::Greater => effect_size > 0.0,
+    };
+    if detectable {
+        Ok(())
+    } else {
+        Err(StatsError::BadParams)
+    }
+}
+
+/// Finds the smallest sample size `n >= min` for which the increasing
+/// function `power_at` reaches `power`
+fn solve_sample_size<F: Fn(f64) -> f64>(power_at: F, min: f64, power: f64) -> Result<f64> {
+    if power_at(min) >= power {
+        return Ok(min);
+    }
+    let mut lo = min;
+    let mut hi = 2.0 * min;
+    while power_at(hi) < power {
+        if hi >= MAX_SAMPLE_SIZE {
+            return Err(StatsError::ComputationFailedToConverge);
+        }
+        lo = hi;
+        hi *= 2.0;
+    }
+    Ok(bisect(|n| power_at(n) - power, lo, hi))
+}
+
+/// Computes the power of a t-test at significance level `alpha` to detect
+/// the standardized effect size `effect_size` (Cohen's `d`) with samples
+/// of size `n`, where `n` is the size of each group for two-sample designs
+///
+/// # Formula
+///
+/// ```ignore
+/// power = P(|T'| > t_(1-α/2))
+/// ```
+///
+/// for two-sided alternatives, where `T'` follows a noncentral t
+/// distribution with noncentrality `d √n` and `n - 1` degrees of freedom
+/// for one-sample designs, or `d √(n/2)` and `2n - 2` degrees of freedom
+/// for two-sample designs. One-sided alternatives reject in a single tail
+/// at level `α`.
+///
+/// # Errors
+///
+/// Returns an error if `n` is not greater than `1`, if `alpha` is not in
+/// `(0, 1)` or if `effect_size` is not finite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{t_test_power, Alternative, SampleDesign};
+///
+/// let power = t_test_power(0.5, 64.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided)
+///     .unwrap();
+/// assert!((power - 0.8014596).abs() < 1e-7);
+/// ```
+pub fn t_test_power(
+    effect_size: f64,
+    n: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    if n.is_nan() || n <= 1.0 {
+        return Err(StatsError::ArgGt("n", 1.0));
+    }
+    Ok(t_power(effect_size, n, alpha, design, alternative))
+}
+
+fn t_power(
+    effect_size: f64,
+    n: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> f64 {
+    let freedom = match design {
+        SampleDesign::OneSample => n - 1.0,
+        SampleDesign::TwoSample => 2.0 * n - 2.0,
+    };
+    let ncp = effect_size * design.ncp_scale(n);
+    let null = StudentsT::new(0.0, 1.0, freedom).unwrap();
+    match alternative {
+        Alternative::TwoSided => {
+            let q = null.inverse_sf(alpha / 2.0);
+            noncentral_t_sf(freedom, ncp, q) + noncentral_t_cdf(freedom, ncp, -q)
+        }
+        Alternative::Greater => noncentral_t_sf(freedom, ncp, null.inverse_sf(alpha)),
+        Alternative::Less => noncentral_t_cdf(freedom, ncp, -null.inverse_sf(alpha)),
+    }
+}
+
+/// Computes the sample size needed for a t-test at significance level
+/// `alpha` to detect the effect size `effect_size` (Cohen's `d`) with
+/// probability `power`. The size of each group is returned for two-sample
+/// designs and is fractional; round it up to obtain the required number of
+/// observations.
+///
+/// # Errors
+///
+/// Returns an error if `alpha` or `power` are not in `(0, 1)`, if
+/// `effect_size` is not finite, if `effect_size` is zero or lies in the
+/// direction opposite to a one-sided alternative, or if `power` cannot be
+/// reached with fewer than `1e9` observations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{t_test_sample_size, Alternative, SampleDesign};
+///
+/// let n = t_test_sample_size(0.5, 0.8, 0.05, SampleDesign::TwoSample, Alternative::TwoSided)
+///     .unwrap();
+/// assert_eq!(n.ceil(), 64.0);
+/// ```
+pub fn t_test_sample_size(
+    effect_size: f64,
+    power: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    check_power(power)?;
+    check_detectable(effect_size, alternative)?;
+    solve_sample_size(
+        |n| t_power(effect_size, n, alpha, design, alternative),
+        2.0,
+        power,
+    )
+}
+
+/// Computes the power of a normal approximation test for proportions at
+/// significance level `alpha` to detect the effect size `effect_size`
+/// (Cohen's `h`, see `cohens_h`) with samples of size `n`, where `n` is
+/// the size of each group for two-sample designs
+///
+/// # Formula
+///
+/// ```ignore
+/// power = Φ(h √m - z_(1-α/2)) + Φ(-h √m - z_(1-α/2))
+/// ```
+///
+/// for two-sided alternatives, where `m = n` for one-sample designs and
+/// `m = n / 2` for two-sample designs. One-sided alternatives reject in a
+/// single tail at level `α`.
+///
+/// # Errors
+///
+/// Returns an error if `n` is not positive, if `alpha` is not in `(0, 1)`
+/// or if `effect_size` is not finite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{cohens_h, proportion_test_power, Alternative, SampleDesign};
+///
+/// let h = cohens_h(0.65, 0.45).unwrap();
+/// let power =
+///     proportion_test_power(h, 100.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided)
+///         .unwrap();
+/// assert!((power - 0.8166924).abs() < 1e-7);
+/// ```
+pub fn proportion_test_power(
+    effect_size: f64,
+    n: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    if n.is_nan() || n <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("n"));
+    }
+    Ok(proportion_power(effect_size, n, alpha, design, alternative))
+}
+
+fn proportion_power(
+    effect_size: f64,
+    n: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> f64 {
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let ncp = effect_size * design.ncp_scale(n);
+    match alternative {
+        Alternative::TwoSided => {
+            let q = normal.inverse_sf(alpha / 2.0);
+            normal.sf(q - ncp) + normal.cdf(-q - ncp)
+        }
+        Alternative::Greater => normal.sf(normal.inverse_sf(alpha) - ncp),
+        Alternative::Less => normal.cdf(-normal.inverse_sf(alpha) - ncp),
+    }
+}
+
+/// Computes the sample size needed for a normal approximation test for
+/// proportions at significance level `alpha` to detect the effect size
+/// `effect_size` (Cohen's `h`) with probability `power`. The size of each
+/// group is returned for two-sample designs and is fractional; round it up
+/// to obtain the required number of observations.
+///
+/// # Errors
+///
+/// Returns an error if `alpha` or `power` are not in `(0, 1)`, if
+/// `effect_size` is not finite, if `effect_size` is zero or lies in the
+/// direction opposite to a one-sided alternative, or if `power` cannot be
+/// reached with fewer than `1e9` observations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{cohens_h, proportion_test_sample_size, Alternative, SampleDesign};
+///
+/// let h = cohens_h(0.65, 0.45).unwrap();
+/// let n = proportion_test_sample_size(h, 0.9, 0.05, SampleDesign::TwoSample, Alternative::TwoSided)
+///     .unwrap();
+/// assert_eq!(n.ceil(), 129.0);
+/// ```
+pub fn proportion_test_sample_size(
+    effect_size: f64,
+    power: f64,
+    alpha: f64,
+    design: SampleDesign,
+    alternative: Alternative,
+) -> Result<f64> {
+    check_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    check_power(power)?;
+    check_detectable(effect_size, alternative)?;
+    solve_sample_size(
+        |n| proportion_power(effect_size, n, alpha, design, alternative),
+        1.0,
+        power,
+    )
+}
+
+/// Computes the power of a chi-squared test with `freedom` degrees of
+/// freedom at significance level `alpha` to detect the effect size
+/// `effect_size` (Cohen's `w`) with `n` observations in total
+///
+/// # Formula
+///
+/// ```ignore
+/// power = P(X' > χ²_(1-α))
+/// ```
+///
+/// where `X'` follows a noncentral chi-squared distribution with `freedom`
+/// degrees of freedom and noncentrality `n w²`
+///
+/// # Errors
+///
+/// Returns an error if `n` or `freedom` are not positive, if `alpha` is
+/// not in `(0, 1)` or if `effect_size` is negative or not finite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_test_power;
+///
+/// let power = chi_squared_test_power(0.3, 100.0, 3.0, 0.05).unwrap();
+/// assert!((power - 0.7112536).abs() < 1e-7);
+/// ```
+pub fn chi_squared_test_power(effect_size: f64, n: f64, freedom: f64, alpha: f64) -> Result<f64> {
+    check_non_negative_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    if n.is_nan() || n <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("n"));
+    }
+    if freedom.is_nan() || freedom <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("freedom"));
+    }
+    Ok(chi_squared_power(effect_size, n, freedom, alpha))
+}
+
+fn chi_squared_power(effect_size: f64, n: f64, freedom: f64, alpha: f64) -> f64 {
+    let q = ChiSquared::new(freedom).unwrap().inverse_sf(alpha);
+    noncentral_chi_squared_sf(freedom, n * effect_size * effect_size, q)
+}
+
+/// Computes the total number of observations needed for a chi-squared test
+/// with `freedom` degrees of freedom at significance level `alpha` to
+/// detect the effect size `effect_size` (Cohen's `w`) with probability
+/// `power`. The result is fractional; round it up to obtain the required
+/// number of observations.
+///
+/// # Errors
+///
+/// Returns an error if `freedom` is not positive, if `alpha` or `power`
+/// are not in `(0, 1)`, if `effect_size` is not positive and finite, or
+/// if `power` cannot be reached with fewer than `1e9` observations
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::chi_squared_test_sample_size;
+///
+/// let n = chi_squared_test_sample_size(0.3, 0.8, 3.0, 0.05).unwrap();
+/// assert_eq!(n.ceil(), 122.0);
+/// ```
+pub fn chi_squared_test_sample_size(
+    effect_size: f64,
+    power: f64,
+    freedom: f64,
+    alpha: f64,
+) -> Result<f64> {
+    check_non_negative_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    check_power(power)?;
+    if effect_size == 0.0 {
+        return Err(StatsError::ArgMustBePositive("effect_size"));
+    }
+    if freedom.is_nan() || freedom <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("freedom"));
+    }
+    solve_sample_size(
+        |n| chi_squared_power(effect_size, n, freedom, alpha),
+        1.0,
+        power,
+    )
+}
+
+/// Computes the power of a balanced one-way ANOVA F-test with `groups`
+/// groups of `n` observations each at significance level `alpha` to
+/// detect the effect size `effect_size` (Cohen's `f`)
+///
+/// # Formula
+///
+/// ```ignore
+/// power = P(F' > F_(1-α))
+/// ```
+///
+/// where `F'` follows a noncentral F distribution with `k - 1` and
+/// `k (n - 1)` degrees of freedom and noncentrality `k n f²` for `k`
+/// groups
+///
+/// # Errors
+///
+/// Returns an error if `groups` is less than `2`, if `n` is not greater
+/// than `1`, if `alpha` is not in `(0, 1)` or if `effect_size` is negative
+/// or not finite
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::anova_power;
+///
+/// let power = anova_power(0.25, 4, 45.0, 0.05).unwrap();
+/// assert!((power - 0.8039869).abs() < 1e-7);
+/// ```
+pub fn anova_power(effect_size: f64, groups: usize, n: f64, alpha: f64) -> Result<f64> {
+    check_non_negative_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    if groups < 2 {
+        return Err(StatsError::ArgGte("groups", 2.0));
+    }
+    if n.is_nan() || n <= 1.0 {
+        return Err(StatsError::ArgGt("n", 1.0));
+    }
+    Ok(anova_power_unchecked(effect_size, groups, n, alpha))
+}
+
+fn anova_power_unchecked(effect_size: f64, groups: usize, n: f64, alpha: f64) -> f64 {
+    let k = groups as f64;
+    let (freedom_1, freedom_2) = (k - 1.0, k * (n - 1.0));
+    let q = FisherSnedecor::new(freedom_1, freedom_2)
+        .unwrap()
+        .inverse_sf(alpha);
+    noncentral_f_sf(freedom_1, freedom_2, k * n * effect_size * effect_size, q)
+}
+
+/// Computes the number of observations per group needed for a balanced
+/// one-way ANOVA F-test with `groups` groups at significance level `alpha`
+/// to detect the effect size `effect_size` (Cohen's `f`) with probability
+/// `power`. The result is fractional; round it up to obtain the required
+/// number of observations.
+///
+/// # Errors
+///
+/// Returns an error if `groups` is less than `2`, if `alpha` or `power`
+/// are not in `(0, 1)`, if `effect_size` is not positive and finite, or
+/// if `power` cannot be reached with fewer than `1e9` observations per
+/// group
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::anova_sample_size;
+///
+/// let n = anova_sample_size(0.25, 4, 0.8, 0.05).unwrap();
+/// assert_eq!(n.ceil(), 45.0);
+/// ```
+pub fn anova_sample_size(effect_size: f64, groups: usize, power: f64, alpha: f64) -> Result<f64> {
+    check_non_negative_effect_size(effect_size)?;
+    check_alpha(alpha)?;
+    check_power(power)?;
+    if effect_size == 0.0 {
+        return Err(StatsError::ArgMustBePositive("effect_size"));
+    }
+    if groups < 2 {
+        return Err(StatsError::ArgGte("groups", 2.0));
+    }
+    solve_sample_size(
+        |n| anova_power_unchecked(effect_size, groups, n, alpha),
+        2.0,
+        power,
+    )
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cohens_h() {
+        assert_almost_eq!(cohens_h(0.65, 0.45).unwrap(), 0.40486007517695730432, 1e-15);
+        assert_eq!(cohens_h(0.3, 0.3).unwrap(), 0.0);
+        assert!(cohens_h(1.5, 0.3).is_err());
+        assert!(cohens_h(0.3, -0.1).is_err());
+    }
+
+    #[test]
+    fn test_t_test_power() {
+        let power = t_test_power(0.5, 64.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.8014595579222540873, 1e-10);
+        let power = t_test_power(0.4, 30.0, 0.05, SampleDesign::OneSample, Alternative::Greater).unwrap();
+        assert_almost_eq!(power, 0.6895127662969707758, 1e-10);
+        let power = t_test_power(-0.4, 30.0, 0.05, SampleDesign::OneSample, Alternative::Less).unwrap();
+        assert_almost_eq!(power, 0.6895127662969707758, 1e-10);
+        let power = t_test_power(0.4, 30.0, 0.01, SampleDesign::OneSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.30533963672994299105, 1e-10);
+        // without an effect the power equals the significance level
+        let power = t_test_power(0.0, 30.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.05, 1e-12);
+        assert!(t_test_power(0.5, 1.0, 0.05, SampleDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(0.5, 10.0, 1.0, SampleDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_power(f64::NAN, 10.0, 0.05, SampleDesign::OneSample, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_t_test_sample_size() {
+        let n = t_test_sample_size(0.5, 0.8, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(n, 63.765610190952236253, 1e-8);
+        let n = t_test_sample_size(0.3, 0.9, 0.05, SampleDesign::OneSample, Alternative::Greater).unwrap();
+        assert_almost_eq!(n, 96.522873514591798968, 1e-8);
+        // large effects reach the power with the smallest possible sample
+        let n = t_test_sample_size(20.0, 0.8, 0.05, SampleDesign::OneSample, Alternative::Greater).unwrap();
+        assert_eq!(n, 2.0);
+        assert!(t_test_sample_size(0.3, 0.9, 0.05, SampleDesign::OneSample, Alternative::Less).is_err());
+        assert!(t_test_sample_size(0.0, 0.9, 0.05, SampleDesign::OneSample, Alternative::TwoSided).is_err());
+        assert!(t_test_sample_size(0.3, 1.0, 0.05, SampleDesign::OneSample, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_proportion_test() {
+        let h = cohens_h(0.65, 0.45).unwrap();
+        let power = proportion_test_power(h, 100.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(power, 0.81669239556145501005, 1e-10);
+        let power = proportion_test_power(0.2, 150.0, 0.05, SampleDesign::OneSample, Alternative::Greater).unwrap();
+        assert_almost_eq!(power, 0.78948515268692213613, 1e-10);
+        let power = proportion_test_power(0.2, 150.0, 0.05, SampleDesign::OneSample, Alternative::Less).unwrap();
+        assert_almost_eq!(power, 0.000021168324992989671109, 1e-14);
+        let n = proportion_test_sample_size(h, 0.9, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).unwrap();
+        assert_almost_eq!(n, 128.20830558767876494, 1e-7);
+        assert!(proportion_test_power(h, 0.0, 0.05, SampleDesign::TwoSample, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_chi_squared_test() {
+        assert_almost_eq!(chi_squared_test_power(0.3, 100.0, 3.0, 0.05).unwrap(), 0.71125359979504227938, 1e-12);
+        assert_almost_eq!(chi_squared_test_power(0.1, 500.0, 1.0, 0.01).unwrap(), 0.36701888474267155313, 1e-12);
+        assert_almost_eq!(chi_squared_test_sample_size(0.3, 0.8, 3.0, 0.05).unwrap(), 121.13959211259188096, 1e-8);
+        assert!(chi_squared_test_power(-0.3, 100.0, 3.0, 0.05).is_err());
+        assert!(chi_squared_test_power(0.3, 100.0, 0.0, 0.05).is_err());
+        assert!(chi_squared_test_sample_size(0.0, 0.8, 3.0, 0.05).is_err());
+    }
+
+    #[test]
+    fn test_anova() {
+        assert_almost_eq!(anova_power(0.25, 4, 45.0, 0.05).unwrap(), 0.80398691286517571269, 1e-12);
+        assert_almost_eq!(anova_power(0.4, 3, 12.5, 0.1).unwrap(), 0.67460218027436063302, 1e-12);
+        assert_almost_eq!(anova_sample_size(0.25, 4, 0.8, 0.05).unwrap(), 44.599274306099867908, 1e-8);
+        assert!(anova_power(0.25, 1, 45.0, 0.05).is_err());
+        assert!(anova_power(0.25, 4, 1.0, 0.05).is_err());
+        assert!(anova_sample_size(0.0, 4, 0.8, 0.05).is_err());
+    }
+}
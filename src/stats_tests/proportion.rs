@@ -0,0 +1,331 @@
+use crate::distribution::{Beta, ContinuousCDF, Normal};
+use crate::stats_tests::{check_level, ConfidenceInterval};
+use crate::{Result, StatsError};
+
+/// The method used to construct a confidence interval for a binomial
+/// proportion
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ProportionInterval {
+    /// The Wald interval `p ± z √(p (1 - p) / n)`, which has poor coverage
+    /// for small samples or proportions close to zero or one
+    Wald,
+    /// The Wilson score interval
+    Wilson,
+    /// The Wilson score interval with continuity correction
+    WilsonCorrected,
+    /// The Agresti-Coull interval, a Wald interval around the Wilson
+    /// center
+    AgrestiCoull,
+    /// The Jeffreys interval, the equal-tailed credible interval under the
+    /// Jeffreys prior `Beta(1/2, 1/2)`
+    Jeffreys,
+    /// The Clopper-Pearson interval obtained by inverting the exact
+    /// binomial test, which guarantees at least nominal coverage
+    ClopperPearson,
+}
+
+/// Returns an error if `successes` out of `trials` is not a valid count
+pub(super) fn check_counts(successes: u64, trials: u64) -> Result<()> {
+    if trials == 0 {
+        Err(StatsError::ArgMustBePositive("trials"))
+    } else if successes > trials {
+        Err(StatsError::ArgLteArg("successes", "trials"))
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns the lower Clopper-Pearson bound with lower tail probability
+/// `alpha` for `x` successes out of `n` trials
+pub(super) fn clopper_pearson_lower(x: u64, n: u64, alpha: f64) -> f64 {
+    if x == 0 {
+        0.0
+    } else {
+        Beta::new(x as f64, (n - x + 1) as f64)
+            .unwrap()
+            .inverse_cdf(alpha)
+    }
+}
+
+/// Returns the upper Clopper-Pearson bound with upper tail probability
+/// `alpha` for `x` successes out of `n` trials
+pub(super) fn clopper_pearson_upper(x: u64, n: u64, alpha: f64) -> f64 {
+    if x == n {
+        1.0
+    } else {
+        Beta::new((x + 1) as f64, (n - x) as f64)
+            .unwrap()
+            .inverse_sf(alpha)
+    }
+}
+
+/// Computes the bounds of a two-sided interval with standard normal
+/// quantile `z`, without checking the arguments
+fn interval_bounds(x: u64, n: u64, method: ProportionInterval, z: f64, alpha: f64) -> (f64, f64) {
+    let (xf, nf) = (x as f64, n as f64);
+    let p = xf / nf;
+    let z2 = z * z;
+    let (lower, upper) = match method {
+        ProportionInterval::Wald => {
+            let half = z * (p * (1.0 - p) / nf).sqrt();
+            (p - half, p + half)
+        }
+        ProportionInterval::Wilson => {
+            let center = (xf + z2 / 2.0) / (nf + z2);
+            let half = z / (nf + z2) * (xf * (nf - xf) / nf + z2 / 4.0).sqrt();
+            (center - half, center + half)
+        }
+        ProportionInterval::WilsonCorrected => {
+            let denom = 2.0 * (nf + z2);
+            let lower = if x == 0 {
+                0.0
+            } else {
+                let root = (z2 - 2.0 - 1.0 / nf + 4.0 * p * (nf * (1.0 - p) + 1.0)).sqrt();
+                (2.0 * xf + z2 - 1.0 - z * root) / denom
+            };
+            let upper = if x == n {
+                1.0
+            } else {
+                let root = (z2 + 2.0 - 1.0 / nf + 4.0 * p * (nf * (1.0 - p) - 1.0)).sqrt();
+                (2.0 * xf + z2 + 1.0 + z * root) / denom
+            };
+            (lower, upper)
+        }
+        ProportionInterval::AgrestiCoull => {
+            let n_tilde = nf + z2;
+            let p_tilde = (xf + z2 / 2.0) / n_tilde;
+            let half = z * (p_tilde * (1.0 - p_tilde) / n_tilde).sqrt();
+            (p_tilde - half, p_tilde + half)
+        }
+        ProportionInterval::Jeffreys => {
+            let dist = Beta::new(xf + 0.5, nf - xf + 0.5).unwrap();
+            let lower = if x == 0 {
+                0.0
+            } else {
+                dist.inverse_cdf(alpha / 2.0)
+            };
+            let upper = if x == n {
+                1.0
+            } else {
+                dist.inverse_cdf(1.0 - alpha / 2.0)
+            };
+            (lower, upper)
+        }
+        ProportionInterval::ClopperPearson => (
+            clopper_pearson_lower(x, n, alpha / 2.0),
+            clopper_pearson_upper(x, n, alpha / 2.0),
+        ),
+    };
+    (lower.max(0.0), upper.min(1.0))
+}
+
+/// Computes a two-sided confidence interval of level `level` for the
+/// success probability of a binomial distribution from `successes`
+/// observed in `trials` trials, using the method `method`
+///
+/// Intervals that extend beyond `[0, 1]` are truncated.
+///
+/// # Formula
+///
+/// ```ignore
+/// Wald:           p ± z √(p (1 - p) / n)
+/// Wilson:         (x + z^2 / 2) / (n + z^2) ± z / (n + z^2) √(x (n - x) / n + z^2 / 4)
+/// AgrestiCoull:   p' ± z √(p' (1 - p') / (n + z^2)),  p' = (x + z^2 / 2) / (n + z^2)
+/// Jeffreys:       [B^-1(α / 2; x + 1/2, n - x + 1/2), B^-1(1 - α / 2; x + 1/2, n - x + 1/2)]
+/// ClopperPearson: [B^-1(α / 2; x, n - x + 1), B^-1(1 - α / 2; x + 1, n - x)]
+/// ```
+///
+/// where `x` is `successes`, `n` is `trials`, `p = x / n`, `α = 1 -
+/// level`, `z` is the `1 - α / 2` quantile of the standard normal
+/// distribution and `B^-1(q; a, b)` is the inverse cdf of the beta
+/// distribution. The continuity corrected Wilson interval follows
+/// Newcombe (1998).
+///
+/// # Errors
+///
+/// Returns an error if `trials` is zero, if `successes > trials`, or if
+/// `level` is not in `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{proportion_interval, ProportionInterval};
+///
+/// let ci = proportion_interval(81, 263, ProportionInterval::Wilson, 0.95).unwrap();
+/// assert!((ci.lower - 0.2553).abs() < 1e-4);
+/// assert!((ci.upper - 0.3662).abs() < 1e-4);
+/// ```
+pub fn proportion_interval(
+    successes: u64,
+    trials: u64,
+    method: ProportionInterval,
+    level: f64,
+) -> Result<ConfidenceInterval> {
+    check_level(level)?;
+    check_counts(successes, trials)?;
+    let alpha = 1.0 - level;
+    let z = Normal::new(0.0, 1.0)?.inverse_cdf(1.0 - alpha / 2.0);
+    let (lower, upper) = interval_bounds(successes, trials, method, z, alpha);
+    Ok(ConfidenceInterval {
+        lower,
+        upper,
+        level,
+    })
+}
+
+/// Computes Newcombe's hybrid score confidence interval of level `level`
+/// for the difference `p_1 - p_2` of two binomial proportions, estimated
+/// from `successes_1` out of `trials_1` and `successes_2` out of
+/// `trials_2`, combining the Wilson intervals of both proportions.
+/// `correction` selects the continuity corrected Wilson intervals.
+///
+/// # Formula
+///
+/// ```ignore
+/// lower = d - √((p_1 - l_1)^2 + (u_2 - p_2)^2)
+/// upper = d + √((u_1 - p_1)^2 + (p_2 - l_2)^2)
+/// ```
+///
+/// where `d = p_1 - p_2` and `[l_i, u_i]` is the Wilson interval for
+/// `p_i`
+///
+/// # Errors
+///
+/// Returns an error if either number of trials is zero, if either number
+/// of successes exceeds its number of trials, or if `level` is not in
+/// `(0, 1)`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::newcombe_interval;
+///
+/// let ci = newcombe_interval(56, 70, 48, 80, false, 0.95).unwrap();
+/// assert!((ci.lower - 0.0524).abs() < 1e-4);
+/// assert!((ci.upper - 0.3339).abs() < 1e-4);
+/// ```
+pub fn newcombe_interval(
+    successes_1: u64,
+    trials_1: u64,
+    successes_2: u64,
+    trials_2: u64,
+    correction: bool,
+    level: f64,
+) -> Result<ConfidenceInterval> {
+    check_level(level)?;
+    check_counts(successes_1, trials_1)?;
+    check_counts(successes_2, trials_2)?;
+    let alpha = 1.0 - level;
+    let z = Normal::new(0.0, 1.0)?.inverse_cdf(1.0 - alpha / 2.0);
+    let method = if correction {
+        ProportionInterval::WilsonCorrected
+    } else {
+        ProportionInterval::Wilson
+    };
+    let (l1, u1) = interval_bounds(successes_1, trials_1, method, z, alpha);
+    let (l2, u2) = interval_bounds(successes_2, trials_2, method, z, alpha);
+    let p1 = successes_1 as f64 / trials_1 as f64;
+    let p2 = successes_2 as f64 / trials_2 as f64;
+    let d = p1 - p2;
+    Ok(ConfidenceInterval {
+        lower: d - ((p1 - l1).powi(2) + (u2 - p2).powi(2)).sqrt(),
+        upper: d + ((u1 - p1).powi(2) + (p2 - l2).powi(2)).sqrt(),
+        level,
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(x: u64, n: u64, method: ProportionInterval, lower: f64, upper: f64) {
+        let ci = proportion_interval(x, n, method, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, lower, 1e-14);
+        assert_almost_eq!(ci.upper, upper, 1e-14);
+        assert_eq!(ci.level, 0.95);
+    }
+
+    #[test]
+    fn test_wald() {
+        check(81, 263, ProportionInterval::Wald, 0.25219012621310717896, 0.36377945553594225069);
+        check(1, 29, ProportionInterval::Wald, 0.0, 0.10089224323967214508);
+        check(0, 10, ProportionInterval::Wald, 0.0, 0.0);
+        check(3, 7, ProportionInterval::Wald, 0.061972109447263008284, 0.79517074769559413457);
+    }
+
+    #[test]
+    fn test_wilson() {
+        check(81, 263, ProportionInterval::Wilson, 0.25528851987827422577, 0.36620957698280007015);
+        check(0, 10, ProportionInterval::Wilson, 0.0, 0.27753279986288925272);
+        check(1, 29, ProportionInterval::Wilson, 0.0061132142927626605164, 0.17175521879320288131);
+        check(15, 15, ProportionInterval::Wilson, 0.79611669896415137208, 1.0);
+        let ci = proportion_interval(81, 263, ProportionInterval::Wilson, 0.99).unwrap();
+        assert_almost_eq!(ci.lower, 0.24013690268760769189, 1e-14);
+        assert_almost_eq!(ci.upper, 0.38528250231822984767, 1e-14);
+    }
+
+    #[test]
+    fn test_wilson_corrected() {
+        check(81, 263, ProportionInterval::WilsonCorrected, 0.25350868229518517075, 0.3681762010430035558);
+        check(0, 10, ProportionInterval::WilsonCorrected, 0.0, 0.34453721830692260392);
+        check(1, 29, ProportionInterval::WilsonCorrected, 0.0018026402132796716573, 0.19628175100975957764);
+        check(15, 15, ProportionInterval::WilsonCorrected, 0.74653213903938305198, 1.0);
+        check(3, 7, ProportionInterval::WilsonCorrected, 0.11808301126155251304, 0.79762830639351470117);
+    }
+
+    #[test]
+    fn test_agresti_coull() {
+        check(81, 263, ProportionInterval::AgrestiCoull, 0.255220665189996777, 0.36627743167107751892);
+        check(0, 10, ProportionInterval::AgrestiCoull, 0.0, 0.32088730575054577043);
+        check(1, 29, ProportionInterval::AgrestiCoull, 0.0, 0.18628650856170609765);
+        check(15, 15, ProportionInterval::AgrestiCoull, 0.76143688504309936874, 1.0);
+    }
+
+    #[test]
+    fn test_jeffreys() {
+        check(81, 263, ProportionInterval::Jeffreys, 0.25452193499102347234, 0.36564749917408241582);
+        check(0, 10, ProportionInterval::Jeffreys, 0.0, 0.21719626750921057425);
+        check(1, 29, ProportionInterval::Jeffreys, 0.0037461736490570736222, 0.15007768600223176302);
+        check(15, 15, ProportionInterval::Jeffreys, 0.84818333788560229597, 1.0);
+        check(3, 7, ProportionInterval::Jeffreys, 0.13886421910489021616, 0.76549875074790623981);
+    }
+
+    #[test]
+    fn test_clopper_pearson() {
+        check(81, 263, ProportionInterval::ClopperPearson, 0.25273674558527116302, 0.36762192260135136465);
+        check(0, 10, ProportionInterval::ClopperPearson, 0.0, 0.30849710781876082391);
+        check(1, 29, ProportionInterval::ClopperPearson, 0.00087264688357992199413, 0.17764429548872293418);
+        check(15, 15, ProportionInterval::ClopperPearson, 0.7819806390894659443, 1.0);
+        check(3, 7, ProportionInterval::ClopperPearson, 0.09898827844250786464, 0.81594843235991698781);
+    }
+
+    #[test]
+    fn test_proportion_interval_bad() {
+        assert!(proportion_interval(1, 0, ProportionInterval::Wilson, 0.95).is_err());
+        assert!(proportion_interval(3, 2, ProportionInterval::Wilson, 0.95).is_err());
+        assert!(proportion_interval(1, 2, ProportionInterval::Wilson, 1.0).is_err());
+    }
+
+    #[test]
+    fn test_newcombe_interval() {
+        // examples from Newcombe (1998)
+        let ci = newcombe_interval(56, 70, 48, 80, false, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, 0.052431472402364877896, 1e-14);
+        assert_almost_eq!(ci.upper, 0.33387265403690597643, 1e-14);
+        let ci = newcombe_interval(56, 70, 48, 80, true, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, 0.042767872002889739221, 1e-14);
+        assert_almost_eq!(ci.upper, 0.3421862784381804552, 1e-14);
+        let ci = newcombe_interval(9, 10, 3, 10, false, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, 0.17052272393450287595, 1e-14);
+        assert_almost_eq!(ci.upper, 0.80901797353548798443, 1e-14);
+        let ci = newcombe_interval(5, 56, 0, 29, false, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, -0.038137147903536903301, 1e-14);
+        assert_almost_eq!(ci.upper, 0.19256001385511162047, 1e-14);
+        let ci = newcombe_interval(10, 10, 0, 20, true, 0.95).unwrap();
+        assert_almost_eq!(ci.lower, 0.60139312810016919091, 1e-14);
+        assert_almost_eq!(ci.upper, 1.0, 1e-14);
+        assert!(newcombe_interval(1, 0, 1, 2, false, 0.95).is_err());
+        assert!(newcombe_interval(1, 2, 3, 2, false, 0.95).is_err());
+    }
+}
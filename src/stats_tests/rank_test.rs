@@ -0,0 +1,758 @@
+use crate::distribution::{ChiSquared, ContinuousCDF, Normal};
+use crate::statistics::{Data, OrderStatistics, RankTieBreaker};
+use crate::stats_tests::{adjust_p_values, p_value, Adjustment, Alternative};
+use crate::{Result, StatsError};
+
+/// The result of a rank-based test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RankTestResult {
+    /// The rank statistic of the test
+    pub statistic: f64,
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// Whether the p-value was computed from the exact null distribution
+    /// rather than its continuity-corrected normal approximation
+    pub exact: bool,
+}
+
+/// The largest sample size for which the exact null distributions are
+/// used
+const EXACT_MAX_N: usize = 50;
+
+/// Returns the sizes of the groups of tied values in `data`
+fn tie_sizes(data: &[f64]) -> Vec<f64> {
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mut sizes = Vec::new();
+    let mut i = 0;
+    while i < sorted.len() {
+        let mut j = i + 1;
+        while j < sorted.len() && sorted[j] == sorted[i] {
+            j += 1;
+        }
+        sizes.push((j - i) as f64);
+        i = j;
+    }
+    sizes
+}
+
+/// Computes the p-value of the integer-valued statistic `stat` from its
+/// exact null distribution `pmf` on `0, 1, ...`, which is symmetric about
+/// its mean `center`
+fn exact_p_value(pmf: &[f64], stat: f64, center: f64, alternative: Alternative) -> f64 {
+    let k = stat.round() as usize;
+    let lower = || pmf[..=k].iter().sum::<f64>();
+    let upper = || pmf[k..].iter().sum::<f64>();
+    let p = match alternative {
+        Alternative::Less => lower(),
+        Alternative::Greater => upper(),
+        Alternative::TwoSided => {
+            let tail = if stat > center { upper() } else { lower() };
+            2.0 * tail
+        }
+    };
+    p.min(1.0)
+}
+
+/// Computes the p-value of the statistic `stat` with null mean `mean` and
+/// standard deviation `sd` from the continuity-corrected normal
+/// approximation
+fn normal_p_value(stat: f64, mean: f64, sd: f64, alternative: Alternative) -> f64 {
+    let diff = stat - mean;
+    let correction = match alternative {
+        Alternative::TwoSided => {
+            if diff == 0.0 {
+                0.0
+            } else {
+                0.5 * diff.signum()
+            }
+        }
+        Alternative::Greater => 0.5,
+        Alternative::Less => -0.5,
+    };
+    let z = (diff - correction) / sd;
+    p_value(&Normal::new(0.0, 1.0).unwrap(), z, alternative)
+}
+
+/// Computes the null distribution of the Mann-Whitney U statistic for
+/// samples of sizes `m` and `n` without ties, using the recurrence
+/// `p_{i,j}(u) = i / (i + j) p_{i-1,j}(u - j) + j / (i + j) p_{i,j-1}(u)`
+fn mann_whitney_pmf(m: usize, n: usize) -> Vec<f64> {
+    // prev[j] holds the distribution for samples of sizes (i - 1, j)
+    let mut prev: Vec<Vec<f64>> = vec![vec![1.0]; n + 1];
+    for i in 1..=m {
+        let mut cur: Vec<Vec<f64>> = Vec::with_capacity(n + 1);
+        cur.push(vec![1.0]);
+        for j in 1..=n {
+            let (wi, wj) = (i as f64 / (i + j) as f64, j as f64 / (i + j) as f64);
+            let mut pmf = vec![0.0; i * j + 1];
+            for (u, p) in prev[j].iter().enumerate() {
+                pmf[u + j] += wi * p;
+            }
+            for (u, p) in cur[j - 1].iter().enumerate() {
+                pmf[u] += wj * p;
+            }
+            cur.push(pmf);
+        }
+        prev = cur;
+    }
+    prev.swap_remove(n)
+}
+
+/// Computes the null distribution of the Wilcoxon signed-rank statistic
+/// for `n` nonzero differences without ties, i.e. the distribution of the
+/// sum of a uniformly random subset of `1, ..., n`
+fn signed_rank_pmf(n: usize) -> Vec<f64> {
+    let mut pmf = vec![0.0; n * (n + 1) / 2 + 1];
+    pmf[0] = 1.0;
+    for k in 1..=n {
+        let max = k * (k + 1) / 2;
+        for v in (k..=max).rev() {
+            pmf[v] = 0.5 * (pmf[v] + pmf[v - k]);
+        }
+        for p in pmf[..k].iter_mut() {
+            *p *= 0.5;
+        }
+    }
+    pmf
+}
+
+/// Performs the Mann-Whitney U test (also known as the Wilcoxon rank-sum
+/// test) of the null hypothesis that the distributions of `x` and `y` are
+/// equal, against the alternative that `x` is stochastically less than,
+/// greater than, or different from `y`
+///
+/// The statistic is `U = R_x - n_x (n_x + 1) / 2`, where `R_x` is the sum
+/// of the ranks of `x` in the pooled sample with ties assigned their
+/// average rank. If both samples have fewer than 50 elements and there
+/// are no ties, the p-value is computed from the exact null distribution
+/// of `U`. Otherwise the normal approximation with tie-corrected variance
+///
+/// ```ignore
+/// σ^2 = n_x n_y / 12 * (N + 1 - Σ (t^3 - t) / (N (N - 1)))
+/// ```
+///
+/// and a continuity correction is used, where `N = n_x + n_y` and `t` are
+/// the sizes of the groups of ties.
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty or contains `NaN`
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{mann_whitney_u_test, Alternative};
+///
+/// let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+/// let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.17, 1.29];
+/// let result = mann_whitney_u_test(&x, &y, Alternative::Greater).unwrap();
+/// assert_eq!(result.statistic, 58.0);
+/// assert!(result.p_value < 0.1);
+/// ```
+pub fn mann_whitney_u_test(
+    x: &[f64],
+    y: &[f64],
+    alternative: Alternative,
+) -> Result<RankTestResult> {
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if y.is_empty() {
+        return Err(StatsError::ArgGte("y.len()", 1.0));
+    }
+    if x.iter().chain(y).any(|v| v.is_nan()) {
+        return Err(StatsError::BadParams);
+    }
+    let pooled: Vec<f64> = x.iter().chain(y).cloned().collect();
+    let ranks = Data::new(pooled.clone()).ranks(RankTieBreaker::Average);
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let u = ranks[..x.len()].iter().sum::<f64>() - nx * (nx + 1.0) / 2.0;
+    let ties = tie_sizes(&pooled);
+    let has_ties = ties.len() < pooled.len();
+    let mean = nx * ny / 2.0;
+    if x.len() < EXACT_MAX_N && y.len() < EXACT_MAX_N && !has_ties {
+        let pmf = mann_whitney_pmf(x.len(), y.len());
+        return Ok(RankTestResult {
+            statistic: u,
+            p_value: exact_p_value(&pmf, u, mean, alternative),
+            exact: true,
+        });
+    }
+    let n = nx + ny;
+    let tie_term = ties.iter().map(|t| t * t * t - t).sum::<f64>() / (n * (n - 1.0));
+    let sd = (nx * ny / 12.0 * (n + 1.0 - tie_term)).sqrt();
+    if sd == 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    Ok(RankTestResult {
+        statistic: u,
+        p_value: normal_p_value(u, mean, sd, alternative),
+        exact: false,
+    })
+}
+
+/// Performs the Wilcoxon signed-rank test of the null hypothesis that the
+/// distribution of `x - mu` is symmetric about zero, against the
+/// alternative that its location is less than, greater than, or different
+/// from zero
+///
+/// Zero differences are discarded. The statistic `V` is the sum of the
+/// ranks of the absolute differences `|x - mu|` belonging to positive
+/// differences, with ties assigned their average rank. If there are fewer
+/// than 50 differences, no ties and no zeros, the p-value is computed from
+/// the exact null distribution of `V`. Otherwise the normal approximation
+/// with tie-corrected variance
+///
+/// ```ignore
+/// σ^2 = n (n + 1) (2n + 1) / 24 - Σ (t^3 - t) / 48
+/// ```
+///
+/// and a continuity correction is used.
+///
+/// # Errors
+///
+/// Returns an error if `x` contains `NaN` or if all differences are zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{wilcoxon_signed_rank_test, Alternative};
+///
+/// let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+/// let result = wilcoxon_signed_rank_test(&x, 1.0, Alternative::TwoSided).unwrap();
+/// assert_eq!(result.statistic, 43.0);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn wilcoxon_signed_rank_test(
+    x: &[f64],
+    mu: f64,
+    alternative: Alternative,
+) -> Result<RankTestResult> {
+    if x.iter().any(|v| v.is_nan()) || mu.is_nan() {
+        return Err(StatsError::BadParams);
+    }
+    let diffs: Vec<f64> = x.iter().map(|v| v - mu).filter(|&d| d != 0.0).collect();
+    if diffs.is_empty() {
+        return Err(StatsError::ArgGte("number of nonzero differences", 1.0));
+    }
+    let abs: Vec<f64> = diffs.iter().map(|d| d.abs()).collect();
+    let ranks = Data::new(abs.clone()).ranks(RankTieBreaker::Average);
+    let v: f64 = ranks
+        .iter()
+        .zip(&diffs)
+        .filter(|(_, &d)| d > 0.0)
+        .map(|(r, _)| r)
+        .sum();
+    let ties = tie_sizes(&abs);
+    let has_ties = ties.len() < abs.len();
+    let n = diffs.len() as f64;
+    let mean = n * (n + 1.0) / 4.0;
+    if diffs.len() < EXACT_MAX_N && !has_ties && diffs.len() == x.len() {
+        let pmf = signed_rank_pmf(diffs.len());
+        return Ok(RankTestResult {
+            statistic: v,
+            p_value: exact_p_value(&pmf, v, mean, alternative),
+            exact: true,
+        });
+    }
+    let tie_term = ties.iter().map(|t| t * t * t - t).sum::<f64>() / 48.0;
+    let sd = (n * (n + 1.0) * (2.0 * n + 1.0) / 24.0 - tie_term).sqrt();
+    if sd == 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    Ok(RankTestResult {
+        statistic: v,
+        p_value: normal_p_value(v, mean, sd, alternative),
+        exact: false,
+    })
+}
+
+/// Performs the paired Wilcoxon signed-rank test of the null hypothesis
+/// that the distribution of the differences `x[i] - y[i] - mu` is symmetric
+/// about zero. This is equivalent to the one-sample test on the
+/// differences.
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` have different lengths, contain `NaN`
+/// or if all differences are zero
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{wilcoxon_signed_rank_test_paired, Alternative};
+///
+/// let x = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+/// let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.17, 1.29];
+/// let result = wilcoxon_signed_rank_test_paired(&x, &y, 0.0, Alternative::Greater).unwrap();
+/// assert_eq!(result.statistic, 40.0);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn wilcoxon_signed_rank_test_paired(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+) -> Result<RankTestResult> {
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let diffs: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    wilcoxon_signed_rank_test(&diffs, mu, alternative)
+}
+
+/// A pairwise comparison of mean ranks from Dunn's test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DunnComparison {
+    /// The index of the first group
+    pub i: usize,
+    /// The index of the second group
+    pub j: usize,
+    /// The difference `R̄_i - R̄_j` of the mean ranks of the groups
+    pub estimate: f64,
+    /// The standard error of the difference under the null hypothesis
+    pub std_err: f64,
+    /// The z statistic `estimate / std_err`
+    pub statistic: f64,
+    /// The unadjusted two-sided p-value of the comparison
+    pub p_value: f64,
+    /// The p-value adjusted for all pairwise comparisons
+    pub adjusted_p_value: f64,
+}
+
+/// The ranks of the pooled groups together with the group sizes and the
+/// tie sizes
+struct PooledRanks {
+    ranks: Vec<f64>,
+    sizes: Vec<usize>,
+    ties: Vec<f64>,
+}
+
+impl PooledRanks {
+    fn new<T: AsRef<[f64]>>(groups: &[T]) -> Result<PooledRanks> {
+        if groups.len() < 2 {
+            return Err(StatsError::ArgGte("groups.len()", 2.0));
+        }
+        if groups.iter().any(|g| g.as_ref().is_empty()) {
+            return Err(StatsError::ArgGte("group length", 1.0));
+        }
+        let pooled: Vec<f64> = groups.iter().flat_map(|g| g.as_ref()).cloned().collect();
+        if pooled.iter().any(|v| v.is_nan()) {
+            return Err(StatsError::BadParams);
+        }
+        Ok(PooledRanks {
+            ranks: Data::new(pooled.clone()).ranks(RankTieBreaker::Average),
+            sizes: groups.iter().map(|g| g.as_ref().len()).collect(),
+            ties: tie_sizes(&pooled),
+        })
+    }
+
+    /// Returns the mean rank of each group
+    fn mean_ranks(&self) -> Vec<f64> {
+        let mut start = 0;
+        self.sizes
+            .iter()
+            .map(|&size| {
+                let sum: f64 = self.ranks[start..start + size].iter().sum();
+                start += size;
+                sum / size as f64
+            })
+            .collect()
+    }
+
+    /// Returns `1 - Σ (t^3 - t) / (N^3 - N)`, the correction of the rank
+    /// variance for ties
+    fn tie_correction(&self) -> f64 {
+        let n = self.ranks.len() as f64;
+        1.0 - self.ties.iter().map(|t| t * t * t - t).sum::<f64>() / (n * n * n - n)
+    }
+}
+
+/// Performs the Kruskal-Wallis test of the null hypothesis that the
+/// `groups` were drawn from the same distribution, against the
+/// alternative that at least one of them is stochastically larger or
+/// smaller than the others
+///
+/// # Formula
+///
+/// ```ignore
+/// H = (12 / (N (N + 1)) Σ R_i^2 / n_i - 3 (N + 1)) / (1 - Σ (t^3 - t) / (N^3 - N))
+/// ```
+///
+/// where `R_i` is the sum of the ranks of group `i` in the pooled sample
+/// of size `N`, with ties assigned their average rank, and `t` are the
+/// sizes of the groups of ties. The p-value is computed from the
+/// chi-squared distribution with `k - 1` degrees of freedom.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if the data contain `NaN` or if all values are equal
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::kruskal_wallis_test;
+///
+/// let groups = [
+///     vec![2.9, 3.0, 2.5, 2.6, 3.2],
+///     vec![3.8, 2.7, 4.0, 2.4],
+///     vec![2.8, 3.4, 3.7, 2.2, 2.0],
+/// ];
+/// let result = kruskal_wallis_test(&groups).unwrap();
+/// assert!((result.statistic - 0.7714).abs() < 1e-4);
+/// assert!(result.p_value > 0.5);
+/// ```
+pub fn kruskal_wallis_test<T: AsRef<[f64]>>(groups: &[T]) -> Result<RankTestResult> {
+    let pooled = PooledRanks::new(groups)?;
+    let correction = pooled.tie_correction();
+    if correction <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let n = pooled.ranks.len() as f64;
+    let sum: f64 = pooled
+        .mean_ranks()
+        .iter()
+        .zip(&pooled.sizes)
+        .map(|(r, &size)| r * r * size as f64)
+        .sum();
+    let statistic = (12.0 / (n * (n + 1.0)) * sum - 3.0 * (n + 1.0)) / correction;
+    let dist = ChiSquared::new((groups.len() - 1) as f64)?;
+    Ok(RankTestResult {
+        statistic,
+        p_value: dist.sf(statistic),
+        exact: false,
+    })
+}
+
+/// Performs the Friedman test of the null hypothesis that the treatments
+/// of a randomized complete block design have the same effect. Each
+/// element of `blocks` holds the responses to the `k` treatments within
+/// one block.
+///
+/// # Formula
+///
+/// ```ignore
+/// Q = 12 Σ (R_j - n (k + 1) / 2)^2 / (n k (k + 1) - Σ (t^3 - t) / (k - 1))
+/// ```
+///
+/// where `R_j` is the sum over the `n` blocks of the ranks of treatment
+/// `j` within its block, with ties assigned their average rank, and `t`
+/// are the sizes of the groups of ties within each block. The p-value is
+/// computed from the chi-squared distribution with `k - 1` degrees of
+/// freedom.
+///
+/// # Errors
+///
+/// Returns an error if there are no blocks, fewer than two treatments,
+/// blocks of different lengths, `NaN` values or if every block is
+/// constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::friedman_test;
+///
+/// let blocks = [
+///     [1.0, 2.0, 3.0, 4.0],
+///     [2.0, 1.0, 4.0, 3.0],
+///     [1.0, 3.0, 2.0, 4.0],
+///     [1.0, 1.0, 3.0, 4.0],
+///     [2.0, 2.0, 2.0, 3.0],
+///     [1.0, 2.0, 4.0, 4.0],
+///     [3.0, 1.0, 2.0, 4.0],
+/// ];
+/// let result = friedman_test(&blocks).unwrap();
+/// assert_eq!(result.statistic, 13.6875);
+/// assert!(result.p_value < 0.01);
+/// ```
+pub fn friedman_test<T: AsRef<[f64]>>(blocks: &[T]) -> Result<RankTestResult> {
+    if blocks.is_empty() {
+        return Err(StatsError::ArgGte("blocks.len()", 1.0));
+    }
+    let k = blocks[0].as_ref().len();
+    if k < 2 {
+        return Err(StatsError::ArgGte("number of treatments", 2.0));
+    }
+    if blocks.iter().any(|b| b.as_ref().len() != k) {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    if blocks.iter().any(|b| b.as_ref().iter().any(|v| v.is_nan())) {
+        return Err(StatsError::BadParams);
+    }
+    let mut rank_sums = vec![0.0; k];
+    let mut tie_term = 0.0;
+    for block in blocks {
+        let ranks = Data::new(block.as_ref().to_vec()).ranks(RankTieBreaker::Average);
+        for (sum, r) in rank_sums.iter_mut().zip(ranks) {
+            *sum += r;
+        }
+        tie_term += tie_sizes(block.as_ref())
+            .iter()
+            .map(|t| t * t * t - t)
+            .sum::<f64>();
+    }
+    let (n, kf) = (blocks.len() as f64, k as f64);
+    let denom = n * kf * (kf + 1.0) - tie_term / (kf - 1.0);
+    if denom <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let center = n * (kf + 1.0) / 2.0;
+    let ss: f64 = rank_sums.iter().map(|r| (r - center) * (r - center)).sum();
+    let statistic = 12.0 * ss / denom;
+    let dist = ChiSquared::new(kf - 1.0)?;
+    Ok(RankTestResult {
+        statistic,
+        p_value: dist.sf(statistic),
+        exact: false,
+    })
+}
+
+/// Performs Dunn's test of all pairwise differences between the mean
+/// ranks of `groups`, typically as a post-hoc test after a significant
+/// Kruskal-Wallis test, adjusting the p-values with `adjustment`
+///
+/// # Formula
+///
+/// ```ignore
+/// z_ij = (R̄_i - R̄_j) / √((N (N + 1) / 12 - Σ (t^3 - t) / (12 (N - 1))) (1 / n_i + 1 / n_j))
+/// ```
+///
+/// where `R̄_i` is the mean rank of group `i` in the pooled sample of size
+/// `N` and `t` are the sizes of the groups of ties. The unadjusted
+/// p-values are two-sided and computed from the standard normal
+/// distribution.
+///
+/// The comparisons are returned in the order `(0, 1), (0, 2), ..., (1, 2), ...`.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if the data contain `NaN` or if all values are equal
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{dunn_test, Adjustment};
+///
+/// let groups = [
+///     vec![2.9, 3.0, 2.5, 2.6, 3.2],
+///     vec![3.8, 2.7, 4.0, 2.4],
+///     vec![2.8, 3.4, 3.7, 2.2, 2.0],
+/// ];
+/// let comparisons = dunn_test(&groups, Adjustment::Holm).unwrap();
+/// assert_eq!(comparisons.len(), 3);
+/// assert!(comparisons.iter().all(|c| c.adjusted_p_value > 0.05));
+/// ```
+pub fn dunn_test<T: AsRef<[f64]>>(
+    groups: &[T],
+    adjustment: Adjustment,
+) -> Result<Vec<DunnComparison>> {
+    let pooled = PooledRanks::new(groups)?;
+    let n = pooled.ranks.len() as f64;
+    let variance = (n * (n + 1.0) / 12.0) * pooled.tie_correction();
+    if variance <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let means = pooled.mean_ranks();
+    let normal = Normal::new(0.0, 1.0).unwrap();
+    let k = means.len();
+    let mut comparisons = Vec::with_capacity(k * (k - 1) / 2);
+    for i in 0..k {
+        for j in i + 1..k {
+            let estimate = means[i] - means[j];
+            let std_err =
+                (variance * (1.0 / pooled.sizes[i] as f64 + 1.0 / pooled.sizes[j] as f64)).sqrt();
+            let statistic = estimate / std_err;
+            comparisons.push(DunnComparison {
+                i,
+                j,
+                estimate,
+                std_err,
+                statistic,
+                p_value: p_value(&normal, statistic, Alternative::TwoSided),
+                adjusted_p_value: 0.0,
+            });
+        }
+    }
+    let p: Vec<f64> = comparisons.iter().map(|c| c.p_value).collect();
+    let adjusted = adjust_p_values(&p, adjustment)?;
+    for (c, p) in comparisons.iter_mut().zip(adjusted) {
+        c.adjusted_p_value = p;
+    }
+    Ok(comparisons)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X: [f64; 9] = [1.83, 0.50, 1.62, 2.48, 1.68, 1.88, 1.55, 3.06, 1.30];
+    const Y: [f64; 9] = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.06, 3.17, 1.29];
+
+    fn check(r: RankTestResult, stat: f64, p: f64, exact: bool) {
+        assert_eq!(r.statistic, stat);
+        // the normal approximation is limited by the accuracy of erfc
+        assert_almost_eq!(r.p_value, p, if exact { 1e-14 } else { 1e-10 });
+        assert_eq!(r.exact, exact);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_test_exact() {
+        let y = [0.878, 0.647, 0.598, 2.05, 1.06, 1.29, 1.07, 3.17, 1.28];
+        check(mann_whitney_u_test(&X, &y, Alternative::TwoSided).unwrap(), 58.0, 0.13591114767585355820649938297, true);
+        check(mann_whitney_u_test(&X, &y, Alternative::Greater).unwrap(), 58.0, 0.067955573837926779103249691485, true);
+        check(mann_whitney_u_test(&X, &y, Alternative::Less).unwrap(), 58.0, 0.943253805018510900863842040313, true);
+        check(mann_whitney_u_test(&y, &X, Alternative::Less).unwrap(), 23.0, 0.067955573837926779103249691485, true);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_test_ties() {
+        check(mann_whitney_u_test(&X, &Y, Alternative::TwoSided).unwrap(), 58.0, 0.132919458185318823920648567291, false);
+        check(mann_whitney_u_test(&X, &Y, Alternative::Greater).unwrap(), 58.0, 0.0664597290926594119603242836453, false);
+        check(mann_whitney_u_test(&X, &Y, Alternative::Less).unwrap(), 58.0, 0.944204400289712740409303487405, false);
+        let x: Vec<f64> = (0..30).map(|i| (i % 7) as f64).collect();
+        let y: Vec<f64> = (0..25).map(|i| (i % 5) as f64 + 1.5).collect();
+        check(mann_whitney_u_test(&x, &y, Alternative::TwoSided).unwrap(), 300.0, 0.20636721767455180055248927009, false);
+        check(mann_whitney_u_test(&x, &y, Alternative::Greater).unwrap(), 300.0, 0.899829241641635779321870547539, false);
+        check(mann_whitney_u_test(&x, &y, Alternative::Less).unwrap(), 300.0, 0.103183608837275900276244635045, false);
+        // a statistic at its mean gets no continuity correction
+        let x = [1.0, 2.0, 2.0, 3.0];
+        check(mann_whitney_u_test(&x, &x, Alternative::TwoSided).unwrap(), 8.0, 1.0, false);
+    }
+
+    #[test]
+    fn test_mann_whitney_u_test_errors() {
+        assert!(mann_whitney_u_test(&[], &Y, Alternative::TwoSided).is_err());
+        assert!(mann_whitney_u_test(&X, &[], Alternative::TwoSided).is_err());
+        assert!(mann_whitney_u_test(&X, &[f64::NAN], Alternative::TwoSided).is_err());
+        assert!(mann_whitney_u_test(&[1.0; 50], &[1.0; 3], Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_mann_whitney_pmf() {
+        let pmf = mann_whitney_pmf(2, 3);
+        let expected = [0.1, 0.1, 0.2, 0.2, 0.2, 0.1, 0.1];
+        assert_eq!(pmf.len(), expected.len());
+        for (p, e) in pmf.iter().zip(&expected) {
+            assert_almost_eq!(*p, *e, 1e-15);
+        }
+        assert_almost_eq!(mann_whitney_pmf(40, 45).iter().sum::<f64>(), 1.0, 1e-13);
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank_test() {
+        check(wilcoxon_signed_rank_test(&X, 1.0, Alternative::TwoSided).unwrap(), 43.0, 0.01171875, true);
+        check(wilcoxon_signed_rank_test(&X, 1.0, Alternative::Greater).unwrap(), 43.0, 0.005859375, true);
+        check(wilcoxon_signed_rank_test(&X, 1.0, Alternative::Less).unwrap(), 43.0, 0.99609375, true);
+        let z = [1.5, 2.0, -0.5, 2.0, 3.5, 0.0, 1.0, -1.0, 2.5, 1.5];
+        check(wilcoxon_signed_rank_test(&z, 0.0, Alternative::TwoSided).unwrap(), 41.5, 0.027986088969632598644717011906, false);
+        check(wilcoxon_signed_rank_test(&z, 0.0, Alternative::Greater).unwrap(), 41.5, 0.013993044484816299322358505953, false);
+        check(wilcoxon_signed_rank_test(&z, 0.0, Alternative::Less).unwrap(), 41.5, 0.989728073301025905171211690988, false);
+        assert!(wilcoxon_signed_rank_test(&[2.0, 2.0], 2.0, Alternative::TwoSided).is_err());
+        assert!(wilcoxon_signed_rank_test(&[f64::NAN], 0.0, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_wilcoxon_signed_rank_test_paired() {
+        check(wilcoxon_signed_rank_test_paired(&X, &Y, 0.0, Alternative::TwoSided).unwrap(), 40.0, 0.0390625, true);
+        check(wilcoxon_signed_rank_test_paired(&X, &Y, 0.0, Alternative::Greater).unwrap(), 40.0, 0.01953125, true);
+        check(wilcoxon_signed_rank_test_paired(&X, &Y, 0.0, Alternative::Less).unwrap(), 40.0, 0.986328125, true);
+        assert!(wilcoxon_signed_rank_test_paired(&X, &Y[1..], 0.0, Alternative::TwoSided).is_err());
+    }
+
+    #[test]
+    fn test_signed_rank_pmf() {
+        let pmf = signed_rank_pmf(3);
+        let expected = [0.125, 0.125, 0.125, 0.25, 0.125, 0.125, 0.125];
+        assert_eq!(pmf, expected);
+    }
+
+    const G1: [&[f64]; 3] = [&[2.9, 3.0, 2.5, 2.6, 3.2], &[3.8, 2.7, 4.0, 2.4], &[2.8, 3.4, 3.7, 2.2, 2.0]];
+    const G2: [&[f64]; 4] = [&[1.0, 2.0, 2.0, 3.0, 5.0, 5.0], &[4.0, 6.0, 6.0, 7.0, 8.0], &[9.0, 10.0, 10.0, 12.0], &[2.0, 3.0, 11.0]];
+
+    #[test]
+    fn test_kruskal_wallis_test() {
+        let r = kruskal_wallis_test(&G1).unwrap();
+        assert_almost_eq!(r.statistic, 0.7714285714285715, 1e-14);
+        assert_almost_eq!(r.p_value, 0.67996477357889382, 1e-14);
+        assert!(!r.exact);
+        let r = kruskal_wallis_test(&G2).unwrap();
+        assert_almost_eq!(r.statistic, 10.307613596947624, 1e-13);
+        assert_almost_eq!(r.p_value, 0.016124400885713777, 1e-14);
+        // invariant under monotone transformations
+        let exp: Vec<Vec<f64>> = G2.iter().map(|g| g.iter().map(|x| x.exp()).collect()).collect();
+        assert_almost_eq!(kruskal_wallis_test(&exp).unwrap().statistic, 10.307613596947624, 1e-13);
+    }
+
+    #[test]
+    fn test_kruskal_wallis_test_errors() {
+        assert!(kruskal_wallis_test(&[[1.0, 2.0]]).is_err());
+        assert!(kruskal_wallis_test(&[vec![1.0, 2.0], vec![]]).is_err());
+        assert!(kruskal_wallis_test(&[[1.0, f64::NAN], [2.0, 3.0]]).is_err());
+        assert!(kruskal_wallis_test(&[[1.0, 1.0], [1.0, 1.0]]).is_err());
+    }
+
+    #[test]
+    fn test_friedman_test() {
+        let blocks = [
+            [1.0, 2.0, 3.0, 4.0],
+            [2.0, 1.0, 4.0, 3.0],
+            [1.0, 3.0, 2.0, 4.0],
+            [1.0, 1.0, 3.0, 4.0],
+            [2.0, 2.0, 2.0, 3.0],
+            [1.0, 2.0, 4.0, 4.0],
+            [3.0, 1.0, 2.0, 4.0],
+        ];
+        let r = friedman_test(&blocks).unwrap();
+        assert_eq!(r.statistic, 13.6875);
+        assert_almost_eq!(r.p_value, 0.0033629049637788445, 1e-15);
+        let blocks = [
+            [5.40, 5.50, 5.55],
+            [5.85, 5.70, 5.75],
+            [5.20, 5.60, 5.50],
+            [5.55, 5.50, 5.40],
+            [5.90, 5.85, 5.70],
+            [5.45, 5.55, 5.60],
+        ];
+        let r = friedman_test(&blocks).unwrap();
+        assert_eq!(r.statistic, 0.0);
+        assert_eq!(r.p_value, 1.0);
+    }
+
+    #[test]
+    fn test_friedman_test_errors() {
+        assert!(friedman_test::<[f64; 2]>(&[]).is_err());
+        assert!(friedman_test(&[[1.0], [2.0]]).is_err());
+        assert!(friedman_test(&[vec![1.0, 2.0], vec![1.0, 2.0, 3.0]]).is_err());
+        assert!(friedman_test(&[[1.0, f64::NAN], [2.0, 3.0]]).is_err());
+        assert!(friedman_test(&[[1.0, 1.0], [2.0, 2.0]]).is_err());
+    }
+
+    #[test]
+    fn test_dunn_test() {
+        let comparisons = dunn_test(&G2, Adjustment::Holm).unwrap();
+        let expected = [
+            (0, 1, -5.683333333333334, 3.2192740626104014, -1.7654083569153814, 0.077495159894482273, 0.37090951930002962),
+            (0, 2, -10.833333333333334, 3.4317576814678692, -3.1567885436187268, 0.0015951697764805187, 0.009571018658883112),
+            (0, 3, -3.5833333333333335, 3.7593021880631611, -0.95319108549225486, 0.34049326227797764, 0.68098652455595528),
+            (1, 2, -5.15, 3.5663871981402725, -1.4440383822276835, 0.14872817899750304, 0.44618453699250912),
+            (1, 3, 2.1, 3.8825906047279268, 0.54087598044531863, 0.58859306771354505, 0.68098652455595528),
+            (2, 3, 7.25, 4.0605104479657746, 1.7854898030448582, 0.074181903860005924, 0.37090951930002962),
+        ];
+        assert_eq!(comparisons.len(), expected.len());
+        for (c, &(i, j, estimate, std_err, z, p, adjusted)) in comparisons.iter().zip(&expected) {
+            assert_eq!((c.i, c.j), (i, j));
+            assert_almost_eq!(c.estimate, estimate, 1e-13);
+            assert_almost_eq!(c.std_err, std_err, 1e-14);
+            assert_almost_eq!(c.statistic, z, 1e-14);
+            assert_almost_eq!(c.p_value, p, 1e-10);
+            assert_almost_eq!(c.adjusted_p_value, adjusted, 1e-10);
+        }
+        let bonferroni = dunn_test(&G2, Adjustment::Bonferroni).unwrap();
+        assert_almost_eq!(bonferroni[1].adjusted_p_value, 6.0 * 0.0015951697764805187, 1e-10);
+        assert!(dunn_test(&[[1.0, 1.0], [1.0, 1.0]], Adjustment::Holm).is_err());
+    }
+}
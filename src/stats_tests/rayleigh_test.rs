@@ -0,0 +1,129 @@
+use crate::statistics::CircularStatistics;
+use crate::{Result, StatsError};
+
+/// The result of a Rayleigh test of uniformity on the circle
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RayleighTestResult {
+    /// The Rayleigh statistic `Z = n R^2`
+    pub statistic: f64,
+    /// The mean resultant length `R` of the sample
+    pub mean_resultant_length: f64,
+    /// The p-value of the test
+    pub p_value: f64,
+}
+
+/// Performs the Rayleigh test of the null hypothesis that the angles `x`,
+/// in radians, are uniformly distributed on the circle, against the
+/// alternative of a unimodal distribution such as the von Mises
+/// distribution
+///
+/// # Formula
+///
+/// ```ignore
+/// Z = n R^2
+/// p = exp(sqrt(1 + 4n + 4(n^2 - (n R)^2)) - (1 + 2n))
+/// ```
+///
+/// where `R` is the mean resultant length of `x`. The p-value follows
+/// Zar's approximation of the null distribution of `Z`, which is accurate
+/// for all but the smallest samples.
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than two elements or contains `NaN`
+/// or infinite values
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::rayleigh_test;
+///
+/// // flight directions of ten birds in degrees
+/// let degrees = [66.0, 75.0, 86.0, 88.0, 88.0, 93.0, 97.0, 101.0, 118.0, 130.0];
+/// let x: Vec<f64> = degrees.iter().map(|d: &f64| d.to_radians()).collect();
+/// let result = rayleigh_test(&x).unwrap();
+/// assert!(result.p_value < 1e-4);
+/// ```
+pub fn rayleigh_test(x: &[f64]) -> Result<RayleighTestResult> {
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if x.iter().any(|v| !v.is_finite()) {
+        return Err(StatsError::BadParams);
+    }
+    let n = x.len() as f64;
+    let mean_resultant_length = x.mean_resultant_length();
+    let r = n * mean_resultant_length;
+    let p_value = ((1.0 + 4.0 * n + 4.0 * (n * n - r * r)).sqrt() - (1.0 + 2.0 * n)).exp();
+    Ok(RayleighTestResult {
+        statistic: n * mean_resultant_length * mean_resultant_length,
+        mean_resultant_length,
+        p_value: p_value.min(1.0),
+    })
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distribution::VonMises;
+    use rand::distributions::Distribution;
+    use rand::SeedableRng;
+    use std::f64;
+
+    #[test]
+    fn test_rayleigh_test() {
+        let degrees = [66.0, 75.0, 86.0, 88.0, 88.0, 93.0, 97.0, 101.0, 118.0, 130.0];
+        let x: Vec<f64> = degrees.iter().map(|d: &f64| d.to_radians()).collect();
+        let result = rayleigh_test(&x).unwrap();
+        assert_almost_eq!(result.mean_resultant_length, 0.9521367226607904, 1e-15);
+        assert_almost_eq!(result.statistic, 9.065643386392308, 1e-14);
+        assert_almost_eq!(result.p_value, 5.3038587698647495e-06, 1e-18);
+
+        let x = [0.3, 2.0, 4.1, 5.5, 1.2, 3.3, 0.9, 2.7];
+        let result = rayleigh_test(&x).unwrap();
+        assert_almost_eq!(result.mean_resultant_length, 0.2103037371170951, 1e-15);
+        assert_almost_eq!(result.statistic, 0.35382129476332996, 1e-15);
+        assert_almost_eq!(result.p_value, 0.7143834756989393, 1e-15);
+    }
+
+    #[test]
+    fn test_rayleigh_test_uniform() {
+        let x: Vec<f64> = (0..12).map(|i| i as f64 * f64::consts::PI / 6.0).collect();
+        let result = rayleigh_test(&x).unwrap();
+        assert_almost_eq!(result.statistic, 0.0, 1e-15);
+        assert_almost_eq!(result.p_value, 1.0, 1e-12);
+    }
+
+    #[test]
+    fn test_rayleigh_test_size() {
+        // the rejection rate at the 5% level under uniformity, and the power
+        // against a von Mises alternative
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        let uniform = VonMises::new(0.0, 0.0).unwrap();
+        let concentrated = VonMises::new(1.0, 2.0).unwrap();
+        let trials = 2000;
+        let mut size = 0;
+        let mut power = 0;
+        for _ in 0..trials {
+            let x: Vec<f64> = (0..20).map(|_| uniform.sample(&mut rng)).collect();
+            if rayleigh_test(&x).unwrap().p_value < 0.05 {
+                size += 1;
+            }
+            let x: Vec<f64> = (0..20).map(|_| concentrated.sample(&mut rng)).collect();
+            if rayleigh_test(&x).unwrap().p_value < 0.05 {
+                power += 1;
+            }
+        }
+        assert!((size as f64 / trials as f64 - 0.05).abs() < 0.015);
+        assert!(power as f64 / trials as f64 > 0.9);
+    }
+
+    #[test]
+    fn test_rayleigh_test_errors() {
+        assert!(rayleigh_test(&[]).is_err());
+        assert!(rayleigh_test(&[1.0]).is_err());
+        assert!(rayleigh_test(&[1.0, f64::NAN]).is_err());
+        assert!(rayleigh_test(&[1.0, f64::INFINITY]).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use crate::distribution::StudentsT;
+use crate::statistics::Statistics;
+use crate::stats_tests::{check_level, p_value, pivot_interval, Alternative, ConfidenceInterval};
+use crate::{Result, StatsError};
+
+/// The result of a Student's t-test
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TTestResult {
+    /// The t statistic `(estimate - mu) / std_err`
+    pub statistic: f64,
+    /// The degrees of freedom of the null t distribution
+    pub df: f64,
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// The estimated mean, or difference of means
+    pub estimate: f64,
+    /// The standard error of the estimate
+    pub std_err: f64,
+    /// The confidence interval for the mean, or difference of means
+    pub confidence_interval: ConfidenceInterval,
+    /// The alternative hypothesis the p-value and interval refer to
+    pub alternative: Alternative,
+}
+
+impl TTestResult {
+    fn new(
+        estimate: f64,
+        std_err: f64,
+        df: f64,
+        mu: f64,
+        alternative: Alternative,
+        level: f64,
+    ) -> Result<TTestResult> {
+        if std_err.is_nan() || std_err <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let dist = StudentsT::new(0.0, 1.0, df)?;
+        let statistic = (estimate - mu) / std_err;
+        Ok(TTestResult {
+            statistic,
+            df,
+            p_value: p_value(&dist, statistic, alternative),
+            estimate,
+            std_err,
+            confidence_interval: pivot_interval(&dist, estimate, std_err, alternative, level),
+            alternative,
+        })
+    }
+}
+
+/// Performs a one-sample t-test of the null hypothesis that the mean of
+/// the population `x` was drawn from equals `mu`, and computes a
+/// confidence interval of level `level` for that mean
+///
+/// # Formula
+///
+/// ```ignore
+/// t = (x̄ - μ) / (s / √n)
+/// ```
+///
+/// where `x̄` and `s` are the sample mean and standard deviation and `t`
+/// follows a Student's t distribution with `n - 1` degrees of freedom
+///
+/// # Errors
+///
+/// Returns an error if `x` has fewer than two elements, if `level` is
+/// not in `(0, 1)`, or if the data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{one_sample_t_test, Alternative};
+///
+/// let x = [5.1, 4.9, 5.6, 5.8, 6.0, 5.3, 5.5];
+/// let result = one_sample_t_test(&x, 5.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(result.p_value < 0.05);
+/// assert!(!result.confidence_interval.contains(5.0));
+/// ```
+pub fn one_sample_t_test(
+    x: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<TTestResult> {
+    check_level(level)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    let n = x.len() as f64;
+    let std_err = (x.variance() / n).sqrt();
+    TTestResult::new(x.mean(), std_err, n - 1.0, mu, alternative, level)
+}
+
+/// Performs a two-sample t-test with pooled variance of the null
+/// hypothesis that the difference between the means of the populations
+/// `x` and `y` were drawn from equals `mu`, assuming both populations
+/// share the same variance
+///
+/// # Formula
+///
+/// ```ignore
+/// s_p^2 = ((n_x - 1) * s_x^2 + (n_y - 1) * s_y^2) / (n_x + n_y - 2)
+/// t = (x̄ - ȳ - μ) / (s_p * √(1 / n_x + 1 / n_y))
+/// ```
+///
+/// where `t` follows a Student's t distribution with `n_x + n_y - 2`
+/// degrees of freedom
+///
+/// # Errors
+///
+/// Returns an error if either sample is empty, if the samples have fewer
+/// than three elements in total, if `level` is not in `(0, 1)`, or if the
+/// data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{two_sample_t_test, Alternative};
+///
+/// let x = [19.1, 20.3, 18.7, 21.0, 19.8];
+/// let y = [21.2, 22.0, 20.9, 22.4, 21.7];
+/// let result = two_sample_t_test(&x, &y, 0.0, Alternative::Less, 0.95).unwrap();
+/// assert_eq!(result.df, 8.0);
+/// assert!(result.p_value < 0.01);
+/// ```
+pub fn two_sample_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<TTestResult> {
+    check_level(level)?;
+    if x.is_empty() {
+        return Err(StatsError::ArgGte("x.len()", 1.0));
+    }
+    if y.is_empty() {
+        return Err(StatsError::ArgGte("y.len()", 1.0));
+    }
+    if x.len() + y.len() < 3 {
+        return Err(StatsError::ArgGte("x.len() + y.len()", 3.0));
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let ss = |data: &[f64]| {
+        let mean = data.mean();
+        data.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>()
+    };
+    let df = nx + ny - 2.0;
+    let pooled = (ss(x) + ss(y)) / df;
+    let std_err = (pooled * (1.0 / nx + 1.0 / ny)).sqrt();
+    TTestResult::new(x.mean() - y.mean(), std_err, df, mu, alternative, level)
+}
+
+/// Performs Welch's unequal variances t-test of the null hypothesis that
+/// the difference between the means of the populations `x` and `y` were
+/// drawn from equals `mu`
+///
+/// # Formula
+///
+/// ```ignore
+/// t = (x̄ - ȳ - μ) / √(s_x^2 / n_x + s_y^2 / n_y)
+/// ν = (s_x^2 / n_x + s_y^2 / n_y)^2
+///     / ((s_x^2 / n_x)^2 / (n_x - 1) + (s_y^2 / n_y)^2 / (n_y - 1))
+/// ```
+///
+/// where `t` approximately follows a Student's t distribution with the
+/// Welch–Satterthwaite degrees of freedom `ν`
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `level` is not in `(0, 1)`, or if the data are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{welch_t_test, Alternative};
+///
+/// let x = [19.1, 20.3, 18.7, 21.0, 19.8];
+/// let y = [24.2, 17.0, 20.9, 26.4, 22.7, 18.3];
+/// let result = welch_t_test(&x, &y, 0.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert!(result.df < 9.0);
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn welch_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<TTestResult> {
+    check_level(level)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if y.len() < 2 {
+        return Err(StatsError::ArgGte("y.len()", 2.0));
+    }
+    let (nx, ny) = (x.len() as f64, y.len() as f64);
+    let vx = x.variance() / nx;
+    let vy = y.variance() / ny;
+    let std_err = (vx + vy).sqrt();
+    let df = (vx + vy) * (vx + vy) / (vx * vx / (nx - 1.0) + vy * vy / (ny - 1.0));
+    TTestResult::new(x.mean() - y.mean(), std_err, df, mu, alternative, level)
+}
+
+/// Performs a paired t-test of the null hypothesis that the mean of the
+/// pairwise differences `x[i] - y[i]` equals `mu`. This is equivalent to
+/// a one-sample t-test on the differences.
+///
+/// # Errors
+///
+/// Returns an error if `x` and `y` have different lengths, if there are
+/// fewer than two pairs, if `level` is not in `(0, 1)`, or if the
+/// differences are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{paired_t_test, Alternative};
+///
+/// let before = [72.0, 80.5, 65.2, 90.1, 77.7];
+/// let after = [70.1, 78.0, 64.9, 86.3, 75.0];
+/// let result = paired_t_test(&before, &after, 0.0, Alternative::Greater, 0.95).unwrap();
+/// assert_eq!(result.df, 4.0);
+/// assert!(result.p_value < 0.05);
+/// ```
+pub fn paired_t_test(
+    x: &[f64],
+    y: &[f64],
+    mu: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<TTestResult> {
+    if x.len() != y.len() {
+        return Err(StatsError::ContainersMustBeSameLength);
+    }
+    let diff: Vec<f64> = x.iter().zip(y).map(|(a, b)| a - b).collect();
+    one_sample_t_test(&diff, mu, alternative, level)
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X: [f64; 7] = [5.1, 4.9, 5.6, 5.8, 6.0, 5.3, 5.5];
+    const A: [f64; 5] = [19.1, 20.3, 18.7, 21.0, 19.8];
+    const B: [f64; 5] = [21.2, 22.0, 20.9, 22.4, 21.7];
+    const C: [f64; 6] = [24.2, 17.0, 20.9, 26.4, 22.7, 18.3];
+
+    fn check(r: TTestResult, t: f64, df: f64, p: f64, lower: f64, upper: f64) {
+        assert_almost_eq!(r.statistic, t, 1e-12);
+        assert_almost_eq!(r.df, df, 1e-12);
+        assert_almost_eq!(r.p_value, p, 1e-12);
+        if lower.is_finite() {
+            assert_almost_eq!(r.confidence_interval.lower, lower, 1e-10);
+        } else {
+            assert_eq!(r.confidence_interval.lower, lower);
+        }
+        if upper.is_finite() {
+            assert_almost_eq!(r.confidence_interval.upper, upper, 1e-10);
+        } else {
+            assert_eq!(r.confidence_interval.upper, upper);
+        }
+    }
+
+    #[test]
+    fn test_one_sample_t_test() {
+        let r = one_sample_t_test(&X, 5.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(r.estimate, 5.4571428571428570414, 1e-14);
+        assert_almost_eq!(r.std_err, 0.14615246907831241788, 1e-14);
+        assert_eq!(r.confidence_interval.level, 0.95);
+        check(r, 3.127849019765157863, 6.0, 0.020380298573351712626, 5.0995206484810357153, 5.8147650658046783674);
+        let r = one_sample_t_test(&X, 5.0, Alternative::Less, 0.9).unwrap();
+        check(r, 3.127849019765157863, 6.0, 0.98980985071332414369, f64::NEG_INFINITY, 5.6675667144753492571);
+        let r = one_sample_t_test(&X, 5.0, Alternative::Greater, 0.99).unwrap();
+        check(r, 3.127849019765157863, 6.0, 0.010190149286675856313, 4.9978341105074822389, f64::INFINITY);
+    }
+
+    #[test]
+    fn test_one_sample_t_test_errors() {
+        assert!(one_sample_t_test(&[1.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(one_sample_t_test(&[2.0, 2.0, 2.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(one_sample_t_test(&X, 0.0, Alternative::TwoSided, 0.0).is_err());
+        assert!(one_sample_t_test(&X, 0.0, Alternative::TwoSided, 1.0).is_err());
+        assert!(one_sample_t_test(&X, 0.0, Alternative::TwoSided, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_two_sample_t_test() {
+        let r = two_sample_t_test(&A, &B, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(r.estimate, -1.859999999999998721, 1e-12);
+        assert_almost_eq!(r.std_err, 0.49193495504995377654, 1e-14);
+        check(r, -3.7809876710450960083, 8.0, 0.0053805591512601737933, -2.9944040405966677096, -0.72559595940332973248);
+        let r = two_sample_t_test(&A, &B, 0.0, Alternative::Less, 0.95).unwrap();
+        check(r, -3.7809876710450960083, 8.0, 0.0026902795756300868966, f64::NEG_INFINITY, -0.94522331974400674779);
+        let r = two_sample_t_test(&A, &C, -1.0, Alternative::Greater, 0.9).unwrap();
+        check(r, -0.48646362082185621503, 9.0, 0.6808675732901219158, -4.0872309136599445681, f64::INFINITY);
+        assert!(two_sample_t_test(&A, &[], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[], &A, 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[1.0], &[2.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(two_sample_t_test(&[1.0], &[2.0, 3.0], 0.0, Alternative::TwoSided, 0.95).is_ok());
+    }
+
+    #[test]
+    fn test_welch_t_test() {
+        let r = welch_t_test(&A, &C, 0.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(r.std_err, 1.5125071166040102544, 1e-14);
+        check(r, -1.1922808914660223194, 5.7854332812032742334, 0.27974854082192526294, -5.5378329928512631754, 1.9311663261845985456);
+        let r = welch_t_test(&A, &C, 0.0, Alternative::Less, 0.9).unwrap();
+        check(r, -1.1922808914660223194, 5.7854332812032742334, 0.13987427041096263147, f64::NEG_INFINITY, 0.38425638521252217001);
+        let r = welch_t_test(&A, &B, -1.0, Alternative::Greater, 0.95).unwrap();
+        check(r, -1.7481986005907419179, 6.8965517241379314814, 0.93772024242794544274, -2.7941170405230858985, f64::INFINITY);
+        assert!(welch_t_test(&A, &[1.0], 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(welch_t_test(&[1.0], &A, 0.0, Alternative::TwoSided, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_paired_t_test() {
+        let x = [72.0, 80.5, 65.2, 90.1, 77.7];
+        let y = [70.1, 78.0, 64.9, 86.3, 75.0];
+        let r = paired_t_test(&x, &y, 0.0, Alternative::Greater, 0.95).unwrap();
+        assert_almost_eq!(r.estimate, 2.24, 1e-12);
+        check(r, 3.9017048800330596036, 4.0, 0.0087580962748803444593, 1.0160896971451023871, f64::INFINITY);
+        let r = paired_t_test(&x, &y, 1.0, Alternative::TwoSided, 0.95).unwrap();
+        check(r, 2.1598723443040155797, 4.0, 0.096902957399728400336, 0.64602064306043555715, 3.8339793569395655797);
+        assert!(paired_t_test(&x, &y[1..], 0.0, Alternative::TwoSided, 0.95).is_err());
+    }
+}
@@ -0,0 +1,349 @@
+use crate::distribution::{ContinuousCDF, FisherSnedecor};
+use crate::statistics::{Data, OrderStatistics, Statistics};
+use crate::stats_tests::{
+    check_level, one_way_anova, Alternative, ChiSquaredTestResult, ConfidenceInterval, FTestResult,
+};
+use crate::{Result, StatsError};
+
+/// The result of an F-test for the ratio of two variances
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VarianceRatioTestResult {
+    /// The F statistic `(s_x^2 / s_y^2) / ratio`
+    pub statistic: f64,
+    /// The numerator degrees of freedom of the null F distribution
+    pub df1: f64,
+    /// The denominator degrees of freedom of the null F distribution
+    pub df2: f64,
+    /// The p-value of the test for the requested alternative
+    pub p_value: f64,
+    /// The estimated ratio `s_x^2 / s_y^2` of the variances
+    pub estimate: f64,
+    /// The confidence interval for the ratio of the variances
+    pub confidence_interval: ConfidenceInterval,
+    /// The alternative hypothesis the p-value and interval refer to
+    pub alternative: Alternative,
+}
+
+/// Performs the F-test of the null hypothesis that the ratio of the
+/// variances of the normal populations `x` and `y` were drawn from equals
+/// `ratio`, and computes a confidence interval of level `level` for that
+/// ratio
+///
+/// # Formula
+///
+/// ```ignore
+/// F = (s_x^2 / s_y^2) / ratio
+/// ```
+///
+/// where `s_x^2` and `s_y^2` are the sample variances and `F` follows an
+/// F distribution with `n_x - 1` and `n_y - 1` degrees of freedom. The
+/// test is sensitive to departures from normality; prefer
+/// `brown_forsythe_test` for non-normal data.
+///
+/// # Errors
+///
+/// Returns an error if either sample has fewer than two elements, if
+/// `ratio` is not positive, if `level` is not in `(0, 1)`, or if either
+/// sample is constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::{variance_ratio_test, Alternative};
+///
+/// let x = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6, 22.9, 17.1];
+/// let y = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1, 21.9];
+/// let result = variance_ratio_test(&x, &y, 1.0, Alternative::TwoSided, 0.95).unwrap();
+/// assert_eq!((result.df1, result.df2), (7.0, 6.0));
+/// assert!(result.p_value < 0.05);
+/// assert!(!result.confidence_interval.contains(1.0));
+/// ```
+pub fn variance_ratio_test(
+    x: &[f64],
+    y: &[f64],
+    ratio: f64,
+    alternative: Alternative,
+    level: f64,
+) -> Result<VarianceRatioTestResult> {
+    check_level(level)?;
+    if x.len() < 2 {
+        return Err(StatsError::ArgGte("x.len()", 2.0));
+    }
+    if y.len() < 2 {
+        return Err(StatsError::ArgGte("y.len()", 2.0));
+    }
+    if ratio.is_nan() || ratio <= 0.0 {
+        return Err(StatsError::ArgMustBePositive("ratio"));
+    }
+    let (var_x, var_y) = (x.variance(), y.variance());
+    if var_x.is_nan() || var_x <= 0.0 || var_y.is_nan() || var_y <= 0.0 {
+        return Err(StatsError::BadParams);
+    }
+    let (df1, df2) = ((x.len() - 1) as f64, (y.len() - 1) as f64);
+    let dist = FisherSnedecor::new(df1, df2)?;
+    let estimate = var_x / var_y;
+    let statistic = estimate / ratio;
+    let p_value = match alternative {
+        Alternative::TwoSided => (2.0 * dist.cdf(statistic).min(dist.sf(statistic))).min(1.0),
+        Alternative::Less => dist.cdf(statistic),
+        Alternative::Greater => dist.sf(statistic),
+    };
+    let alpha = 1.0 - level;
+    let (lower, upper) = match alternative {
+        Alternative::TwoSided => (
+            estimate / dist.inverse_sf(alpha / 2.0),
+            estimate / dist.inverse_cdf(alpha / 2.0),
+        ),
+        Alternative::Less => (0.0, estimate / dist.inverse_cdf(alpha)),
+        Alternative::Greater => (estimate / dist.inverse_sf(alpha), f64::INFINITY),
+    };
+    Ok(VarianceRatioTestResult {
+        statistic,
+        df1,
+        df2,
+        p_value,
+        estimate,
+        confidence_interval: ConfidenceInterval {
+            lower,
+            upper,
+            level,
+        },
+        alternative,
+    })
+}
+
+/// Performs Bartlett's test of the null hypothesis that all `groups` were
+/// drawn from normal populations with equal variances
+///
+/// # Formula
+///
+/// ```ignore
+/// K^2 = ((N - k) ln s_p^2 - Σ (n_i - 1) ln s_i^2) / (1 + (Σ 1 / (n_i - 1) - 1 / (N - k)) / (3 (k - 1)))
+/// ```
+///
+/// where `s_i^2` are the sample variances of the `k` groups, `s_p^2` is
+/// the pooled variance and `K^2` approximately follows a chi-squared
+/// distribution with `k - 1` degrees of freedom. Like the F-test, the test
+/// is sensitive to departures from normality.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group has
+/// fewer than two elements, or if the data within any group are constant
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::bartlett_test;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3, 12.4, 4.2],
+/// ];
+/// let result = bartlett_test(&groups).unwrap();
+/// assert_eq!(result.df, 2.0);
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn bartlett_test<T: AsRef<[f64]>>(groups: &[T]) -> Result<ChiSquaredTestResult> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups.len()", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().len() < 2) {
+        return Err(StatsError::ArgGte("group length", 2.0));
+    }
+    let k = groups.len() as f64;
+    let (mut df_total, mut pooled, mut ln_sum, mut reciprocal_sum) = (0.0, 0.0, 0.0, 0.0);
+    for g in groups {
+        let variance = g.as_ref().variance();
+        if variance.is_nan() || variance <= 0.0 {
+            return Err(StatsError::BadParams);
+        }
+        let df = (g.as_ref().len() - 1) as f64;
+        df_total += df;
+        pooled += df * variance;
+        ln_sum += df * variance.ln();
+        reciprocal_sum += 1.0 / df;
+    }
+    pooled /= df_total;
+    let statistic = (df_total * pooled.ln() - ln_sum)
+        / (1.0 + (reciprocal_sum - 1.0 / df_total) / (3.0 * (k - 1.0)));
+    ChiSquaredTestResult::new(statistic, k - 1.0)
+}
+
+/// Performs a one-way analysis of variance on the absolute deviations of
+/// each group from its center
+fn deviation_anova<T: AsRef<[f64]>, F: Fn(&[f64]) -> f64>(
+    groups: &[T],
+    center: F,
+) -> Result<FTestResult> {
+    if groups.len() < 2 {
+        return Err(StatsError::ArgGte("groups.len()", 2.0));
+    }
+    if groups.iter().any(|g| g.as_ref().is_empty()) {
+        return Err(StatsError::ArgMustBePositive("group length"));
+    }
+    let deviations: Vec<Vec<f64>> = groups
+        .iter()
+        .map(|g| {
+            let c = center(g.as_ref());
+            g.as_ref().iter().map(|x| (x - c).abs()).collect()
+        })
+        .collect();
+    let anova = one_way_anova(&deviations)?;
+    Ok(FTestResult {
+        statistic: anova.between.f.unwrap(),
+        df1: anova.between.df,
+        df2: anova.within.df,
+        p_value: anova.between.p_value.unwrap(),
+    })
+}
+
+/// Performs Levene's test of the null hypothesis that all `groups` were
+/// drawn from populations with equal variances
+///
+/// The statistic is the F statistic of a one-way analysis of variance of
+/// the absolute deviations `|x_ij - x̄_i|` of each observation from the
+/// mean of its group, which approximately follows an F distribution with
+/// `k - 1` and `N - k` degrees of freedom.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if there are no more observations than groups, or if all
+/// absolute deviations within each group are equal
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::levene_test;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3, 12.4, 4.2],
+/// ];
+/// let result = levene_test(&groups).unwrap();
+/// assert_eq!((result.df1, result.df2), (2.0, 14.0));
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn levene_test<T: AsRef<[f64]>>(groups: &[T]) -> Result<FTestResult> {
+    deviation_anova(groups, |g| g.mean())
+}
+
+/// Performs the Brown-Forsythe test of the null hypothesis that all
+/// `groups` were drawn from populations with equal variances
+///
+/// This is Levene's test with the absolute deviations `|x_ij - x̃_i|`
+/// taken from the median of each group rather than its mean, which makes
+/// the test robust to non-normal populations.
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two groups, if any group is
+/// empty, if there are no more observations than groups, or if all
+/// absolute deviations within each group are equal
+///
+/// # Examples
+///
+/// ```
+/// use statrs::stats_tests::brown_forsythe_test;
+///
+/// let groups = [
+///     vec![6.9, 5.4, 5.8, 4.6, 4.0],
+///     vec![8.3, 6.8, 7.8, 9.2, 6.5],
+///     vec![8.0, 10.5, 8.1, 6.9, 9.3, 12.4, 4.2],
+/// ];
+/// let result = brown_forsythe_test(&groups).unwrap();
+/// assert_eq!((result.df1, result.df2), (2.0, 14.0));
+/// assert!(result.p_value > 0.05);
+/// ```
+pub fn brown_forsythe_test<T: AsRef<[f64]>>(groups: &[T]) -> Result<FTestResult> {
+    deviation_anova(groups, |g| Data::new(g.to_vec()).median())
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const X: [f64; 8] = [19.1, 20.3, 18.7, 21.0, 19.8, 20.6, 22.9, 17.1];
+    const Y: [f64; 7] = [21.2, 22.0, 20.9, 22.4, 21.7, 21.1, 21.9];
+    const G1: [&[f64]; 3] = [&[6.9, 5.4, 5.8, 4.6, 4.0], &[8.3, 6.8, 7.8, 9.2, 6.5], &[8.0, 10.5, 8.1, 6.9, 9.3, 12.4, 4.2]];
+    const G2: [&[f64]; 4] = [
+        &[1.2, 3.4, 2.2, 2.9, 1.8, 2.5],
+        &[0.4, 5.9, 3.3, 7.7, 1.1, 6.2, 4.8],
+        &[2.1, 2.4, 2.0, 2.6],
+        &[3.5, 9.9, 0.2, 6.6, 4.1],
+    ];
+
+    #[test]
+    fn test_variance_ratio_test() {
+        let r = variance_ratio_test(&X, &Y, 1.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(r.statistic, 9.8946428571428571, 1e-12);
+        assert_almost_eq!(r.estimate, 9.8946428571428571, 1e-12);
+        assert_eq!((r.df1, r.df2), (7.0, 6.0));
+        assert_almost_eq!(r.p_value, 0.012546458078744863, 1e-13);
+        assert_almost_eq!(r.confidence_interval.lower, 1.7372827939083537, 1e-11);
+        assert_almost_eq!(r.confidence_interval.upper, 50.646685419216681, 1e-9);
+        let r = variance_ratio_test(&X, &Y, 1.0, Alternative::Greater, 0.95).unwrap();
+        assert_almost_eq!(r.p_value, 0.0062732290393724316, 1e-13);
+        assert_almost_eq!(r.confidence_interval.lower, 2.352138374359639, 1e-11);
+        assert_eq!(r.confidence_interval.upper, f64::INFINITY);
+        let r = variance_ratio_test(&X, &Y, 1.0, Alternative::Less, 0.95).unwrap();
+        assert_almost_eq!(r.p_value, 0.99372677096062757, 1e-13);
+        assert_eq!(r.confidence_interval.lower, 0.0);
+        assert_almost_eq!(r.confidence_interval.upper, 38.252381098498632, 1e-9);
+        let r = variance_ratio_test(&X, &Y, 4.0, Alternative::TwoSided, 0.95).unwrap();
+        assert_almost_eq!(r.statistic, 2.4736607142857143, 1e-12);
+        assert_almost_eq!(r.p_value, 0.28995740349638518, 1e-13);
+    }
+
+    #[test]
+    fn test_variance_ratio_test_errors() {
+        assert!(variance_ratio_test(&X[..1], &Y, 1.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(variance_ratio_test(&X, &Y[..1], 1.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(variance_ratio_test(&X, &Y, 0.0, Alternative::TwoSided, 0.95).is_err());
+        assert!(variance_ratio_test(&X, &Y, 1.0, Alternative::TwoSided, 1.0).is_err());
+        assert!(variance_ratio_test(&X, &[1.0, 1.0], 1.0, Alternative::TwoSided, 0.95).is_err());
+    }
+
+    #[test]
+    fn test_bartlett_test() {
+        let r = bartlett_test(&G1).unwrap();
+        assert_almost_eq!(r.statistic, 4.4553661957879264, 1e-13);
+        assert_eq!(r.df, 2.0);
+        assert_almost_eq!(r.p_value, 0.10777785181326496, 1e-14);
+        let r = bartlett_test(&G2).unwrap();
+        assert_almost_eq!(r.statistic, 17.075247020810756, 1e-12);
+        assert_eq!(r.df, 3.0);
+        assert_almost_eq!(r.p_value, 0.00068199932903550678, 1e-15);
+        assert!(bartlett_test(&[[1.0, 2.0]]).is_err());
+        assert!(bartlett_test(&[vec![1.0, 2.0], vec![1.0]]).is_err());
+        assert!(bartlett_test(&[[1.0, 2.0], [3.0, 3.0]]).is_err());
+    }
+
+    #[test]
+    fn test_levene_test() {
+        let r = levene_test(&G1).unwrap();
+        assert_almost_eq!(r.statistic, 1.8705275430315137, 1e-13);
+        assert_eq!((r.df1, r.df2), (2.0, 14.0));
+        assert_almost_eq!(r.p_value, 0.19056368357265689, 1e-13);
+        let r = levene_test(&G2).unwrap();
+        assert_almost_eq!(r.statistic, 5.105177405776681, 1e-13);
+        assert_eq!((r.df1, r.df2), (3.0, 18.0));
+        assert_almost_eq!(r.p_value, 0.0098984862997110252, 1e-14);
+        assert!(levene_test(&[[1.0, 2.0]]).is_err());
+        assert!(levene_test(&[vec![1.0, 2.0], vec![]]).is_err());
+    }
+
+    #[test]
+    fn test_brown_forsythe_test() {
+        let r = brown_forsythe_test(&G1).unwrap();
+        assert_almost_eq!(r.statistic, 1.4993673589452221, 1e-13);
+        assert_almost_eq!(r.p_value, 0.25702778600464808, 1e-13);
+        let r = brown_forsythe_test(&G2).unwrap();
+        assert_almost_eq!(r.statistic, 3.1040241601575027, 1e-13);
+        assert_almost_eq!(r.p_value, 0.052593932429624907, 1e-13);
+    }
+}
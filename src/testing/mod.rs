@@ -29,3 +29,37 @@ pub fn load_data(path: &str) -> Vec<f64> {
     }
     data
 }
+
+/// Loads a grouped test data file, where each line holds a group label
+/// followed by a value, into one vector of `f64`'s per group in order of
+/// first appearance. Path is relative to /data.
+///
+/// # Panics
+///
+/// Panics if the file does not exist or could not be opened, or
+/// there was an error reading the file.
+pub fn load_groups(path: &str) -> Vec<Vec<f64>> {
+    let path_prefix = "./data/".to_string();
+    let true_path = path_prefix + path.trim().trim_start_matches('/');
+
+    let f = File::open(true_path).unwrap();
+    let mut reader = BufReader::new(f);
+
+    let mut buf = String::new();
+    let mut labels: Vec<String> = vec![];
+    let mut groups: Vec<Vec<f64>> = vec![];
+    while reader.read_line(&mut buf).unwrap() > 0 {
+        let mut fields = buf.split_whitespace();
+        let label = fields.next().unwrap();
+        let value = fields.next().unwrap().parse::<f64>().unwrap();
+        match labels.iter().position(|l| l == label) {
+            Some(i) => groups[i].push(value),
+            None => {
+                labels.push(label.to_string());
+                groups.push(vec![value]);
+            }
+        }
+        buf.clear();
+    }
+    groups
+}